@@ -18,7 +18,8 @@ use crate::{
     publication::{publisher::Publisher, publisher_listener::PublisherListener},
     subscription::{subcriber_listener::SubscriberListener, subscriber::Subscriber},
     topic_definition::{
-        topic::Topic, topic_listener::TopicListener, type_support::PythonTypeRepresentation,
+        content_filtered_topic::ContentFilteredTopic, multi_topic::MultiTopic, topic::Topic,
+        topic_listener::TopicListener, type_support::PythonTypeRepresentation,
     },
 };
 
@@ -175,6 +176,74 @@ impl DomainParticipant {
         }
     }
 
+    #[pyo3(signature = (name, related_topic, filter_expression, expression_parameters = Vec::new()))]
+    pub fn create_contentfilteredtopic(
+        &self,
+        name: String,
+        related_topic: &Topic,
+        filter_expression: String,
+        expression_parameters: Vec<String>,
+    ) -> PyResult<ContentFilteredTopic> {
+        match self.0.create_contentfilteredtopic(
+            &name,
+            related_topic.as_ref(),
+            filter_expression,
+            &expression_parameters,
+        ) {
+            Ok(t) => Ok(t.into()),
+            Err(e) => Err(into_pyerr(e)),
+        }
+    }
+
+    pub fn delete_contentfilteredtopic(
+        &self,
+        a_contentfilteredtopic: &ContentFilteredTopic,
+    ) -> PyResult<()> {
+        match self
+            .0
+            .delete_contentfilteredtopic(a_contentfilteredtopic.as_ref())
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(into_pyerr(e)),
+        }
+    }
+
+    #[pyo3(signature = (name, type_, subscription_expression, expression_parameters = Vec::new()))]
+    pub fn create_multitopic(
+        &self,
+        name: String,
+        type_: Py<PyAny>,
+        subscription_expression: String,
+        expression_parameters: Vec<String>,
+    ) -> PyResult<MultiTopic> {
+        let type_name = Python::with_gil(|py| type_.getattr(py, "__name__"))?.to_string();
+
+        TYPE_REGISTRY
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap()
+            .insert(type_name.clone(), type_.clone());
+
+        let dynamic_type_representation = Arc::new(PythonTypeRepresentation::try_from(type_)?);
+        match self.0.create_dynamic_multitopic(
+            &name,
+            &type_name,
+            subscription_expression,
+            &expression_parameters,
+            dynamic_type_representation,
+        ) {
+            Ok(t) => Ok(t.into()),
+            Err(e) => Err(into_pyerr(e)),
+        }
+    }
+
+    pub fn delete_multitopic(&self, a_multitopic: &MultiTopic) -> PyResult<()> {
+        match self.0.delete_multitopic(a_multitopic.as_ref()) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(into_pyerr(e)),
+        }
+    }
+
     pub fn lookup_topicdescription(&self, topic_name: String) -> PyResult<Option<Topic>> {
         match self.0.lookup_topicdescription(&topic_name) {
             Ok(t) => Ok(t.map(Topic::from)),
@@ -1,6 +1,42 @@
 use pyo3::prelude::*;
 
-use super::{error::into_pyerr, status::StatusKind};
+use super::{error::into_pyerr, instance::InstanceHandle, status::StatusKind};
+
+#[pyclass]
+#[derive(Clone)]
+pub enum Entity {
+    DomainParticipant { handle: InstanceHandle },
+    Publisher { handle: InstanceHandle },
+    Subscriber { handle: InstanceHandle },
+    Topic { handle: InstanceHandle },
+    DataReader { handle: InstanceHandle },
+    DataWriter { handle: InstanceHandle },
+}
+
+impl From<dust_dds::infrastructure::condition::Entity> for Entity {
+    fn from(value: dust_dds::infrastructure::condition::Entity) -> Self {
+        match value {
+            dust_dds::infrastructure::condition::Entity::DomainParticipant(h) => {
+                Entity::DomainParticipant { handle: h.into() }
+            }
+            dust_dds::infrastructure::condition::Entity::Publisher(h) => {
+                Entity::Publisher { handle: h.into() }
+            }
+            dust_dds::infrastructure::condition::Entity::Subscriber(h) => {
+                Entity::Subscriber { handle: h.into() }
+            }
+            dust_dds::infrastructure::condition::Entity::Topic(h) => {
+                Entity::Topic { handle: h.into() }
+            }
+            dust_dds::infrastructure::condition::Entity::DataReader(h) => {
+                Entity::DataReader { handle: h.into() }
+            }
+            dust_dds::infrastructure::condition::Entity::DataWriter(h) => {
+                Entity::DataWriter { handle: h.into() }
+            }
+        }
+    }
+}
 
 #[pyclass]
 #[derive(Clone)]
@@ -38,6 +74,42 @@ impl StatusCondition {
         self.0.set_enabled_statuses(&mask).map_err(into_pyerr)
     }
 
+    pub fn get_entity(&self) -> PyResult<Entity> {
+        Ok(self.0.get_entity().map_err(into_pyerr)?.into())
+    }
+
+    pub fn get_trigger_value(&self) -> PyResult<bool> {
+        self.0.get_trigger_value().map_err(into_pyerr)
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Default)]
+pub struct GuardCondition(dust_dds::infrastructure::condition::GuardCondition);
+
+impl From<dust_dds::infrastructure::condition::GuardCondition> for GuardCondition {
+    fn from(value: dust_dds::infrastructure::condition::GuardCondition) -> Self {
+        Self(value)
+    }
+}
+
+impl From<GuardCondition> for dust_dds::infrastructure::condition::GuardCondition {
+    fn from(value: GuardCondition) -> Self {
+        value.0
+    }
+}
+
+#[pymethods]
+impl GuardCondition {
+    #[new]
+    pub fn new() -> Self {
+        Self(dust_dds::infrastructure::condition::GuardCondition::new())
+    }
+
+    pub fn set_trigger_value(&self, value: bool) -> PyResult<()> {
+        self.0.set_trigger_value(value).map_err(into_pyerr)
+    }
+
     pub fn get_trigger_value(&self) -> PyResult<bool> {
         self.0.get_trigger_value().map_err(into_pyerr)
     }
@@ -2,10 +2,11 @@ use pyo3::prelude::*;
 
 use super::qos_policy::{
     DataRepresentationQosPolicy, DeadlineQosPolicy, DestinationOrderQosPolicy, DurabilityQosPolicy,
-    EntityFactoryQosPolicy, GroupDataQosPolicy, HistoryQosPolicy, LatencyBudgetQosPolicy,
-    LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy, OwnershipStrengthQosPolicy,
-    PartitionQosPolicy, PresentationQosPolicy, ReaderDataLifecycleQosPolicy, ReliabilityQosPolicy,
-    ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, TopicDataQosPolicy,
+    DurabilityServiceQosPolicy, EntityFactoryQosPolicy, GroupDataQosPolicy, HistoryQosPolicy,
+    LatencyBudgetQosPolicy, LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy,
+    OwnershipStrengthQosPolicy, PartitionQosPolicy, PresentationQosPolicy,
+    ReaderDataLifecycleQosPolicy, ReliabilityQosPolicy, ResourceLimitsQosPolicy,
+    TimeBasedFilterQosPolicy, TopicDataQosPolicy, TransportMulticastQosPolicy,
     TransportPriorityQosPolicy, UserDataQosPolicy, WriterDataLifecycleQosPolicy,
     DEFAULT_RELIABILITY_QOS_POLICY_DATA_READER_AND_TOPICS,
     DEFAULT_RELIABILITY_QOS_POLICY_DATA_WRITER,
@@ -216,6 +217,7 @@ impl TopicQos {
     #[pyo3(signature = (
         topic_data = TopicDataQosPolicy::default(),
         durability = DurabilityQosPolicy::default(),
+        durability_service = DurabilityServiceQosPolicy::default(),
         deadline = DeadlineQosPolicy::default(),
         latency_budget = LatencyBudgetQosPolicy::default(),
         liveliness = LivelinessQosPolicy::default(),
@@ -232,6 +234,7 @@ impl TopicQos {
     pub fn new(
         topic_data: TopicDataQosPolicy,
         durability: DurabilityQosPolicy,
+        durability_service: DurabilityServiceQosPolicy,
         deadline: DeadlineQosPolicy,
         latency_budget: LatencyBudgetQosPolicy,
         liveliness: LivelinessQosPolicy,
@@ -247,6 +250,7 @@ impl TopicQos {
         Self(dust_dds::infrastructure::qos::TopicQos {
             topic_data: topic_data.into(),
             durability: durability.into(),
+            durability_service: durability_service.into(),
             deadline: deadline.into(),
             latency_budget: latency_budget.into(),
             liveliness: liveliness.into(),
@@ -269,6 +273,10 @@ impl TopicQos {
         self.0.durability.clone().into()
     }
 
+    fn get_durability_service(&self) -> DurabilityServiceQosPolicy {
+        self.0.durability_service.clone().into()
+    }
+
     fn get_deadline(&self) -> DeadlineQosPolicy {
         self.0.deadline.clone().into()
     }
@@ -331,6 +339,7 @@ impl DataWriterQos {
     #[new]
     #[pyo3(signature = (
         durability = DurabilityQosPolicy::default(),
+        durability_service = DurabilityServiceQosPolicy::default(),
         deadline = DeadlineQosPolicy::default(),
         latency_budget = LatencyBudgetQosPolicy::default(),
         liveliness = LivelinessQosPolicy::default(),
@@ -349,6 +358,7 @@ impl DataWriterQos {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         durability: DurabilityQosPolicy,
+        durability_service: DurabilityServiceQosPolicy,
         deadline: DeadlineQosPolicy,
         latency_budget: LatencyBudgetQosPolicy,
         liveliness: LivelinessQosPolicy,
@@ -366,6 +376,7 @@ impl DataWriterQos {
     ) -> Self {
         Self(dust_dds::infrastructure::qos::DataWriterQos {
             durability: durability.into(),
+            durability_service: durability_service.into(),
             deadline: deadline.into(),
             latency_budget: latency_budget.into(),
             liveliness: liveliness.into(),
@@ -387,6 +398,10 @@ impl DataWriterQos {
         self.0.durability.clone().into()
     }
 
+    fn get_durability_service(&self) -> DurabilityServiceQosPolicy {
+        self.0.durability_service.clone().into()
+    }
+
     fn get_deadline(&self) -> DeadlineQosPolicy {
         self.0.deadline.clone().into()
     }
@@ -469,6 +484,7 @@ impl DataReaderQos {
         time_based_filter = TimeBasedFilterQosPolicy::default(),
         reader_data_lifecycle = ReaderDataLifecycleQosPolicy::default(),
         representation = DataRepresentationQosPolicy::default(),
+        transport_multicast = TransportMulticastQosPolicy::default(),
     ))]
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -485,6 +501,7 @@ impl DataReaderQos {
         time_based_filter: TimeBasedFilterQosPolicy,
         reader_data_lifecycle: ReaderDataLifecycleQosPolicy,
         representation: DataRepresentationQosPolicy,
+        transport_multicast: TransportMulticastQosPolicy,
     ) -> Self {
         Self(dust_dds::infrastructure::qos::DataReaderQos {
             durability: durability.into(),
@@ -500,6 +517,7 @@ impl DataReaderQos {
             time_based_filter: time_based_filter.into(),
             reader_data_lifecycle: reader_data_lifecycle.into(),
             representation: representation.into(),
+            transport_multicast: transport_multicast.into(),
         })
     }
 
@@ -550,4 +568,8 @@ impl DataReaderQos {
     fn get_reader_data_lifecycle(&self) -> ReaderDataLifecycleQosPolicy {
         self.0.reader_data_lifecycle.clone().into()
     }
+
+    fn get_transport_multicast(&self) -> TransportMulticastQosPolicy {
+        self.0.transport_multicast.clone().into()
+    }
 }
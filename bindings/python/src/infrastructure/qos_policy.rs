@@ -1,3 +1,5 @@
+use std::net::IpAddr;
+
 use pyo3::prelude::*;
 
 use super::time::DurationKind;
@@ -645,6 +647,90 @@ impl ResourceLimitsQosPolicy {
     }
 }
 
+#[pyclass]
+#[derive(Clone, Default)]
+pub struct DurabilityServiceQosPolicy(
+    dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy,
+);
+
+impl From<DurabilityServiceQosPolicy>
+    for dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy
+{
+    fn from(value: DurabilityServiceQosPolicy) -> Self {
+        value.0
+    }
+}
+
+impl From<dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy>
+    for DurabilityServiceQosPolicy
+{
+    fn from(value: dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl DurabilityServiceQosPolicy {
+    #[new]
+    pub fn new(
+        service_cleanup_delay: DurationKind,
+        history_kind: HistoryQosPolicyKind,
+        max_samples: Length,
+        max_instances: Length,
+        max_samples_per_instance: Length,
+    ) -> Self {
+        Self(
+            dust_dds::infrastructure::qos_policy::DurabilityServiceQosPolicy {
+                service_cleanup_delay: service_cleanup_delay.into(),
+                history_kind: history_kind.into(),
+                max_samples: max_samples.into(),
+                max_instances: max_instances.into(),
+                max_samples_per_instance: max_samples_per_instance.into(),
+            },
+        )
+    }
+
+    pub fn get_service_cleanup_delay(&self) -> DurationKind {
+        self.0.service_cleanup_delay.into()
+    }
+
+    pub fn set_service_cleanup_delay(&mut self, value: DurationKind) {
+        self.0.service_cleanup_delay = value.into()
+    }
+
+    pub fn get_history_kind(&self) -> HistoryQosPolicyKind {
+        self.0.history_kind.into()
+    }
+
+    pub fn set_history_kind(&mut self, value: HistoryQosPolicyKind) {
+        self.0.history_kind = value.into()
+    }
+
+    pub fn get_max_samples(&self) -> Length {
+        self.0.max_samples.into()
+    }
+
+    pub fn set_max_samples(&mut self, value: Length) {
+        self.0.max_samples = value.into()
+    }
+
+    pub fn get_max_instances(&self) -> Length {
+        self.0.max_instances.into()
+    }
+
+    pub fn set_max_instances(&mut self, value: Length) {
+        self.0.max_instances = value.into()
+    }
+
+    pub fn get_max_samples_per_instance(&self) -> Length {
+        self.0.max_samples_per_instance.into()
+    }
+
+    pub fn set_max_samples_per_instance(&mut self, value: Length) {
+        self.0.max_samples_per_instance = value.into()
+    }
+}
+
 #[pyclass]
 #[derive(Clone, Default)]
 pub struct TransportPriorityQosPolicy(
@@ -683,6 +769,100 @@ impl TransportPriorityQosPolicy {
     }
 }
 
+#[pyclass]
+#[derive(Clone)]
+pub struct TransportMulticastSettings(
+    dust_dds::infrastructure::qos_policy::TransportMulticastSettings,
+);
+
+impl From<TransportMulticastSettings>
+    for dust_dds::infrastructure::qos_policy::TransportMulticastSettings
+{
+    fn from(value: TransportMulticastSettings) -> Self {
+        value.0
+    }
+}
+
+impl From<dust_dds::infrastructure::qos_policy::TransportMulticastSettings>
+    for TransportMulticastSettings
+{
+    fn from(value: dust_dds::infrastructure::qos_policy::TransportMulticastSettings) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl TransportMulticastSettings {
+    #[new]
+    #[pyo3(signature = (receive_address, receive_port = None))]
+    pub fn new(receive_address: IpAddr, receive_port: Option<u16>) -> Self {
+        Self(
+            dust_dds::infrastructure::qos_policy::TransportMulticastSettings {
+                receive_address,
+                receive_port,
+            },
+        )
+    }
+
+    pub fn get_receive_address(&self) -> IpAddr {
+        self.0.receive_address
+    }
+
+    pub fn set_receive_address(&mut self, value: IpAddr) {
+        self.0.receive_address = value
+    }
+
+    pub fn get_receive_port(&self) -> Option<u16> {
+        self.0.receive_port
+    }
+
+    pub fn set_receive_port(&mut self, value: Option<u16>) {
+        self.0.receive_port = value
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Default)]
+pub struct TransportMulticastQosPolicy(
+    dust_dds::infrastructure::qos_policy::TransportMulticastQosPolicy,
+);
+
+impl From<TransportMulticastQosPolicy>
+    for dust_dds::infrastructure::qos_policy::TransportMulticastQosPolicy
+{
+    fn from(value: TransportMulticastQosPolicy) -> Self {
+        value.0
+    }
+}
+
+impl From<dust_dds::infrastructure::qos_policy::TransportMulticastQosPolicy>
+    for TransportMulticastQosPolicy
+{
+    fn from(value: dust_dds::infrastructure::qos_policy::TransportMulticastQosPolicy) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl TransportMulticastQosPolicy {
+    #[new]
+    pub fn new(value: Vec<TransportMulticastSettings>) -> Self {
+        Self(
+            dust_dds::infrastructure::qos_policy::TransportMulticastQosPolicy {
+                value: value.into_iter().map(|s| s.into()).collect(),
+            },
+        )
+    }
+
+    pub fn get_value(&self) -> Vec<TransportMulticastSettings> {
+        self.0.value.iter().cloned().map(|s| s.into()).collect()
+    }
+
+    pub fn set_value(&mut self, value: Vec<TransportMulticastSettings>) {
+        self.0.value = value.into_iter().map(|s| s.into()).collect()
+    }
+}
+
 #[pyclass]
 #[derive(Clone, Default)]
 pub struct LifespanQosPolicy(dust_dds::infrastructure::qos_policy::LifespanQosPolicy);
@@ -873,6 +1053,7 @@ impl PartitionQosPolicy {
 pub enum PresentationQosPolicyAccessScopeKind {
     Instance,
     Topic,
+    Group,
 }
 
 impl From<PresentationQosPolicyAccessScopeKind>
@@ -886,6 +1067,9 @@ impl From<PresentationQosPolicyAccessScopeKind>
             PresentationQosPolicyAccessScopeKind::Topic => {
                 dust_dds::infrastructure::qos_policy::PresentationQosPolicyAccessScopeKind::Topic
             }
+            PresentationQosPolicyAccessScopeKind::Group => {
+                dust_dds::infrastructure::qos_policy::PresentationQosPolicyAccessScopeKind::Group
+            }
         }
     }
 }
@@ -899,6 +1083,7 @@ impl From<dust_dds::infrastructure::qos_policy::PresentationQosPolicyAccessScope
         match value {
             dust_dds::infrastructure::qos_policy::PresentationQosPolicyAccessScopeKind::Instance => PresentationQosPolicyAccessScopeKind::Instance,
             dust_dds::infrastructure::qos_policy::PresentationQosPolicyAccessScopeKind::Topic => PresentationQosPolicyAccessScopeKind::Topic,
+            dust_dds::infrastructure::qos_policy::PresentationQosPolicyAccessScopeKind::Group => PresentationQosPolicyAccessScopeKind::Group,
         }
     }
 }
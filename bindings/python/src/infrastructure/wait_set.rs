@@ -1,11 +1,20 @@
 use pyo3::prelude::*;
 
-use super::{condition::StatusCondition, error::into_pyerr, time::Duration};
+use super::{
+    condition::{GuardCondition, StatusCondition},
+    error::into_pyerr,
+    time::Duration,
+};
+use crate::subscription::{query_condition::QueryCondition, read_condition::ReadCondition};
 
 #[pyclass]
 #[derive(Clone)]
+#[allow(clippy::enum_variant_names)]
 pub enum Condition {
     StatusCondition { condition: StatusCondition },
+    ReadCondition { condition: ReadCondition },
+    QueryCondition { condition: QueryCondition },
+    GuardCondition { condition: GuardCondition },
 }
 
 impl From<dust_dds::infrastructure::wait_set::Condition> for Condition {
@@ -16,6 +25,21 @@ impl From<dust_dds::infrastructure::wait_set::Condition> for Condition {
                     condition: c.into(),
                 }
             }
+            dust_dds::infrastructure::wait_set::Condition::ReadCondition(c) => {
+                Condition::ReadCondition {
+                    condition: c.into(),
+                }
+            }
+            dust_dds::infrastructure::wait_set::Condition::QueryCondition(c) => {
+                Condition::QueryCondition {
+                    condition: c.into(),
+                }
+            }
+            dust_dds::infrastructure::wait_set::Condition::GuardCondition(c) => {
+                Condition::GuardCondition {
+                    condition: c.into(),
+                }
+            }
         }
     }
 }
@@ -26,6 +50,15 @@ impl From<Condition> for dust_dds::infrastructure::wait_set::Condition {
             Condition::StatusCondition { condition } => {
                 dust_dds::infrastructure::wait_set::Condition::StatusCondition(condition.into())
             }
+            Condition::ReadCondition { condition } => {
+                dust_dds::infrastructure::wait_set::Condition::ReadCondition(condition.into())
+            }
+            Condition::QueryCondition { condition } => {
+                dust_dds::infrastructure::wait_set::Condition::QueryCondition(condition.into())
+            }
+            Condition::GuardCondition { condition } => {
+                dust_dds::infrastructure::wait_set::Condition::GuardCondition(condition.into())
+            }
         }
     }
 }
@@ -24,12 +24,18 @@ fn dust_dds(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<publication::data_writer::DataWriter>()?;
     m.add_class::<subscription::subscriber::Subscriber>()?;
     m.add_class::<subscription::data_reader::DataReader>()?;
+    m.add_class::<subscription::read_condition::ReadCondition>()?;
+    m.add_class::<subscription::query_condition::QueryCondition>()?;
     m.add_class::<topic_definition::topic::Topic>()?;
+    m.add_class::<topic_definition::content_filtered_topic::ContentFilteredTopic>()?;
+    m.add_class::<topic_definition::multi_topic::MultiTopic>()?;
     m.add_class::<topic_definition::type_support::TypeKind>()?;
 
     m.add_class::<infrastructure::time::Duration>()?;
     m.add_class::<infrastructure::time::DurationKind>()?;
     m.add_class::<infrastructure::status::StatusKind>()?;
+    m.add_class::<infrastructure::condition::Entity>()?;
+    m.add_class::<infrastructure::condition::GuardCondition>()?;
     m.add_class::<infrastructure::wait_set::Condition>()?;
     m.add_class::<infrastructure::wait_set::WaitSet>()?;
 
@@ -55,8 +61,11 @@ fn dust_dds(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<infrastructure::qos_policy::ReliabilityQosPolicy>()?;
     m.add_class::<infrastructure::qos_policy::ReliabilityQosPolicyKind>()?;
     m.add_class::<infrastructure::qos_policy::ResourceLimitsQosPolicy>()?;
+    m.add_class::<infrastructure::qos_policy::DurabilityServiceQosPolicy>()?;
     m.add_class::<infrastructure::qos_policy::TimeBasedFilterQosPolicy>()?;
     m.add_class::<infrastructure::qos_policy::TopicDataQosPolicy>()?;
+    m.add_class::<infrastructure::qos_policy::TransportMulticastQosPolicy>()?;
+    m.add_class::<infrastructure::qos_policy::TransportMulticastSettings>()?;
     m.add_class::<infrastructure::qos_policy::TransportPriorityQosPolicy>()?;
     m.add_class::<infrastructure::qos_policy::UserDataQosPolicy>()?;
 
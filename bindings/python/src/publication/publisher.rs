@@ -108,6 +108,19 @@ impl Publisher {
             .map_err(into_pyerr)
     }
 
+    pub fn wait_for_unacknowledged_writers(
+        &self,
+        max_wait: Duration,
+    ) -> PyResult<Vec<InstanceHandle>> {
+        Ok(self
+            .0
+            .wait_for_unacknowledged_writers(max_wait.into())
+            .map_err(into_pyerr)?
+            .into_iter()
+            .map(InstanceHandle::from)
+            .collect())
+    }
+
     pub fn get_participant(&self) -> DomainParticipant {
         self.0.get_participant().into()
     }
@@ -1,5 +1,7 @@
 use super::{
     data_reader_listener::DataReaderListener,
+    query_condition::QueryCondition,
+    read_condition::ReadCondition,
     sample_info::{InstanceStateKind, SampleInfo, SampleStateKind, ViewStateKind},
     subscriber::Subscriber,
 };
@@ -22,6 +24,21 @@ use crate::{
 };
 use pyo3::{exceptions::PyTypeError, prelude::*};
 
+#[derive(FromPyObject)]
+pub enum AnyReadCondition {
+    ReadCondition(ReadCondition),
+    QueryCondition(QueryCondition),
+}
+
+impl AsRef<dust_dds::subscription::read_condition::ReadCondition> for AnyReadCondition {
+    fn as_ref(&self) -> &dust_dds::subscription::read_condition::ReadCondition {
+        match self {
+            AnyReadCondition::ReadCondition(c) => c.as_ref(),
+            AnyReadCondition::QueryCondition(c) => c.as_ref(),
+        }
+    }
+}
+
 #[pyclass]
 pub struct DataReader(dust_dds::subscription::data_reader::DataReader<PythonDdsData>);
 
@@ -341,12 +358,130 @@ impl DataReader {
         }
     }
 
+    pub fn read_w_condition(
+        &self,
+        max_samples: i32,
+        a_condition: AnyReadCondition,
+    ) -> PyResult<Vec<Sample>> {
+        let type_ = DomainParticipant::get_type(&self.0.get_topicdescription().get_type_name())
+            .ok_or(PyTypeError::new_err("Type information not found"))?;
+        match self.0.read_w_condition(max_samples, a_condition) {
+            Ok(s) => Ok(s
+                .into_iter()
+                .map(|s| Sample {
+                    sample: s,
+                    type_: type_.clone(),
+                })
+                .collect()),
+            Err(dust_dds::infrastructure::error::DdsError::NoData) => Ok(Vec::new()),
+            Err(e) => Err(PyTypeError::new_err(format!("{:?}", e))),
+        }
+    }
+
+    pub fn take_w_condition(
+        &self,
+        max_samples: i32,
+        a_condition: AnyReadCondition,
+    ) -> PyResult<Vec<Sample>> {
+        let type_ = DomainParticipant::get_type(&self.0.get_topicdescription().get_type_name())
+            .ok_or(PyTypeError::new_err("Type information not found"))?;
+        match self.0.take_w_condition(max_samples, a_condition) {
+            Ok(s) => Ok(s
+                .into_iter()
+                .map(|s| Sample {
+                    sample: s,
+                    type_: type_.clone(),
+                })
+                .collect()),
+            Err(dust_dds::infrastructure::error::DdsError::NoData) => Ok(Vec::new()),
+            Err(e) => Err(PyTypeError::new_err(format!("{:?}", e))),
+        }
+    }
+
     pub fn get_key_value(&self, _key_holder: Py<PyAny>, _handle: InstanceHandle) -> PyResult<()> {
         unimplemented!()
     }
 
-    pub fn lookup_instance(&self, _instance: Py<PyAny>) -> PyResult<Option<InstanceHandle>> {
-        unimplemented!()
+    pub fn lookup_instance(&self, instance: Py<PyAny>) -> PyResult<Option<InstanceHandle>> {
+        Ok(self
+            .0
+            .lookup_instance(&PythonDdsData::from_py_object(instance)?)
+            .map_err(into_pyerr)?
+            .map(InstanceHandle::from))
+    }
+
+    #[pyo3(signature = (
+        sample_states=ANY_SAMPLE_STATE.to_vec(),
+        view_states=ANY_VIEW_STATE.to_vec(),
+        instance_states=ANY_INSTANCE_STATE.to_vec(),
+    ))]
+    pub fn create_readcondition(
+        &self,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+    ) -> PyResult<ReadCondition> {
+        let sample_states: Vec<_> = sample_states
+            .into_iter()
+            .map(dust_dds::subscription::sample_info::SampleStateKind::from)
+            .collect();
+        let view_states: Vec<_> = view_states
+            .into_iter()
+            .map(dust_dds::subscription::sample_info::ViewStateKind::from)
+            .collect();
+        let instance_states: Vec<_> = instance_states
+            .into_iter()
+            .map(dust_dds::subscription::sample_info::InstanceStateKind::from)
+            .collect();
+        Ok(self
+            .0
+            .create_readcondition(&sample_states, &view_states, &instance_states)
+            .map_err(into_pyerr)?
+            .into())
+    }
+
+    #[pyo3(signature = (
+        query_expression,
+        query_parameters,
+        sample_states=ANY_SAMPLE_STATE.to_vec(),
+        view_states=ANY_VIEW_STATE.to_vec(),
+        instance_states=ANY_INSTANCE_STATE.to_vec(),
+    ))]
+    pub fn create_querycondition(
+        &self,
+        query_expression: String,
+        query_parameters: Vec<String>,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+    ) -> PyResult<QueryCondition> {
+        let sample_states: Vec<_> = sample_states
+            .into_iter()
+            .map(dust_dds::subscription::sample_info::SampleStateKind::from)
+            .collect();
+        let view_states: Vec<_> = view_states
+            .into_iter()
+            .map(dust_dds::subscription::sample_info::ViewStateKind::from)
+            .collect();
+        let instance_states: Vec<_> = instance_states
+            .into_iter()
+            .map(dust_dds::subscription::sample_info::InstanceStateKind::from)
+            .collect();
+        Ok(self
+            .0
+            .create_querycondition(
+                &sample_states,
+                &view_states,
+                &instance_states,
+                query_expression,
+                &query_parameters,
+            )
+            .map_err(into_pyerr)?
+            .into())
+    }
+
+    pub fn delete_readcondition(&self, a_condition: AnyReadCondition) -> PyResult<()> {
+        self.0.delete_readcondition(a_condition).map_err(into_pyerr)
     }
 
     pub fn get_liveliness_changed_status(&self) -> PyResult<LivelinessChangedStatus> {
@@ -1,5 +1,7 @@
 pub mod data_reader;
 pub mod data_reader_listener;
+pub mod query_condition;
+pub mod read_condition;
 pub mod sample_info;
 pub mod subcriber_listener;
 pub mod subscriber;
@@ -0,0 +1,72 @@
+use pyo3::prelude::*;
+
+use crate::infrastructure::error::into_pyerr;
+
+use super::sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind};
+
+#[pyclass]
+#[derive(Clone)]
+pub struct QueryCondition(dust_dds::subscription::query_condition::QueryCondition);
+
+impl From<dust_dds::subscription::query_condition::QueryCondition> for QueryCondition {
+    fn from(value: dust_dds::subscription::query_condition::QueryCondition) -> Self {
+        Self(value)
+    }
+}
+
+impl From<QueryCondition> for dust_dds::subscription::query_condition::QueryCondition {
+    fn from(value: QueryCondition) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<dust_dds::subscription::read_condition::ReadCondition> for QueryCondition {
+    fn as_ref(&self) -> &dust_dds::subscription::read_condition::ReadCondition {
+        self.0.as_ref()
+    }
+}
+
+#[pymethods]
+impl QueryCondition {
+    pub fn get_query_expression(&self) -> String {
+        self.0.get_query_expression()
+    }
+
+    pub fn get_query_parameters(&self) -> PyResult<Vec<String>> {
+        self.0.get_query_parameters().map_err(into_pyerr)
+    }
+
+    pub fn set_query_parameters(&self, query_parameters: Vec<String>) -> PyResult<()> {
+        self.0
+            .set_query_parameters(&query_parameters)
+            .map_err(into_pyerr)
+    }
+
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.0
+            .get_sample_state_mask()
+            .into_iter()
+            .map(SampleStateKind::from)
+            .collect()
+    }
+
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.0
+            .get_view_state_mask()
+            .into_iter()
+            .map(ViewStateKind::from)
+            .collect()
+    }
+
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.0
+            .get_instance_state_mask()
+            .into_iter()
+            .map(InstanceStateKind::from)
+            .collect()
+    }
+
+    pub fn get_trigger_value(&self) -> PyResult<bool> {
+        self.0.get_trigger_value().map_err(into_pyerr)
+    }
+}
@@ -0,0 +1,58 @@
+use pyo3::prelude::*;
+
+use crate::infrastructure::error::into_pyerr;
+
+use super::sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind};
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ReadCondition(dust_dds::subscription::read_condition::ReadCondition);
+
+impl From<dust_dds::subscription::read_condition::ReadCondition> for ReadCondition {
+    fn from(value: dust_dds::subscription::read_condition::ReadCondition) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ReadCondition> for dust_dds::subscription::read_condition::ReadCondition {
+    fn from(value: ReadCondition) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<dust_dds::subscription::read_condition::ReadCondition> for ReadCondition {
+    fn as_ref(&self) -> &dust_dds::subscription::read_condition::ReadCondition {
+        &self.0
+    }
+}
+
+#[pymethods]
+impl ReadCondition {
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.0
+            .get_sample_state_mask()
+            .into_iter()
+            .map(SampleStateKind::from)
+            .collect()
+    }
+
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.0
+            .get_view_state_mask()
+            .into_iter()
+            .map(ViewStateKind::from)
+            .collect()
+    }
+
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.0
+            .get_instance_state_mask()
+            .into_iter()
+            .map(InstanceStateKind::from)
+            .collect()
+    }
+
+    pub fn get_trigger_value(&self) -> PyResult<bool> {
+        self.0.get_trigger_value().map_err(into_pyerr)
+    }
+}
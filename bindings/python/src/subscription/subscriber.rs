@@ -9,11 +9,16 @@ use crate::{
         qos::{DataReaderQos, SubscriberQos},
         status::{SampleLostStatus, StatusKind},
     },
-    topic_definition::{topic::Topic, type_support::PythonDdsData},
+    topic_definition::{topic_description::TopicDescription, type_support::PythonDdsData},
 };
 
 use super::{
-    data_reader::DataReader, data_reader_listener::DataReaderListener,
+    data_reader::DataReader,
+    data_reader_listener::DataReaderListener,
+    sample_info::{
+        InstanceStateKind, SampleStateKind, ViewStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE,
+        ANY_VIEW_STATE,
+    },
     subcriber_listener::SubscriberListener,
 };
 
@@ -37,7 +42,7 @@ impl Subscriber {
     #[pyo3(signature = (a_topic, qos = None, a_listener = None, mask = Vec::new()))]
     pub fn create_datareader(
         &self,
-        a_topic: &Topic,
+        a_topic: TopicDescription,
         qos: Option<DataReaderQos>,
         a_listener: Option<Py<PyAny>>,
         mask: Vec<StatusKind>,
@@ -63,10 +68,12 @@ impl Subscriber {
             None => None,
         };
 
-        match self
-            .0
-            .create_datareader(a_topic.as_ref(), qos, listener, &mask)
-        {
+        match self.0.create_datareader(
+            dust_dds::topic_definition::topic_description::TopicDescription::from(a_topic),
+            qos,
+            listener,
+            &mask,
+        ) {
             Ok(dr) => Ok(dr.into()),
             Err(e) => Err(PyTypeError::new_err(format!("{:?}", e))),
         }
@@ -86,6 +93,46 @@ impl Subscriber {
             .map(DataReader::from))
     }
 
+    pub fn begin_access(&self) -> PyResult<()> {
+        self.0.begin_access().map_err(into_pyerr)
+    }
+
+    pub fn end_access(&self) -> PyResult<()> {
+        self.0.end_access().map_err(into_pyerr)
+    }
+
+    #[pyo3(signature = (
+        sample_states=ANY_SAMPLE_STATE.to_vec(),
+        view_states=ANY_VIEW_STATE.to_vec(),
+        instance_states=ANY_INSTANCE_STATE.to_vec(),
+    ))]
+    pub fn get_datareaders(
+        &self,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+    ) -> PyResult<Vec<DataReader>> {
+        let sample_states: Vec<_> = sample_states
+            .into_iter()
+            .map(dust_dds::subscription::sample_info::SampleStateKind::from)
+            .collect();
+        let view_states: Vec<_> = view_states
+            .into_iter()
+            .map(dust_dds::subscription::sample_info::ViewStateKind::from)
+            .collect();
+        let instance_states: Vec<_> = instance_states
+            .into_iter()
+            .map(dust_dds::subscription::sample_info::InstanceStateKind::from)
+            .collect();
+        Ok(self
+            .0
+            .get_datareaders(&sample_states, &view_states, &instance_states)
+            .map_err(into_pyerr)?
+            .into_iter()
+            .map(DataReader::from)
+            .collect())
+    }
+
     pub fn notify_datareaders(&self) -> PyResult<()> {
         self.0.notify_datareaders().map_err(into_pyerr)
     }
@@ -0,0 +1,61 @@
+use pyo3::prelude::*;
+
+use crate::{domain::domain_participant::DomainParticipant, infrastructure::error::into_pyerr};
+
+use super::topic::Topic;
+
+#[pyclass]
+pub struct ContentFilteredTopic(
+    dust_dds::topic_definition::content_filtered_topic::ContentFilteredTopic,
+);
+
+impl AsRef<dust_dds::topic_definition::content_filtered_topic::ContentFilteredTopic>
+    for ContentFilteredTopic
+{
+    fn as_ref(&self) -> &dust_dds::topic_definition::content_filtered_topic::ContentFilteredTopic {
+        &self.0
+    }
+}
+
+impl From<dust_dds::topic_definition::content_filtered_topic::ContentFilteredTopic>
+    for ContentFilteredTopic
+{
+    fn from(
+        value: dust_dds::topic_definition::content_filtered_topic::ContentFilteredTopic,
+    ) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl ContentFilteredTopic {
+    pub fn get_related_topic(&self) -> Topic {
+        self.0.get_related_topic().into()
+    }
+
+    pub fn get_filter_expression(&self) -> String {
+        self.0.get_filter_expression()
+    }
+
+    pub fn get_expression_parameters(&self) -> PyResult<Vec<String>> {
+        self.0.get_expression_parameters().map_err(into_pyerr)
+    }
+
+    pub fn set_expression_parameters(&self, expression_parameters: Vec<String>) -> PyResult<()> {
+        self.0
+            .set_expression_parameters(&expression_parameters)
+            .map_err(into_pyerr)
+    }
+
+    pub fn get_participant(&self) -> DomainParticipant {
+        self.0.get_participant().into()
+    }
+
+    pub fn get_type_name(&self) -> String {
+        self.0.get_type_name()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.0.get_name()
+    }
+}
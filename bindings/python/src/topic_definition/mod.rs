@@ -1,3 +1,6 @@
+pub mod content_filtered_topic;
+pub mod multi_topic;
 pub mod topic;
+pub mod topic_description;
 pub mod topic_listener;
 pub mod type_support;
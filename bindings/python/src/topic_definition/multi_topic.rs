@@ -0,0 +1,47 @@
+use pyo3::prelude::*;
+
+use crate::{domain::domain_participant::DomainParticipant, infrastructure::error::into_pyerr};
+
+#[pyclass]
+pub struct MultiTopic(dust_dds::topic_definition::multi_topic::MultiTopic);
+
+impl AsRef<dust_dds::topic_definition::multi_topic::MultiTopic> for MultiTopic {
+    fn as_ref(&self) -> &dust_dds::topic_definition::multi_topic::MultiTopic {
+        &self.0
+    }
+}
+
+impl From<dust_dds::topic_definition::multi_topic::MultiTopic> for MultiTopic {
+    fn from(value: dust_dds::topic_definition::multi_topic::MultiTopic) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl MultiTopic {
+    pub fn get_subscription_expression(&self) -> String {
+        self.0.get_subscription_expression()
+    }
+
+    pub fn get_expression_parameters(&self) -> PyResult<Vec<String>> {
+        self.0.get_expression_parameters().map_err(into_pyerr)
+    }
+
+    pub fn set_expression_parameters(&self, expression_parameters: Vec<String>) -> PyResult<()> {
+        self.0
+            .set_expression_parameters(&expression_parameters)
+            .map_err(into_pyerr)
+    }
+
+    pub fn get_participant(&self) -> DomainParticipant {
+        self.0.get_participant().into()
+    }
+
+    pub fn get_type_name(&self) -> String {
+        self.0.get_type_name()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.0.get_name()
+    }
+}
@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+
+use super::{content_filtered_topic::ContentFilteredTopic, multi_topic::MultiTopic, topic::Topic};
+
+#[derive(FromPyObject)]
+pub enum TopicDescription<'a> {
+    Topic(PyRef<'a, Topic>),
+    ContentFilteredTopic(PyRef<'a, ContentFilteredTopic>),
+    MultiTopic(PyRef<'a, MultiTopic>),
+}
+
+impl From<TopicDescription<'_>>
+    for dust_dds::topic_definition::topic_description::TopicDescription
+{
+    fn from(value: TopicDescription<'_>) -> Self {
+        match value {
+            TopicDescription::Topic(t) => t.as_ref().into(),
+            TopicDescription::ContentFilteredTopic(t) => t.as_ref().into(),
+            TopicDescription::MultiTopic(t) => t.as_ref().into(),
+        }
+    }
+}
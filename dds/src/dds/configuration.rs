@@ -1,7 +1,89 @@
-use std::time::Duration;
+use std::{
+    net::{IpAddr, SocketAddr},
+    ops::RangeInclusive,
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
 
 use crate::infrastructure::error::{DdsError, DdsResult};
 
+/// Largest participant id for which the well-known unicast ports of a domain don't overlap with the ports of the next domain
+pub const MAX_PARTICIPANT_ID: u32 = 119;
+
+/// IP versions used by the UDP transport of the participants.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IpVersionMode {
+    /// Only UDPv4 sockets and locators are used
+    Ipv4Only,
+    /// Only UDPv6 sockets and locators are used
+    Ipv6Only,
+    /// Both UDPv4 and UDPv6 sockets are opened and the locators of both versions are announced
+    DualStack,
+}
+
+/// Address of a remote host to which the participants are announced by unicast. When no port is given the participant is
+/// announced on the well-known metatraffic unicast ports of the configured range of participant ids on that host.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct InitialPeer {
+    address: IpAddr,
+    port: Option<u16>,
+}
+
+impl InitialPeer {
+    /// Construct a new initial peer with the given `address` and optional `port`
+    pub fn new(address: IpAddr, port: Option<u16>) -> Self {
+        Self { address, port }
+    }
+
+    /// IP address of the peer
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    /// Port of the peer. [`None`] means the well-known metatraffic unicast ports
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+}
+
+impl From<IpAddr> for InitialPeer {
+    fn from(address: IpAddr) -> Self {
+        Self::new(address, None)
+    }
+}
+
+impl From<SocketAddr> for InitialPeer {
+    fn from(socket_addr: SocketAddr) -> Self {
+        Self::new(socket_addr.ip(), Some(socket_addr.port()))
+    }
+}
+
+impl FromStr for InitialPeer {
+    type Err = DdsError;
+
+    /// Parse an initial peer in the form `ip[:port]`. IPv6 addresses with a port are written as `[ip]:port`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(socket_addr) = s.parse::<SocketAddr>() {
+            Ok(socket_addr.into())
+        } else if let Ok(address) = s.parse::<IpAddr>() {
+            Ok(address.into())
+        } else {
+            Err(DdsError::Error(format!("Invalid initial peer {}", s)))
+        }
+    }
+}
+
+impl IpVersionMode {
+    pub(crate) fn uses_ipv4(&self) -> bool {
+        matches!(self, IpVersionMode::Ipv4Only | IpVersionMode::DualStack)
+    }
+
+    pub(crate) fn uses_ipv6(&self) -> bool {
+        matches!(self, IpVersionMode::Ipv6Only | IpVersionMode::DualStack)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 /// This struct specifies the high-level configuration for the DustDDS library. The configuration can be set for use by the
 /// [`DomainParticipantFactory::set_configuration`](dust_dds::domain::domain_participant_factory::DomainParticipantFactory::set_configuration) method.
@@ -11,6 +93,13 @@ pub struct DustDdsConfiguration {
     fragment_size: usize,
     udp_receive_buffer_size: Option<usize>,
     participant_announcement_interval: Duration,
+    participant_lease_duration: Duration,
+    persistent_storage_directory: Option<PathBuf>,
+    ip_version_mode: IpVersionMode,
+    participant_id: Option<u32>,
+    initial_peers: Vec<InitialPeer>,
+    initial_peers_participant_id_range: RangeInclusive<u32>,
+    enable_multicast: bool,
 }
 
 impl DustDdsConfiguration {
@@ -25,6 +114,8 @@ impl DustDdsConfiguration {
     }
 
     /// Maximum size for the data fragments. Types with serialized data above this size will be transmitted as fragments.
+    /// The fragments are made smaller if they do not fit in the [`max_datagram_size`](crate::transport::Transport::max_datagram_size)
+    /// of a transport of the participant.
     pub fn fragment_size(&self) -> usize {
         self.fragment_size
     }
@@ -38,6 +129,43 @@ impl DustDdsConfiguration {
     pub fn participant_announcement_interval(&self) -> Duration {
         self.participant_announcement_interval
     }
+
+    /// Duration for which the remote participants consider the participant alive after each announcement.
+    pub fn participant_lease_duration(&self) -> Duration {
+        self.participant_lease_duration
+    }
+
+    /// Directory where the samples of the writers with PERSISTENT durability are stored. [`None`] means that no persistent
+    /// storage is used and these writers behave as TRANSIENT writers
+    pub fn persistent_storage_directory(&self) -> Option<&PathBuf> {
+        self.persistent_storage_directory.as_ref()
+    }
+
+    /// IP versions used for the communication with the remote participants
+    pub fn ip_version_mode(&self) -> IpVersionMode {
+        self.ip_version_mode
+    }
+
+    /// Participant id used to compute the well-known unicast ports of the participants. [`None`] means that the first
+    /// participant id whose ports are not in use on this host is selected
+    pub fn participant_id(&self) -> Option<u32> {
+        self.participant_id
+    }
+
+    /// Addresses of the remote hosts to which the participants are announced by unicast
+    pub fn initial_peers(&self) -> &[InitialPeer] {
+        &self.initial_peers
+    }
+
+    /// Range of participant ids whose well-known ports are used for the initial peers without port
+    pub fn initial_peers_participant_id_range(&self) -> &RangeInclusive<u32> {
+        &self.initial_peers_participant_id_range
+    }
+
+    /// Whether multicast is used for the discovery of the participants
+    pub fn enable_multicast(&self) -> bool {
+        self.enable_multicast
+    }
 }
 
 impl Default for DustDdsConfiguration {
@@ -48,6 +176,13 @@ impl Default for DustDdsConfiguration {
             fragment_size: 1344,
             udp_receive_buffer_size: None,
             participant_announcement_interval: Duration::from_secs(5),
+            participant_lease_duration: Duration::from_secs(100),
+            persistent_storage_directory: None,
+            ip_version_mode: IpVersionMode::Ipv4Only,
+            participant_id: None,
+            initial_peers: Vec::new(),
+            initial_peers_participant_id_range: 0..=4,
+            enable_multicast: true,
         }
     }
 }
@@ -69,11 +204,32 @@ impl DustDdsConfigurationBuilder {
     /// Build a new configuration
     pub fn build(self) -> DdsResult<DustDdsConfiguration> {
         let fragment_size_range = 8..=65000;
+        let participant_id_range = 0..=MAX_PARTICIPANT_ID;
         if !fragment_size_range.contains(&self.configuration.fragment_size) {
             Err(DdsError::Error(format!(
                 "Interface size out of range. Value must be between in {:?}",
                 fragment_size_range
             )))
+        } else if self
+            .configuration
+            .participant_id
+            .is_some_and(|id| !participant_id_range.contains(&id))
+        {
+            Err(DdsError::Error(format!(
+                "Participant id out of range. Value must be between in {:?}",
+                participant_id_range
+            )))
+        } else if self
+            .configuration
+            .initial_peers_participant_id_range
+            .is_empty()
+            || !participant_id_range
+                .contains(self.configuration.initial_peers_participant_id_range.end())
+        {
+            Err(DdsError::Error(format!(
+                "Initial peers participant id range out of range. Values must be between in {:?}",
+                participant_id_range
+            )))
         } else {
             Ok(self.configuration)
         }
@@ -112,4 +268,61 @@ impl DustDdsConfigurationBuilder {
         self.configuration.participant_announcement_interval = participant_announcement_interval;
         self
     }
+
+    /// Set the duration for which the remote participants consider the participant alive after each announcement.
+    /// A remote participant which is not announced again within its lease duration is removed together with all its
+    /// endpoints. This value should be larger than the participant announcement interval.
+    pub fn participant_lease_duration(mut self, participant_lease_duration: Duration) -> Self {
+        self.configuration.participant_lease_duration = participant_lease_duration;
+        self
+    }
+
+    /// Set the directory where the samples of the writers with PERSISTENT durability are stored. [`None`] corresponds to
+    /// not using any persistent storage
+    pub fn persistent_storage_directory(
+        mut self,
+        persistent_storage_directory: Option<PathBuf>,
+    ) -> Self {
+        self.configuration.persistent_storage_directory = persistent_storage_directory;
+        self
+    }
+
+    /// Set the IP versions used for the communication with the remote participants. With [`IpVersionMode::Ipv6Only`] and
+    /// [`IpVersionMode::DualStack`] the participants are also discovered using the IPv6 multicast group `ff02::ffff:239.255.0.1`
+    pub fn ip_version_mode(mut self, ip_version_mode: IpVersionMode) -> Self {
+        self.configuration.ip_version_mode = ip_version_mode;
+        self
+    }
+
+    /// Set the participant id used to compute the well-known unicast ports of the participants as specified in RTPS 9.6.1.1.
+    /// [`None`] corresponds to selecting the first participant id whose ports are not in use on this host. With a fixed
+    /// participant id, the creation of a participant fails if its ports are already in use.
+    pub fn participant_id(mut self, participant_id: Option<u32>) -> Self {
+        self.configuration.participant_id = participant_id;
+        self
+    }
+
+    /// Set the addresses of the remote hosts to which the participants are announced by unicast. The participants are
+    /// announced to the initial peers in addition to the multicast discovery group, unless multicast is disabled.
+    pub fn initial_peers(mut self, initial_peers: Vec<InitialPeer>) -> Self {
+        self.configuration.initial_peers = initial_peers;
+        self
+    }
+
+    /// Set the range of participant ids whose well-known metatraffic unicast ports are used for the initial peers
+    /// which are given without port
+    pub fn initial_peers_participant_id_range(
+        mut self,
+        initial_peers_participant_id_range: RangeInclusive<u32>,
+    ) -> Self {
+        self.configuration.initial_peers_participant_id_range = initial_peers_participant_id_range;
+        self
+    }
+
+    /// Set whether multicast is used for the discovery of the participants. With multicast disabled the participants
+    /// are discovered purely by unicast, starting from the initial peers.
+    pub fn enable_multicast(mut self, enable_multicast: bool) -> Self {
+        self.configuration.enable_multicast = enable_multicast;
+        self
+    }
 }
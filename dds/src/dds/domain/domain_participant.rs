@@ -17,7 +17,10 @@ use crate::{
     },
     publication::{publisher::Publisher, publisher_listener::PublisherListener},
     subscription::{subscriber::Subscriber, subscriber_listener::SubscriberListener},
-    topic_definition::{topic::Topic, topic_listener::TopicListener, type_support::TypeSupport},
+    topic_definition::{
+        content_filtered_topic::ContentFilteredTopic, multi_topic::MultiTopic, topic::Topic,
+        topic_listener::TopicListener, type_support::TypeSupport,
+    },
     xtypes::dynamic_type::DynamicType,
 };
 
@@ -195,6 +198,114 @@ impl DomainParticipant {
         block_on(self.participant_async.delete_topic(a_topic.topic_async()))
     }
 
+    /// This operation creates a [`ContentFilteredTopic`]. As stated in the [`ContentFilteredTopic`] documentation, it can be used to do
+    /// content-based subscriptions. The related [`Topic`] being subscribed to is specified by means of the `related_topic` parameter.
+    /// The [`ContentFilteredTopic`] only relates to samples published under that [`Topic`], filtered according to their content.
+    /// The filtering is done by means of evaluating a logical expression that involves the values of some of the data-fields in the sample.
+    /// The logical expression is derived from the `filter_expression` and `expression_parameters` arguments.
+    /// In case the `filter_expression` can not be parsed or refers to fields which are not part of the type of the related [`Topic`],
+    /// the operation returns [`DdsError::BadParameter`](crate::infrastructure::error::DdsError). In case the `related_topic` was
+    /// created from a different [`DomainParticipant`] the operation returns [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self, related_topic))]
+    pub fn create_contentfilteredtopic(
+        &self,
+        name: &str,
+        related_topic: &Topic,
+        filter_expression: String,
+        expression_parameters: &[String],
+    ) -> DdsResult<ContentFilteredTopic> {
+        block_on(self.participant_async.create_contentfilteredtopic(
+            name,
+            related_topic.topic_async(),
+            filter_expression,
+            expression_parameters,
+        ))
+        .map(ContentFilteredTopic::new)
+    }
+
+    /// This operation deletes a [`ContentFilteredTopic`].
+    /// The deletion of a [`ContentFilteredTopic`] is not allowed if there are any existing [`DataReader`](crate::subscription::data_reader::DataReader)
+    /// objects that are using the [`ContentFilteredTopic`]. If the [`DomainParticipant::delete_contentfilteredtopic()`] operation is called on a
+    /// [`ContentFilteredTopic`] with existing [`DataReader`](crate::subscription::data_reader::DataReader) objects attached to it, it will return
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    /// The [`DomainParticipant::delete_contentfilteredtopic()`] operation must be called on the same [`DomainParticipant`] object used to create
+    /// the [`ContentFilteredTopic`]. If it is called on a different [`DomainParticipant`], the operation will have no effect and it will return
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self, a_contentfilteredtopic))]
+    pub fn delete_contentfilteredtopic(
+        &self,
+        a_contentfilteredtopic: &ContentFilteredTopic,
+    ) -> DdsResult<()> {
+        block_on(
+            self.participant_async
+                .delete_contentfilteredtopic(a_contentfilteredtopic.content_filtered_topic_async()),
+        )
+    }
+
+    /// This operation creates a [`MultiTopic`]. As stated in the [`MultiTopic`] documentation, it can be used to subscribe to multiple
+    /// topics and combine/filter the received data into a resulting type. In particular, [`MultiTopic`] provides a content-based
+    /// subscription mechanism. The resulting type is specified by the `type_name` argument and the generic type argument `Foo`.
+    /// The list of topics and the logic used to combine filter and re-arrange the information from each [`Topic`] are specified
+    /// using the `subscription_expression` and `expression_parameters` arguments.
+    /// In case the `subscription_expression` can not be parsed, refers to topics which do not exist on this [`DomainParticipant`],
+    /// refers to fields which are not part of their types or does not provide a value for all the members of the resulting type,
+    /// the operation returns an error and no [`MultiTopic`] is created.
+    #[tracing::instrument(skip(self))]
+    pub fn create_multitopic<Foo>(
+        &self,
+        name: &str,
+        type_name: &str,
+        subscription_expression: String,
+        expression_parameters: &[String],
+    ) -> DdsResult<MultiTopic>
+    where
+        Foo: TypeSupport,
+    {
+        block_on(self.participant_async.create_multitopic::<Foo>(
+            name,
+            type_name,
+            subscription_expression,
+            expression_parameters,
+        ))
+        .map(MultiTopic::new)
+    }
+
+    #[doc(hidden)]
+    #[tracing::instrument(skip(self, dynamic_type_representation))]
+    pub fn create_dynamic_multitopic(
+        &self,
+        name: &str,
+        type_name: &str,
+        subscription_expression: String,
+        expression_parameters: &[String],
+        dynamic_type_representation: std::sync::Arc<dyn DynamicType + Send + Sync>,
+    ) -> DdsResult<MultiTopic> {
+        block_on(self.participant_async.create_dynamic_multitopic(
+            name,
+            type_name,
+            subscription_expression,
+            expression_parameters,
+            dynamic_type_representation,
+        ))
+        .map(MultiTopic::new)
+    }
+
+    /// This operation deletes a [`MultiTopic`].
+    /// The deletion of a [`MultiTopic`] is not allowed if there are any existing [`DataReader`](crate::subscription::data_reader::DataReader)
+    /// objects that are using the [`MultiTopic`]. If the [`DomainParticipant::delete_multitopic()`] operation is called on a [`MultiTopic`]
+    /// with existing [`DataReader`](crate::subscription::data_reader::DataReader) objects attached to it, it will return
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    /// The [`DomainParticipant::delete_multitopic()`] operation must be called on the same [`DomainParticipant`] object used to create
+    /// the [`MultiTopic`]. If it is called on a different [`DomainParticipant`], the operation will have no effect and it will return
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self, a_multitopic))]
+    pub fn delete_multitopic(&self, a_multitopic: &MultiTopic) -> DdsResult<()> {
+        block_on(
+            self.participant_async
+                .delete_multitopic(a_multitopic.multi_topic_async()),
+        )
+    }
+
     /// This operation gives access to an existing (or ready to exist) enabled [`Topic`], based on its name. The operation takes
     /// as arguments the name of the [`Topic`], a timeout and the type as a generic type argument `Foo`.
     /// If a [`Topic`] of the same name and type already exists, it gives access to it, otherwise it waits (blocks the caller) until another mechanism
@@ -264,6 +375,7 @@ impl DomainParticipant {
     /// under certain topics.
     /// The Topic to ignore is identified by the handle argument. This handle is the one that appears in the [`SampleInfo`](crate::subscription::sample_info::SampleInfo) retrieved when
     /// reading the data-samples from the built-in [`DataReader`](crate::subscription::data_reader::DataReader) to the *DCPSTopic* topic.
+    /// The publications and subscriptions to the Topic which are already matched are unmatched.
     /// The [`DomainParticipant::ignore_topic()`] operation is not reversible.
     #[tracing::instrument(skip(self))]
     pub fn ignore_topic(&self, handle: InstanceHandle) -> DdsResult<()> {
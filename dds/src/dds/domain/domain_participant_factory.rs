@@ -12,9 +12,11 @@ use crate::{
         qos::{DomainParticipantFactoryQos, DomainParticipantQos, QosKind},
         status::StatusKind,
     },
+    persistent_storage::PersistentStorage,
+    transport::TransportFactory,
 };
 
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use tracing::warn;
 
 /// DomainId type alias
@@ -52,6 +54,34 @@ impl DomainParticipantFactory {
         .map(DomainParticipant::new)
     }
 
+    /// This operation creates a new [`DomainParticipant`] in the same way as [`DomainParticipantFactory::create_participant`]. The messages
+    /// sent by this participant to the locators of each kind in `transport_factory_list` use the transport created by the corresponding
+    /// [`TransportFactory`], which takes precedence over the factory registered with [`DomainParticipantFactory::register_transport`] and over
+    /// the built-in UDP transport. If the list contains the invalid or the reserved locator kind the operation fails with
+    /// [`DdsError::BadParameter`](crate::infrastructure::error::DdsError::BadParameter) and no [`DomainParticipant`] is created.
+    #[tracing::instrument(skip(self, a_listener, transport_factory_list))]
+    pub fn create_participant_with_transports(
+        &self,
+        domain_id: DomainId,
+        qos: QosKind<DomainParticipantQos>,
+        a_listener: Option<Box<dyn DomainParticipantListener + Send>>,
+        mask: &[StatusKind],
+        transport_factory_list: Vec<(i32, Arc<dyn TransportFactory>)>,
+    ) -> DdsResult<DomainParticipant> {
+        block_on(
+            self.participant_factory_async
+                .create_participant_with_transports(
+                    domain_id,
+                    qos,
+                    a_listener
+                        .map::<Box<dyn DomainParticipantListenerAsync + Send>, _>(|b| Box::new(b)),
+                    mask,
+                    transport_factory_list,
+                ),
+        )
+        .map(DomainParticipant::new)
+    }
+
     /// This operation deletes an existing [`DomainParticipant`]. This operation can only be invoked if all domain entities belonging to
     /// the participant have already been deleted otherwise the error [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError::PreconditionNotMet) is returned. If the
     /// participant has been previously deleted this operation returns the error [`DdsError::AlreadyDeleted`](crate::infrastructure::error::DdsError::AlreadyDeleted).
@@ -137,4 +167,34 @@ impl DomainParticipantFactory {
     pub fn get_configuration(&self) -> DdsResult<DustDdsConfiguration> {
         block_on(self.participant_factory_async.get_configuration())
     }
+
+    /// Set the [`PersistentStorage`] used by the participants created afterwards to keep the samples of the writers with
+    /// PERSISTENT durability. [`None`] corresponds to using an
+    /// [`AppendOnlyLogStorage`](crate::persistent_storage::AppendOnlyLogStorage) in the
+    /// [`persistent_storage_directory`](crate::configuration::DustDdsConfiguration::persistent_storage_directory) of the configuration.
+    pub fn set_persistent_storage(
+        &self,
+        persistent_storage: Option<Arc<dyn PersistentStorage>>,
+    ) -> DdsResult<()> {
+        block_on(
+            self.participant_factory_async
+                .set_persistent_storage(persistent_storage),
+        )
+    }
+
+    /// Register the [`TransportFactory`] which creates the transport of the locators of kind `locator_kind` for the participants
+    /// created afterwards. The locators on which these transports receive are announced together with the locators of the other
+    /// transports of the participants. Registering a factory for a kind which already has one replaces it, and registering a factory
+    /// for the UDPv4 or UDPv6 kind replaces the built-in UDP transport of that kind.
+    /// This operation fails with [`DdsError::BadParameter`](crate::infrastructure::error::DdsError::BadParameter) for the invalid and reserved kinds.
+    pub fn register_transport(
+        &self,
+        locator_kind: i32,
+        transport_factory: Arc<dyn TransportFactory>,
+    ) -> DdsResult<()> {
+        block_on(
+            self.participant_factory_async
+                .register_transport(locator_kind, transport_factory),
+        )
+    }
 }
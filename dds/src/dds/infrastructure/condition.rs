@@ -1,9 +1,28 @@
 use crate::{
-    dds_async::condition::StatusConditionAsync, implementation::runtime::executor::block_on,
+    dds_async::condition::{GuardConditionAsync, StatusConditionAsync},
+    implementation::runtime::executor::block_on,
     infrastructure::error::DdsResult,
 };
 
-use super::status::StatusKind;
+use super::{instance::InstanceHandle, status::StatusKind};
+
+/// Entity associated with a [`StatusCondition`]. Each variant contains the [`InstanceHandle`] of the Entity as returned by its
+/// `get_instance_handle` operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entity {
+    /// Domain participant variant
+    DomainParticipant(InstanceHandle),
+    /// Publisher variant
+    Publisher(InstanceHandle),
+    /// Subscriber variant
+    Subscriber(InstanceHandle),
+    /// Topic variant
+    Topic(InstanceHandle),
+    /// Data reader variant
+    DataReader(InstanceHandle),
+    /// Data writer variant
+    DataWriter(InstanceHandle),
+}
 
 /// A [`StatusCondition`] object is a specific Condition that is associated with each Entity.
 /// The *trigger_value* of the [`StatusCondition`] depends on the communication status of that entity (e.g., arrival of data, loss of
@@ -42,7 +61,7 @@ impl StatusCondition {
 
     /// This operation returns the Entity associated with the [`StatusCondition`]. Note that there is exactly one Entity associated with
     /// each [`StatusCondition`].
-    pub fn get_entity(&self) {
+    pub fn get_entity(&self) -> DdsResult<Entity> {
         block_on(self.condition_async.get_entity())
     }
 }
@@ -54,3 +73,50 @@ impl StatusCondition {
         block_on(self.condition_async.get_trigger_value())
     }
 }
+
+/// A [`GuardCondition`] object is a specific Condition whose *trigger_value* is completely under the control of the application.
+/// When a [`GuardCondition`] is initially created, the *trigger_value* is [`false`].
+/// The purpose of the [`GuardCondition`] is to provide the means for the application to manually wake up a
+/// [`WaitSet`](crate::infrastructure::wait_set::WaitSet). This is accomplished by attaching the [`GuardCondition`] to the
+/// [`WaitSet`](crate::infrastructure::wait_set::WaitSet) and then setting the *trigger_value* by means of the
+/// [`GuardCondition::set_trigger_value`] operation.
+#[derive(Clone, Default)]
+pub struct GuardCondition {
+    condition_async: GuardConditionAsync,
+}
+
+impl GuardCondition {
+    /// Create a new [`GuardCondition`] with a *trigger_value* of [`false`].
+    #[tracing::instrument]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn from_async(condition_async: GuardConditionAsync) -> Self {
+        Self { condition_async }
+    }
+
+    pub(crate) fn condition_async(&self) -> &GuardConditionAsync {
+        &self.condition_async
+    }
+}
+
+impl GuardCondition {
+    /// This operation sets the *trigger_value* of the [`GuardCondition`].
+    /// [`WaitSet`](crate::infrastructure::wait_set::WaitSet) objects behavior depend on the changes of the *trigger_value* of their
+    /// attached conditions. Therefore, any [`WaitSet`](crate::infrastructure::wait_set::WaitSet) to which the [`GuardCondition`] is
+    /// attached is potentially affected by this operation.
+    #[tracing::instrument(skip(self))]
+    pub fn set_trigger_value(&self, value: bool) -> DdsResult<()> {
+        block_on(self.condition_async.set_trigger_value(value))
+    }
+}
+
+/// This implementation block contains the Condition operations for the [`GuardCondition`].
+impl GuardCondition {
+    /// This operation retrieves the *trigger_value* of the [`GuardCondition`].
+    #[tracing::instrument(skip(self))]
+    pub fn get_trigger_value(&self) -> DdsResult<bool> {
+        block_on(self.condition_async.get_trigger_value())
+    }
+}
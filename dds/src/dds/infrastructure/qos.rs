@@ -6,11 +6,12 @@ use crate::infrastructure::{
 use super::{
     qos_policy::{
         DataRepresentationQosPolicy, DeadlineQosPolicy, DestinationOrderQosPolicy,
-        DurabilityQosPolicy, EntityFactoryQosPolicy, GroupDataQosPolicy, HistoryQosPolicy,
-        HistoryQosPolicyKind, LatencyBudgetQosPolicy, LifespanQosPolicy, LivelinessQosPolicy,
-        OwnershipQosPolicy, OwnershipStrengthQosPolicy, PartitionQosPolicy, PresentationQosPolicy,
-        ReaderDataLifecycleQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
-        ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, TopicDataQosPolicy,
+        DurabilityQosPolicy, DurabilityServiceQosPolicy, EntityFactoryQosPolicy,
+        GroupDataQosPolicy, HistoryQosPolicy, HistoryQosPolicyKind, LatencyBudgetQosPolicy,
+        LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy, OwnershipStrengthQosPolicy,
+        PartitionQosPolicy, PresentationQosPolicy, ReaderDataLifecycleQosPolicy,
+        ReliabilityQosPolicy, ReliabilityQosPolicyKind, ResourceLimitsQosPolicy,
+        TimeBasedFilterQosPolicy, TopicDataQosPolicy, TransportMulticastQosPolicy,
         TransportPriorityQosPolicy, UserDataQosPolicy, WriterDataLifecycleQosPolicy,
     },
     time::DurationKind,
@@ -59,6 +60,8 @@ pub struct PublisherQos {
 pub struct DataWriterQos {
     /// Value of the durability QoS policy.
     pub durability: DurabilityQosPolicy,
+    /// Value of the durability service QoS policy.
+    pub durability_service: DurabilityServiceQosPolicy,
     /// Value of the deadline QoS policy.
     pub deadline: DeadlineQosPolicy,
     /// Value of the latency budget QoS policy.
@@ -100,6 +103,7 @@ impl Default for DataWriterQos {
                 )),
             },
             durability: DurabilityQosPolicy::default(),
+            durability_service: DurabilityServiceQosPolicy::default(),
             deadline: DeadlineQosPolicy::default(),
             latency_budget: LatencyBudgetQosPolicy::default(),
             liveliness: LivelinessQosPolicy::default(),
@@ -146,6 +150,7 @@ impl DataWriterQos {
 
     pub(crate) fn check_immutability(&self, other: &Self) -> DdsResult<()> {
         if self.durability != other.durability
+            || self.durability_service != other.durability_service
             || self.liveliness != other.liveliness
             || self.reliability != other.reliability
             || self.destination_order != other.destination_order
@@ -212,6 +217,8 @@ pub struct DataReaderQos {
     pub reader_data_lifecycle: ReaderDataLifecycleQosPolicy,
     /// Value of the data representation QoS policy.
     pub representation: DataRepresentationQosPolicy,
+    /// Value of the transport multicast QoS policy.
+    pub transport_multicast: TransportMulticastQosPolicy,
 }
 
 impl Default for DataReaderQos {
@@ -236,6 +243,7 @@ impl Default for DataReaderQos {
             time_based_filter: TimeBasedFilterQosPolicy::default(),
             reader_data_lifecycle: ReaderDataLifecycleQosPolicy::default(),
             representation: DataRepresentationQosPolicy::default(),
+            transport_multicast: TransportMulticastQosPolicy::default(),
         }
     }
 }
@@ -265,6 +273,16 @@ impl DataReaderQos {
             return Err(DdsError::InconsistentPolicy);
         }
 
+        // The TRANSPORT_MULTICAST groups must be multicast addresses
+        if self
+            .transport_multicast
+            .value
+            .iter()
+            .any(|s| !s.receive_address.is_multicast())
+        {
+            return Err(DdsError::BadParameter);
+        }
+
         Ok(())
     }
 
@@ -276,6 +294,7 @@ impl DataReaderQos {
             || self.history != other.history
             || self.resource_limits != other.resource_limits
             || self.ownership != other.ownership
+            || self.transport_multicast != other.transport_multicast
         {
             Err(DdsError::ImmutablePolicy)
         } else {
@@ -291,6 +310,8 @@ pub struct TopicQos {
     pub topic_data: TopicDataQosPolicy,
     /// Value of the durability QoS policy.
     pub durability: DurabilityQosPolicy,
+    /// Value of the durability service QoS policy.
+    pub durability_service: DurabilityServiceQosPolicy,
     /// Value of the deadline QoS policy.
     pub deadline: DeadlineQosPolicy,
     /// Value of the latency budget QoS policy.
@@ -327,6 +348,7 @@ impl Default for TopicQos {
             },
             topic_data: TopicDataQosPolicy::default(),
             durability: DurabilityQosPolicy::default(),
+            durability_service: DurabilityServiceQosPolicy::default(),
             deadline: DeadlineQosPolicy::default(),
             latency_budget: LatencyBudgetQosPolicy::default(),
             liveliness: LivelinessQosPolicy::default(),
@@ -365,6 +387,7 @@ impl TopicQos {
 
     pub(crate) fn check_immutability(&self, other: &Self) -> DdsResult<()> {
         if self.durability != other.durability
+            || self.durability_service != other.durability_service
             || self.liveliness != other.liveliness
             || self.reliability != other.reliability
             || self.destination_order != other.destination_order
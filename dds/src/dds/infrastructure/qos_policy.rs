@@ -11,6 +11,7 @@ use crate::{
     },
 };
 use core::cmp::Ordering;
+use std::net::IpAddr;
 
 /// QosPolicyId type alias
 pub type QosPolicyId = i32;
@@ -138,7 +139,9 @@ const TOPICDATA_QOS_POLICY_NAME: &str = "TopicData";
 const TRANSPORTPRIORITY_QOS_POLICY_NAME: &str = "TransportPriority";
 const GROUPDATA_QOS_POLICY_NAME: &str = "GroupData";
 const LIFESPAN_QOS_POLICY_NAME: &str = "Lifespan";
+const DURABILITYSERVICE_QOS_POLICY_NAME: &str = "DurabilityService";
 const DATA_REPRESENTATION_QOS_POLICY_NAME: &str = "DataRepresentation";
+const TRANSPORTMULTICAST_QOS_POLICY_NAME: &str = "TransportMulticast";
 
 /// QosPolicy Id representing an invalid QoS policy
 pub const INVALID_QOS_POLICY_ID: QosPolicyId = 0;
@@ -412,6 +415,8 @@ pub enum PresentationQosPolicyAccessScopeKind {
     Instance,
     /// Access scope per topic
     Topic,
+    /// Access scope per group of entities (Publisher or Subscriber)
+    Group,
 }
 
 impl PartialOrd for PresentationQosPolicyAccessScopeKind {
@@ -420,10 +425,17 @@ impl PartialOrd for PresentationQosPolicyAccessScopeKind {
             PresentationQosPolicyAccessScopeKind::Instance => match other {
                 PresentationQosPolicyAccessScopeKind::Instance => Some(Ordering::Equal),
                 PresentationQosPolicyAccessScopeKind::Topic => Some(Ordering::Less),
+                PresentationQosPolicyAccessScopeKind::Group => Some(Ordering::Less),
             },
             PresentationQosPolicyAccessScopeKind::Topic => match other {
                 PresentationQosPolicyAccessScopeKind::Instance => Some(Ordering::Greater),
                 PresentationQosPolicyAccessScopeKind::Topic => Some(Ordering::Equal),
+                PresentationQosPolicyAccessScopeKind::Group => Some(Ordering::Less),
+            },
+            PresentationQosPolicyAccessScopeKind::Group => match other {
+                PresentationQosPolicyAccessScopeKind::Instance => Some(Ordering::Greater),
+                PresentationQosPolicyAccessScopeKind::Topic => Some(Ordering::Greater),
+                PresentationQosPolicyAccessScopeKind::Group => Some(Ordering::Equal),
             },
         }
     }
@@ -446,6 +458,8 @@ impl PartialOrd for PresentationQosPolicyAccessScopeKind {
 ///   independently. That is, changes made to instances within each individual DataWriter will be available as coherent with
 ///   respect to other changes to instances in that same DataWriter, but will not be grouped with changes made to instances
 ///   belonging to a different DataWriter.
+/// - If access_scope is set to GROUP, then coherent changes made to instances through DataWriter entities attached to a
+///   common Publisher are made available as a unit to remote subscribers.
 ///   If ordered_access is set, then the access_scope controls the maximum extent for which order will be preserved by the Service.
 /// - If access_scope is set to INSTANCE (the lowest level), then changes to each instance are considered unordered relative
 ///   to changes to any other instance. That means that changes (creations, deletions, modifications) made to two instances
@@ -455,6 +469,8 @@ impl PartialOrd for PresentationQosPolicyAccessScopeKind {
 ///   available to subscribers in the same order they occur. Changes made to instances through different DataWriter entities
 ///   are not necessarily seen in the order they occur. This is the case, even if the changes are made by a single application
 ///   thread using DataWriter objects attached to the same Publisher.
+/// - If access_scope is set to GROUP, changes made to instances via DataWriter entities attached to the same Publisher
+///   are made available to subscribers in the same order they occur.
 ///
 /// Note that this QoS policy controls the scope at which related changes are made available to the subscriber. This means the
 /// subscriber can access the changes in a coherent manner and in the proper order; however, it does not necessarily imply that the
@@ -1070,6 +1086,55 @@ impl Default for ResourceLimitsQosPolicy {
     }
 }
 
+/// This policy is used to configure the [`HistoryQosPolicy`] and [`ResourceLimitsQosPolicy`] used by the fictitious
+/// [`DataReader`](crate::subscription::data_reader::DataReader) and [`DataWriter`](crate::publication::data_writer::DataWriter)
+/// used by the durability service.
+///
+/// The durability service is the part of the Service that implements the [`DurabilityQosPolicyKind::Transient`] and
+/// [`DurabilityQosPolicyKind::Persistent`] kinds of durability. It keeps the samples of the
+/// [`DataWriter`](crate::publication::data_writer::DataWriter) available to late-joining readers after the
+/// [`DataWriter`](crate::publication::data_writer::DataWriter) is deleted.
+/// The `service_cleanup_delay` controls when the service is able to remove all information regarding a data instance. That
+/// happens when the instance is disposed and the `service_cleanup_delay` has elapsed since the
+/// [`DataWriter`](crate::publication::data_writer::DataWriter) was handed over to the service.
+/// The `history_kind`, `max_samples`, `max_instances` and `max_samples_per_instance` limit the samples kept by
+/// the service in the same way as the [`HistoryQosPolicy`] and [`ResourceLimitsQosPolicy`] do for a regular
+/// [`DataWriter`](crate::publication::data_writer::DataWriter).
+#[derive(Debug, PartialEq, Eq, Clone, XTypesSerialize, XTypesDeserialize)]
+pub struct DurabilityServiceQosPolicy {
+    /// Delay before the service removes the information of a disposed instance.
+    pub service_cleanup_delay: DurationKind,
+    /// Kind of history kept by the service.
+    pub history_kind: HistoryQosPolicyKind,
+    /// Maximum number of samples limit.
+    pub max_samples: Length,
+    /// Maximum number of instances limit.
+    pub max_instances: Length,
+    /// Maximum number of samples per instance limit.
+    pub max_samples_per_instance: Length,
+}
+
+impl QosPolicy for DurabilityServiceQosPolicy {
+    fn name(&self) -> &str {
+        DURABILITYSERVICE_QOS_POLICY_NAME
+    }
+}
+
+impl Default for DurabilityServiceQosPolicy {
+    fn default() -> Self {
+        Self {
+            service_cleanup_delay: DurationKind::Finite(Duration::new(
+                DURATION_ZERO_SEC,
+                DURATION_ZERO_NSEC,
+            )),
+            history_kind: HistoryQosPolicyKind::KeepLast(1),
+            max_samples: Length::Unlimited,
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Unlimited,
+        }
+    }
+}
+
 /// This policy controls the behavior of the Entity as a factory for other entities.
 ///
 /// This policy concerns only DomainParticipant (as factory for Publisher, Subscriber, and Topic), Publisher (as factory for
@@ -1206,6 +1271,34 @@ impl QosPolicy for DataRepresentationQosPolicy {
     }
 }
 
+/// Multicast group on which a [`DataReader`](crate::subscription::data_reader::DataReader) receives the data.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TransportMulticastSettings {
+    /// Multicast address of the group.
+    pub receive_address: IpAddr,
+    /// Port on which the data is received. [`None`] corresponds to the well-known user-defined multicast port of the domain.
+    pub receive_port: Option<u16>,
+}
+
+/// This policy is a Dust DDS extension which allows a [`DataReader`](crate::subscription::data_reader::DataReader) to
+/// request that the data is sent to it on multicast groups instead of on its unicast locators.
+/// The participant of the [`DataReader`](crate::subscription::data_reader::DataReader) joins the groups when the
+/// [`DataReader`](crate::subscription::data_reader::DataReader) is created and announces them to the remote participants.
+/// A [`DataWriter`](crate::publication::data_writer::DataWriter) sends a single copy of each sample to all the matched
+/// readers sharing the same groups.
+/// An empty value corresponds to receiving the data on unicast only.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct TransportMulticastQosPolicy {
+    /// Multicast groups on which the data is received.
+    pub value: Vec<TransportMulticastSettings>,
+}
+
+impl QosPolicy for TransportMulticastQosPolicy {
+    fn name(&self) -> &str {
+        TRANSPORTMULTICAST_QOS_POLICY_NAME
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
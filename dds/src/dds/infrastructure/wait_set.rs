@@ -4,20 +4,57 @@ use crate::{
     infrastructure::{error::DdsResult, time::Duration},
 };
 
-use super::condition::StatusCondition;
+use super::condition::{GuardCondition, StatusCondition};
+use crate::subscription::{query_condition::QueryCondition, read_condition::ReadCondition};
 
 /// Enumeration of the different Condition objects that can be associated with a [`WaitSet`].
 #[derive(Clone)]
 pub enum Condition {
     /// Status condition variant
     StatusCondition(StatusCondition),
+    /// Read condition variant
+    ReadCondition(ReadCondition),
+    /// Query condition variant
+    QueryCondition(QueryCondition),
+    /// Guard condition variant
+    GuardCondition(GuardCondition),
 }
+
 impl Condition {
+    pub(crate) fn new(condition_async: ConditionAsync) -> Self {
+        match condition_async {
+            ConditionAsync::StatusCondition(c) => Condition::StatusCondition(StatusCondition::new(c)),
+            ConditionAsync::ReadCondition(c) => Condition::ReadCondition(ReadCondition::new(c)),
+            ConditionAsync::QueryCondition(c) => Condition::QueryCondition(QueryCondition::new(c)),
+            ConditionAsync::GuardCondition(c) => {
+                Condition::GuardCondition(GuardCondition::from_async(c))
+            }
+        }
+    }
+
+    pub(crate) fn condition_async(&self) -> ConditionAsync {
+        match self {
+            Condition::StatusCondition(c) => {
+                ConditionAsync::StatusCondition(c.condition_async().clone())
+            }
+            Condition::ReadCondition(c) => ConditionAsync::ReadCondition(c.condition_async().clone()),
+            Condition::QueryCondition(c) => {
+                ConditionAsync::QueryCondition(c.condition_async().clone())
+            }
+            Condition::GuardCondition(c) => {
+                ConditionAsync::GuardCondition(c.condition_async().clone())
+            }
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     /// This operation retrieves the trigger_value of the Condition.
     pub fn get_trigger_value(&self) -> DdsResult<bool> {
         match self {
             Condition::StatusCondition(c) => c.get_trigger_value(),
+            Condition::ReadCondition(c) => c.get_trigger_value(),
+            Condition::QueryCondition(c) => c.get_trigger_value(),
+            Condition::GuardCondition(c) => c.get_trigger_value(),
         }
     }
 }
@@ -50,11 +87,7 @@ impl WaitSet {
     pub fn wait(&self, timeout: Duration) -> DdsResult<Vec<Condition>> {
         Ok(block_on(self.waitset_async.wait(timeout))?
             .into_iter()
-            .map(|c| match c {
-                ConditionAsync::StatusCondition(sc) => {
-                    Condition::StatusCondition(StatusCondition::new(sc))
-                }
-            })
+            .map(Condition::new)
             .collect())
     }
 
@@ -64,18 +97,14 @@ impl WaitSet {
     /// Adding a [`Condition`] that is already attached to the [`WaitSet`] has no effect.
     #[tracing::instrument(skip(self, cond))]
     pub fn attach_condition(&mut self, cond: Condition) -> DdsResult<()> {
-        match cond {
-            Condition::StatusCondition(sc) => block_on(self.waitset_async.attach_condition(
-                ConditionAsync::StatusCondition(sc.condition_async().clone()),
-            )),
-        }
+        block_on(self.waitset_async.attach_condition(cond.condition_async()))
     }
 
     /// Detaches a [`Condition`] from the [`WaitSet`].
     /// If the [`Condition`] was not attached to the [`WaitSet`], the operation will return [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError::PreconditionNotMet).
-    #[tracing::instrument(skip(self, _cond))]
-    pub fn detach_condition(&self, _cond: Condition) -> DdsResult<()> {
-        todo!()
+    #[tracing::instrument(skip(self, cond))]
+    pub fn detach_condition(&self, cond: Condition) -> DdsResult<()> {
+        block_on(self.waitset_async.detach_condition(cond.condition_async()))
     }
 
     /// This operation retrieves the list of attached conditions.
@@ -83,11 +112,7 @@ impl WaitSet {
     pub fn get_conditions(&self) -> DdsResult<Vec<Condition>> {
         Ok(block_on(self.waitset_async.get_conditions())?
             .into_iter()
-            .map(|c| match c {
-                ConditionAsync::StatusCondition(sc) => {
-                    Condition::StatusCondition(StatusCondition::new(sc))
-                }
-            })
+            .map(Condition::new)
             .collect())
     }
 }
@@ -24,3 +24,11 @@ pub mod topic_definition;
 /// Contains the [`DustDdsConfiguration`](crate::configuration::DustDdsConfiguration) struct that allow configuring the runtime options
 /// of the DustDDS systems
 pub mod configuration;
+
+/// Contains the [`PersistentStorage`](crate::persistent_storage::PersistentStorage) trait used to keep the samples of the
+/// writers with PERSISTENT durability and its default append-only log implementation
+pub mod persistent_storage;
+
+/// Contains the [`Transport`](crate::transport::Transport) and [`TransportFactory`](crate::transport::TransportFactory) traits
+/// used to plug custom transports of the RTPS messages into the participants
+pub mod transport;
@@ -0,0 +1,285 @@
+use std::{
+    collections::HashSet,
+    fs::{File, OpenOptions},
+    io::{BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::infrastructure::{error::DdsResult, instance::InstanceHandle};
+
+/// Storage used by the Service to keep the samples written by the [`DataWriter`](crate::publication::data_writer::DataWriter)
+/// entities with [`DurabilityQosPolicyKind::Persistent`](crate::infrastructure::qos_policy::DurabilityQosPolicyKind::Persistent).
+/// The samples are stored as opaque serialized changes, keyed by topic name and instance, so that they survive a restart of the
+/// application and can be replayed to the late-joining [`DataReader`](crate::subscription::data_reader::DataReader) entities.
+pub trait PersistentStorage: Send + Sync {
+    /// Append the serialized `change` of the instance identified by `instance_handle` to the changes stored for the topic
+    /// with name `topic_name`.
+    fn append(
+        &self,
+        topic_name: &str,
+        instance_handle: InstanceHandle,
+        change: &[u8],
+    ) -> DdsResult<()>;
+
+    /// Read all the changes stored for the topic with name `topic_name` in the order in which they were appended.
+    fn read(&self, topic_name: &str) -> DdsResult<Vec<(InstanceHandle, Vec<u8>)>>;
+
+    /// Replace all the changes stored for the topic with name `topic_name` by `changes`. This operation is used by the Service
+    /// to discard the changes that are no longer needed.
+    fn replace(&self, topic_name: &str, changes: &[(InstanceHandle, Vec<u8>)]) -> DdsResult<()>;
+}
+
+/// Default [`PersistentStorage`] implementation. The changes of each topic are appended to a log file in the configured
+/// directory. Each record of the log contains the instance handle, the length of the change and the serialized change itself.
+/// A record which was not completely written, e.g. because the application was terminated while appending it, is removed before
+/// the first change is appended to the log.
+pub struct AppendOnlyLogStorage {
+    directory: PathBuf,
+    // Logs which end with a complete record since they were opened by this storage
+    checked_log_list: Mutex<HashSet<PathBuf>>,
+}
+
+impl AppendOnlyLogStorage {
+    /// Construct a new storage keeping its log files in `directory`. The directory is created if it doesn't exist.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            checked_log_list: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Directory where the log files are kept
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn log_path(&self, topic_name: &str) -> PathBuf {
+        // Topic names may contain characters which are not valid in file names
+        let mut file_name = String::new();
+        for b in topic_name.bytes() {
+            if b.is_ascii_alphanumeric() || b == b'_' || b == b'-' {
+                file_name.push(b as char);
+            } else {
+                file_name.push_str(&format!("%{:02X}", b));
+            }
+        }
+        file_name.push_str(".log");
+        self.directory.join(file_name)
+    }
+
+    fn write_record(
+        buf: &mut dyn Write,
+        instance_handle: InstanceHandle,
+        change: &[u8],
+    ) -> std::io::Result<()> {
+        buf.write_all(instance_handle.as_ref())?;
+        buf.write_all(&(change.len() as u32).to_le_bytes())?;
+        buf.write_all(change)
+    }
+
+    /// Records of the log and length of the part of the log made of complete records
+    fn read_records(log: &[u8]) -> (Vec<(InstanceHandle, Vec<u8>)>, usize) {
+        let mut changes = Vec::new();
+        let mut data = log;
+        while data.len() >= 20 {
+            let mut instance_handle = [0; 16];
+            instance_handle.copy_from_slice(&data[0..16]);
+            let length = u32::from_le_bytes([data[16], data[17], data[18], data[19]]) as usize;
+            if data.len() < 20 + length {
+                break;
+            }
+            changes.push((
+                InstanceHandle::new(instance_handle),
+                data[20..20 + length].to_vec(),
+            ));
+            data = &data[20 + length..];
+        }
+        (changes, log.len() - data.len())
+    }
+}
+
+impl PersistentStorage for AppendOnlyLogStorage {
+    fn append(
+        &self,
+        topic_name: &str,
+        instance_handle: InstanceHandle,
+        change: &[u8],
+    ) -> DdsResult<()> {
+        let mut checked_log_list = self.checked_log_list.lock().expect("Lock not poisoned");
+        std::fs::create_dir_all(&self.directory)?;
+        let log_path = self.log_path(topic_name);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .write(true)
+            .truncate(false)
+            .open(&log_path)?;
+        // An incomplete record at the end of the log is removed so that
+        // the records appended after it can be read
+        if !checked_log_list.contains(&log_path) {
+            let mut log = Vec::new();
+            file.read_to_end(&mut log)?;
+            let (_, complete_length) = Self::read_records(&log);
+            file.set_len(complete_length as u64)?;
+            checked_log_list.insert(log_path.clone());
+        }
+
+        let mut record = Vec::new();
+        Self::write_record(&mut record, instance_handle, change)?;
+        let r = file
+            .seek(SeekFrom::End(0))
+            .and_then(|_| file.write_all(&record))
+            .and_then(|_| file.sync_data());
+        if r.is_err() {
+            // The record may have been partially written
+            checked_log_list.remove(&log_path);
+        }
+        Ok(r?)
+    }
+
+    fn read(&self, topic_name: &str) -> DdsResult<Vec<(InstanceHandle, Vec<u8>)>> {
+        let _guard = self.checked_log_list.lock().expect("Lock not poisoned");
+        let mut log = Vec::new();
+        match File::open(self.log_path(topic_name)) {
+            Ok(mut file) => {
+                file.read_to_end(&mut log)?;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        }
+
+        // A record which was not completely written is ignored
+        let (changes, _) = Self::read_records(&log);
+        Ok(changes)
+    }
+
+    fn replace(&self, topic_name: &str, changes: &[(InstanceHandle, Vec<u8>)]) -> DdsResult<()> {
+        let mut checked_log_list = self.checked_log_list.lock().expect("Lock not poisoned");
+        std::fs::create_dir_all(&self.directory)?;
+        let log_path = self.log_path(topic_name);
+        let mut new_log_path = log_path.clone().into_os_string();
+        new_log_path.push(".new");
+
+        // The new log is completely written before replacing the existing one
+        // so that the stored changes are never lost
+        let mut file = BufWriter::new(File::create(&new_log_path)?);
+        for (instance_handle, change) in changes {
+            Self::write_record(&mut file, *instance_handle, change)?;
+        }
+        file.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        std::fs::rename(new_log_path, &log_path)?;
+        checked_log_list.insert(log_path);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "dust_dds_persistent_storage_{}_{}",
+            name,
+            std::process::id()
+        ));
+        std::fs::remove_dir_all(&directory).ok();
+        directory
+    }
+
+    #[test]
+    fn appended_changes_are_read_in_order() {
+        let directory = storage_directory("append");
+        let storage = AppendOnlyLogStorage::new(&directory);
+        let instance1 = InstanceHandle::new([1; 16]);
+        let instance2 = InstanceHandle::new([2; 16]);
+
+        storage.append("My/Topic", instance1, &[1, 2, 3]).unwrap();
+        storage.append("My/Topic", instance2, &[]).unwrap();
+        storage.append("OtherTopic", instance1, &[7]).unwrap();
+        storage.append("My/Topic", instance1, &[4]).unwrap();
+
+        assert_eq!(
+            storage.read("My/Topic").unwrap(),
+            vec![
+                (instance1, vec![1, 2, 3]),
+                (instance2, vec![]),
+                (instance1, vec![4])
+            ]
+        );
+        assert_eq!(
+            storage.read("OtherTopic").unwrap(),
+            vec![(instance1, vec![7])]
+        );
+        assert_eq!(storage.read("UnknownTopic").unwrap(), vec![]);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn replaced_changes_discard_previous_log() {
+        let directory = storage_directory("replace");
+        let storage = AppendOnlyLogStorage::new(&directory);
+        let instance = InstanceHandle::new([1; 16]);
+
+        storage.append("Topic", instance, &[1]).unwrap();
+        storage.append("Topic", instance, &[2]).unwrap();
+        storage.replace("Topic", &[(instance, vec![2])]).unwrap();
+        storage.append("Topic", instance, &[3]).unwrap();
+
+        assert_eq!(
+            storage.read("Topic").unwrap(),
+            vec![(instance, vec![2]), (instance, vec![3])]
+        );
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn incomplete_record_is_ignored() {
+        let directory = storage_directory("incomplete");
+        let storage = AppendOnlyLogStorage::new(&directory);
+        let instance = InstanceHandle::new([1; 16]);
+
+        storage.append("Topic", instance, &[1, 2]).unwrap();
+        storage.append("Topic", instance, &[3, 4]).unwrap();
+        let log_path = storage.log_path("Topic");
+        let log_length = std::fs::metadata(&log_path).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&log_path)
+            .unwrap()
+            .set_len(log_length - 1)
+            .unwrap();
+
+        assert_eq!(storage.read("Topic").unwrap(), vec![(instance, vec![1, 2])]);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn incomplete_record_is_removed_before_appending() {
+        let directory = storage_directory("append_after_incomplete");
+        let storage = AppendOnlyLogStorage::new(&directory);
+        let instance = InstanceHandle::new([1; 16]);
+
+        storage.append("Topic", instance, &[1, 2]).unwrap();
+        storage.append("Topic", instance, &[3, 4]).unwrap();
+        let log_path = storage.log_path("Topic");
+        let log_length = std::fs::metadata(&log_path).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&log_path)
+            .unwrap()
+            .set_len(log_length - 1)
+            .unwrap();
+
+        // The application is restarted after the incomplete record was written
+        let storage = AppendOnlyLogStorage::new(&directory);
+        storage.append("Topic", instance, &[5]).unwrap();
+
+        assert_eq!(
+            storage.read("Topic").unwrap(),
+            vec![(instance, vec![1, 2]), (instance, vec![5])]
+        );
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
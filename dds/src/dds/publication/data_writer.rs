@@ -14,7 +14,10 @@ use crate::{
         time::{Duration, Time},
     },
     publication::{data_writer_listener::DataWriterListener, publisher::Publisher},
-    topic_definition::{topic::Topic, type_support::DdsSerialize},
+    topic_definition::{
+        topic::Topic,
+        type_support::{DdsDeserialize, DdsSerialize},
+    },
 };
 
 /// The [`DataWriter`] allows the application to set the value of the
@@ -140,7 +143,10 @@ where
     /// This operation returns [`DdsError::BadParameter`](crate::infrastructure::error::DdsError) if the `handle` does not
     /// correspond to an existing data object known to the [`DataWriter`].
     #[tracing::instrument(skip(self, key_holder))]
-    pub fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()> {
+    pub fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()>
+    where
+        Foo: for<'de> DdsDeserialize<'de>,
+    {
         block_on(self.writer_async.get_key_value(key_holder, handle))
     }
 
@@ -162,6 +162,21 @@ impl Publisher {
         block_on(self.publisher_async.wait_for_acknowledgments(max_wait))
     }
 
+    /// This operation blocks the calling thread in the same way as [`Publisher::wait_for_acknowledgments`] but instead of failing when
+    /// `max_wait` elapses it returns the [`InstanceHandle`] of the [`DataWriter`] entities whose data is not yet acknowledged by all
+    /// the matched reliable [`DataReader`](crate::subscription::data_reader::DataReader) entities. An empty list indicates that all
+    /// the samples written have been acknowledged.
+    #[tracing::instrument(skip(self))]
+    pub fn wait_for_unacknowledged_writers(
+        &self,
+        max_wait: Duration,
+    ) -> DdsResult<Vec<InstanceHandle>> {
+        block_on(
+            self.publisher_async
+                .wait_for_unacknowledged_writers(max_wait),
+        )
+    }
+
     /// This operation returns the [`DomainParticipant`] to which the [`Publisher`] belongs.
     #[tracing::instrument(skip(self))]
     pub fn get_participant(&self) -> DomainParticipant {
@@ -208,10 +223,13 @@ impl Publisher {
     #[tracing::instrument(skip(self))]
     pub fn copy_from_topic_qos(
         &self,
-        _a_datawriter_qos: &mut DataWriterQos,
-        _a_topic_qos: &TopicQos,
+        a_datawriter_qos: &mut DataWriterQos,
+        a_topic_qos: &TopicQos,
     ) -> DdsResult<()> {
-        todo!()
+        block_on(
+            self.publisher_async
+                .copy_from_topic_qos(a_datawriter_qos, a_topic_qos),
+        )
     }
 }
 
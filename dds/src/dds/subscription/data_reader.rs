@@ -15,12 +15,17 @@ use crate::{
     },
     rtps::messages::submessage_elements::Data,
     subscription::data_reader_listener::DataReaderListener,
-    topic_definition::{topic::Topic, type_support::DdsDeserialize},
+    topic_definition::{
+        topic::Topic,
+        type_support::{DdsDeserialize, DdsSerialize},
+    },
 };
 
 use std::marker::PhantomData;
 
 use super::{
+    query_condition::QueryCondition,
+    read_condition::ReadCondition,
     sample_info::{InstanceStateKind, SampleInfo, SampleStateKind, ViewStateKind},
     subscriber::Subscriber,
 };
@@ -302,6 +307,80 @@ impl<Foo> DataReader<Foo> {
         ))
     }
 
+    /// This operation accesses via [`DataReader::read`] the samples that match the criteria specified in the [`ReadCondition`].
+    /// This operation is especially useful in combination with [`QueryCondition`] to filter data samples based on the content.
+    /// The specified [`ReadCondition`] must be attached to the [`DataReader`]; otherwise the operation will fail and return
+    /// [`DdsError::PreconditionNotMet`].
+    /// In case the [`ReadCondition`] is a 'plain' [`ReadCondition`] and not the specialized [`QueryCondition`], the
+    /// operation is equivalent to calling [`DataReader::read`] and passing as `sample_states`, `view_states` and `instance_states`
+    /// the value of the corresponding attributes in `a_condition`. Using this operation the application can avoid repeating
+    /// the same parameters specified when creating the [`ReadCondition`].
+    /// The samples are accessed with the same semantics as the [`DataReader::read`] operation.
+    #[tracing::instrument(skip(self, a_condition))]
+    pub fn read_w_condition(
+        &self,
+        max_samples: i32,
+        a_condition: impl AsRef<ReadCondition>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        block_on(
+            self.reader_async
+                .read_w_condition(max_samples, a_condition.as_ref().condition_async()),
+        )
+    }
+
+    /// This operation is analogous to [`DataReader::read_w_condition`] except it accesses samples via the [`DataReader::take`] operation.
+    #[tracing::instrument(skip(self, a_condition))]
+    pub fn take_w_condition(
+        &self,
+        max_samples: i32,
+        a_condition: impl AsRef<ReadCondition>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        block_on(
+            self.reader_async
+                .take_w_condition(max_samples, a_condition.as_ref().condition_async()),
+        )
+    }
+
+    /// This operation accesses a collection of [`Sample`] values from the [`DataReader`]. The behavior is identical to
+    /// [`DataReader::read_next_instance`] except that all samples returned satisfy the specified [`ReadCondition`].
+    /// The specified [`ReadCondition`] must be attached to the [`DataReader`]; otherwise the operation will fail and return
+    /// [`DdsError::PreconditionNotMet`].
+    #[tracing::instrument(skip(self, a_condition))]
+    pub fn read_next_instance_w_condition(
+        &self,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: impl AsRef<ReadCondition>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        block_on(self.reader_async.read_next_instance_w_condition(
+            max_samples,
+            previous_handle,
+            a_condition.as_ref().condition_async(),
+        ))
+    }
+
+    /// This operation accesses a collection of [`Sample`] values from the [`DataReader`] and removes them from the [`DataReader`].
+    /// This operation has the same behavior as [`DataReader::read_next_instance_w_condition`] except that the samples are 'taken'
+    /// from the [`DataReader`] such that they are no longer accessible via subsequent 'read' or 'take' operations.
+    #[tracing::instrument(skip(self, a_condition))]
+    pub fn take_next_instance_w_condition(
+        &self,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: impl AsRef<ReadCondition>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        block_on(self.reader_async.take_next_instance_w_condition(
+            max_samples,
+            previous_handle,
+            a_condition.as_ref().condition_async(),
+        ))
+    }
+}
+
+impl<Foo> DataReader<Foo>
+where
+    Foo: DdsSerialize + for<'de> DdsDeserialize<'de>,
+{
     /// This operation can be used to retrieve the instance key that corresponds to an `handle`.
     /// The operation will only fill the fields that form the key inside the `key_holder` instance.
     /// This operation may return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError)
@@ -323,6 +402,58 @@ impl<Foo> DataReader<Foo> {
     }
 }
 
+impl<Foo> DataReader<Foo> {
+    /// This operation creates a [`ReadCondition`]. The returned [`ReadCondition`] will be attached and belong to the [`DataReader`].
+    #[tracing::instrument(skip(self))]
+    pub fn create_readcondition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<ReadCondition> {
+        block_on(
+            self.reader_async
+                .create_readcondition(sample_states, view_states, instance_states),
+        )
+        .map(ReadCondition::new)
+    }
+
+    /// This operation creates a [`QueryCondition`]. The returned [`QueryCondition`] will be attached and belong to the [`DataReader`].
+    /// The syntax of the `query_expression` is that of an SQL WHERE clause in which the members of the data type can be
+    /// compared with constants and with the `query_parameters`, referenced as `%0`, `%1`, etc.
+    /// If the `query_expression` is not valid or references a member not present in the data type, the operation will fail
+    /// and return [`DdsError::BadParameter`].
+    #[tracing::instrument(skip(self))]
+    pub fn create_querycondition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        query_expression: String,
+        query_parameters: &[String],
+    ) -> DdsResult<QueryCondition> {
+        block_on(self.reader_async.create_querycondition(
+            sample_states,
+            view_states,
+            instance_states,
+            query_expression,
+            query_parameters,
+        ))
+        .map(QueryCondition::new)
+    }
+
+    /// This operation deletes a [`ReadCondition`] attached to the [`DataReader`]. Since [`QueryCondition`] specializes
+    /// [`ReadCondition`] it can also be used to delete a [`QueryCondition`]. If the [`ReadCondition`] is not attached to
+    /// the [`DataReader`], the operation will return the error [`DdsError::PreconditionNotMet`].
+    #[tracing::instrument(skip(self, a_condition))]
+    pub fn delete_readcondition(&self, a_condition: impl AsRef<ReadCondition>) -> DdsResult<()> {
+        block_on(
+            self.reader_async
+                .delete_readcondition(a_condition.as_ref().condition_async()),
+        )
+    }
+}
+
 impl<Foo> DataReader<Foo> {
     /// This operation allows access to the [`LivelinessChangedStatus`].
     #[tracing::instrument(skip(self))]
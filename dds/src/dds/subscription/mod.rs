@@ -4,6 +4,12 @@ pub mod data_reader;
 /// Contains the [`DataReaderListener`](crate::subscription::data_reader_listener::DataReaderListener) trait.
 pub mod data_reader_listener;
 
+/// Contains the [`QueryCondition`](crate::subscription::query_condition::QueryCondition) object.
+pub mod query_condition;
+
+/// Contains the [`ReadCondition`](crate::subscription::read_condition::ReadCondition) object.
+pub mod read_condition;
+
 /// Contains the [`SampleInfo`](crate::subscription::sample_info::SampleInfo) and any related objects.
 pub mod sample_info;
 
@@ -0,0 +1,94 @@
+use crate::{
+    dds_async::query_condition::QueryConditionAsync, implementation::runtime::executor::block_on,
+    infrastructure::error::DdsResult,
+};
+
+use super::{
+    read_condition::ReadCondition,
+    sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind},
+};
+
+/// A [`QueryCondition`] objects is a specialized [`ReadCondition`] that allows the application to also specify a filter
+/// on the locally available data.
+/// The query (`query_expression`) is similar to an SQL WHERE clause can be parameterized by arguments that are dynamically
+/// changeable by the [`QueryCondition::set_query_parameters`] operation.
+/// The *trigger_value* of the [`QueryCondition`] is [`true`] if there is at least one sample in the
+/// [`DataReader`](crate::subscription::data_reader::DataReader) whose state matches the masks of the [`QueryCondition`]
+/// and whose data satisfies the query expression.
+#[derive(Clone)]
+pub struct QueryCondition {
+    condition_async: QueryConditionAsync,
+    read_condition: ReadCondition,
+}
+
+impl QueryCondition {
+    pub(crate) fn new(condition_async: QueryConditionAsync) -> Self {
+        let read_condition = ReadCondition::new(condition_async.as_ref().clone());
+        Self {
+            condition_async,
+            read_condition,
+        }
+    }
+
+    pub(crate) fn condition_async(&self) -> &QueryConditionAsync {
+        &self.condition_async
+    }
+}
+
+impl AsRef<ReadCondition> for QueryCondition {
+    fn as_ref(&self) -> &ReadCondition {
+        &self.read_condition
+    }
+}
+
+impl QueryCondition {
+    /// This operation returns the `query_expression` associated with the [`QueryCondition`]. That is, the expression
+    /// specified when the [`QueryCondition`] was created.
+    #[tracing::instrument(skip(self))]
+    pub fn get_query_expression(&self) -> String {
+        self.condition_async.get_query_expression()
+    }
+
+    /// This operation returns the `query_parameters` associated with the [`QueryCondition`]. That is, the parameters specified
+    /// on the last successful call to [`QueryCondition::set_query_parameters`], or if [`QueryCondition::set_query_parameters`]
+    /// was never called, the arguments specified when the [`QueryCondition`] was created.
+    #[tracing::instrument(skip(self))]
+    pub fn get_query_parameters(&self) -> DdsResult<Vec<String>> {
+        block_on(self.condition_async.get_query_parameters())
+    }
+
+    /// This operation changes the `query_parameters` associated with the [`QueryCondition`]. The number of parameters must
+    /// cover all the parameters referenced by the query expression, otherwise the operation returns
+    /// [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn set_query_parameters(&self, query_parameters: &[String]) -> DdsResult<()> {
+        block_on(self.condition_async.set_query_parameters(query_parameters))
+    }
+
+    /// This operation returns the set of sample_states that are taken into account to determine the *trigger_value* of the [`QueryCondition`].
+    #[tracing::instrument(skip(self))]
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.condition_async.get_sample_state_mask()
+    }
+
+    /// This operation returns the set of view_states that are taken into account to determine the *trigger_value* of the [`QueryCondition`].
+    #[tracing::instrument(skip(self))]
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.condition_async.get_view_state_mask()
+    }
+
+    /// This operation returns the set of instance_states that are taken into account to determine the *trigger_value* of the [`QueryCondition`].
+    #[tracing::instrument(skip(self))]
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.condition_async.get_instance_state_mask()
+    }
+}
+
+/// This implementation block contains the Condition operations for the [`QueryCondition`].
+impl QueryCondition {
+    /// This operation retrieves the *trigger_value* of the [`QueryCondition`].
+    #[tracing::instrument(skip(self))]
+    pub fn get_trigger_value(&self) -> DdsResult<bool> {
+        block_on(self.condition_async.get_trigger_value())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    dds_async::read_condition::ReadConditionAsync, implementation::runtime::executor::block_on,
+    infrastructure::error::DdsResult,
+};
+
+use super::sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind};
+
+/// A [`ReadCondition`] object is a specific Condition that is associated with a [`DataReader`](crate::subscription::data_reader::DataReader).
+/// [`ReadCondition`] objects allow an application to specify the data samples it is interested in by specifying the desired
+/// sample_states, view_states, and instance_states. This allows the middleware to enable the condition only when suitable
+/// information is available. They are to be used in conjunction with a [`WaitSet`](crate::infrastructure::wait_set::WaitSet)
+/// as normal conditions. More than one [`ReadCondition`] may be attached to the same [`DataReader`](crate::subscription::data_reader::DataReader).
+/// The *trigger_value* of the [`ReadCondition`] is [`true`] if there is at least one sample in the
+/// [`DataReader`](crate::subscription::data_reader::DataReader) whose state matches the masks of the [`ReadCondition`].
+#[derive(Clone)]
+pub struct ReadCondition {
+    condition_async: ReadConditionAsync,
+}
+
+impl ReadCondition {
+    pub(crate) fn new(condition_async: ReadConditionAsync) -> Self {
+        Self { condition_async }
+    }
+
+    pub(crate) fn condition_async(&self) -> &ReadConditionAsync {
+        &self.condition_async
+    }
+}
+
+impl AsRef<ReadCondition> for ReadCondition {
+    fn as_ref(&self) -> &ReadCondition {
+        self
+    }
+}
+
+impl ReadCondition {
+    /// This operation returns the set of sample_states that are taken into account to determine the *trigger_value* of the [`ReadCondition`].
+    /// These are the sample_states specified when the [`ReadCondition`] was created.
+    #[tracing::instrument(skip(self))]
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.condition_async.get_sample_state_mask()
+    }
+
+    /// This operation returns the set of view_states that are taken into account to determine the *trigger_value* of the [`ReadCondition`].
+    /// These are the view_states specified when the [`ReadCondition`] was created.
+    #[tracing::instrument(skip(self))]
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.condition_async.get_view_state_mask()
+    }
+
+    /// This operation returns the set of instance_states that are taken into account to determine the *trigger_value* of the [`ReadCondition`].
+    /// These are the instance_states specified when the [`ReadCondition`] was created.
+    #[tracing::instrument(skip(self))]
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.condition_async.get_instance_state_mask()
+    }
+}
+
+/// This implementation block contains the Condition operations for the [`ReadCondition`].
+impl ReadCondition {
+    /// This operation retrieves the *trigger_value* of the [`ReadCondition`].
+    #[tracing::instrument(skip(self))]
+    pub fn get_trigger_value(&self) -> DdsResult<bool> {
+        block_on(self.condition_async.get_trigger_value())
+    }
+}
@@ -12,11 +12,13 @@ use crate::{
         qos::{DataReaderQos, QosKind, SubscriberQos, TopicQos},
         status::{SampleLostStatus, StatusKind},
     },
-    topic_definition::topic::Topic,
+    topic_definition::topic_description::TopicDescription,
 };
 
 use super::{
-    data_reader::DataReader, data_reader_listener::DataReaderListener,
+    data_reader::DataReader,
+    data_reader_listener::DataReaderListener,
+    sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind},
     subscriber_listener::SubscriberListener,
 };
 
@@ -41,9 +43,11 @@ impl Subscriber {
 
 impl Subscriber {
     /// This operation creates a [`DataReader`]. The returned [`DataReader`] will be attached and belong to the [`Subscriber`].
-    /// The [`DataReader`] returned by this operation has an associated [`Topic`] and a type `Foo`.
-    /// The [`Topic`] passed to this operation must have been created from the same [`DomainParticipant`] that was used to create this
-    /// [`Subscriber`]. If the [`Topic`] was created from a different [`DomainParticipant`], the operation will fail and
+    /// The [`DataReader`] returned by this operation has an associated [`TopicDescription`] and a type `Foo`.
+    /// The [`TopicDescription`] passed to this operation can be either a [`Topic`](crate::topic_definition::topic::Topic), a
+    /// [`ContentFilteredTopic`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic) or a
+    /// [`MultiTopic`](crate::topic_definition::multi_topic::MultiTopic) and must have been created
+    /// from the same [`DomainParticipant`] that was used to create this [`Subscriber`]. If the [`TopicDescription`] was created from a different [`DomainParticipant`], the operation will fail and
     /// return a [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError). In case of failure, the operation
     /// will return an error and no writer will be created.
     ///
@@ -52,14 +56,14 @@ impl Subscriber {
     /// [`DataReaderQos`] by means of the operation [`Subscriber::get_default_datareader_qos`] and using the resulting qos
     /// to create the [`DataReader`]. A common application pattern to construct the [`DataReaderQos`] to ensure consistency with the
     /// associated [`TopicQos`] is to:
-    /// 1. Retrieve the QoS policies on the associated [`Topic`] by means of the [`Topic::get_qos`] operation.
+    /// 1. Retrieve the QoS policies on the associated [`Topic`](crate::topic_definition::topic::Topic) by means of the [`Topic::get_qos`](crate::topic_definition::topic::Topic::get_qos) operation.
     /// 2. Retrieve the default [`DataReaderQos`] qos by means of the [`Subscriber::get_default_datareader_qos`] operation.
     /// 3. Combine those two qos policies using the [`Subscriber::copy_from_topic_qos`] and selectively modify policies as desired and
     /// use the resulting [`DataReaderQos`] to construct the [`DataReader`].
     #[tracing::instrument(skip(self, a_topic, a_listener))]
     pub fn create_datareader<'a, Foo>(
         &self,
-        a_topic: &Topic,
+        a_topic: impl Into<TopicDescription>,
         qos: QosKind<DataReaderQos>,
         a_listener: Option<Box<dyn DataReaderListener<'a, Foo = Foo> + Send + 'a>>,
         mask: &[StatusKind],
@@ -69,7 +73,7 @@ impl Subscriber {
     {
         block_on(
             self.subscriber_async.create_datareader::<Foo>(
-                a_topic.topic_async(),
+                a_topic.into().topic_description_async(),
                 qos,
                 a_listener.map::<Box<dyn DataReaderListenerAsync<Foo = Foo> + Send + 'a>, _>(|b| {
                     Box::new(b)
@@ -91,7 +95,7 @@ impl Subscriber {
         )
     }
 
-    /// This operation retrieves a previously created [`DataReader`] belonging to the [`Subscriber`] that is attached to a [`Topic`].
+    /// This operation retrieves a previously created [`DataReader`] belonging to the [`Subscriber`] that is attached to a [`Topic`](crate::topic_definition::topic::Topic).
     /// If no such [`DataReader`] exists, the operation will succeed but return [`None`].
     /// If multiple [`DataReader`] attached to the [`Subscriber`] satisfy this condition, then the operation will return one of them. It is not
     /// specified which one.
@@ -104,6 +108,65 @@ impl Subscriber {
         )
     }
 
+    /// This operation indicates that the application is about to access the data samples in any of the [`DataReader`] objects attached to
+    /// the [`Subscriber`].
+    /// The application is required to use this operation only if the [`PresentationQosPolicy`](crate::infrastructure::qos_policy::PresentationQosPolicy)
+    /// of the [`Subscriber`] to which the [`DataReader`] belongs has the `access_scope` set to
+    /// [`PresentationQosPolicyAccessScopeKind::Group`](crate::infrastructure::qos_policy::PresentationQosPolicyAccessScopeKind).
+    /// In the aforementioned case, the operation [`Subscriber::begin_access`] must be called prior to calling any of the sample-accessing
+    /// operations, namely: [`Subscriber::get_datareaders`] on the [`Subscriber`] and [`DataReader::read`], [`DataReader::take`],
+    /// [`DataReader::read_w_condition`] and [`DataReader::take_w_condition`] on any [`DataReader`].
+    /// Otherwise the sample-accessing operations may return data which is not coherent or ordered with respect to the data of the other
+    /// [`DataReader`] objects.
+    /// While the access is in progress, the [`Subscriber`] does not make newly received coherent sets available so that the data accessed
+    /// by the application does not change.
+    /// Calls to [`Subscriber::begin_access`] can be nested. In that case, the application must call [`Subscriber::end_access`] as many times
+    /// as it called [`Subscriber::begin_access`].
+    #[tracing::instrument(skip(self))]
+    pub fn begin_access(&self) -> DdsResult<()> {
+        block_on(self.subscriber_async.begin_access())
+    }
+
+    /// This operation indicates that the application has finished accessing the data samples in [`DataReader`] objects managed by the
+    /// [`Subscriber`].
+    /// This operation must be used to 'close' a corresponding [`Subscriber::begin_access`].
+    /// After calling [`Subscriber::end_access`] the application should no longer access any of the [`Sample`](crate::subscription::data_reader::Sample)
+    /// elements returned from the sample-accessing operations.
+    /// If there is no matching call to [`Subscriber::begin_access`], the operation will return the error
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn end_access(&self) -> DdsResult<()> {
+        block_on(self.subscriber_async.end_access())
+    }
+
+    /// This operation allows the application to access the [`DataReader`] objects that contain samples with the specified `sample_states`,
+    /// `view_states`, and `instance_states`.
+    /// If the [`PresentationQosPolicy`](crate::infrastructure::qos_policy::PresentationQosPolicy) of the [`Subscriber`] has the `access_scope`
+    /// set to [`PresentationQosPolicyAccessScopeKind::Group`](crate::infrastructure::qos_policy::PresentationQosPolicyAccessScopeKind) and
+    /// `ordered_access` set to [`true`], then the returned collection contains one [`DataReader`] for each of the selected samples, in the
+    /// order in which the samples were written by the remote publisher. The same [`DataReader`] may therefore appear several times
+    /// and the application should read or take one sample from each of the entries in turn, i.e. with `max_samples` equal to 1.
+    /// Otherwise each [`DataReader`] with selected samples appears once and the order of the collection is not specified.
+    /// The readers are returned as [`DataReader<Foo>`] in the same way as [`Subscriber::lookup_datareader`]. When the readers are of
+    /// different types, the application can identify them by comparing [`DataReader::get_instance_handle`] with the one of the readers it
+    /// created.
+    #[tracing::instrument(skip(self))]
+    pub fn get_datareaders<Foo>(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<DataReader<Foo>>> {
+        Ok(block_on(self.subscriber_async.get_datareaders::<Foo>(
+            sample_states,
+            view_states,
+            instance_states,
+        ))?
+        .into_iter()
+        .map(DataReader::new)
+        .collect())
+    }
+
     /// This operation invokes the operation [`DataReaderListener::on_data_available`] on the listener objects attached to contained [`DataReader`]
     /// entities with a [`StatusKind::DataAvailable`] that is considered changed.
     /// This operation is typically invoked from the [`SubscriberListener::on_data_on_readers`] operation. That way the
@@ -158,16 +221,19 @@ impl Subscriber {
 
     /// This operation copies the policies in the `a_topic_qos` to the corresponding policies in the `a_datareader_qos`.
     /// This is a *convenience* operation most useful in combination with the operations [`Subscriber::get_default_datareader_qos`] and
-    /// [`Topic::get_qos`]. This operation can be used to merge the [`DataReader`] default qos policies with the
-    /// corresponding ones on the [`Topic`]. The resulting qos can then be used to create a new [`DataReader`], or set its qos.
+    /// [`Topic::get_qos`](crate::topic_definition::topic::Topic::get_qos). This operation can be used to merge the [`DataReader`] default qos policies with the
+    /// corresponding ones on the [`Topic`](crate::topic_definition::topic::Topic). The resulting qos can then be used to create a new [`DataReader`], or set its qos.
     /// This operation does not check the resulting `a_datareader_qos` for consistency. This is because the merged `a_datareader_qos`
     /// may not be the final one, as the application can still modify some policies prior to applying the policies to the [`DataReader`].
     #[tracing::instrument]
     pub fn copy_from_topic_qos(
-        _a_datareader_qos: &mut DataReaderQos,
-        _a_topic_qos: &TopicQos,
+        a_datareader_qos: &mut DataReaderQos,
+        a_topic_qos: &TopicQos,
     ) -> DdsResult<()> {
-        todo!()
+        block_on(SubscriberAsync::copy_from_topic_qos(
+            a_datareader_qos,
+            a_topic_qos,
+        ))
     }
 
     /// This operation is used to set the QoS policies of the Entity and replacing the values of any policies previously set.
@@ -1,6 +1,15 @@
+/// Contains the [`ContentFilteredTopic`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic).
+pub mod content_filtered_topic;
+
+/// Contains the [`MultiTopic`](crate::topic_definition::multi_topic::MultiTopic).
+pub mod multi_topic;
+
 /// Contains the [`Topic`](crate::topic_definition::topic::Topic) and any related objects.
 pub mod topic;
 
+/// Contains the [`TopicDescription`](crate::topic_definition::topic_description::TopicDescription) enum.
+pub mod topic_description;
+
 /// Contains the [`TopicListener`](crate::topic_definition::topic_listener::TopicListener) trait.
 pub mod topic_listener;
 
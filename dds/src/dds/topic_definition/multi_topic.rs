@@ -0,0 +1,84 @@
+use crate::{
+    dds_async::multi_topic::MultiTopicAsync, domain::domain_participant::DomainParticipant,
+    implementation::runtime::executor::block_on, infrastructure::error::DdsResult,
+};
+
+/// [`MultiTopic`] is a specialization of TopicDescription that allows subscriptions to combine/filter/rearrange data coming from
+/// several topics. [`MultiTopic`] allows a more sophisticated subscription that can select and combine data received from multiple
+/// topics into a single resulting type (specified by the inherited `type_name`). The data will then be filtered (selection) and
+/// possibly re-arranged (aggregation/projection) according to a `subscription_expression` with parameters `expression_parameters`.
+/// - The `subscription_expression` is a string that identifies the selection and re-arrangement of data from the associated topics.
+///   It is similar to an SQL clause where the SELECT part provides the fields to be kept, the FROM part provides the names of the
+///   topics that are searched for those fields, and the WHERE clause gives the content filter. The topics combined may have
+///   different types but they are restricted in that the type of the fields used for the NATURAL JOIN operation must be the same.
+/// - The `expression_parameters` attribute is a sequence of strings that give values to the 'parameters' (i.e., "%n" tokens) in
+///   the `subscription_expression`. The number of supplied parameters must fit with the requested values in the
+///   `subscription_expression` (i.e., the number of %n tokens).
+///
+/// The samples of the related topics are joined on all the fields with the same name.
+/// [`DataReader`](crate::subscription::data_reader::DataReader) entities associated with a [`MultiTopic`] are alerted of data
+/// modifications by the usual listener or wait-set/condition mechanisms whenever modifications occur to the data associated with
+/// any of the topics relevant to the [`MultiTopic`].
+pub struct MultiTopic {
+    multi_topic_async: MultiTopicAsync,
+}
+
+impl MultiTopic {
+    pub(crate) fn new(multi_topic_async: MultiTopicAsync) -> Self {
+        Self { multi_topic_async }
+    }
+
+    pub(crate) fn multi_topic_async(&self) -> &MultiTopicAsync {
+        &self.multi_topic_async
+    }
+}
+
+impl MultiTopic {
+    /// This operation returns the `subscription_expression` associated with the [`MultiTopic`]. That is, the expression specified
+    /// when the [`MultiTopic`] was created.
+    #[tracing::instrument(skip(self))]
+    pub fn get_subscription_expression(&self) -> String {
+        self.multi_topic_async.get_subscription_expression()
+    }
+
+    /// This operation returns the `expression_parameters` associated with the [`MultiTopic`]. That is, the parameters specified on
+    /// the last successful call to [`MultiTopic::set_expression_parameters`], or if [`MultiTopic::set_expression_parameters`] was
+    /// never called, the parameters specified when the [`MultiTopic`] was created.
+    #[tracing::instrument(skip(self))]
+    pub fn get_expression_parameters(&self) -> DdsResult<Vec<String>> {
+        block_on(self.multi_topic_async.get_expression_parameters())
+    }
+
+    /// This operation changes the `expression_parameters` associated with the [`MultiTopic`]. The new parameters are applied to
+    /// all the [`DataReader`](crate::subscription::data_reader::DataReader) objects created from this [`MultiTopic`]. If the
+    /// number of parameters does not cover the parameters referenced by the `subscription_expression` the operation returns
+    /// [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn set_expression_parameters(&self, expression_parameters: &[String]) -> DdsResult<()> {
+        block_on(
+            self.multi_topic_async
+                .set_expression_parameters(expression_parameters),
+        )
+    }
+}
+
+/// This implementation block represents the TopicDescription operations for the [`MultiTopic`].
+impl MultiTopic {
+    /// This operation returns the [`DomainParticipant`] to which the [`MultiTopic`] belongs.
+    #[tracing::instrument(skip(self))]
+    pub fn get_participant(&self) -> DomainParticipant {
+        DomainParticipant::new(self.multi_topic_async.get_participant())
+    }
+
+    /// The name of the type resulting from the subscription expression of the [`MultiTopic`]
+    #[tracing::instrument(skip(self))]
+    pub fn get_type_name(&self) -> String {
+        self.multi_topic_async.get_type_name()
+    }
+
+    /// The name used to create the [`MultiTopic`]
+    #[tracing::instrument(skip(self))]
+    pub fn get_name(&self) -> String {
+        self.multi_topic_async.get_name()
+    }
+}
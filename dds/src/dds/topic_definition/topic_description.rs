@@ -0,0 +1,80 @@
+use crate::{
+    dds_async::topic_description::TopicDescriptionAsync,
+    domain::domain_participant::DomainParticipant,
+};
+
+use super::{content_filtered_topic::ContentFilteredTopic, multi_topic::MultiTopic, topic::Topic};
+
+/// [`TopicDescription`] represents the fact that both publications and subscriptions are tied to a single data-type. It is
+/// the common description of the [`Topic`], [`ContentFilteredTopic`] and [`MultiTopic`] objects that can be used to create a
+/// [`DataReader`](crate::subscription::data_reader::DataReader).
+pub enum TopicDescription {
+    /// Topic variant
+    Topic(Topic),
+    /// Content filtered topic variant
+    ContentFilteredTopic(ContentFilteredTopic),
+    /// Multi topic variant
+    MultiTopic(MultiTopic),
+}
+
+impl TopicDescription {
+    pub(crate) fn topic_description_async(&self) -> TopicDescriptionAsync {
+        match self {
+            TopicDescription::Topic(t) => t.topic_async().into(),
+            TopicDescription::ContentFilteredTopic(t) => t.content_filtered_topic_async().into(),
+            TopicDescription::MultiTopic(t) => t.multi_topic_async().into(),
+        }
+    }
+}
+
+impl From<&Topic> for TopicDescription {
+    fn from(value: &Topic) -> Self {
+        TopicDescription::Topic(Topic::new(value.topic_async().clone()))
+    }
+}
+
+impl From<&ContentFilteredTopic> for TopicDescription {
+    fn from(value: &ContentFilteredTopic) -> Self {
+        TopicDescription::ContentFilteredTopic(ContentFilteredTopic::new(
+            value.content_filtered_topic_async().clone(),
+        ))
+    }
+}
+
+impl From<&MultiTopic> for TopicDescription {
+    fn from(value: &MultiTopic) -> Self {
+        TopicDescription::MultiTopic(MultiTopic::new(value.multi_topic_async().clone()))
+    }
+}
+
+impl TopicDescription {
+    /// This operation returns the [`DomainParticipant`] to which the [`TopicDescription`] belongs.
+    #[tracing::instrument(skip(self))]
+    pub fn get_participant(&self) -> DomainParticipant {
+        match self {
+            TopicDescription::Topic(t) => t.get_participant(),
+            TopicDescription::ContentFilteredTopic(t) => t.get_participant(),
+            TopicDescription::MultiTopic(t) => t.get_participant(),
+        }
+    }
+
+    /// The name of the type used to create the [`TopicDescription`]
+    #[tracing::instrument(skip(self))]
+    pub fn get_type_name(&self) -> String {
+        match self {
+            TopicDescription::Topic(t) => t.get_type_name(),
+            TopicDescription::ContentFilteredTopic(t) => t.get_type_name(),
+            TopicDescription::MultiTopic(t) => t.get_type_name(),
+        }
+    }
+
+    /// The name used to create the [`TopicDescription`]
+    #[tracing::instrument(skip(self))]
+    pub fn get_name(&self) -> String {
+        match self {
+            TopicDescription::Topic(t) => t.get_name(),
+            TopicDescription::ContentFilteredTopic(t) => t.get_name(),
+            TopicDescription::MultiTopic(t) => t.get_name(),
+        }
+    }
+}
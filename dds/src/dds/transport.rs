@@ -0,0 +1,106 @@
+use std::sync::{Arc, OnceLock};
+
+use crate::{
+    dds_async::domain_participant::DomainParticipantAsync,
+    domain::domain_participant_factory::DomainId,
+    implementation::actors::{
+        domain_participant_actor, domain_participant_factory_actor::MAX_DATAGRAM_SIZE,
+    },
+    infrastructure::error::DdsResult,
+    rtps::{messages::overall_structure::RtpsMessageRead, types::Locator},
+};
+
+/// Transport used by a participant to send the RTPS messages to the [`Locator`]s of one kind.
+pub trait Transport: Send + Sync {
+    /// Send the serialized RTPS message in `datagram` to `locator`.
+    fn send(&self, datagram: &[u8], locator: &Locator);
+
+    /// Largest datagram, in bytes, which can be sent with the transport. The messages sent together in a datagram
+    /// and the fragments of the samples written by the participant are sized to fit in it. The default is the
+    /// maximum size of a UDP datagram.
+    fn max_datagram_size(&self) -> usize {
+        MAX_DATAGRAM_SIZE
+    }
+
+    /// Stop the receive loop of the transport and release its resources. This operation is called once when the participant
+    /// of the transport is deleted or when the creation of the participant fails after the transport was created. No message is
+    /// sent with the transport afterwards.
+    fn close(&self) {}
+}
+
+/// Locators on which a participant receives the RTPS messages through a [`Transport`]. These locators are announced
+/// to the remote participants during discovery. The metatraffic multicast locators are also used to announce the participant.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransportLocators {
+    /// Locators on which the user-defined data is received.
+    pub default_unicast_locator_list: Vec<Locator>,
+    /// Locators on which the discovery traffic directed to the participant is received.
+    pub metatraffic_unicast_locator_list: Vec<Locator>,
+    /// Locators on which the discovery traffic of all the participants of the domain is received.
+    pub metatraffic_multicast_locator_list: Vec<Locator>,
+}
+
+/// Factory of the [`Transport`] of each participant. A [`TransportFactory`] is registered for a locator kind, either on the
+/// [`DomainParticipantFactory`](crate::domain::domain_participant_factory::DomainParticipantFactory) for all the participants
+/// created afterwards or when creating a single participant, and all the messages sent to the locators of that kind by these
+/// participants use its transports. The UDPv4 and UDPv6 locators use a built-in factory unless another one is registered for their kind.
+pub trait TransportFactory: Send + Sync {
+    /// Create the transport of a participant of the domain with id `domain_id` and return it together with the locators
+    /// on which it receives. The transport is responsible for running the receive loop which delivers the messages it receives
+    /// to `transport_receiver`.
+    fn create_transport(
+        &self,
+        domain_id: DomainId,
+        transport_receiver: TransportReceiver,
+    ) -> DdsResult<(Box<dyn Transport>, TransportLocators)>;
+}
+
+/// Handle used by the receive loop of a [`Transport`] to deliver the RTPS messages it receives to its participant.
+#[derive(Clone, Default)]
+pub struct TransportReceiver {
+    participant: Arc<OnceLock<DomainParticipantAsync>>,
+}
+
+impl TransportReceiver {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn set_participant(&self, participant: DomainParticipantAsync) {
+        self.participant.set(participant).ok();
+    }
+
+    /// Deliver a message received on a metatraffic locator. The messages received before the participant is
+    /// completely created are discarded. This operation fails with [`DdsError::AlreadyDeleted`](crate::infrastructure::error::DdsError::AlreadyDeleted) once the participant
+    /// has been deleted, which signals that the receive loop can be stopped.
+    pub fn receive_metatraffic(&self, rtps_message: RtpsMessageRead) -> DdsResult<()> {
+        let Some(participant) = self.participant.get() else {
+            return Ok(());
+        };
+        participant.participant_address().send_actor_mail(
+            domain_participant_actor::ProcessMetatrafficRtpsMessage {
+                rtps_message,
+                participant: participant.clone(),
+                executor_handle: participant.executor_handle().clone(),
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Deliver a message received on a user-defined data locator. The messages received before the participant is
+    /// completely created are discarded. This operation fails with [`DdsError::AlreadyDeleted`](crate::infrastructure::error::DdsError::AlreadyDeleted) once the participant
+    /// has been deleted, which signals that the receive loop can be stopped.
+    pub fn receive_user_defined(&self, rtps_message: RtpsMessageRead) -> DdsResult<()> {
+        let Some(participant) = self.participant.get() else {
+            return Ok(());
+        };
+        participant.participant_address().send_actor_mail(
+            domain_participant_actor::ProcessUserDefinedRtpsMessage {
+                rtps_message,
+                participant: participant.clone(),
+                executor_handle: participant.executor_handle().clone(),
+            },
+        )?;
+        Ok(())
+    }
+}
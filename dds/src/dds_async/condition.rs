@@ -1,16 +1,41 @@
+use std::{
+    sync::{Arc, Mutex},
+    task::Waker,
+};
+
 use crate::{
     implementation::{
         actor::ActorAddress,
-        actors::status_condition_actor::{self, StatusConditionActor},
+        actors::{
+            data_reader_actor::{self, DataReaderActor},
+            data_writer_actor::{self, DataWriterActor},
+            domain_participant_actor::{self, DomainParticipantActor},
+            publisher_actor::{self, PublisherActor},
+            status_condition_actor::{self, StatusConditionActor},
+            subscriber_actor::{self, SubscriberActor},
+            topic_actor::{self, TopicActor},
+        },
         runtime::{executor::ExecutorHandle, timer::TimerHandle},
     },
-    infrastructure::{error::DdsResult, status::StatusKind},
+    infrastructure::{condition::Entity, error::DdsResult, status::StatusKind},
 };
 
+/// Address of the entity to which a [`StatusConditionAsync`] belongs.
+#[derive(Clone)]
+pub(crate) enum EntityAddress {
+    DomainParticipant(ActorAddress<DomainParticipantActor>),
+    Publisher(ActorAddress<PublisherActor>),
+    Subscriber(ActorAddress<SubscriberActor>),
+    Topic(ActorAddress<TopicActor>),
+    DataReader(ActorAddress<DataReaderActor>),
+    DataWriter(ActorAddress<DataWriterActor>),
+}
+
 /// Async version of [`StatusCondition`](crate::infrastructure::condition::StatusCondition).
 #[derive(Clone)]
 pub struct StatusConditionAsync {
     address: ActorAddress<StatusConditionActor>,
+    entity: EntityAddress,
     _executor_handle: ExecutorHandle,
     timer_handle: TimerHandle,
 }
@@ -18,11 +43,13 @@ pub struct StatusConditionAsync {
 impl StatusConditionAsync {
     pub(crate) fn new(
         address: ActorAddress<StatusConditionActor>,
+        entity: EntityAddress,
         executor_handle: ExecutorHandle,
         timer_handle: TimerHandle,
     ) -> Self {
         Self {
             address,
+            entity,
             _executor_handle: executor_handle,
             timer_handle,
         }
@@ -67,8 +94,39 @@ impl StatusConditionAsync {
 
     /// Async version of [`get_entity`](crate::infrastructure::condition::StatusCondition::get_entity).
     #[tracing::instrument(skip(self))]
-    pub async fn get_entity(&self) {
-        todo!()
+    pub async fn get_entity(&self) -> DdsResult<Entity> {
+        Ok(match &self.entity {
+            EntityAddress::DomainParticipant(a) => Entity::DomainParticipant(
+                a.send_actor_mail(domain_participant_actor::GetInstanceHandle)?
+                    .receive_reply()
+                    .await,
+            ),
+            EntityAddress::Publisher(a) => Entity::Publisher(
+                a.send_actor_mail(publisher_actor::GetInstanceHandle)?
+                    .receive_reply()
+                    .await,
+            ),
+            EntityAddress::Subscriber(a) => Entity::Subscriber(
+                a.send_actor_mail(subscriber_actor::GetInstanceHandle)?
+                    .receive_reply()
+                    .await,
+            ),
+            EntityAddress::Topic(a) => Entity::Topic(
+                a.send_actor_mail(topic_actor::GetInstanceHandle)?
+                    .receive_reply()
+                    .await,
+            ),
+            EntityAddress::DataReader(a) => Entity::DataReader(
+                a.send_actor_mail(data_reader_actor::GetInstanceHandle)?
+                    .receive_reply()
+                    .await,
+            ),
+            EntityAddress::DataWriter(a) => Entity::DataWriter(
+                a.send_actor_mail(data_writer_actor::GetInstanceHandle)?
+                    .receive_reply()
+                    .await,
+            ),
+        })
     }
 }
 
@@ -83,3 +141,72 @@ impl StatusConditionAsync {
             .await)
     }
 }
+
+struct GuardConditionInner {
+    trigger_value: bool,
+    waker_list: Vec<Waker>,
+}
+
+/// Async version of [`GuardCondition`](crate::infrastructure::condition::GuardCondition).
+#[derive(Clone)]
+pub struct GuardConditionAsync {
+    inner: Arc<Mutex<GuardConditionInner>>,
+}
+
+impl Default for GuardConditionAsync {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(GuardConditionInner {
+                trigger_value: false,
+                waker_list: Vec::new(),
+            })),
+        }
+    }
+}
+
+impl GuardConditionAsync {
+    /// Create a new [`GuardConditionAsync`]
+    #[tracing::instrument]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the trigger value or registers the waker to be woken up when the trigger
+    /// value is set if it is not yet set.
+    pub(crate) fn register_waker(&self, waker: &Waker) -> bool {
+        let mut inner_lock = self.inner.lock().expect("Mutex shouldn't be poisoned");
+        if !inner_lock.trigger_value && !inner_lock.waker_list.iter().any(|w| w.will_wake(waker)) {
+            inner_lock.waker_list.push(waker.clone());
+        }
+        inner_lock.trigger_value
+    }
+
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl GuardConditionAsync {
+    /// Async version of [`set_trigger_value`](crate::infrastructure::condition::GuardCondition::set_trigger_value).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_trigger_value(&self, value: bool) -> DdsResult<()> {
+        let mut inner_lock = self.inner.lock().expect("Mutex shouldn't be poisoned");
+        inner_lock.trigger_value = value;
+        if value {
+            for waker in inner_lock.waker_list.drain(..) {
+                waker.wake();
+            }
+        }
+        Ok(())
+    }
+
+    /// Async version of [`get_trigger_value`](crate::infrastructure::condition::GuardCondition::get_trigger_value).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trigger_value(&self) -> DdsResult<bool> {
+        Ok(self
+            .inner
+            .lock()
+            .expect("Mutex shouldn't be poisoned")
+            .trigger_value)
+    }
+}
@@ -0,0 +1,170 @@
+use super::{
+    data_reader::DataReaderAsync, domain_participant::DomainParticipantAsync,
+    subscriber::SubscriberAsync, topic::TopicAsync,
+};
+use crate::{
+    implementation::{
+        actors::{data_reader_actor, domain_participant_actor, subscriber_actor, topic_actor},
+        xtypes_glue::member_values::get_member_paths,
+    },
+    infrastructure::error::DdsResult,
+};
+
+/// Async version of [`ContentFilteredTopic`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic).
+#[derive(Clone)]
+pub struct ContentFilteredTopicAsync {
+    name: String,
+    related_topic: TopicAsync,
+    filter_expression: String,
+}
+
+impl ContentFilteredTopicAsync {
+    pub(crate) fn new(name: String, related_topic: TopicAsync, filter_expression: String) -> Self {
+        Self {
+            name,
+            related_topic,
+            filter_expression,
+        }
+    }
+}
+
+impl ContentFilteredTopicAsync {
+    /// Async version of [`get_related_topic`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic::get_related_topic).
+    #[tracing::instrument(skip(self))]
+    pub fn get_related_topic(&self) -> TopicAsync {
+        self.related_topic.clone()
+    }
+
+    /// Async version of [`get_filter_expression`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic::get_filter_expression).
+    #[tracing::instrument(skip(self))]
+    pub fn get_filter_expression(&self) -> String {
+        self.filter_expression.clone()
+    }
+
+    /// Async version of [`get_expression_parameters`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic::get_expression_parameters).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_expression_parameters(&self) -> DdsResult<Vec<String>> {
+        Ok(self
+            .related_topic
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetContentFilteredTopic {
+                name: self.name.clone(),
+            })?
+            .receive_reply()
+            .await?
+            .filter
+            .parameters)
+    }
+
+    /// Async version of [`set_expression_parameters`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic::set_expression_parameters).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_expression_parameters(
+        &self,
+        expression_parameters: &[String],
+    ) -> DdsResult<()> {
+        let participant_address = self.related_topic.participant_address();
+        let content_filter = participant_address
+            .send_actor_mail(domain_participant_actor::GetContentFilteredTopic {
+                name: self.name.clone(),
+            })?
+            .receive_reply()
+            .await?;
+        let type_support = self
+            .related_topic
+            .topic_address()
+            .send_actor_mail(topic_actor::GetTypeSupport)?
+            .receive_reply()
+            .await;
+        content_filter.filter.expression.validate(
+            &get_member_paths(type_support.as_ref())?,
+            expression_parameters,
+        )?;
+
+        participant_address
+            .send_actor_mail(
+                domain_participant_actor::SetContentFilteredTopicParameters {
+                    name: self.name.clone(),
+                    expression_parameters: expression_parameters.to_vec(),
+                },
+            )?
+            .receive_reply()
+            .await?;
+
+        let subscriber_list = participant_address
+            .send_actor_mail(domain_participant_actor::GetSubscriberList)?
+            .receive_reply()
+            .await;
+        for subscriber_address in subscriber_list {
+            let data_reader_list = subscriber_address
+                .send_actor_mail(subscriber_actor::GetDataReaderList)?
+                .receive_reply()
+                .await;
+            for dr in data_reader_list {
+                if dr
+                    .send_actor_mail(data_reader_actor::GetContentFilteredTopicName)?
+                    .receive_reply()
+                    .await
+                    .as_ref()
+                    == Some(&self.name)
+                {
+                    dr.send_actor_mail(data_reader_actor::SetContentFilterParameters {
+                        expression_parameters: expression_parameters.to_vec(),
+                    })?
+                    .receive_reply()
+                    .await;
+
+                    // Re-announce the reader so that the matched writers apply the new filter
+                    if dr
+                        .send_actor_mail(data_reader_actor::IsEnabled)?
+                        .receive_reply()
+                        .await
+                    {
+                        let subscriber_status_condition = subscriber_address
+                            .send_actor_mail(subscriber_actor::GetStatuscondition)?
+                            .receive_reply()
+                            .await;
+                        let subscriber = SubscriberAsync::new(
+                            subscriber_address.clone(),
+                            subscriber_status_condition,
+                            self.get_participant(),
+                        );
+                        let reader_status_condition = dr
+                            .send_actor_mail(data_reader_actor::GetStatuscondition)?
+                            .receive_reply()
+                            .await;
+                        DataReaderAsync::<()>::new(
+                            dr.clone(),
+                            reader_status_condition,
+                            subscriber,
+                            self.related_topic.clone(),
+                        )
+                        .announce_reader()
+                        .await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// This implementation block represents the TopicDescription operations for the [`ContentFilteredTopicAsync`].
+impl ContentFilteredTopicAsync {
+    /// Async version of [`get_participant`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic::get_participant).
+    #[tracing::instrument(skip(self))]
+    pub fn get_participant(&self) -> DomainParticipantAsync {
+        self.related_topic.get_participant()
+    }
+
+    /// Async version of [`get_type_name`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic::get_type_name).
+    #[tracing::instrument(skip(self))]
+    pub fn get_type_name(&self) -> String {
+        self.related_topic.get_type_name()
+    }
+
+    /// Async version of [`get_name`](crate::topic_definition::content_filtered_topic::ContentFilteredTopic::get_name).
+    #[tracing::instrument(skip(self))]
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
@@ -1,20 +1,24 @@
 use super::{
-    condition::StatusConditionAsync, data_reader_listener::DataReaderListenerAsync,
-    subscriber::SubscriberAsync, topic::TopicAsync,
+    condition::{EntityAddress, StatusConditionAsync},
+    data_reader_listener::DataReaderListenerAsync,
+    query_condition::QueryConditionAsync,
+    read_condition::ReadConditionAsync,
+    subscriber::SubscriberAsync,
+    topic::TopicAsync,
 };
 use crate::{
-    builtin_topics::{PublicationBuiltinTopicData, DCPS_SUBSCRIPTION},
+    builtin_topics::PublicationBuiltinTopicData,
     implementation::{
         actor::ActorAddress,
         actors::{
             any_data_reader_listener::AnyDataReaderListener,
             data_reader_actor::{self, DataReaderActor},
-            domain_participant_actor::{self, DomainParticipantActor},
-            status_condition_actor::StatusConditionActor,
+            status_condition_actor::{self, StatusConditionActor},
             subscriber_actor::{self, SubscriberActor},
-            topic_actor,
+            topic_actor::{self, TopicActor},
         },
-        data_representation_builtin_endpoints::discovered_reader_data::DiscoveredReaderData,
+        content_filter::{ContentFilter, FilterExpression},
+        xtypes_glue::key_and_instance_handle::get_instance_handle_from_serialized_foo,
     },
     infrastructure::{
         error::{DdsError, DdsResult},
@@ -26,12 +30,14 @@ use crate::{
         },
         time::Duration,
     },
+    rtps::messages::submessage_elements::Data,
     subscription::{
         data_reader::Sample,
         sample_info::{
             InstanceStateKind, SampleStateKind, ViewStateKind, ANY_INSTANCE_STATE, ANY_VIEW_STATE,
         },
     },
+    topic_definition::type_support::{DdsDeserialize, DdsSerialize},
 };
 use std::marker::PhantomData;
 
@@ -60,10 +66,6 @@ impl<Foo> DataReaderAsync<Foo> {
         }
     }
 
-    pub(crate) fn participant_address(&self) -> &ActorAddress<DomainParticipantActor> {
-        self.subscriber.participant_address()
-    }
-
     pub(crate) fn subscriber_address(&self) -> &ActorAddress<SubscriberActor> {
         self.subscriber.subscriber_address()
     }
@@ -72,62 +74,48 @@ impl<Foo> DataReaderAsync<Foo> {
         &self.reader_address
     }
 
-    async fn announce_reader(&self) -> DdsResult<()> {
-        let builtin_publisher = self
-            .get_subscriber()
-            .get_participant()
-            .get_builtin_publisher()
-            .await?;
-        if let Some(sedp_subscriptions_announcer) = builtin_publisher
-            .lookup_datawriter::<DiscoveredReaderData>(DCPS_SUBSCRIPTION)
-            .await?
-        {
-            let subscriber_qos = self
-                .subscriber_address()
-                .send_actor_mail(subscriber_actor::GetQos)?
-                .receive_reply()
-                .await;
-            let default_unicast_locator_list = self
-                .participant_address()
-                .send_actor_mail(domain_participant_actor::GetDefaultUnicastLocatorList)?
-                .receive_reply()
-                .await;
-            let default_multicast_locator_list = self
-                .participant_address()
-                .send_actor_mail(domain_participant_actor::GetDefaultMulticastLocatorList)?
-                .receive_reply()
-                .await;
-            let topic_data = self
-                .topic
-                .topic_address()
-                .send_actor_mail(topic_actor::GetQos)?
-                .receive_reply()
-                .await
-                .topic_data;
-            let xml_type = "".to_string();//self
-                // .topic
-                // .topic_address()
-                // .send_actor_mail(topic_actor::GetTypeSupport)?
-                // .receive_reply()
-                // .await
-                // .xml_type();
-            let discovered_reader_data = self
-                .reader_address
-                .send_actor_mail(data_reader_actor::AsDiscoveredReaderData {
-                    subscriber_qos,
-                    default_unicast_locator_list,
-                    default_multicast_locator_list,
-                    topic_data,
-                    xml_type,
-                })?
-                .receive_reply()
-                .await?;
+    /// Reader of the related topics which forward their samples to this reader
+    /// if it was created from a MultiTopic.
+    async fn get_multi_topic_source_reader_list(
+        &self,
+    ) -> DdsResult<Vec<ActorAddress<DataReaderActor>>> {
+        Ok(self
+            .subscriber_address()
+            .send_actor_mail(subscriber_actor::GetMultiTopicSourceReaderList {
+                handle: self.get_instance_handle().await?,
+            })?
+            .receive_reply()
+            .await)
+    }
 
-            sedp_subscriptions_announcer
-                .write(&discovered_reader_data, None)
-                .await?;
+    pub(crate) async fn announce_reader(&self) -> DdsResult<()> {
+        // The reader of a MultiTopic is announced through the readers of its
+        // related topics since there are no writers of the MultiTopic itself
+        let multi_topic_source_reader_list = self.get_multi_topic_source_reader_list().await?;
+        if multi_topic_source_reader_list.is_empty() {
+            self.announce_data_reader(&self.reader_address, self.topic.topic_address())
+                .await
+        } else {
+            for source_reader in multi_topic_source_reader_list {
+                let source_topic = source_reader
+                    .send_actor_mail(data_reader_actor::GetTopicAddress)?
+                    .receive_reply()
+                    .await;
+                self.announce_data_reader(&source_reader, &source_topic)
+                    .await?;
+            }
+            Ok(())
         }
-        Ok(())
+    }
+
+    async fn announce_data_reader(
+        &self,
+        reader_address: &ActorAddress<DataReaderActor>,
+        topic_address: &ActorAddress<TopicActor>,
+    ) -> DdsResult<()> {
+        self.subscriber
+            .announce_data_reader(reader_address, topic_address)
+            .await
     }
 }
 
@@ -343,20 +331,225 @@ impl<Foo> DataReaderAsync<Foo> {
             .collect())
     }
 
+    /// Async version of [`read_w_condition`](crate::subscription::data_reader::DataReader::read_w_condition).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub async fn read_w_condition(
+        &self,
+        max_samples: i32,
+        a_condition: impl AsRef<ReadConditionAsync>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        let condition_id = self.check_read_condition(a_condition.as_ref())?;
+        let samples = self
+            .reader_address
+            .send_actor_mail(data_reader_actor::ReadWCondition {
+                max_samples,
+                condition_id,
+            })?
+            .receive_reply()
+            .await?;
+        Ok(samples
+            .into_iter()
+            .map(|(data, sample_info)| Sample::new(data, sample_info))
+            .collect())
+    }
+
+    /// Async version of [`take_w_condition`](crate::subscription::data_reader::DataReader::take_w_condition).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub async fn take_w_condition(
+        &self,
+        max_samples: i32,
+        a_condition: impl AsRef<ReadConditionAsync>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        let condition_id = self.check_read_condition(a_condition.as_ref())?;
+        let samples = self
+            .reader_address
+            .send_actor_mail(data_reader_actor::TakeWCondition {
+                max_samples,
+                condition_id,
+            })?
+            .receive_reply()
+            .await?;
+        Ok(samples
+            .into_iter()
+            .map(|(data, sample_info)| Sample::new(data, sample_info))
+            .collect())
+    }
+
+    /// Async version of [`read_next_instance_w_condition`](crate::subscription::data_reader::DataReader::read_next_instance_w_condition).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub async fn read_next_instance_w_condition(
+        &self,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: impl AsRef<ReadConditionAsync>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        let condition_id = self.check_read_condition(a_condition.as_ref())?;
+        let samples = self
+            .reader_address
+            .send_actor_mail(data_reader_actor::ReadNextInstanceWCondition {
+                max_samples,
+                previous_handle,
+                condition_id,
+            })?
+            .receive_reply()
+            .await?;
+        Ok(samples
+            .into_iter()
+            .map(|(data, sample_info)| Sample::new(data, sample_info))
+            .collect())
+    }
+
+    /// Async version of [`take_next_instance_w_condition`](crate::subscription::data_reader::DataReader::take_next_instance_w_condition).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub async fn take_next_instance_w_condition(
+        &self,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: impl AsRef<ReadConditionAsync>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        let condition_id = self.check_read_condition(a_condition.as_ref())?;
+        let samples = self
+            .reader_address
+            .send_actor_mail(data_reader_actor::TakeNextInstanceWCondition {
+                max_samples,
+                previous_handle,
+                condition_id,
+            })?
+            .receive_reply()
+            .await?;
+        Ok(samples
+            .into_iter()
+            .map(|(data, sample_info)| Sample::new(data, sample_info))
+            .collect())
+    }
+}
+
+impl<Foo> DataReaderAsync<Foo>
+where
+    Foo: DdsSerialize + for<'de> DdsDeserialize<'de>,
+{
     /// Async version of [`get_key_value`](crate::subscription::data_reader::DataReader::get_key_value).
-    #[tracing::instrument(skip(self, _key_holder))]
+    #[tracing::instrument(skip(self, key_holder))]
     pub async fn get_key_value(
         &self,
-        _key_holder: &mut Foo,
-        _handle: InstanceHandle,
+        key_holder: &mut Foo,
+        handle: InstanceHandle,
     ) -> DdsResult<()> {
-        todo!()
+        let serialized_data = self
+            .reader_address
+            .send_actor_mail(data_reader_actor::GetKeyValue {
+                handle,
+                serialized_key_holder: Data::from(key_holder.serialize_data()?),
+            })?
+            .receive_reply()
+            .await?;
+        *key_holder = Foo::deserialize_data(serialized_data.as_ref())?;
+        Ok(())
     }
 
     /// Async version of [`lookup_instance`](crate::subscription::data_reader::DataReader::lookup_instance).
-    #[tracing::instrument(skip(self, _instance))]
-    pub async fn lookup_instance(&self, _instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
-        todo!()
+    #[tracing::instrument(skip(self, instance))]
+    pub async fn lookup_instance(&self, instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
+        let type_support = self
+            .topic
+            .topic_address()
+            .send_actor_mail(topic_actor::GetTypeSupport)?
+            .receive_reply()
+            .await;
+
+        let serialized_foo = instance.serialize_data()?;
+        let instance_handle =
+            get_instance_handle_from_serialized_foo(&serialized_foo, type_support.as_ref())?;
+
+        Ok(self
+            .reader_address
+            .send_actor_mail(data_reader_actor::LookupInstance { instance_handle })?
+            .receive_reply()
+            .await)
+    }
+}
+
+impl<Foo> DataReaderAsync<Foo> {
+    fn check_read_condition(&self, a_condition: &ReadConditionAsync) -> DdsResult<u64> {
+        if a_condition.reader_address() == &self.reader_address {
+            Ok(a_condition.condition_id())
+        } else {
+            Err(DdsError::PreconditionNotMet(
+                "Condition does not belong to this DataReader".to_string(),
+            ))
+        }
+    }
+
+    async fn create_condition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        query: Option<ContentFilter>,
+    ) -> DdsResult<ReadConditionAsync> {
+        let condition_id = self
+            .reader_address
+            .send_actor_mail(data_reader_actor::CreateReadCondition {
+                sample_states: sample_states.to_vec(),
+                view_states: view_states.to_vec(),
+                instance_states: instance_states.to_vec(),
+                query,
+            })?
+            .receive_reply()
+            .await?;
+        Ok(ReadConditionAsync::new(
+            condition_id,
+            self.reader_address.clone(),
+            sample_states.to_vec(),
+            view_states.to_vec(),
+            instance_states.to_vec(),
+            self.subscriber.get_participant().timer_handle().clone(),
+        ))
+    }
+
+    /// Async version of [`create_readcondition`](crate::subscription::data_reader::DataReader::create_readcondition).
+    #[tracing::instrument(skip(self))]
+    pub async fn create_readcondition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<ReadConditionAsync> {
+        self.create_condition(sample_states, view_states, instance_states, None)
+            .await
+    }
+
+    /// Async version of [`create_querycondition`](crate::subscription::data_reader::DataReader::create_querycondition).
+    #[tracing::instrument(skip(self))]
+    pub async fn create_querycondition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        query_expression: String,
+        query_parameters: &[String],
+    ) -> DdsResult<QueryConditionAsync> {
+        let query = ContentFilter {
+            expression: FilterExpression::parse(&query_expression)?,
+            parameters: query_parameters.to_vec(),
+        };
+        let read_condition = self
+            .create_condition(sample_states, view_states, instance_states, Some(query))
+            .await?;
+        Ok(QueryConditionAsync::new(read_condition, query_expression))
+    }
+
+    /// Async version of [`delete_readcondition`](crate::subscription::data_reader::DataReader::delete_readcondition).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub async fn delete_readcondition(
+        &self,
+        a_condition: impl AsRef<ReadConditionAsync>,
+    ) -> DdsResult<()> {
+        let condition_id = self.check_read_condition(a_condition.as_ref())?;
+        self.reader_address
+            .send_actor_mail(data_reader_actor::DeleteReadCondition { condition_id })?
+            .receive_reply()
+            .await
     }
 }
 
@@ -364,7 +557,11 @@ impl<Foo> DataReaderAsync<Foo> {
     /// Async version of [`get_liveliness_changed_status`](crate::subscription::data_reader::DataReader::get_liveliness_changed_status).
     #[tracing::instrument(skip(self))]
     pub async fn get_liveliness_changed_status(&self) -> DdsResult<LivelinessChangedStatus> {
-        todo!()
+        Ok(self
+            .reader_address
+            .send_actor_mail(data_reader_actor::GetLivelinessChangedStatus)?
+            .receive_reply()
+            .await)
     }
 
     /// Async version of [`get_requested_deadline_missed_status`](crate::subscription::data_reader::DataReader::get_requested_deadline_missed_status).
@@ -372,7 +569,11 @@ impl<Foo> DataReaderAsync<Foo> {
     pub async fn get_requested_deadline_missed_status(
         &self,
     ) -> DdsResult<RequestedDeadlineMissedStatus> {
-        todo!()
+        Ok(self
+            .reader_address
+            .send_actor_mail(data_reader_actor::GetRequestedDeadlineMissedStatus)?
+            .receive_reply()
+            .await)
     }
 
     /// Async version of [`get_requested_incompatible_qos_status`](crate::subscription::data_reader::DataReader::get_requested_incompatible_qos_status).
@@ -380,19 +581,31 @@ impl<Foo> DataReaderAsync<Foo> {
     pub async fn get_requested_incompatible_qos_status(
         &self,
     ) -> DdsResult<RequestedIncompatibleQosStatus> {
-        todo!()
+        Ok(self
+            .reader_address
+            .send_actor_mail(data_reader_actor::GetRequestedIncompatibleQosStatus)?
+            .receive_reply()
+            .await)
     }
 
     /// Async version of [`get_sample_lost_status`](crate::subscription::data_reader::DataReader::get_sample_lost_status).
     #[tracing::instrument(skip(self))]
     pub async fn get_sample_lost_status(&self) -> DdsResult<SampleLostStatus> {
-        todo!()
+        Ok(self
+            .reader_address
+            .send_actor_mail(data_reader_actor::GetSampleLostStatus)?
+            .receive_reply()
+            .await)
     }
 
     /// Async version of [`get_sample_rejected_status`](crate::subscription::data_reader::DataReader::get_sample_rejected_status).
     #[tracing::instrument(skip(self))]
     pub async fn get_sample_rejected_status(&self) -> DdsResult<SampleRejectedStatus> {
-        todo!()
+        Ok(self
+            .reader_address
+            .send_actor_mail(data_reader_actor::GetSampleRejectedStatus)?
+            .receive_reply()
+            .await)
     }
 
     /// Async version of [`get_subscription_matched_status`](crate::subscription::data_reader::DataReader::get_subscription_matched_status).
@@ -509,6 +722,7 @@ impl<Foo> DataReaderAsync<Foo> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            EntityAddress::DataReader(self.reader_address.clone()),
             self.subscriber.get_participant().executor_handle().clone(),
             self.subscriber.get_participant().timer_handle().clone(),
         )
@@ -517,7 +731,11 @@ impl<Foo> DataReaderAsync<Foo> {
     /// Async version of [`get_status_changes`](crate::subscription::data_reader::DataReader::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        Ok(self
+            .status_condition_address
+            .send_actor_mail(status_condition_actor::GetStatusChanges)?
+            .receive_reply()
+            .await)
     }
 
     /// Async version of [`enable`](crate::subscription::data_reader::DataReader::enable).
@@ -533,6 +751,12 @@ impl<Foo> DataReaderAsync<Foo> {
                 .send_actor_mail(data_reader_actor::Enable)?
                 .receive_reply()
                 .await;
+            for source_reader in self.get_multi_topic_source_reader_list().await? {
+                source_reader
+                    .send_actor_mail(data_reader_actor::Enable)?
+                    .receive_reply()
+                    .await;
+            }
 
             self.announce_reader().await?;
         }
@@ -1,9 +1,11 @@
 use super::{
-    condition::StatusConditionAsync, data_writer_listener::DataWriterListenerAsync,
-    publisher::PublisherAsync, topic::TopicAsync,
+    condition::{EntityAddress, StatusConditionAsync},
+    data_writer_listener::DataWriterListenerAsync,
+    publisher::PublisherAsync,
+    topic::TopicAsync,
 };
 use crate::{
-    builtin_topics::{SubscriptionBuiltinTopicData, DCPS_PUBLICATION},
+    builtin_topics::SubscriptionBuiltinTopicData,
     implementation::{
         actor::ActorAddress,
         actors::{
@@ -11,10 +13,9 @@ use crate::{
             data_writer_actor::{self, DataWriterActor},
             domain_participant_actor::{self, DomainParticipantActor},
             publisher_actor::{self, PublisherActor},
-            status_condition_actor::StatusConditionActor,
+            status_condition_actor::{self, StatusConditionActor},
             topic_actor,
         },
-        data_representation_builtin_endpoints::discovered_writer_data::DiscoveredWriterData,
         data_representation_inline_qos::{
             parameter_id_values::{PID_KEY_HASH, PID_STATUS_INFO},
             types::{
@@ -29,7 +30,7 @@ use crate::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DataWriterQos, QosKind},
-        qos_policy::ReliabilityQosPolicyKind,
+        qos_policy::{DurabilityQosPolicyKind, LivelinessQosPolicyKind, ReliabilityQosPolicyKind},
         status::{
             LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
             PublicationMatchedStatus, StatusKind,
@@ -40,7 +41,7 @@ use crate::{
         messages::submessage_elements::{Data, Parameter, ParameterList},
         types::ChangeKind,
     },
-    topic_definition::type_support::DdsSerialize,
+    topic_definition::type_support::{DdsDeserialize, DdsSerialize},
     xtypes::{serialize::XTypesSerialize, xcdr_serializer::Xcdr1LeSerializer},
 };
 use std::{marker::PhantomData, sync::Arc};
@@ -94,58 +95,40 @@ impl<Foo> DataWriterAsync<Foo> {
         &self.writer_address
     }
 
-    async fn announce_writer(&self) -> DdsResult<()> {
-        let builtin_publisher = self
-            .get_publisher()
-            .get_participant()
-            .get_builtin_publisher()
-            .await?;
-        if let Some(sedp_publications_announcer) = builtin_publisher
-            .lookup_datawriter::<DiscoveredWriterData>(DCPS_PUBLICATION)
-            .await?
+    async fn attach_persistent_storage(&self) -> DdsResult<()> {
+        if let Some(persistent_storage) = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetPersistentStorage)?
+            .receive_reply()
+            .await
         {
-            let publisher_qos = self.get_publisher().get_qos().await?;
-            let default_unicast_locator_list = self
+            // The stored samples are already served by the durability service if a
+            // previous writer of the topic was deleted by this application
+            let replay_stored_changes = !self
                 .participant_address()
-                .send_actor_mail(domain_participant_actor::GetDefaultUnicastLocatorList)?
+                .send_actor_mail(
+                    domain_participant_actor::IsPersistentTopicInDurabilityService {
+                        topic_name: self.topic.get_name(),
+                    },
+                )?
                 .receive_reply()
                 .await;
-            let default_multicast_locator_list = self
-                .participant_address()
-                .send_actor_mail(domain_participant_actor::GetDefaultMulticastLocatorList)?
-                .receive_reply()
-                .await;
-            let topic_data = self
-                .topic
-                .topic_address()
-                .send_actor_mail(topic_actor::GetQos)?
-                .receive_reply()
-                .await
-                .topic_data;
-            let xml_type = "".to_string(); //self
-                                           //     .topic
-                                           //     .topic_address()
-                                           //     .send_actor_mail(topic_actor::GetTypeSupport)?
-                                           //     .receive_reply()
-                                           //     .await
-                                           //     .xml_type();
-            let discovered_writer_data = self
-                .writer_address
-                .send_actor_mail(data_writer_actor::AsDiscoveredWriterData {
-                    publisher_qos,
-                    default_unicast_locator_list,
-                    default_multicast_locator_list,
-                    topic_data,
-                    xml_type,
+            self.writer_address
+                .send_actor_mail(data_writer_actor::AttachPersistentStorage {
+                    persistent_storage,
+                    replay_stored_changes,
                 })?
                 .receive_reply()
                 .await?;
-            sedp_publications_announcer
-                .write(&discovered_writer_data, None)
-                .await?;
         }
         Ok(())
     }
+
+    async fn announce_writer(&self) -> DdsResult<()> {
+        self.publisher
+            .announce_data_writer(&self.writer_address, self.topic.topic_address())
+            .await
+    }
 }
 
 impl<Foo> DataWriterAsync<Foo>
@@ -190,9 +173,15 @@ where
         let serialized_data = instance.serialize_data()?;
         let instance_handle =
             get_instance_handle_from_serialized_foo(&serialized_data, type_support.as_ref())?;
+        // Only the key is kept since it is all that identifies the instance
+        let serialized_key =
+            get_serialized_key_from_serialized_foo(&serialized_data, type_support.as_ref())?;
 
         self.writer_address
-            .send_actor_mail(data_writer_actor::RegisterInstanceWTimestamp { instance_handle })?
+            .send_actor_mail(data_writer_actor::RegisterInstanceWTimestamp {
+                instance_handle,
+                serialized_key: Data::from(serialized_key),
+            })?
             .receive_reply()
             .await
     }
@@ -311,7 +300,13 @@ where
         }
         let pid_status_info = Parameter::new(PID_STATUS_INFO, Arc::from(serialized_status_info));
         let pid_key_hash = Parameter::new(PID_KEY_HASH, Arc::from(*instance_handle.as_ref()));
-        let inline_qos = ParameterList::new(vec![pid_status_info, pid_key_hash]);
+        let group_inline_qos = self
+            .publisher_address()
+            .send_actor_mail(publisher_actor::GetGroupInlineQos)?
+            .receive_reply()
+            .await;
+        let inline_qos =
+            ParameterList::new([vec![pid_status_info, pid_key_hash], group_inline_qos].concat());
 
         let change = self
             .writer_address
@@ -354,13 +349,21 @@ where
     }
 
     /// Async version of [`get_key_value`](crate::publication::data_writer::DataWriter::get_key_value).
-    #[tracing::instrument(skip(self, _key_holder))]
-    pub async fn get_key_value(
-        &self,
-        _key_holder: &mut Foo,
-        _handle: InstanceHandle,
-    ) -> DdsResult<()> {
-        todo!()
+    #[tracing::instrument(skip(self, key_holder))]
+    pub async fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()>
+    where
+        Foo: for<'de> DdsDeserialize<'de>,
+    {
+        let serialized_data = self
+            .writer_address
+            .send_actor_mail(data_writer_actor::GetKeyValue {
+                handle,
+                serialized_key_holder: Data::from(key_holder.serialize_data()?),
+            })?
+            .receive_reply()
+            .await?;
+        *key_holder = Foo::deserialize_data(serialized_data.as_ref())?;
+        Ok(())
     }
 
     /// Async version of [`lookup_instance`](crate::publication::data_writer::DataWriter::lookup_instance).
@@ -437,16 +440,38 @@ where
             .await;
 
         let instance_handle = match handle {
-            Some(_) => todo!(),
-            None => self.register_instance_w_timestamp(data, timestamp),
-        }
-        .await?
-        .ok_or(DdsError::PreconditionNotMet(
-            "Failed to register instance".to_string(),
-        ))?;
+            Some(h) => {
+                if self
+                    .writer_address
+                    .send_actor_mail(data_writer_actor::LookupInstance { instance_handle: h })?
+                    .receive_reply()
+                    .await?
+                    .is_none()
+                {
+                    return Err(DdsError::BadParameter);
+                }
+                if h != key {
+                    return Err(DdsError::PreconditionNotMet(
+                        "Handle does not match instance".to_string(),
+                    ));
+                }
+                h
+            }
+            None => self
+                .register_instance_w_timestamp(data, timestamp)
+                .await?
+                .ok_or(DdsError::PreconditionNotMet(
+                    "Failed to register instance".to_string(),
+                ))?,
+        };
 
         let pid_key_hash = Parameter::new(PID_KEY_HASH, Arc::from(*instance_handle.as_ref()));
-        let parameter_list = ParameterList::new(vec![pid_key_hash]);
+        let group_inline_qos = self
+            .publisher_address()
+            .send_actor_mail(publisher_actor::GetGroupInlineQos)?
+            .receive_reply()
+            .await;
+        let parameter_list = ParameterList::new([vec![pid_key_hash], group_inline_qos].concat());
 
         let change = self
             .writer_address
@@ -620,7 +645,13 @@ where
 
         let pid_status_info = Parameter::new(PID_STATUS_INFO, Arc::from(serialized_status_info));
         let pid_key_hash = Parameter::new(PID_KEY_HASH, Arc::from(*instance_handle.as_ref()));
-        let inline_qos = ParameterList::new(vec![pid_status_info, pid_key_hash]);
+        let group_inline_qos = self
+            .publisher_address()
+            .send_actor_mail(publisher_actor::GetGroupInlineQos)?
+            .receive_reply()
+            .await;
+        let inline_qos =
+            ParameterList::new([vec![pid_status_info, pid_key_hash], group_inline_qos].concat());
 
         let change = self
             .writer_address
@@ -692,7 +723,11 @@ impl<Foo> DataWriterAsync<Foo> {
     /// Async version of [`get_liveliness_lost_status`](crate::publication::data_writer::DataWriter::get_liveliness_lost_status).
     #[tracing::instrument(skip(self))]
     pub async fn get_liveliness_lost_status(&self) -> DdsResult<LivelinessLostStatus> {
-        todo!()
+        Ok(self
+            .writer_address
+            .send_actor_mail(data_writer_actor::GetLivelinessLostStatus)?
+            .receive_reply()
+            .await)
     }
 
     /// Async version of [`get_offered_deadline_missed_status`](crate::publication::data_writer::DataWriter::get_offered_deadline_missed_status).
@@ -714,7 +749,11 @@ impl<Foo> DataWriterAsync<Foo> {
     pub async fn get_offered_incompatible_qos_status(
         &self,
     ) -> DdsResult<OfferedIncompatibleQosStatus> {
-        todo!()
+        Ok(self
+            .writer_address
+            .send_actor_mail(data_writer_actor::GetOfferedIncompatibleQosStatus)?
+            .receive_reply()
+            .await)
     }
 
     /// Async version of [`get_publication_matched_status`](crate::publication::data_writer::DataWriter::get_publication_matched_status).
@@ -742,7 +781,26 @@ impl<Foo> DataWriterAsync<Foo> {
     /// Async version of [`assert_liveliness`](crate::publication::data_writer::DataWriter::assert_liveliness).
     #[tracing::instrument(skip(self))]
     pub async fn assert_liveliness(&self) -> DdsResult<()> {
-        todo!()
+        let message_sender_actor = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetMessageSender)?
+            .receive_reply()
+            .await;
+        self.writer_address
+            .send_actor_mail(data_writer_actor::AssertLiveliness {
+                writer_address: self.writer_address.clone(),
+                message_sender_actor,
+                executor_handle: self.publisher.get_participant().executor_handle().clone(),
+                timer_handle: self.publisher.get_participant().timer_handle().clone(),
+            })?
+            .receive_reply()
+            .await?;
+
+        // Asserting the liveliness of a MANUAL_BY_PARTICIPANT writer asserts the liveliness of its participant
+        if self.get_qos().await?.liveliness.kind == LivelinessQosPolicyKind::ManualByParticipant {
+            self.publisher.get_participant().assert_liveliness().await?;
+        }
+        Ok(())
     }
 
     /// Async version of [`get_matched_subscription_data`](crate::publication::data_writer::DataWriter::get_matched_subscription_data).
@@ -816,6 +874,7 @@ impl<Foo> DataWriterAsync<Foo> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            EntityAddress::DataWriter(self.writer_address.clone()),
             self.publisher.get_participant().executor_handle().clone(),
             self.publisher.get_participant().timer_handle().clone(),
         )
@@ -824,7 +883,11 @@ impl<Foo> DataWriterAsync<Foo> {
     /// Async version of [`get_status_changes`](crate::publication::data_writer::DataWriter::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        Ok(self
+            .status_condition_address
+            .send_actor_mail(status_condition_actor::GetStatusChanges)?
+            .receive_reply()
+            .await)
     }
 
     /// Async version of [`enable`](crate::publication::data_writer::DataWriter::enable).
@@ -836,6 +899,11 @@ impl<Foo> DataWriterAsync<Foo> {
             .receive_reply()
             .await
         {
+            let qos = self.get_qos().await?;
+            if qos.durability.kind == DurabilityQosPolicyKind::Persistent {
+                self.attach_persistent_storage().await?;
+            }
+
             let message_sender_actor = self
                 .participant_address()
                 .send_actor_mail(domain_participant_actor::GetMessageSender)?
@@ -851,6 +919,22 @@ impl<Foo> DataWriterAsync<Foo> {
                 .receive_reply()
                 .await;
 
+            // The participant periodically asserts the liveliness of its AUTOMATIC writers
+            if let (LivelinessQosPolicyKind::Automatic, DurationKind::Finite(lease_duration)) =
+                (qos.liveliness.kind, qos.liveliness.lease_duration)
+            {
+                self.participant_address()
+                    .send_actor_mail(
+                        domain_participant_actor::RegisterAutomaticLivelinessWriter {
+                            writer_handle: self.get_instance_handle().await?,
+                            lease_duration: lease_duration.into(),
+                            participant: self.publisher.get_participant().clone(),
+                        },
+                    )?
+                    .receive_reply()
+                    .await;
+            }
+
             self.announce_writer().await?;
         }
         Ok(())
@@ -1,7 +1,13 @@
 use super::{
-    condition::StatusConditionAsync, domain_participant_listener::DomainParticipantListenerAsync,
-    publisher::PublisherAsync, publisher_listener::PublisherListenerAsync,
-    subscriber::SubscriberAsync, subscriber_listener::SubscriberListenerAsync, topic::TopicAsync,
+    condition::{EntityAddress, StatusConditionAsync},
+    content_filtered_topic::ContentFilteredTopicAsync,
+    domain_participant_listener::DomainParticipantListenerAsync,
+    multi_topic::MultiTopicAsync,
+    publisher::PublisherAsync,
+    publisher_listener::PublisherListenerAsync,
+    subscriber::SubscriberAsync,
+    subscriber_listener::SubscriberListenerAsync,
+    topic::TopicAsync,
     topic_listener::TopicListenerAsync,
 };
 use crate::{
@@ -19,8 +25,20 @@ use crate::{
             subscriber_actor::{self, SubscriberActor},
             topic_actor::{self, TopicActor},
         },
-        data_representation_builtin_endpoints::spdp_discovered_participant_data::SpdpDiscoveredParticipantData,
+        content_filter::{
+            ContentFilter, ContentFilterProperty, FilterExpression, SubscriptionExpression,
+        },
+        data_representation_builtin_endpoints::{
+            discovered_topic_data::DiscoveredTopicData,
+            participant_message_data::{
+                ParticipantMessageData, DCPS_PARTICIPANT_MESSAGE,
+                PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE,
+            },
+            spdp_discovered_participant_data::SpdpDiscoveredParticipantData,
+        },
+        multi_topic::MultiTopicProperty,
         runtime::{executor::ExecutorHandle, timer::TimerHandle},
+        xtypes_glue::member_values::get_member_paths,
     },
     infrastructure::{
         error::{DdsError, DdsResult},
@@ -29,6 +47,8 @@ use crate::{
         status::StatusKind,
         time::{Duration, Time},
     },
+    rtps::types::Guid,
+    subscription::sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
     topic_definition::type_support::TypeSupport,
     xtypes::dynamic_type::DynamicType,
 };
@@ -103,6 +123,36 @@ impl DomainParticipantAsync {
         Ok(())
     }
 
+    pub(crate) async fn announce_participant_liveliness(&self, kind: [u8; 4]) -> DdsResult<()> {
+        if self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::IsEnabled)?
+            .receive_reply()
+            .await
+        {
+            let builtin_publisher = self.get_builtin_publisher().await?;
+
+            if let Some(participant_message_writer) = builtin_publisher
+                .lookup_datawriter::<ParticipantMessageData>(DCPS_PARTICIPANT_MESSAGE)
+                .await?
+            {
+                let participant_handle = self
+                    .participant_address
+                    .send_actor_mail(domain_participant_actor::GetInstanceHandle)?
+                    .receive_reply()
+                    .await;
+                let data = ParticipantMessageData {
+                    participant_guid_prefix: Guid::from(<[u8; 16]>::from(participant_handle))
+                        .prefix(),
+                    kind,
+                    data: vec![],
+                };
+                participant_message_writer.write(&data, None).await?;
+            }
+        }
+        Ok(())
+    }
+
     async fn announce_deleted_topic(&self, topic: Actor<TopicActor>) -> DdsResult<()> {
         let builtin_publisher = self.get_builtin_publisher().await?;
 
@@ -343,6 +393,30 @@ impl DomainParticipantAsync {
             if BUILT_IN_TOPIC_NAME_LIST.contains(&topic_name.as_str()) {
                 return Ok(());
             }
+            if self
+                .participant_address
+                .send_actor_mail(domain_participant_actor::HasContentFilteredTopics {
+                    related_topic_name: topic_name.clone(),
+                })?
+                .receive_reply()
+                .await
+            {
+                return Err(DdsError::PreconditionNotMet(
+                    "Topic still related to some content filtered topic".to_string(),
+                ));
+            }
+            if self
+                .participant_address
+                .send_actor_mail(domain_participant_actor::HasMultiTopics {
+                    related_topic_name: topic_name.clone(),
+                })?
+                .receive_reply()
+                .await
+            {
+                return Err(DdsError::PreconditionNotMet(
+                    "Topic still related to some multi topic".to_string(),
+                ));
+            }
             let publisher_list = self
                 .participant_address
                 .send_actor_mail(domain_participant_actor::GetPublisherList)?
@@ -408,6 +482,208 @@ impl DomainParticipantAsync {
         }
     }
 
+    /// Async version of [`create_contentfilteredtopic`](crate::domain::domain_participant::DomainParticipant::create_contentfilteredtopic).
+    #[tracing::instrument(skip(self, related_topic))]
+    pub async fn create_contentfilteredtopic(
+        &self,
+        name: &str,
+        related_topic: &TopicAsync,
+        filter_expression: String,
+        expression_parameters: &[String],
+    ) -> DdsResult<ContentFilteredTopicAsync> {
+        if related_topic.participant_address() != &self.participant_address {
+            return Err(DdsError::PreconditionNotMet(
+                "Related topic must belong to the same participant".to_string(),
+            ));
+        }
+        let expression = FilterExpression::parse(&filter_expression)?;
+        let type_support = related_topic
+            .topic_address()
+            .send_actor_mail(topic_actor::GetTypeSupport)?
+            .receive_reply()
+            .await;
+        expression.validate(
+            &get_member_paths(type_support.as_ref())?,
+            expression_parameters,
+        )?;
+
+        self.participant_address
+            .send_actor_mail(domain_participant_actor::CreateContentFilteredTopic {
+                content_filter: ContentFilterProperty {
+                    content_filtered_topic_name: name.to_string(),
+                    related_topic_name: related_topic.get_name(),
+                    filter_expression: filter_expression.clone(),
+                    filter: ContentFilter {
+                        expression,
+                        parameters: expression_parameters.to_vec(),
+                    },
+                },
+            })?
+            .receive_reply()
+            .await?;
+
+        Ok(ContentFilteredTopicAsync::new(
+            name.to_string(),
+            related_topic.clone(),
+            filter_expression,
+        ))
+    }
+
+    /// Async version of [`delete_contentfilteredtopic`](crate::domain::domain_participant::DomainParticipant::delete_contentfilteredtopic).
+    #[tracing::instrument(skip(self, a_contentfilteredtopic))]
+    pub async fn delete_contentfilteredtopic(
+        &self,
+        a_contentfilteredtopic: &ContentFilteredTopicAsync,
+    ) -> DdsResult<()> {
+        if a_contentfilteredtopic
+            .get_related_topic()
+            .participant_address()
+            != &self.participant_address
+        {
+            return Err(DdsError::PreconditionNotMet(
+                "Content filtered topic can only be deleted from its parent participant"
+                    .to_string(),
+            ));
+        }
+        let name = a_contentfilteredtopic.get_name();
+        let subscriber_list = self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::GetSubscriberList)?
+            .receive_reply()
+            .await;
+        for subscriber in subscriber_list {
+            let data_reader_list = subscriber
+                .send_actor_mail(subscriber_actor::GetDataReaderList)?
+                .receive_reply()
+                .await;
+            for dr in data_reader_list {
+                if dr
+                    .send_actor_mail(data_reader_actor::GetContentFilteredTopicName)?
+                    .receive_reply()
+                    .await
+                    .as_ref()
+                    == Some(&name)
+                {
+                    return Err(DdsError::PreconditionNotMet(
+                        "Content filtered topic still attached to some data reader".to_string(),
+                    ));
+                }
+            }
+        }
+
+        self.participant_address
+            .send_actor_mail(domain_participant_actor::DeleteContentFilteredTopic { name })?
+            .receive_reply()
+            .await
+    }
+
+    /// Async version of [`create_multitopic`](crate::domain::domain_participant::DomainParticipant::create_multitopic).
+    #[tracing::instrument(skip(self))]
+    pub async fn create_multitopic<Foo>(
+        &self,
+        name: &str,
+        type_name: &str,
+        subscription_expression: String,
+        expression_parameters: &[String],
+    ) -> DdsResult<MultiTopicAsync>
+    where
+        Foo: TypeSupport,
+    {
+        let type_support = Arc::new(Foo::get_type());
+
+        self.create_dynamic_multitopic(
+            name,
+            type_name,
+            subscription_expression,
+            expression_parameters,
+            type_support,
+        )
+        .await
+    }
+
+    #[doc(hidden)]
+    #[tracing::instrument(skip(self, dynamic_type_representation))]
+    pub async fn create_dynamic_multitopic(
+        &self,
+        name: &str,
+        type_name: &str,
+        subscription_expression: String,
+        expression_parameters: &[String],
+        dynamic_type_representation: Arc<dyn DynamicType + Send + Sync>,
+    ) -> DdsResult<MultiTopicAsync> {
+        let multi_topic = MultiTopicProperty {
+            multi_topic_name: name.to_string(),
+            type_name: type_name.to_string(),
+            expression: SubscriptionExpression::parse(&subscription_expression)?,
+            subscription_expression: subscription_expression.clone(),
+            expression_parameters: expression_parameters.to_vec(),
+        };
+        MultiTopicAsync::create_join(self, &multi_topic, dynamic_type_representation.clone())
+            .await?;
+
+        let (topic_address, topic_status_condition) = self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::CreateMultiTopic {
+                multi_topic,
+                type_support: dynamic_type_representation,
+                executor_handle: self.executor_handle.clone(),
+            })?
+            .receive_reply()
+            .await?;
+        let topic = TopicAsync::new(
+            topic_address,
+            topic_status_condition,
+            type_name.to_string(),
+            name.to_string(),
+            self.clone(),
+        );
+
+        Ok(MultiTopicAsync::new(topic, subscription_expression))
+    }
+
+    /// Async version of [`delete_multitopic`](crate::domain::domain_participant::DomainParticipant::delete_multitopic).
+    #[tracing::instrument(skip(self, a_multitopic))]
+    pub async fn delete_multitopic(&self, a_multitopic: &MultiTopicAsync) -> DdsResult<()> {
+        if a_multitopic.topic().participant_address() != &self.participant_address {
+            return Err(DdsError::PreconditionNotMet(
+                "Multi topic can only be deleted from its parent participant".to_string(),
+            ));
+        }
+        let name = a_multitopic.get_name();
+        let subscriber_list = self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::GetSubscriberList)?
+            .receive_reply()
+            .await;
+        for subscriber in subscriber_list {
+            let data_reader_list = subscriber
+                .send_actor_mail(subscriber_actor::GetDataReaderList)?
+                .receive_reply()
+                .await;
+            for dr in data_reader_list {
+                if dr
+                    .send_actor_mail(data_reader_actor::GetMultiTopicName)?
+                    .receive_reply()
+                    .await
+                    .as_ref()
+                    == Some(&name)
+                {
+                    return Err(DdsError::PreconditionNotMet(
+                        "Multi topic still attached to some data reader".to_string(),
+                    ));
+                }
+            }
+        }
+
+        let deleted_topic = self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::DeleteMultiTopic { name })?
+            .receive_reply()
+            .await?;
+        deleted_topic.stop().await;
+        Ok(())
+    }
+
     /// Async version of [`find_topic`](crate::domain::domain_participant::DomainParticipant::find_topic).
     #[tracing::instrument(skip(self))]
     pub async fn find_topic<Foo>(
@@ -505,7 +781,36 @@ impl DomainParticipantAsync {
     /// Async version of [`ignore_topic`](crate::domain::domain_participant::DomainParticipant::ignore_topic).
     #[tracing::instrument(skip(self))]
     pub async fn ignore_topic(&self, handle: InstanceHandle) -> DdsResult<()> {
-        todo!()
+        // A topic announced on the DCPSTopic built-in topic can be ignored before
+        // the participant processes its discovery data
+        let topic_name = match self
+            .get_builtin_subscriber()
+            .lookup_datareader::<DiscoveredTopicData>(DCPS_TOPIC)
+            .await?
+        {
+            Some(sedp_topics_detector) => sedp_topics_detector
+                .read_instance(
+                    1,
+                    handle,
+                    ANY_SAMPLE_STATE,
+                    ANY_VIEW_STATE,
+                    ANY_INSTANCE_STATE,
+                )
+                .await
+                .ok()
+                .and_then(|samples| samples.into_iter().next())
+                .and_then(|sample| sample.data().ok())
+                .map(|data| data.topic_builtin_topic_data.name().to_owned()),
+            None => None,
+        };
+        self.participant_address
+            .send_actor_mail(domain_participant_actor::IgnoreTopic {
+                handle,
+                topic_name,
+                participant: self.clone(),
+            })?
+            .receive_reply()
+            .await
     }
 
     /// Async version of [`ignore_publication`](crate::domain::domain_participant::DomainParticipant::ignore_publication).
@@ -580,13 +885,36 @@ impl DomainParticipantAsync {
             self.announce_deleted_topic(deleted_topic).await?;
         }
 
+        for deleted_multi_topic in self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::DrainMultiTopicList)?
+            .receive_reply()
+            .await
+        {
+            deleted_multi_topic.stop().await;
+        }
+
         Ok(())
     }
 
     /// Async version of [`assert_liveliness`](crate::domain::domain_participant::DomainParticipant::assert_liveliness).
     #[tracing::instrument(skip(self))]
     pub async fn assert_liveliness(&self) -> DdsResult<()> {
-        todo!()
+        if !self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::IsEnabled)?
+            .receive_reply()
+            .await
+        {
+            return Err(DdsError::NotEnabled);
+        }
+
+        self.participant_address
+            .send_actor_mail(domain_participant_actor::AssertLiveliness)?
+            .receive_reply()
+            .await;
+        self.announce_participant_liveliness(PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE)
+            .await
     }
 
     /// Async version of [`set_default_publisher_qos`](crate::domain::domain_participant::DomainParticipant::set_default_publisher_qos).
@@ -694,8 +1022,84 @@ impl DomainParticipantAsync {
 
     /// Async version of [`contains_entity`](crate::domain::domain_participant::DomainParticipant::contains_entity).
     #[tracing::instrument(skip(self))]
-    pub async fn contains_entity(&self, _a_handle: InstanceHandle) -> DdsResult<bool> {
-        todo!()
+    pub async fn contains_entity(&self, a_handle: InstanceHandle) -> DdsResult<bool> {
+        let publisher_list = self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::GetPublisherList)?
+            .receive_reply()
+            .await;
+        for publisher in publisher_list {
+            if publisher
+                .send_actor_mail(publisher_actor::GetInstanceHandle)?
+                .receive_reply()
+                .await
+                == a_handle
+            {
+                return Ok(true);
+            }
+            let data_writer_list = publisher
+                .send_actor_mail(publisher_actor::GetDataWriterList)?
+                .receive_reply()
+                .await;
+            for dw in data_writer_list {
+                if dw
+                    .send_actor_mail(data_writer_actor::GetInstanceHandle)?
+                    .receive_reply()
+                    .await
+                    == a_handle
+                {
+                    return Ok(true);
+                }
+            }
+        }
+
+        let subscriber_list = self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::GetSubscriberList)?
+            .receive_reply()
+            .await;
+        for subscriber in subscriber_list {
+            if subscriber
+                .send_actor_mail(subscriber_actor::GetInstanceHandle)?
+                .receive_reply()
+                .await
+                == a_handle
+            {
+                return Ok(true);
+            }
+            let data_reader_list = subscriber
+                .send_actor_mail(subscriber_actor::GetDataReaderList)?
+                .receive_reply()
+                .await;
+            for dr in data_reader_list {
+                if dr
+                    .send_actor_mail(data_reader_actor::GetInstanceHandle)?
+                    .receive_reply()
+                    .await
+                    == a_handle
+                {
+                    return Ok(true);
+                }
+            }
+        }
+
+        let topic_list = self
+            .participant_address
+            .send_actor_mail(domain_participant_actor::GetTopicList)?
+            .receive_reply()
+            .await;
+        for topic in topic_list {
+            if topic
+                .send_actor_mail(topic_actor::GetInstanceHandle)?
+                .receive_reply()
+                .await
+                == a_handle
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
     }
 
     /// Async version of [`get_current_time`](crate::domain::domain_participant::DomainParticipant::get_current_time).
@@ -756,6 +1160,7 @@ impl DomainParticipantAsync {
     pub fn get_statuscondition(&self) -> StatusConditionAsync {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            EntityAddress::DomainParticipant(self.participant_address.clone()),
             self.executor_handle.clone(),
             self.timer_handle.clone(),
         )
@@ -11,7 +11,7 @@ use crate::{
         actors::{
             domain_participant_actor,
             domain_participant_factory_actor::{self, DomainParticipantFactoryActor},
-            subscriber_actor,
+            message_sender_actor, subscriber_actor,
         },
         data_representation_builtin_endpoints::spdp_discovered_participant_data::SpdpDiscoveredParticipantData,
         runtime::executor::Executor,
@@ -21,7 +21,10 @@ use crate::{
         qos::{DomainParticipantFactoryQos, DomainParticipantQos, QosKind},
         status::StatusKind,
     },
+    persistent_storage::PersistentStorage,
+    transport::TransportFactory,
 };
+use std::sync::Arc;
 
 /// Async version of [`DomainParticipantFactory`](crate::domain::domain_participant_factory::DomainParticipantFactory).
 /// Unlike the sync version, the [`DomainParticipantFactoryAsync`] is not a singleton and can be created by means of
@@ -59,6 +62,19 @@ impl DomainParticipantFactoryAsync {
         qos: QosKind<DomainParticipantQos>,
         a_listener: Option<Box<dyn DomainParticipantListenerAsync + Send + 'static>>,
         mask: &[StatusKind],
+    ) -> DdsResult<DomainParticipantAsync> {
+        self.create_participant_with_transports(domain_id, qos, a_listener, mask, Vec::new())
+            .await
+    }
+
+    /// Async version of [`create_participant_with_transports`](crate::domain::domain_participant_factory::DomainParticipantFactory::create_participant_with_transports).
+    pub async fn create_participant_with_transports(
+        &self,
+        domain_id: DomainId,
+        qos: QosKind<DomainParticipantQos>,
+        a_listener: Option<Box<dyn DomainParticipantListenerAsync + Send + 'static>>,
+        mask: &[StatusKind],
+        transport_factory_list: Vec<(i32, Arc<dyn TransportFactory>)>,
     ) -> DdsResult<DomainParticipantAsync> {
         let status_kind = mask.to_vec();
         let participant_address = self
@@ -68,6 +84,7 @@ impl DomainParticipantFactoryAsync {
                 qos,
                 listener: a_listener,
                 status_kind,
+                transport_factory_list,
             })
             .receive_reply()
             .await?;
@@ -139,6 +156,15 @@ impl DomainParticipantFactoryAsync {
                     .await;
                 spdp_participant_writer.dispose(&data, None).await?;
             }
+            // The transports are closed once the last messages of the participant are sent
+            let message_sender_actor = deleted_participant
+                .send_actor_mail(domain_participant_actor::GetMessageSender)
+                .receive_reply()
+                .await;
+            message_sender_actor
+                .send_actor_mail(message_sender_actor::CloseTransports)?
+                .receive_reply()
+                .await;
             deleted_participant.stop().await;
             Ok(())
         } else {
@@ -254,4 +280,33 @@ impl DomainParticipantFactoryAsync {
             .receive_reply()
             .await)
     }
+
+    /// Async version of [`set_persistent_storage`](crate::domain::domain_participant_factory::DomainParticipantFactory::set_persistent_storage).
+    pub async fn set_persistent_storage(
+        &self,
+        persistent_storage: Option<Arc<dyn PersistentStorage>>,
+    ) -> DdsResult<()> {
+        self.domain_participant_factory_actor
+            .send_actor_mail(domain_participant_factory_actor::SetPersistentStorage {
+                persistent_storage,
+            })
+            .receive_reply()
+            .await;
+        Ok(())
+    }
+
+    /// Async version of [`register_transport`](crate::domain::domain_participant_factory::DomainParticipantFactory::register_transport).
+    pub async fn register_transport(
+        &self,
+        locator_kind: i32,
+        transport_factory: Arc<dyn TransportFactory>,
+    ) -> DdsResult<()> {
+        self.domain_participant_factory_actor
+            .send_actor_mail(domain_participant_factory_actor::RegisterTransport {
+                locator_kind,
+                transport_factory,
+            })
+            .receive_reply()
+            .await
+    }
 }
@@ -1,5 +1,7 @@
 /// Classes related to the async status conditions.
 pub mod condition;
+/// Classes related to the async content filtered topic.
+pub mod content_filtered_topic;
 /// Classes related to the async data reader.
 pub mod data_reader;
 /// Classes related to the async data reader listener.
@@ -14,16 +16,24 @@ pub mod domain_participant;
 pub mod domain_participant_factory;
 /// Classes related to the async domain participant listener.
 pub mod domain_participant_listener;
+/// Classes related to the async multi topic.
+pub mod multi_topic;
 /// Classes related to the async publisher.
 pub mod publisher;
 /// Classes related to the async publisher listener.
 pub mod publisher_listener;
+/// Classes related to the async query condition.
+pub mod query_condition;
+/// Classes related to the async read condition.
+pub mod read_condition;
 /// Classes related to the async subscriber.
 pub mod subscriber;
 /// Classes related to the async subscriber listener.
 pub mod subscriber_listener;
 /// Classes related to the async topic.
 pub mod topic;
+/// Classes related to the async topic description.
+pub mod topic_description;
 /// Classes related to the async topic listener.
 pub mod topic_listener;
 /// Classes related to the async wait set.
@@ -0,0 +1,164 @@
+use super::{domain_participant::DomainParticipantAsync, topic::TopicAsync};
+use crate::{
+    implementation::{
+        actors::{data_reader_actor, domain_participant_actor, subscriber_actor, topic_actor},
+        multi_topic::{MultiTopicJoin, MultiTopicProperty},
+    },
+    infrastructure::error::{DdsError, DdsResult},
+    xtypes::dynamic_type::DynamicType,
+};
+use std::sync::Arc;
+
+/// Async version of [`MultiTopic`](crate::topic_definition::multi_topic::MultiTopic).
+#[derive(Clone)]
+pub struct MultiTopicAsync {
+    topic: TopicAsync,
+    subscription_expression: String,
+}
+
+impl MultiTopicAsync {
+    pub(crate) fn new(topic: TopicAsync, subscription_expression: String) -> Self {
+        Self {
+            topic,
+            subscription_expression,
+        }
+    }
+
+    /// Topic of the MultiTopic type used by the readers created from the MultiTopic.
+    pub(crate) fn topic(&self) -> &TopicAsync {
+        &self.topic
+    }
+
+    /// Creates the join of the related topics of the MultiTopic described by
+    /// `multi_topic`. The related topics are returned in the order in which they
+    /// appear in the subscription expression.
+    pub(crate) async fn create_join(
+        participant: &DomainParticipantAsync,
+        multi_topic: &MultiTopicProperty,
+        type_support: Arc<dyn DynamicType + Send + Sync>,
+    ) -> DdsResult<(MultiTopicJoin, Vec<TopicAsync>)> {
+        let mut related_topic_list = Vec::new();
+        let mut related_type_support_list = Vec::new();
+        for topic_name in &multi_topic.expression.topic_names {
+            let related_topic = participant
+                .lookup_topicdescription(topic_name)
+                .await?
+                .ok_or_else(|| {
+                    DdsError::PreconditionNotMet(format!(
+                        "Related topic {} does not exist",
+                        topic_name
+                    ))
+                })?;
+            related_type_support_list.push(
+                related_topic
+                    .topic_address()
+                    .send_actor_mail(topic_actor::GetTypeSupport)?
+                    .receive_reply()
+                    .await,
+            );
+            related_topic_list.push(related_topic);
+        }
+        let join = MultiTopicJoin::new(multi_topic, type_support, related_type_support_list)?;
+        Ok((join, related_topic_list))
+    }
+}
+
+impl MultiTopicAsync {
+    /// Async version of [`get_subscription_expression`](crate::topic_definition::multi_topic::MultiTopic::get_subscription_expression).
+    #[tracing::instrument(skip(self))]
+    pub fn get_subscription_expression(&self) -> String {
+        self.subscription_expression.clone()
+    }
+
+    /// Async version of [`get_expression_parameters`](crate::topic_definition::multi_topic::MultiTopic::get_expression_parameters).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_expression_parameters(&self) -> DdsResult<Vec<String>> {
+        Ok(self
+            .topic
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetMultiTopic {
+                name: self.get_name(),
+            })?
+            .receive_reply()
+            .await?
+            .expression_parameters)
+    }
+
+    /// Async version of [`set_expression_parameters`](crate::topic_definition::multi_topic::MultiTopic::set_expression_parameters).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_expression_parameters(
+        &self,
+        expression_parameters: &[String],
+    ) -> DdsResult<()> {
+        let participant_address = self.topic.participant_address();
+        let mut multi_topic = participant_address
+            .send_actor_mail(domain_participant_actor::GetMultiTopic {
+                name: self.get_name(),
+            })?
+            .receive_reply()
+            .await?;
+        multi_topic.expression_parameters = expression_parameters.to_vec();
+        let type_support = self
+            .topic
+            .topic_address()
+            .send_actor_mail(topic_actor::GetTypeSupport)?
+            .receive_reply()
+            .await;
+        Self::create_join(&self.get_participant(), &multi_topic, type_support).await?;
+
+        participant_address
+            .send_actor_mail(domain_participant_actor::SetMultiTopicParameters {
+                name: self.get_name(),
+                expression_parameters: expression_parameters.to_vec(),
+            })?
+            .receive_reply()
+            .await?;
+
+        let subscriber_list = participant_address
+            .send_actor_mail(domain_participant_actor::GetSubscriberList)?
+            .receive_reply()
+            .await;
+        for subscriber_address in subscriber_list {
+            let data_reader_list = subscriber_address
+                .send_actor_mail(subscriber_actor::GetDataReaderList)?
+                .receive_reply()
+                .await;
+            for dr in data_reader_list {
+                if dr
+                    .send_actor_mail(data_reader_actor::GetMultiTopicName)?
+                    .receive_reply()
+                    .await
+                    == Some(self.get_name())
+                {
+                    dr.send_actor_mail(data_reader_actor::SetMultiTopicParameters {
+                        expression_parameters: expression_parameters.to_vec(),
+                    })?
+                    .receive_reply()
+                    .await;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// This implementation block represents the TopicDescription operations for the [`MultiTopicAsync`].
+impl MultiTopicAsync {
+    /// Async version of [`get_participant`](crate::topic_definition::multi_topic::MultiTopic::get_participant).
+    #[tracing::instrument(skip(self))]
+    pub fn get_participant(&self) -> DomainParticipantAsync {
+        self.topic.get_participant()
+    }
+
+    /// Async version of [`get_type_name`](crate::topic_definition::multi_topic::MultiTopic::get_type_name).
+    #[tracing::instrument(skip(self))]
+    pub fn get_type_name(&self) -> String {
+        self.topic.get_type_name()
+    }
+
+    /// Async version of [`get_name`](crate::topic_definition::multi_topic::MultiTopic::get_name).
+    #[tracing::instrument(skip(self))]
+    pub fn get_name(&self) -> String {
+        self.topic.get_name()
+    }
+}
@@ -1,7 +1,10 @@
 use super::{
-    condition::StatusConditionAsync, data_writer::DataWriterAsync,
-    data_writer_listener::DataWriterListenerAsync, domain_participant::DomainParticipantAsync,
-    publisher_listener::PublisherListenerAsync, topic::TopicAsync,
+    condition::{EntityAddress, StatusConditionAsync},
+    data_writer::DataWriterAsync,
+    data_writer_listener::DataWriterListenerAsync,
+    domain_participant::DomainParticipantAsync,
+    publisher_listener::PublisherListenerAsync,
+    topic::TopicAsync,
 };
 use crate::{
     builtin_topics::DCPS_PUBLICATION,
@@ -11,15 +14,18 @@ use crate::{
             any_data_writer_listener::AnyDataWriterListener,
             data_writer_actor::{self, DataWriterActor},
             domain_participant_actor::{self, DomainParticipantActor},
+            message_sender_actor,
             publisher_actor::{self, PublisherActor},
             status_condition_actor::StatusConditionActor,
             topic_actor::{self, TopicActor},
         },
+        data_representation_builtin_endpoints::discovered_writer_data::DiscoveredWriterData,
     },
     infrastructure::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DataWriterQos, PublisherQos, QosKind, TopicQos},
+        qos_policy::DurabilityQosPolicyKind,
         status::StatusKind,
         time::Duration,
     },
@@ -54,6 +60,182 @@ impl PublisherAsync {
         &self.publisher_address
     }
 
+    pub(crate) async fn announce_data_writer(
+        &self,
+        writer_address: &ActorAddress<DataWriterActor>,
+        topic_address: &ActorAddress<TopicActor>,
+    ) -> DdsResult<()> {
+        let builtin_publisher = self.participant.get_builtin_publisher().await?;
+        if let Some(sedp_publications_announcer) = builtin_publisher
+            .lookup_datawriter::<DiscoveredWriterData>(DCPS_PUBLICATION)
+            .await?
+        {
+            let publisher_qos = self.get_qos().await?;
+            let default_unicast_locator_list = self
+                .participant_address()
+                .send_actor_mail(domain_participant_actor::GetDefaultUnicastLocatorList)?
+                .receive_reply()
+                .await;
+            let default_multicast_locator_list = self
+                .participant_address()
+                .send_actor_mail(domain_participant_actor::GetDefaultMulticastLocatorList)?
+                .receive_reply()
+                .await;
+            let topic_data = topic_address
+                .send_actor_mail(topic_actor::GetQos)?
+                .receive_reply()
+                .await
+                .topic_data;
+            let xml_type = "".to_string();
+            let discovered_writer_data = writer_address
+                .send_actor_mail(data_writer_actor::AsDiscoveredWriterData {
+                    publisher_qos,
+                    default_unicast_locator_list,
+                    default_multicast_locator_list,
+                    topic_data,
+                    xml_type,
+                })?
+                .receive_reply()
+                .await?;
+            sedp_publications_announcer
+                .write(&discovered_writer_data, None)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn release_deleted_data_writer(
+        &self,
+        writer: Actor<DataWriterActor>,
+        topic: &ActorAddress<TopicActor>,
+    ) -> DdsResult<()> {
+        self.participant_address()
+            .send_actor_mail(
+                domain_participant_actor::UnregisterAutomaticLivelinessWriter {
+                    writer_handle: writer
+                        .send_actor_mail(data_writer_actor::GetInstanceHandle)
+                        .receive_reply()
+                        .await,
+                    participant: self.participant.clone(),
+                },
+            )?
+            .receive_reply()
+            .await;
+
+        let writer_qos = writer
+            .send_actor_mail(data_writer_actor::GetQos)
+            .receive_reply()
+            .await;
+        if matches!(
+            writer_qos.durability.kind,
+            DurabilityQosPolicyKind::Transient | DurabilityQosPolicyKind::Persistent
+        ) {
+            // The samples of a TRANSIENT or PERSISTENT writer outlive the writer so it
+            // is kept alive by the durability service instead of being disposed
+            self.hand_over_data_writer_to_durability_service(writer, writer_qos)
+                .await
+        } else {
+            self.unregister_deleted_data_writer_instances(&writer)
+                .await?;
+            self.announce_deleted_data_writer(&writer, topic).await?;
+            writer.stop().await;
+            Ok(())
+        }
+    }
+
+    async fn hand_over_data_writer_to_durability_service(
+        &self,
+        writer: Actor<DataWriterActor>,
+        writer_qos: DataWriterQos,
+    ) -> DdsResult<()> {
+        let data_writer_handle = writer
+            .send_actor_mail(data_writer_actor::GetInstanceHandle)
+            .receive_reply()
+            .await;
+        writer
+            .send_actor_mail(data_writer_actor::HandOverToDurabilityService {
+                writer_address: writer.address(),
+                executor_handle: self.participant.executor_handle().clone(),
+                timer_handle: self.participant.timer_handle().clone(),
+            })
+            .receive_reply()
+            .await?;
+        let topic_name = writer
+            .send_actor_mail(data_writer_actor::GetTopicName)
+            .receive_reply()
+            .await?;
+        let publisher_qos = self.get_qos().await?;
+        self.participant_address()
+            .send_actor_mail(domain_participant_actor::AddDurabilityServiceWriter {
+                publisher_qos,
+                data_writer_handle,
+                data_writer: writer,
+                topic_name,
+                durability_kind: writer_qos.durability.kind,
+                executor_handle: self.participant.executor_handle().clone(),
+            })?
+            .receive_reply()
+            .await;
+        Ok(())
+    }
+
+    async fn unregister_deleted_data_writer_instances(
+        &self,
+        writer: &Actor<DataWriterActor>,
+    ) -> DdsResult<()> {
+        let group_inline_qos = self
+            .publisher_address
+            .send_actor_mail(publisher_actor::GetGroupInlineQos)?
+            .receive_reply()
+            .await;
+        let timestamp = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetCurrentTime)?
+            .receive_reply()
+            .await;
+        let message_sender_actor = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetMessageSender)?
+            .receive_reply()
+            .await;
+        let publisher_mask_listener = self
+            .publisher_address
+            .send_actor_mail(publisher_actor::GetListener)?
+            .receive_reply()
+            .await;
+        let participant_mask_listener = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetListener)?
+            .receive_reply()
+            .await;
+
+        // Deleting the writer unregisters all its instances
+        let change_list = writer
+            .send_actor_mail(data_writer_actor::NewUnregisterAllInstancesChanges {
+                group_inline_qos,
+                timestamp,
+            })
+            .receive_reply()
+            .await?;
+        for change in change_list {
+            writer
+                .send_actor_mail(data_writer_actor::AddChange {
+                    change,
+                    now: timestamp,
+                    message_sender_actor: message_sender_actor.clone(),
+                    writer_address: writer.address(),
+                    publisher_mask_listener: publisher_mask_listener.clone(),
+                    participant_mask_listener: participant_mask_listener.clone(),
+                    publisher: self.clone(),
+                    executor_handle: self.participant.executor_handle().clone(),
+                    timer_handle: self.participant.timer_handle().clone(),
+                })
+                .receive_reply()
+                .await;
+        }
+        Ok(())
+    }
+
     async fn announce_deleted_data_writer(
         &self,
         writer: &Actor<DataWriterActor>,
@@ -161,6 +343,7 @@ impl PublisherAsync {
                 topic_name,
                 type_name,
                 topic_status_condition,
+                type_support,
                 has_key,
                 data_max_size_serialized,
                 qos,
@@ -209,19 +392,6 @@ impl PublisherAsync {
         a_datawriter: &DataWriterAsync<Foo>,
     ) -> DdsResult<()> {
         let writer_handle = a_datawriter.get_instance_handle().await?;
-
-        let message_sender_actor = self
-            .participant
-            .participant_address()
-            .send_actor_mail(domain_participant_actor::GetMessageSender)?
-            .receive_reply()
-            .await;
-
-        a_datawriter
-            .writer_address()
-            .send_actor_mail(data_writer_actor::SendMessage {
-                message_sender_actor,
-            })?;
         let topic = a_datawriter.get_topic();
 
         let deleted_writer = self
@@ -232,10 +402,8 @@ impl PublisherAsync {
             .receive_reply()
             .await?;
 
-        self.announce_deleted_data_writer(&deleted_writer, topic.topic_address())
-            .await?;
-        deleted_writer.stop().await;
-        Ok(())
+        self.release_deleted_data_writer(deleted_writer, topic.topic_address())
+            .await
     }
 
     /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::lookup_datawriter).
@@ -294,34 +462,141 @@ impl PublisherAsync {
         }
     }
 
-    /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::suspend_publications).
+    /// Async version of [`suspend_publications`](crate::publication::publisher::Publisher::suspend_publications).
     #[tracing::instrument(skip(self))]
     pub async fn suspend_publications(&self) -> DdsResult<()> {
-        todo!()
+        self.publisher_address
+            .send_actor_mail(publisher_actor::SuspendPublications)?
+            .receive_reply()
+            .await;
+        Ok(())
     }
 
-    /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::resume_publications).
+    /// Async version of [`resume_publications`](crate::publication::publisher::Publisher::resume_publications).
     #[tracing::instrument(skip(self))]
     pub async fn resume_publications(&self) -> DdsResult<()> {
-        todo!()
+        let data_writer_list = self
+            .publisher_address
+            .send_actor_mail(publisher_actor::ResumePublications)?
+            .receive_reply()
+            .await?;
+        if data_writer_list.is_empty() {
+            return Ok(());
+        }
+
+        let message_sender_actor = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetMessageSender)?
+            .receive_reply()
+            .await;
+        // The messages of all the writers are packed together in as few datagrams as possible
+        // by a sender used only by this publisher. If this is abandoned before the batch is sent
+        // the messages are sent when the sender is dropped.
+        let batch = message_sender_actor
+            .send_actor_mail(message_sender_actor::NewBatch)?
+            .receive_reply()
+            .await;
+        let batch_actor = Actor::spawn(batch, self.participant.executor_handle());
+        for data_writer in data_writer_list {
+            // Writers deleted in the meantime have nothing left to send
+            if let Ok(reply) = data_writer.send_actor_mail(data_writer_actor::ResumePublications {
+                message_sender_actor: batch_actor.address(),
+            }) {
+                reply.receive_reply().await;
+            }
+        }
+        batch_actor
+            .send_actor_mail(message_sender_actor::SendBatch)
+            .receive_reply()
+            .await;
+        Ok(())
     }
 
     /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::begin_coherent_changes).
     #[tracing::instrument(skip(self))]
     pub async fn begin_coherent_changes(&self) -> DdsResult<()> {
-        todo!()
+        self.publisher_address
+            .send_actor_mail(publisher_actor::BeginCoherentChanges)?
+            .receive_reply()
+            .await;
+        Ok(())
     }
 
     /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::end_coherent_changes).
     #[tracing::instrument(skip(self))]
     pub async fn end_coherent_changes(&self) -> DdsResult<()> {
-        todo!()
+        let message_sender_actor = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetMessageSender)?
+            .receive_reply()
+            .await;
+        let now = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetCurrentTime)?
+            .receive_reply()
+            .await;
+        self.publisher_address
+            .send_actor_mail(publisher_actor::EndCoherentChanges {
+                now,
+                message_sender_actor,
+            })?
+            .receive_reply()
+            .await
     }
 
     /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::wait_for_acknowledgments).
     #[tracing::instrument(skip(self))]
-    pub async fn wait_for_acknowledgments(&self, _max_wait: Duration) -> DdsResult<()> {
-        todo!()
+    pub async fn wait_for_acknowledgments(&self, max_wait: Duration) -> DdsResult<()> {
+        if self
+            .wait_for_unacknowledged_writers(max_wait)
+            .await?
+            .is_empty()
+        {
+            Ok(())
+        } else {
+            Err(DdsError::Timeout)
+        }
+    }
+
+    /// Async version of [`wait_for_unacknowledged_writers`](crate::publication::publisher::Publisher::wait_for_unacknowledged_writers).
+    #[tracing::instrument(skip(self))]
+    pub async fn wait_for_unacknowledged_writers(
+        &self,
+        max_wait: Duration,
+    ) -> DdsResult<Vec<InstanceHandle>> {
+        let data_writer_list = self
+            .publisher_address
+            .send_actor_mail(publisher_actor::GetDataWriterList)?
+            .receive_reply()
+            .await;
+        let pending_data_writer_list = data_writer_list.clone();
+        let result = self
+            .participant
+            .timer_handle()
+            .timeout(
+                max_wait.into(),
+                Box::pin(async move {
+                    for data_writer in pending_data_writer_list {
+                        while !are_all_changes_acknowledged(&data_writer).await {}
+                    }
+                }),
+            )
+            .await;
+
+        let mut unacknowledged_writer_list = Vec::new();
+        if result.is_err() {
+            for data_writer in data_writer_list {
+                if !are_all_changes_acknowledged(&data_writer).await {
+                    // A writer deleted in the meantime has nothing left to be acknowledged
+                    if let Ok(reply) =
+                        data_writer.send_actor_mail(data_writer_actor::GetInstanceHandle)
+                    {
+                        unacknowledged_writer_list.push(reply.receive_reply().await);
+                    }
+                }
+            }
+        }
+        Ok(unacknowledged_writer_list)
     }
 
     /// Async version of [`get_participant`](crate::publication::publisher::Publisher::get_participant).
@@ -338,25 +613,14 @@ impl PublisherAsync {
             .send_actor_mail(publisher_actor::DrainDataWriterList)?
             .receive_reply()
             .await;
-        let message_sender_actor = self
-            .participant
-            .participant_address()
-            .send_actor_mail(domain_participant_actor::GetMessageSender)?
-            .receive_reply()
-            .await;
 
         for deleted_writer_actor in deleted_writer_actor_list {
             let topic_address = deleted_writer_actor
                 .send_actor_mail(data_writer_actor::GetTopicAddress)
                 .receive_reply()
                 .await;
-            deleted_writer_actor.send_actor_mail(data_writer_actor::SendMessage {
-                message_sender_actor: message_sender_actor.clone(),
-            });
-
-            self.announce_deleted_data_writer(&deleted_writer_actor, &topic_address)
+            self.release_deleted_data_writer(deleted_writer_actor, &topic_address)
                 .await?;
-            deleted_writer_actor.stop().await;
         }
         Ok(())
     }
@@ -399,18 +663,70 @@ impl PublisherAsync {
     #[tracing::instrument(skip(self))]
     pub async fn copy_from_topic_qos(
         &self,
-        _a_datawriter_qos: &mut DataWriterQos,
-        _a_topic_qos: &TopicQos,
+        a_datawriter_qos: &mut DataWriterQos,
+        a_topic_qos: &TopicQos,
     ) -> DdsResult<()> {
-        todo!()
+        a_datawriter_qos.durability = a_topic_qos.durability.clone();
+        a_datawriter_qos.durability_service = a_topic_qos.durability_service.clone();
+        a_datawriter_qos.deadline = a_topic_qos.deadline.clone();
+        a_datawriter_qos.latency_budget = a_topic_qos.latency_budget.clone();
+        a_datawriter_qos.liveliness = a_topic_qos.liveliness.clone();
+        a_datawriter_qos.reliability = a_topic_qos.reliability.clone();
+        a_datawriter_qos.destination_order = a_topic_qos.destination_order.clone();
+        a_datawriter_qos.history = a_topic_qos.history.clone();
+        a_datawriter_qos.resource_limits = a_topic_qos.resource_limits.clone();
+        a_datawriter_qos.transport_priority = a_topic_qos.transport_priority.clone();
+        a_datawriter_qos.lifespan = a_topic_qos.lifespan.clone();
+        a_datawriter_qos.ownership = a_topic_qos.ownership.clone();
+        a_datawriter_qos.representation = a_topic_qos.representation.clone();
+        Ok(())
     }
 }
 
 impl PublisherAsync {
     /// Async version of [`set_qos`](crate::publication::publisher::Publisher::set_qos).
     #[tracing::instrument(skip(self))]
-    pub async fn set_qos(&self, _qos: QosKind<PublisherQos>) -> DdsResult<()> {
-        todo!()
+    pub async fn set_qos(&self, qos: QosKind<PublisherQos>) -> DdsResult<()> {
+        let qos = match qos {
+            QosKind::Default => {
+                self.participant_address()
+                    .send_actor_mail(domain_participant_actor::GetDefaultPublisherQos)?
+                    .receive_reply()
+                    .await
+            }
+            QosKind::Specific(q) => q,
+        };
+
+        self.publisher_address
+            .send_actor_mail(publisher_actor::SetQos {
+                qos: QosKind::Specific(qos),
+            })?
+            .receive_reply()
+            .await?;
+
+        // The partition of the writers is announced with the publisher
+        // QoS so that the remote readers are rematched against it
+        let data_writer_list = self
+            .publisher_address
+            .send_actor_mail(publisher_actor::GetDataWriterList)?
+            .receive_reply()
+            .await;
+        for data_writer in data_writer_list {
+            if data_writer
+                .send_actor_mail(data_writer_actor::IsEnabled)?
+                .receive_reply()
+                .await
+            {
+                let topic_address = data_writer
+                    .send_actor_mail(data_writer_actor::GetTopicAddress)?
+                    .receive_reply()
+                    .await;
+                self.announce_data_writer(&data_writer, &topic_address)
+                    .await?;
+            }
+        }
+
+        Ok(())
     }
 
     /// Async version of [`get_qos`](crate::publication::publisher::Publisher::get_qos).
@@ -444,6 +760,7 @@ impl PublisherAsync {
     pub fn get_statuscondition(&self) -> StatusConditionAsync {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            EntityAddress::Publisher(self.publisher_address.clone()),
             self.participant.executor_handle().clone(),
             self.participant.timer_handle().clone(),
         )
@@ -475,3 +792,11 @@ impl PublisherAsync {
             .await)
     }
 }
+
+async fn are_all_changes_acknowledged(data_writer: &ActorAddress<DataWriterActor>) -> bool {
+    // A writer deleted while waiting has nothing left to be acknowledged
+    match data_writer.send_actor_mail(data_writer_actor::AreAllChangesAcknowledge) {
+        Ok(reply) => reply.receive_reply().await,
+        Err(_) => true,
+    }
+}
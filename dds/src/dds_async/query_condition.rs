@@ -0,0 +1,87 @@
+use super::read_condition::ReadConditionAsync;
+use crate::{
+    implementation::actors::data_reader_actor,
+    infrastructure::error::DdsResult,
+    subscription::sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind},
+};
+
+/// Async version of [`QueryCondition`](crate::subscription::query_condition::QueryCondition).
+#[derive(Clone)]
+pub struct QueryConditionAsync {
+    read_condition: ReadConditionAsync,
+    query_expression: String,
+}
+
+impl QueryConditionAsync {
+    pub(crate) fn new(read_condition: ReadConditionAsync, query_expression: String) -> Self {
+        Self {
+            read_condition,
+            query_expression,
+        }
+    }
+}
+
+impl AsRef<ReadConditionAsync> for QueryConditionAsync {
+    fn as_ref(&self) -> &ReadConditionAsync {
+        &self.read_condition
+    }
+}
+
+impl QueryConditionAsync {
+    /// Async version of [`get_query_expression`](crate::subscription::query_condition::QueryCondition::get_query_expression).
+    #[tracing::instrument(skip(self))]
+    pub fn get_query_expression(&self) -> String {
+        self.query_expression.clone()
+    }
+
+    /// Async version of [`get_query_parameters`](crate::subscription::query_condition::QueryCondition::get_query_parameters).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_query_parameters(&self) -> DdsResult<Vec<String>> {
+        self.read_condition
+            .reader_address()
+            .send_actor_mail(data_reader_actor::GetQueryParameters {
+                condition_id: self.read_condition.condition_id(),
+            })?
+            .receive_reply()
+            .await
+    }
+
+    /// Async version of [`set_query_parameters`](crate::subscription::query_condition::QueryCondition::set_query_parameters).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_query_parameters(&self, query_parameters: &[String]) -> DdsResult<()> {
+        self.read_condition
+            .reader_address()
+            .send_actor_mail(data_reader_actor::SetQueryParameters {
+                condition_id: self.read_condition.condition_id(),
+                query_parameters: query_parameters.to_vec(),
+            })?
+            .receive_reply()
+            .await
+    }
+
+    /// Async version of [`get_sample_state_mask`](crate::subscription::query_condition::QueryCondition::get_sample_state_mask).
+    #[tracing::instrument(skip(self))]
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.read_condition.get_sample_state_mask()
+    }
+
+    /// Async version of [`get_view_state_mask`](crate::subscription::query_condition::QueryCondition::get_view_state_mask).
+    #[tracing::instrument(skip(self))]
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.read_condition.get_view_state_mask()
+    }
+
+    /// Async version of [`get_instance_state_mask`](crate::subscription::query_condition::QueryCondition::get_instance_state_mask).
+    #[tracing::instrument(skip(self))]
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.read_condition.get_instance_state_mask()
+    }
+}
+
+impl QueryConditionAsync {
+    /// Async version of [`get_trigger_value`](crate::subscription::query_condition::QueryCondition::get_trigger_value).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trigger_value(&self) -> DdsResult<bool> {
+        self.read_condition.get_trigger_value().await
+    }
+}
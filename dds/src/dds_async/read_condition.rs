@@ -0,0 +1,91 @@
+use crate::{
+    implementation::{
+        actor::ActorAddress,
+        actors::data_reader_actor::{self, DataReaderActor},
+        runtime::timer::TimerHandle,
+    },
+    infrastructure::error::DdsResult,
+    subscription::sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind},
+};
+
+/// Async version of [`ReadCondition`](crate::subscription::read_condition::ReadCondition).
+#[derive(Clone)]
+pub struct ReadConditionAsync {
+    condition_id: u64,
+    reader_address: ActorAddress<DataReaderActor>,
+    sample_states: Vec<SampleStateKind>,
+    view_states: Vec<ViewStateKind>,
+    instance_states: Vec<InstanceStateKind>,
+    timer_handle: TimerHandle,
+}
+
+impl ReadConditionAsync {
+    pub(crate) fn new(
+        condition_id: u64,
+        reader_address: ActorAddress<DataReaderActor>,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        timer_handle: TimerHandle,
+    ) -> Self {
+        Self {
+            condition_id,
+            reader_address,
+            sample_states,
+            view_states,
+            instance_states,
+            timer_handle,
+        }
+    }
+
+    pub(crate) fn condition_id(&self) -> u64 {
+        self.condition_id
+    }
+
+    pub(crate) fn reader_address(&self) -> &ActorAddress<DataReaderActor> {
+        &self.reader_address
+    }
+
+    pub(crate) fn timer_handle(&self) -> &TimerHandle {
+        &self.timer_handle
+    }
+}
+
+impl AsRef<ReadConditionAsync> for ReadConditionAsync {
+    fn as_ref(&self) -> &ReadConditionAsync {
+        self
+    }
+}
+
+impl ReadConditionAsync {
+    /// Async version of [`get_sample_state_mask`](crate::subscription::read_condition::ReadCondition::get_sample_state_mask).
+    #[tracing::instrument(skip(self))]
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.sample_states.clone()
+    }
+
+    /// Async version of [`get_view_state_mask`](crate::subscription::read_condition::ReadCondition::get_view_state_mask).
+    #[tracing::instrument(skip(self))]
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.view_states.clone()
+    }
+
+    /// Async version of [`get_instance_state_mask`](crate::subscription::read_condition::ReadCondition::get_instance_state_mask).
+    #[tracing::instrument(skip(self))]
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.instance_states.clone()
+    }
+}
+
+impl ReadConditionAsync {
+    /// Async version of [`get_trigger_value`](crate::subscription::read_condition::ReadCondition::get_trigger_value).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trigger_value(&self) -> DdsResult<bool> {
+        self.reader_address
+            .send_actor_mail(data_reader_actor::GetReadConditionTriggerValue {
+                condition_id: self.condition_id,
+            })?
+            .receive_reply()
+            .await
+    }
+}
@@ -1,7 +1,12 @@
 use super::{
-    condition::StatusConditionAsync, data_reader::DataReaderAsync,
-    data_reader_listener::DataReaderListenerAsync, domain_participant::DomainParticipantAsync,
-    subscriber_listener::SubscriberListenerAsync, topic::TopicAsync,
+    condition::{EntityAddress, StatusConditionAsync},
+    data_reader::DataReaderAsync,
+    data_reader_listener::DataReaderListenerAsync,
+    domain_participant::DomainParticipantAsync,
+    multi_topic::MultiTopicAsync,
+    subscriber_listener::SubscriberListenerAsync,
+    topic::TopicAsync,
+    topic_description::TopicDescriptionAsync,
 };
 use crate::{
     builtin_topics::DCPS_SUBSCRIPTION,
@@ -15,13 +20,17 @@ use crate::{
             subscriber_actor::{self, SubscriberActor},
             topic_actor::{self, TopicActor},
         },
+        data_representation_builtin_endpoints::discovered_reader_data::DiscoveredReaderData,
     },
     infrastructure::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DataReaderQos, QosKind, SubscriberQos, TopicQos},
+        qos_policy::PresentationQosPolicyAccessScopeKind,
         status::{SampleLostStatus, StatusKind},
     },
+    subscription::sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind},
+    xtypes::dynamic_type::DynamicType,
 };
 
 /// Async version of [`Subscriber`](crate::subscription::subscriber::Subscriber).
@@ -53,6 +62,90 @@ impl SubscriberAsync {
         &self.subscriber_address
     }
 
+    async fn data_reader_from_address<Foo>(
+        &self,
+        reader_address: ActorAddress<DataReaderActor>,
+    ) -> DdsResult<DataReaderAsync<Foo>> {
+        let topic_name = reader_address
+            .send_actor_mail(data_reader_actor::GetTopicName)?
+            .receive_reply()
+            .await?;
+        let (topic_address, topic_status_condition) = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::LookupTopicdescription {
+                topic_name: topic_name.clone(),
+            })?
+            .receive_reply()
+            .await?
+            .ok_or(DdsError::AlreadyDeleted)?;
+        let type_name = topic_address
+            .send_actor_mail(topic_actor::GetTypeName)?
+            .receive_reply()
+            .await;
+        let topic = TopicAsync::new(
+            topic_address,
+            topic_status_condition,
+            topic_name,
+            type_name,
+            self.participant.clone(),
+        );
+        let status_condition = reader_address
+            .send_actor_mail(data_reader_actor::GetStatuscondition)?
+            .receive_reply()
+            .await;
+        Ok(DataReaderAsync::new(
+            reader_address,
+            status_condition,
+            self.clone(),
+            topic,
+        ))
+    }
+
+    pub(crate) async fn announce_data_reader(
+        &self,
+        reader_address: &ActorAddress<DataReaderActor>,
+        topic_address: &ActorAddress<TopicActor>,
+    ) -> DdsResult<()> {
+        let builtin_publisher = self.participant.get_builtin_publisher().await?;
+        if let Some(sedp_subscriptions_announcer) = builtin_publisher
+            .lookup_datawriter::<DiscoveredReaderData>(DCPS_SUBSCRIPTION)
+            .await?
+        {
+            let subscriber_qos = self.get_qos().await?;
+            let default_unicast_locator_list = self
+                .participant_address()
+                .send_actor_mail(domain_participant_actor::GetDefaultUnicastLocatorList)?
+                .receive_reply()
+                .await;
+            let default_multicast_locator_list = self
+                .participant_address()
+                .send_actor_mail(domain_participant_actor::GetDefaultMulticastLocatorList)?
+                .receive_reply()
+                .await;
+            let topic_data = topic_address
+                .send_actor_mail(topic_actor::GetQos)?
+                .receive_reply()
+                .await
+                .topic_data;
+            let xml_type = "".to_string();
+            let discovered_reader_data = reader_address
+                .send_actor_mail(data_reader_actor::AsDiscoveredReaderData {
+                    subscriber_qos,
+                    default_unicast_locator_list,
+                    default_multicast_locator_list,
+                    topic_data,
+                    xml_type,
+                })?
+                .receive_reply()
+                .await?;
+
+            sedp_subscriptions_announcer
+                .write(&discovered_reader_data, None)
+                .await?;
+        }
+        Ok(())
+    }
+
     async fn announce_deleted_data_reader(
         &self,
         reader: &Actor<DataReaderActor>,
@@ -98,6 +191,61 @@ impl SubscriberAsync {
         }
         Ok(())
     }
+
+    /// Announces the deletion of a reader. The reader of a MultiTopic is not announced
+    /// itself but through the readers of its related topics which are deleted with it.
+    async fn delete_multi_topic_source_readers(
+        &self,
+        reader: &Actor<DataReaderActor>,
+        topic: &ActorAddress<TopicActor>,
+    ) -> DdsResult<()> {
+        let reader_handle = reader
+            .send_actor_mail(data_reader_actor::GetInstanceHandle)
+            .receive_reply()
+            .await;
+        let source_reader_list = self
+            .subscriber_address
+            .send_actor_mail(subscriber_actor::RemoveMultiTopicSourceReaderList {
+                handle: reader_handle,
+            })?
+            .receive_reply()
+            .await;
+        if source_reader_list.is_empty() {
+            return self.announce_deleted_data_reader(reader, topic).await;
+        }
+
+        let message_sender_actor = self
+            .participant_address()
+            .send_actor_mail(domain_participant_actor::GetMessageSender)?
+            .receive_reply()
+            .await;
+        for source_reader in source_reader_list {
+            let source_topic = source_reader
+                .send_actor_mail(data_reader_actor::GetTopicAddress)
+                .receive_reply()
+                .await;
+            source_reader.send_actor_mail(data_reader_actor::SendMessage {
+                message_sender_actor: message_sender_actor.clone(),
+            });
+            self.announce_deleted_data_reader(&source_reader, &source_topic)
+                .await?;
+            source_reader.stop().await;
+        }
+        Ok(())
+    }
+}
+
+fn type_has_key(type_support: &dyn DynamicType) -> DdsResult<bool> {
+    for index in 0..type_support.get_member_count() {
+        if type_support
+            .get_member_by_index(index)?
+            .get_descriptor()?
+            .is_key
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
 }
 
 impl SubscriberAsync {
@@ -105,7 +253,7 @@ impl SubscriberAsync {
     #[tracing::instrument(skip(self, a_topic, a_listener))]
     pub async fn create_datareader<'a, 'b, Foo>(
         &'a self,
-        a_topic: &'a TopicAsync,
+        a_topic: impl Into<TopicDescriptionAsync>,
         qos: QosKind<DataReaderQos>,
         a_listener: Option<Box<(dyn DataReaderListenerAsync<'b, Foo = Foo> + Send + 'b)>>,
         mask: &'a [StatusKind],
@@ -121,11 +269,72 @@ impl SubscriberAsync {
             .send_actor_mail(domain_participant_actor::GetDefaultUnicastLocatorList)?
             .receive_reply()
             .await;
-        let default_multicast_locator_list = self
-            .participant_address()
-            .send_actor_mail(domain_participant_actor::GetDefaultMulticastLocatorList)?
-            .receive_reply()
-            .await;
+        // A reader requesting the data on multicast groups announces the groups
+        // instead of the default multicast locators of the participant
+        let transport_multicast = match &qos {
+            QosKind::Default => self.get_default_datareader_qos().await?.transport_multicast,
+            QosKind::Specific(q) => q.transport_multicast.clone(),
+        };
+        let default_multicast_locator_list = if transport_multicast.value.is_empty() {
+            self.participant_address()
+                .send_actor_mail(domain_participant_actor::GetDefaultMulticastLocatorList)?
+                .receive_reply()
+                .await
+        } else {
+            self.participant_address()
+                .send_actor_mail(domain_participant_actor::JoinTransportMulticastGroups {
+                    transport_multicast,
+                    participant: self.participant.clone(),
+                })?
+                .receive_reply()
+                .await?
+        };
+
+        let (a_topic, content_filter, multi_topic) = match a_topic.into() {
+            TopicDescriptionAsync::Topic(t) => (t, None, None),
+            TopicDescriptionAsync::ContentFilteredTopic(t) => {
+                let content_filter = self
+                    .participant_address()
+                    .send_actor_mail(domain_participant_actor::GetContentFilteredTopic {
+                        name: t.get_name(),
+                    })?
+                    .receive_reply()
+                    .await?;
+                (t.get_related_topic(), Some(content_filter), None)
+            }
+            TopicDescriptionAsync::MultiTopic(t) => {
+                let multi_topic = self
+                    .participant_address()
+                    .send_actor_mail(domain_participant_actor::GetMultiTopic {
+                        name: t.get_name(),
+                    })?
+                    .receive_reply()
+                    .await?;
+                let type_support = t
+                    .topic()
+                    .topic_address()
+                    .send_actor_mail(topic_actor::GetTypeSupport)?
+                    .receive_reply()
+                    .await;
+                let join =
+                    MultiTopicAsync::create_join(&self.participant, &multi_topic, type_support)
+                        .await?;
+                (t.topic().clone(), None, Some(join))
+            }
+        };
+        let (multi_topic, related_topic_list) = match multi_topic {
+            Some((join, related_topic_list)) => (Some(join), related_topic_list),
+            None => (None, Vec::new()),
+        };
+        // The readers of the related topics of a MultiTopic use the same QoS as the
+        // reader of the MultiTopic
+        let related_topic_qos_list: Vec<_> = related_topic_list
+            .iter()
+            .map(|_| match &qos {
+                QosKind::Default => QosKind::Default,
+                QosKind::Specific(q) => QosKind::Specific(q.clone()),
+            })
+            .collect();
 
         let topic = a_topic.topic_address();
         let topic_name = a_topic.get_name();
@@ -135,20 +344,7 @@ impl SubscriberAsync {
             .send_actor_mail(topic_actor::GetTypeSupport)?
             .receive_reply()
             .await;
-        let has_key = {
-            let mut has_key = false;
-            for index in 0..type_support.get_member_count() {
-                if type_support
-                    .get_member_by_index(index)?
-                    .get_descriptor()?
-                    .is_key
-                {
-                    has_key = true;
-                    break;
-                }
-            }
-            has_key
-        };
+        let has_key = type_has_key(type_support.as_ref())?;
 
         let reader_address = self
             .subscriber_address
@@ -162,23 +358,59 @@ impl SubscriberAsync {
                 qos,
                 a_listener: listener,
                 mask: mask.to_vec(),
-                default_unicast_locator_list,
-                default_multicast_locator_list,
+                default_unicast_locator_list: default_unicast_locator_list.clone(),
+                default_multicast_locator_list: default_multicast_locator_list.clone(),
+                content_filter,
+                multi_topic,
+                multi_topic_reader: None,
                 executor_handle: self.participant.executor_handle().clone(),
             })?
             .receive_reply()
             .await?;
 
+        if !related_topic_list.is_empty() {
+            let multi_topic_reader = reader_address
+                .send_actor_mail(data_reader_actor::GetInstanceHandle)?
+                .receive_reply()
+                .await;
+            for (related_topic, qos) in related_topic_list.into_iter().zip(related_topic_qos_list) {
+                let type_support = related_topic
+                    .topic_address()
+                    .send_actor_mail(topic_actor::GetTypeSupport)?
+                    .receive_reply()
+                    .await;
+                self.subscriber_address
+                    .send_actor_mail(subscriber_actor::CreateDatareader {
+                        topic_address: related_topic.topic_address().clone(),
+                        topic_name: related_topic.get_name(),
+                        type_name: related_topic.get_type_name(),
+                        topic_status_condition: related_topic
+                            .get_statuscondition()
+                            .address()
+                            .clone(),
+                        has_key: type_has_key(type_support.as_ref())?,
+                        type_support,
+                        qos,
+                        a_listener: None,
+                        mask: Vec::new(),
+                        default_unicast_locator_list: default_unicast_locator_list.clone(),
+                        default_multicast_locator_list: default_multicast_locator_list.clone(),
+                        content_filter: None,
+                        multi_topic: None,
+                        multi_topic_reader: Some(multi_topic_reader),
+                        executor_handle: self.participant.executor_handle().clone(),
+                    })?
+                    .receive_reply()
+                    .await?;
+            }
+        }
+
         let status_condition = reader_address
             .send_actor_mail(data_reader_actor::GetStatuscondition)?
             .receive_reply()
             .await;
-        let data_reader = DataReaderAsync::new(
-            reader_address,
-            status_condition,
-            self.clone(),
-            a_topic.clone(),
-        );
+        let data_reader =
+            DataReaderAsync::new(reader_address, status_condition, self.clone(), a_topic);
 
         if self
             .subscriber_address
@@ -229,7 +461,7 @@ impl SubscriberAsync {
             .receive_reply()
             .await?;
 
-        self.announce_deleted_data_reader(&deleted_reader, &topic)
+        self.delete_multi_topic_source_readers(&deleted_reader, &topic)
             .await?;
         deleted_reader.stop().await;
         Ok(())
@@ -290,6 +522,73 @@ impl SubscriberAsync {
         }
     }
 
+    /// Async version of [`begin_access`](crate::subscription::subscriber::Subscriber::begin_access).
+    #[tracing::instrument(skip(self))]
+    pub async fn begin_access(&self) -> DdsResult<()> {
+        self.subscriber_address
+            .send_actor_mail(subscriber_actor::BeginAccess)?
+            .receive_reply()
+            .await;
+        Ok(())
+    }
+
+    /// Async version of [`end_access`](crate::subscription::subscriber::Subscriber::end_access).
+    #[tracing::instrument(skip(self))]
+    pub async fn end_access(&self) -> DdsResult<()> {
+        self.subscriber_address
+            .send_actor_mail(subscriber_actor::EndAccess)?
+            .receive_reply()
+            .await
+    }
+
+    /// Async version of [`get_datareaders`](crate::subscription::subscriber::Subscriber::get_datareaders).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_datareaders<Foo>(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<DataReaderAsync<Foo>>> {
+        let presentation = self.get_qos().await?.presentation;
+        let is_group_ordered = presentation.access_scope
+            == PresentationQosPolicyAccessScopeKind::Group
+            && presentation.ordered_access;
+
+        let data_reader_list = self
+            .subscriber_address
+            .send_actor_mail(subscriber_actor::GetDataReaderList)?
+            .receive_reply()
+            .await;
+        let mut selected_reader_list = Vec::new();
+        for dr in data_reader_list {
+            let group_sequence_number_list = dr
+                .send_actor_mail(data_reader_actor::GetSampleGroupSequenceNumbers {
+                    sample_states: sample_states.to_vec(),
+                    view_states: view_states.to_vec(),
+                    instance_states: instance_states.to_vec(),
+                })?
+                .receive_reply()
+                .await;
+            if is_group_ordered {
+                // The reader is listed once for each of its samples
+                for group_sequence_number in group_sequence_number_list {
+                    selected_reader_list.push((group_sequence_number, dr.clone()));
+                }
+            } else if !group_sequence_number_list.is_empty() {
+                selected_reader_list.push((None, dr));
+            }
+        }
+        // Samples without group sequence number are ordered after all the others
+        selected_reader_list
+            .sort_by_key(|(group_sequence_number, _)| group_sequence_number.unwrap_or(i64::MAX));
+
+        let mut reader_list = Vec::new();
+        for (_, dr) in selected_reader_list {
+            reader_list.push(self.data_reader_from_address(dr).await?);
+        }
+        Ok(reader_list)
+    }
+
     /// Async version of [`notify_datareaders`](crate::subscription::subscriber::Subscriber::notify_datareaders).
     #[tracing::instrument(skip(self))]
     pub async fn notify_datareaders(&self) -> DdsResult<()> {
@@ -334,7 +633,7 @@ impl SubscriberAsync {
                 message_sender_actor: message_sender_actor.clone(),
             });
 
-            self.announce_deleted_data_reader(&deleted_reader_actor, &topic)
+            self.delete_multi_topic_source_readers(&deleted_reader_actor, &topic)
                 .await?;
             deleted_reader_actor.stop().await;
         }
@@ -363,16 +662,86 @@ impl SubscriberAsync {
     /// Async version of [`copy_from_topic_qos`](crate::subscription::subscriber::Subscriber::copy_from_topic_qos).
     #[tracing::instrument]
     pub async fn copy_from_topic_qos(
-        _a_datareader_qos: &mut DataReaderQos,
-        _a_topic_qos: &TopicQos,
+        a_datareader_qos: &mut DataReaderQos,
+        a_topic_qos: &TopicQos,
     ) -> DdsResult<()> {
-        todo!()
+        a_datareader_qos.durability = a_topic_qos.durability.clone();
+        a_datareader_qos.deadline = a_topic_qos.deadline.clone();
+        a_datareader_qos.latency_budget = a_topic_qos.latency_budget.clone();
+        a_datareader_qos.liveliness = a_topic_qos.liveliness.clone();
+        a_datareader_qos.reliability = a_topic_qos.reliability.clone();
+        a_datareader_qos.destination_order = a_topic_qos.destination_order.clone();
+        a_datareader_qos.history = a_topic_qos.history.clone();
+        a_datareader_qos.resource_limits = a_topic_qos.resource_limits.clone();
+        a_datareader_qos.ownership = a_topic_qos.ownership.clone();
+        a_datareader_qos.representation = a_topic_qos.representation.clone();
+        Ok(())
     }
 
     /// Async version of [`set_qos`](crate::subscription::subscriber::Subscriber::set_qos).
     #[tracing::instrument(skip(self))]
-    pub async fn set_qos(&self, _qos: QosKind<SubscriberQos>) -> DdsResult<()> {
-        todo!()
+    pub async fn set_qos(&self, qos: QosKind<SubscriberQos>) -> DdsResult<()> {
+        let qos = match qos {
+            QosKind::Default => {
+                self.participant_address()
+                    .send_actor_mail(domain_participant_actor::GetDefaultSubscriberQos)?
+                    .receive_reply()
+                    .await
+            }
+            QosKind::Specific(q) => q,
+        };
+
+        self.subscriber_address
+            .send_actor_mail(subscriber_actor::SetQos {
+                qos: QosKind::Specific(qos),
+            })?
+            .receive_reply()
+            .await?;
+
+        // The partition of the readers is announced with the subscriber
+        // QoS so that the remote writers are rematched against it
+        let data_reader_list = self
+            .subscriber_address
+            .send_actor_mail(subscriber_actor::GetDataReaderList)?
+            .receive_reply()
+            .await;
+        for data_reader in data_reader_list {
+            if !data_reader
+                .send_actor_mail(data_reader_actor::IsEnabled)?
+                .receive_reply()
+                .await
+            {
+                continue;
+            }
+            let handle = data_reader
+                .send_actor_mail(data_reader_actor::GetInstanceHandle)?
+                .receive_reply()
+                .await;
+            let source_reader_list = self
+                .subscriber_address
+                .send_actor_mail(subscriber_actor::GetMultiTopicSourceReaderList { handle })?
+                .receive_reply()
+                .await;
+            if source_reader_list.is_empty() {
+                let topic_address = data_reader
+                    .send_actor_mail(data_reader_actor::GetTopicAddress)?
+                    .receive_reply()
+                    .await;
+                self.announce_data_reader(&data_reader, &topic_address)
+                    .await?;
+            } else {
+                for source_reader in source_reader_list {
+                    let source_topic = source_reader
+                        .send_actor_mail(data_reader_actor::GetTopicAddress)?
+                        .receive_reply()
+                        .await;
+                    self.announce_data_reader(&source_reader, &source_topic)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
     }
 
     /// Async version of [`get_qos`](crate::subscription::subscriber::Subscriber::get_qos).
@@ -406,6 +775,7 @@ impl SubscriberAsync {
     pub fn get_statuscondition(&self) -> StatusConditionAsync {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            EntityAddress::Subscriber(self.subscriber_address.clone()),
             self.participant.executor_handle().clone(),
             self.participant.timer_handle().clone(),
         )
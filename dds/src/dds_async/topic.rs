@@ -1,5 +1,6 @@
 use super::{
-    condition::StatusConditionAsync, domain_participant::DomainParticipantAsync,
+    condition::{EntityAddress, StatusConditionAsync},
+    domain_participant::DomainParticipantAsync,
     topic_listener::TopicListenerAsync,
 };
 use crate::{
@@ -154,6 +155,7 @@ impl TopicAsync {
     pub fn get_statuscondition(&self) -> StatusConditionAsync {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
+            EntityAddress::Topic(self.topic_address.clone()),
             self.participant.executor_handle().clone(),
             self.participant.timer_handle().clone(),
         )
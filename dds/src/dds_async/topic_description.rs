@@ -0,0 +1,65 @@
+use super::{
+    content_filtered_topic::ContentFilteredTopicAsync, domain_participant::DomainParticipantAsync,
+    multi_topic::MultiTopicAsync, topic::TopicAsync,
+};
+
+/// Async version of [`TopicDescription`](crate::topic_definition::topic_description::TopicDescription).
+#[derive(Clone)]
+pub enum TopicDescriptionAsync {
+    /// Topic variant
+    Topic(TopicAsync),
+    /// Content filtered topic variant
+    ContentFilteredTopic(ContentFilteredTopicAsync),
+    /// Multi topic variant
+    MultiTopic(MultiTopicAsync),
+}
+
+impl From<&TopicAsync> for TopicDescriptionAsync {
+    fn from(value: &TopicAsync) -> Self {
+        TopicDescriptionAsync::Topic(value.clone())
+    }
+}
+
+impl From<&ContentFilteredTopicAsync> for TopicDescriptionAsync {
+    fn from(value: &ContentFilteredTopicAsync) -> Self {
+        TopicDescriptionAsync::ContentFilteredTopic(value.clone())
+    }
+}
+
+impl From<&MultiTopicAsync> for TopicDescriptionAsync {
+    fn from(value: &MultiTopicAsync) -> Self {
+        TopicDescriptionAsync::MultiTopic(value.clone())
+    }
+}
+
+impl TopicDescriptionAsync {
+    /// Async version of [`get_participant`](crate::topic_definition::topic_description::TopicDescription::get_participant).
+    #[tracing::instrument(skip(self))]
+    pub fn get_participant(&self) -> DomainParticipantAsync {
+        match self {
+            TopicDescriptionAsync::Topic(t) => t.get_participant(),
+            TopicDescriptionAsync::ContentFilteredTopic(t) => t.get_participant(),
+            TopicDescriptionAsync::MultiTopic(t) => t.get_participant(),
+        }
+    }
+
+    /// Async version of [`get_type_name`](crate::topic_definition::topic_description::TopicDescription::get_type_name).
+    #[tracing::instrument(skip(self))]
+    pub fn get_type_name(&self) -> String {
+        match self {
+            TopicDescriptionAsync::Topic(t) => t.get_type_name(),
+            TopicDescriptionAsync::ContentFilteredTopic(t) => t.get_type_name(),
+            TopicDescriptionAsync::MultiTopic(t) => t.get_type_name(),
+        }
+    }
+
+    /// Async version of [`get_name`](crate::topic_definition::topic_description::TopicDescription::get_name).
+    #[tracing::instrument(skip(self))]
+    pub fn get_name(&self) -> String {
+        match self {
+            TopicDescriptionAsync::Topic(t) => t.get_name(),
+            TopicDescriptionAsync::ContentFilteredTopic(t) => t.get_name(),
+            TopicDescriptionAsync::MultiTopic(t) => t.get_name(),
+        }
+    }
+}
@@ -1,34 +1,77 @@
+use std::{
+    future::{poll_fn, Future},
+    pin::pin,
+    sync::{Mutex, OnceLock},
+    task::Poll,
+};
+
 use crate::{
-    implementation::runtime::timer::TimerHandle,
+    implementation::runtime::timer::{TimerDriver, TimerHandle},
     infrastructure::{
         error::{DdsError, DdsResult},
         time::Duration,
     },
 };
 
-use super::condition::StatusConditionAsync;
+use super::{
+    condition::{GuardConditionAsync, StatusConditionAsync},
+    query_condition::QueryConditionAsync,
+    read_condition::ReadConditionAsync,
+};
 
 /// Async version of [`Condition`](crate::infrastructure::wait_set::Condition).
 #[derive(Clone)]
 pub enum ConditionAsync {
     /// Status condition variant
     StatusCondition(StatusConditionAsync),
+    /// Read condition variant
+    ReadCondition(ReadConditionAsync),
+    /// Query condition variant
+    QueryCondition(QueryConditionAsync),
+    /// Guard condition variant
+    GuardCondition(GuardConditionAsync),
 }
 
 impl ConditionAsync {
-    pub(crate) fn timer_handle(&self) -> &TimerHandle {
+    pub(crate) fn timer_handle(&self) -> Option<&TimerHandle> {
         match self {
-            ConditionAsync::StatusCondition(s) => s.timer_handle(),
+            ConditionAsync::StatusCondition(s) => Some(s.timer_handle()),
+            ConditionAsync::ReadCondition(r) => Some(r.timer_handle()),
+            ConditionAsync::QueryCondition(q) => Some(q.as_ref().timer_handle()),
+            ConditionAsync::GuardCondition(_) => None,
+        }
+    }
+
+    fn is_same(&self, other: &ConditionAsync) -> bool {
+        match (self, other) {
+            (ConditionAsync::StatusCondition(a), ConditionAsync::StatusCondition(b)) => {
+                a.address() == b.address()
+            }
+            (ConditionAsync::ReadCondition(a), ConditionAsync::ReadCondition(b)) => {
+                is_same_read_condition(a, b)
+            }
+            (ConditionAsync::QueryCondition(a), ConditionAsync::QueryCondition(b)) => {
+                is_same_read_condition(a.as_ref(), b.as_ref())
+            }
+            (ConditionAsync::GuardCondition(a), ConditionAsync::GuardCondition(b)) => a.is_same(b),
+            _ => false,
         }
     }
 }
 
+fn is_same_read_condition(a: &ReadConditionAsync, b: &ReadConditionAsync) -> bool {
+    a.reader_address() == b.reader_address() && a.condition_id() == b.condition_id()
+}
+
 impl ConditionAsync {
     /// Async version of [`get_trigger_value`](crate::infrastructure::wait_set::Condition::get_trigger_value).
     #[tracing::instrument(skip(self))]
     pub async fn get_trigger_value(&self) -> DdsResult<bool> {
         match self {
             ConditionAsync::StatusCondition(c) => c.get_trigger_value().await,
+            ConditionAsync::ReadCondition(c) => c.get_trigger_value().await,
+            ConditionAsync::QueryCondition(c) => c.get_trigger_value().await,
+            ConditionAsync::GuardCondition(c) => c.get_trigger_value().await,
         }
     }
 }
@@ -36,7 +79,7 @@ impl ConditionAsync {
 /// Async version of [`WaitSet`](crate::infrastructure::wait_set::WaitSet).
 #[derive(Default)]
 pub struct WaitSetAsync {
-    conditions: Vec<ConditionAsync>,
+    conditions: Mutex<Vec<ConditionAsync>>,
 }
 
 impl WaitSetAsync {
@@ -46,21 +89,30 @@ impl WaitSetAsync {
         Self::default()
     }
 
+    fn conditions(&self) -> Vec<ConditionAsync> {
+        self.conditions
+            .lock()
+            .expect("Mutex shouldn't be poisoned")
+            .clone()
+    }
+
     /// Async version of [`wait`](crate::infrastructure::wait_set::WaitSet::wait).
     #[tracing::instrument(skip(self))]
     pub async fn wait(&self, timeout: Duration) -> DdsResult<Vec<ConditionAsync>> {
-        if self.conditions.is_empty() {
+        if self.conditions().is_empty() {
             return Err(DdsError::PreconditionNotMet(
                 "WaitSet has no attached conditions".to_string(),
             ));
         };
 
-        let timer_handle = self.conditions[0].timer_handle().clone();
         let start = std::time::Instant::now();
         while std::time::Instant::now().duration_since(start) < timeout.into() {
+            // The conditions are taken on every iteration since they can be detached
+            // by another thread while waiting
+            let conditions = self.conditions();
             let mut finished = false;
             let mut trigger_conditions = Vec::new();
-            for condition in &self.conditions {
+            for condition in &conditions {
                 if condition.get_trigger_value().await? {
                     trigger_conditions.push(condition.clone());
                     finished = true;
@@ -70,9 +122,38 @@ impl WaitSetAsync {
             if finished {
                 return Ok(trigger_conditions);
             }
-            timer_handle
-                .sleep(std::time::Duration::from_millis(20))
-                .await;
+
+            // Guard conditions are not associated with any participant so a WaitSet with only
+            // guard conditions uses a timer of its own
+            let timer_handle = match conditions.iter().find_map(|c| c.timer_handle()) {
+                Some(timer_handle) => timer_handle.clone(),
+                None => {
+                    static GUARD_CONDITION_TIMER: OnceLock<TimerDriver> = OnceLock::new();
+                    GUARD_CONDITION_TIMER.get_or_init(TimerDriver::new).handle()
+                }
+            };
+            let guard_conditions: Vec<_> = conditions
+                .iter()
+                .filter_map(|c| match c {
+                    ConditionAsync::GuardCondition(g) => Some(g),
+                    _ => None,
+                })
+                .collect();
+            // Setting the trigger value of a guard condition wakes up the wait
+            // without waiting for the next check of the other conditions
+            let mut sleep = timer_handle.sleep(std::time::Duration::from_millis(20));
+            poll_fn(|cx| {
+                if guard_conditions
+                    .iter()
+                    .any(|g| g.register_waker(cx.waker()))
+                    || pin!(&mut sleep).poll(cx).is_ready()
+                {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await;
         }
 
         Err(DdsError::Timeout)
@@ -81,19 +162,31 @@ impl WaitSetAsync {
     /// Async version of [`attach_condition`](crate::infrastructure::wait_set::WaitSet::attach_condition).
     #[tracing::instrument(skip(self, cond))]
     pub async fn attach_condition(&mut self, cond: ConditionAsync) -> DdsResult<()> {
-        self.conditions.push(cond);
+        let mut conditions = self.conditions.lock().expect("Mutex shouldn't be poisoned");
+        if !conditions.iter().any(|c| c.is_same(&cond)) {
+            conditions.push(cond);
+        }
         Ok(())
     }
 
     /// Async version of [`detach_condition`](crate::infrastructure::wait_set::WaitSet::detach_condition).
-    #[tracing::instrument(skip(self, _cond))]
-    pub async fn detach_condition(&self, _cond: ConditionAsync) -> DdsResult<()> {
-        todo!()
+    #[tracing::instrument(skip(self, cond))]
+    pub async fn detach_condition(&self, cond: ConditionAsync) -> DdsResult<()> {
+        let mut conditions = self.conditions.lock().expect("Mutex shouldn't be poisoned");
+        match conditions.iter().position(|c| c.is_same(&cond)) {
+            Some(index) => {
+                conditions.remove(index);
+                Ok(())
+            }
+            None => Err(DdsError::PreconditionNotMet(
+                "Condition is not attached to the WaitSet".to_string(),
+            )),
+        }
     }
 
     /// Async version of [`get_conditions`](crate::infrastructure::wait_set::WaitSet::get_conditions).
     #[tracing::instrument(skip(self))]
     pub async fn get_conditions(&self) -> DdsResult<Vec<ConditionAsync>> {
-        Ok(self.conditions.clone())
+        Ok(self.conditions())
     }
 }
@@ -75,6 +75,14 @@ impl<A> Clone for ActorAddress<A> {
     }
 }
 
+impl<A> PartialEq for ActorAddress<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.mail_sender == other.mail_sender
+    }
+}
+
+impl<A> Eq for ActorAddress<A> {}
+
 impl<A> ActorAddress<A> {
     pub fn is_closed(&self) -> bool {
         self.mail_sender.is_closed()
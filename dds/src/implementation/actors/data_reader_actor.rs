@@ -5,7 +5,7 @@ use super::{
     },
     message_sender_actor::MessageSenderActor,
     status_condition_actor::{self, AddCommunicationState, StatusConditionActor},
-    subscriber_actor::{SubscriberListenerMessage, SubscriberListenerOperation},
+    subscriber_actor::{self, SubscriberListenerMessage, SubscriberListenerOperation},
     topic_actor::TopicActor,
 };
 use crate::{
@@ -13,24 +13,33 @@ use crate::{
     dds_async::{subscriber::SubscriberAsync, topic::TopicAsync},
     implementation::{
         actor::{Actor, ActorAddress, Mail, MailHandler},
+        content_filter::{ContentFilter, ContentFilterProperty},
         data_representation_builtin_endpoints::{
             discovered_reader_data::{DiscoveredReaderData, ReaderProxy},
             discovered_writer_data::DiscoveredWriterData,
         },
         data_representation_inline_qos::{
-            parameter_id_values::{PID_KEY_HASH, PID_STATUS_INFO},
+            parameter_id_values::{
+                PID_COHERENT_SET, PID_CONTENT_FILTER_INFO, PID_GROUP_COHERENT_SET,
+                PID_GROUP_SEQ_NUM, PID_KEY_HASH, PID_STATUS_INFO,
+            },
             types::{
-                StatusInfo, STATUS_INFO_DISPOSED, STATUS_INFO_DISPOSED_UNREGISTERED,
-                STATUS_INFO_UNREGISTERED,
+                CoherentSet, ContentFilterInfo, GroupSequenceNumber, StatusInfo, COHERENT_SET_END,
+                STATUS_INFO_DISPOSED, STATUS_INFO_DISPOSED_UNREGISTERED, STATUS_INFO_UNREGISTERED,
             },
         },
+        multi_topic::MultiTopicJoin,
         runtime::{
             executor::{block_on, ExecutorHandle, TaskHandle},
             mpsc::{mpsc_channel, MpscSender},
             timer::TimerHandle,
         },
-        xtypes_glue::key_and_instance_handle::{
-            get_instance_handle_from_serialized_foo, get_instance_handle_from_serialized_key,
+        xtypes_glue::{
+            key_and_instance_handle::{
+                get_instance_handle_from_serialized_foo, get_instance_handle_from_serialized_key,
+                get_serialized_key_from_serialized_foo, set_serialized_key_in_serialized_foo,
+            },
+            member_values::get_member_paths,
         },
     },
     infrastructure::{
@@ -40,7 +49,8 @@ use crate::{
         qos::{DataReaderQos, SubscriberQos},
         qos_policy::{
             DestinationOrderQosPolicyKind, DurabilityQosPolicyKind, HistoryQosPolicyKind,
-            OwnershipQosPolicyKind, QosPolicyId, ReliabilityQosPolicyKind, TopicDataQosPolicy,
+            LivelinessQosPolicyKind, OwnershipQosPolicyKind, PresentationQosPolicyAccessScopeKind,
+            QosPolicyId, ReliabilityQosPolicyKind, TopicDataQosPolicy,
             DATA_REPRESENTATION_QOS_POLICY_ID, DEADLINE_QOS_POLICY_ID,
             DESTINATIONORDER_QOS_POLICY_ID, DURABILITY_QOS_POLICY_ID, LATENCYBUDGET_QOS_POLICY_ID,
             LIVELINESS_QOS_POLICY_ID, OWNERSHIP_QOS_POLICY_ID, PRESENTATION_QOS_POLICY_ID,
@@ -62,9 +72,12 @@ use crate::{
                 data::DataSubmessage, data_frag::DataFragSubmessage, gap::GapSubmessage,
                 heartbeat::HeartbeatSubmessage, heartbeat_frag::HeartbeatFragSubmessage,
             },
+            types::ParameterId,
         },
         reader::RtpsReaderKind,
-        types::{ChangeKind, Guid, GuidPrefix, Locator, ENTITYID_UNKNOWN, GUID_UNKNOWN},
+        types::{
+            ChangeKind, Guid, GuidPrefix, Locator, SequenceNumber, ENTITYID_UNKNOWN, GUID_UNKNOWN,
+        },
         writer_proxy::RtpsWriterProxy,
     },
     subscription::sample_info::{InstanceStateKind, SampleInfo, SampleStateKind, ViewStateKind},
@@ -74,7 +87,7 @@ use crate::{
     },
 };
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{hash_map::Entry, HashMap, HashSet},
     sync::Arc,
     thread::JoinHandle,
 };
@@ -85,15 +98,19 @@ struct InstanceState {
     instance_state: InstanceStateKind,
     most_recent_disposed_generation_count: i32,
     most_recent_no_writers_generation_count: i32,
+    serialized_key: Data,
+    writer_list: HashSet<InstanceHandle>,
 }
 
 impl InstanceState {
-    pub fn new() -> Self {
+    pub fn new(serialized_key: Data) -> Self {
         Self {
             view_state: ViewStateKind::New,
             instance_state: InstanceStateKind::Alive,
             most_recent_disposed_generation_count: 0,
             most_recent_no_writers_generation_count: 0,
+            serialized_key,
+            writer_list: HashSet::new(),
         }
     }
 
@@ -183,6 +200,21 @@ fn build_instance_handle(
     })
 }
 
+fn find_group_sequence_number(
+    inline_qos: &ParameterList,
+    parameter_id: ParameterId,
+) -> Option<SequenceNumber> {
+    inline_qos
+        .parameter()
+        .iter()
+        .find(|&x| x.parameter_id() == parameter_id)
+        .and_then(|p| {
+            let mut deserializer = Xcdr1LeDeserializer::new(p.value());
+            GroupSequenceNumber::deserialize(&mut deserializer).ok()
+        })
+        .map(|g| g.value())
+}
+
 impl SampleLostStatus {
     fn increment(&mut self) {
         self.total_count += 1;
@@ -255,7 +287,7 @@ impl MailHandler<ReadRequestedDeadlineMissedStatus> for DataReaderActor {
 }
 
 impl LivelinessChangedStatus {
-    fn _read_and_reset(&mut self) -> Self {
+    fn read_and_reset(&mut self) -> Self {
         let status = self.clone();
 
         self.alive_count_change = 0;
@@ -319,6 +351,33 @@ struct IndexedSample {
     sample: (Option<Data>, SampleInfo),
 }
 
+struct ReceivedData {
+    inline_qos: ParameterList,
+    data: Data,
+    source_timestamp: Option<rtps::messages::types::Time>,
+    reception_timestamp: rtps::messages::types::Time,
+}
+
+struct PendingCoherentSet {
+    coherent_set: CoherentSet,
+    data: Vec<ReceivedData>,
+    is_incomplete: bool,
+}
+
+struct ReceivedGroupCoherentSet {
+    writer_guid: Guid,
+    group_coherent_set: SequenceNumber,
+    data: Vec<ReceivedData>,
+}
+
+#[derive(Clone)]
+struct ReadConditionState {
+    sample_states: Vec<SampleStateKind>,
+    view_states: Vec<ViewStateKind>,
+    instance_states: Vec<InstanceStateKind>,
+    query: Option<ContentFilter>,
+}
+
 struct DataReaderListenerMessage {
     listener_operation: DataReaderListenerOperation,
     reader_address: ActorAddress<DataReaderActor>,
@@ -373,13 +432,16 @@ pub struct DataReaderActor {
     type_name: String,
     topic_status_condition: ActorAddress<StatusConditionActor>,
     type_support: Arc<dyn DynamicType + Send + Sync>,
-    _liveliness_changed_status: LivelinessChangedStatus,
+    liveliness_changed_status: LivelinessChangedStatus,
     requested_deadline_missed_status: ReaderRequestedDeadlineMissedStatus,
     requested_incompatible_qos_status: RequestedIncompatibleQosStatus,
     sample_lost_status: SampleLostStatus,
     sample_rejected_status: SampleRejectedStatus,
     subscription_matched_status: SubscriptionMatchedStatus,
     matched_publication_list: HashMap<InstanceHandle, PublicationBuiltinTopicData>,
+    alive_writer_list: HashSet<InstanceHandle>,
+    not_alive_writer_list: HashSet<InstanceHandle>,
+    writer_liveliness_task: HashMap<InstanceHandle, TaskHandle>,
     enabled: bool,
     data_available_status_changed_flag: bool,
     incompatible_writer_list: HashSet<InstanceHandle>,
@@ -388,7 +450,17 @@ pub struct DataReaderActor {
     status_kind: Vec<StatusKind>,
     instances: HashMap<InstanceHandle, InstanceState>,
     instance_deadline_missed_task: HashMap<InstanceHandle, TaskHandle>,
+    instance_autopurge_task: HashMap<InstanceHandle, TaskHandle>,
     instance_ownership: HashMap<InstanceHandle, Guid>,
+    read_conditions: HashMap<u64, ReadConditionState>,
+    read_condition_counter: u64,
+    content_filter: Option<ContentFilterProperty>,
+    multi_topic: Option<MultiTopicJoin>,
+    multi_topic_reader: Option<ActorAddress<DataReaderActor>>,
+    coherent_writer_list: HashSet<InstanceHandle>,
+    coherent_sets: HashMap<InstanceHandle, PendingCoherentSet>,
+    group_coherent_writer_list: HashMap<InstanceHandle, InstanceHandle>,
+    group_coherent_sets: Vec<ReceivedGroupCoherentSet>,
 }
 
 impl DataReaderActor {
@@ -403,6 +475,9 @@ impl DataReaderActor {
         qos: DataReaderQos,
         listener: Option<Box<dyn AnyDataReaderListener + Send>>,
         status_kind: Vec<StatusKind>,
+        content_filter: Option<ContentFilterProperty>,
+        multi_topic: Option<MultiTopicJoin>,
+        multi_topic_reader: Option<ActorAddress<DataReaderActor>>,
         handle: &ExecutorHandle,
     ) -> Self {
         let status_condition = Actor::spawn(StatusConditionActor::default(), handle);
@@ -416,13 +491,16 @@ impl DataReaderActor {
             type_name,
             topic_status_condition,
             type_support,
-            _liveliness_changed_status: LivelinessChangedStatus::default(),
+            liveliness_changed_status: LivelinessChangedStatus::default(),
             requested_deadline_missed_status: ReaderRequestedDeadlineMissedStatus::default(),
             requested_incompatible_qos_status: RequestedIncompatibleQosStatus::default(),
             sample_lost_status: SampleLostStatus::default(),
             sample_rejected_status: SampleRejectedStatus::default(),
             subscription_matched_status: SubscriptionMatchedStatus::default(),
             matched_publication_list: HashMap::new(),
+            alive_writer_list: HashSet::new(),
+            not_alive_writer_list: HashSet::new(),
+            writer_liveliness_task: HashMap::new(),
             enabled: false,
             data_available_status_changed_flag: false,
             incompatible_writer_list: HashSet::new(),
@@ -432,7 +510,17 @@ impl DataReaderActor {
             qos,
             instances: HashMap::new(),
             instance_deadline_missed_task: HashMap::new(),
+            instance_autopurge_task: HashMap::new(),
             instance_ownership: HashMap::new(),
+            read_conditions: HashMap::new(),
+            read_condition_counter: 0,
+            content_filter,
+            multi_topic,
+            multi_topic_reader,
+            coherent_writer_list: HashSet::new(),
+            coherent_sets: HashMap::new(),
+            group_coherent_writer_list: HashMap::new(),
+            group_coherent_sets: Vec::new(),
         }
     }
 
@@ -459,6 +547,7 @@ impl DataReaderActor {
         view_states: Vec<ViewStateKind>,
         instance_states: Vec<InstanceStateKind>,
         specific_instance_handle: Option<InstanceHandle>,
+        query: Option<&ContentFilter>,
     ) -> DdsResult<Vec<(Option<Data>, SampleInfo)>> {
         if !self.enabled {
             return Err(DdsError::NotEnabled);
@@ -475,6 +564,7 @@ impl DataReaderActor {
             &view_states,
             &instance_states,
             specific_instance_handle,
+            query,
         )?;
 
         let change_index_list: Vec<usize>;
@@ -499,6 +589,7 @@ impl DataReaderActor {
         view_states: Vec<ViewStateKind>,
         instance_states: Vec<InstanceStateKind>,
         specific_instance_handle: Option<InstanceHandle>,
+        query: Option<&ContentFilter>,
     ) -> DdsResult<Vec<(Option<Data>, SampleInfo)>> {
         if !self.enabled {
             return Err(DdsError::NotEnabled);
@@ -510,6 +601,7 @@ impl DataReaderActor {
             &view_states,
             &instance_states,
             specific_instance_handle,
+            query,
         )?;
 
         self.status_condition
@@ -628,6 +720,14 @@ impl DataReaderActor {
         let writer_guid = Guid::new(source_guid_prefix, data_submessage.writer_id());
         let sequence_number = data_submessage.writer_sn();
         let message_reader_id = data_submessage.reader_id();
+
+        // Receiving data from a writer implicitly asserts its liveliness
+        self.assert_writer_liveliness(
+            InstanceHandle::new(writer_guid.into()),
+            data_reader_address,
+            executor_handle,
+            timer_handle,
+        );
         match &mut self.rtps_reader {
             RtpsReaderKind::Stateful(r) => {
                 if let Some(writer_proxy) = r.matched_writer_lookup(writer_guid) {
@@ -639,6 +739,13 @@ impl DataReaderActor {
                                 writer_proxy.received_change_set(sequence_number);
                                 if sequence_number > expected_seq_num {
                                     writer_proxy.lost_changes_update(sequence_number);
+                                    // A coherent set missing some of its samples must be discarded
+                                    if let Some(coherent_set) = self
+                                        .coherent_sets
+                                        .get_mut(&InstanceHandle::new(writer_guid.into()))
+                                    {
+                                        coherent_set.is_incomplete = true;
+                                    }
                                     self.on_sample_lost(
                                         data_reader_address,
                                         subscriber,
@@ -646,71 +753,36 @@ impl DataReaderActor {
                                         participant_mask_listener,
                                     )?;
                                 }
-                                match self.convert_received_data_to_cache_change(
-                                                writer_guid,
-                                                data_submessage.inline_qos().clone(),
-                                                data_submessage.serialized_payload().clone(),
-                                                source_timestamp,
-                                                reception_timestamp,
-                                            ) {
-                                                Ok(change) => {
-                                                    self.add_change(
-                                                        change,
-                                                        data_reader_address,
-                                                        subscriber,
-                                                        subscriber_mask_listener,
-                                                        participant_mask_listener,
-                                                        executor_handle,
-                                                        timer_handle,
-                                                    )?;
-                                                }
-                                                Err(e) => debug!(
-                                                    "Received invalid data on reader with GUID {guid:?}. Error: {err:?}.
-                                                     Message writer ID: {writer_id:?}
-                                                     Message reader ID: {reader_id:?}
-                                                     Data submessage payload: {payload:?}",
-                                                    guid = self.rtps_reader.guid(),
-                                                    err = e,
-                                                    writer_id = data_submessage.writer_id(),
-                                                    reader_id = data_submessage.reader_id(),
-                                                    payload = data_submessage.serialized_payload(),
-                                                ),
-                                            }
+                                self.add_received_data(
+                                    writer_guid,
+                                    data_submessage,
+                                    source_timestamp,
+                                    reception_timestamp,
+                                    data_reader_address,
+                                    subscriber,
+                                    subscriber_mask_listener,
+                                    participant_mask_listener,
+                                    executor_handle,
+                                    timer_handle,
+                                )?;
                             }
                         }
                         ReliabilityQosPolicyKind::Reliable => {
                             let expected_seq_num = writer_proxy.available_changes_max() + 1;
                             if sequence_number == expected_seq_num {
                                 writer_proxy.received_change_set(sequence_number);
-                                match self.convert_received_data_to_cache_change(
-                                                writer_guid,
-                                                data_submessage.inline_qos().clone(),
-                                                data_submessage.serialized_payload().clone(),
-                                                source_timestamp,
-                                                reception_timestamp,
-                                            ) {
-                                                Ok(change) => {
-                                                    self.add_change(
-                                                        change,
-                                                        data_reader_address,
-                                                        subscriber,
-                                                        subscriber_mask_listener,
-                                                        participant_mask_listener,
-                                                        executor_handle,timer_handle,
-                                                    )?;
-                                                }
-                                                Err(e) => debug!(
-                                                    "Received invalid data on reader with GUID {guid:?}. Error: {err:?}.
-                                                     Message writer ID: {writer_id:?}
-                                                     Message reader ID: {reader_id:?}
-                                                     Data submessage payload: {payload:?}",
-                                                    guid = self.rtps_reader.guid(),
-                                                    err = e,
-                                                    writer_id = data_submessage.writer_id(),
-                                                    reader_id = data_submessage.reader_id(),
-                                                    payload = data_submessage.serialized_payload(),
-                                                ),
-                                            }
+                                self.add_received_data(
+                                    writer_guid,
+                                    data_submessage,
+                                    source_timestamp,
+                                    reception_timestamp,
+                                    data_reader_address,
+                                    subscriber,
+                                    subscriber_mask_listener,
+                                    participant_mask_listener,
+                                    executor_handle,
+                                    timer_handle,
+                                )?;
                             }
                         }
                     }
@@ -722,7 +794,7 @@ impl DataReaderActor {
                 {
                     // Stateless reader behavior. We add the change if the data is correct. No error is printed
                     // because all readers would get changes marked with ENTITYID_UNKNOWN
-                    if let Ok(change) = self.convert_received_data_to_cache_change(
+                    if let Ok(Some(change)) = self.convert_received_data_to_cache_change(
                         writer_guid,
                         data_submessage.inline_qos().clone(),
                         data_submessage.serialized_payload().clone(),
@@ -746,6 +818,187 @@ impl DataReaderActor {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn add_received_data(
+        &mut self,
+        writer_guid: Guid,
+        data_submessage: &DataSubmessage,
+        source_timestamp: Option<rtps::messages::types::Time>,
+        reception_timestamp: rtps::messages::types::Time,
+        data_reader_address: &ActorAddress<DataReaderActor>,
+        subscriber: &SubscriberAsync,
+        subscriber_mask_listener: &(
+            Option<MpscSender<SubscriberListenerMessage>>,
+            Vec<StatusKind>,
+        ),
+        participant_mask_listener: &(
+            Option<MpscSender<ParticipantListenerMessage>>,
+            Vec<StatusKind>,
+        ),
+        executor_handle: &ExecutorHandle,
+        timer_handle: &TimerHandle,
+    ) -> DdsResult<()> {
+        let received_data = ReceivedData {
+            inline_qos: data_submessage.inline_qos().clone(),
+            data: data_submessage.serialized_payload().clone(),
+            source_timestamp,
+            reception_timestamp,
+        };
+
+        let writer_handle = InstanceHandle::new(writer_guid.into());
+        if let Some(&group) = self.group_coherent_writer_list.get(&writer_handle) {
+            let group_coherent_set =
+                find_group_sequence_number(&received_data.inline_qos, PID_GROUP_COHERENT_SET);
+            if let Some(group_coherent_set) =
+                group_coherent_set.filter(|_| received_data.data.is_empty())
+            {
+                // The part of the group coherent set sent by this writer is complete but
+                // it is only made available once the subscriber received all the parts
+                let (data, is_incomplete) = match self.coherent_sets.remove(&writer_handle) {
+                    Some(s) => (s.data, s.is_incomplete),
+                    None => (Vec::new(), false),
+                };
+                self.group_coherent_sets.push(ReceivedGroupCoherentSet {
+                    writer_guid,
+                    group_coherent_set,
+                    data,
+                });
+                subscriber.subscriber_address().send_actor_mail(
+                    subscriber_actor::AddGroupCoherentSetPart {
+                        group,
+                        reader_handle: self.get_instance_handle(),
+                        release: ReleaseGroupCoherentSet {
+                            writer_guid,
+                            group_coherent_set,
+                            is_incomplete,
+                            data_reader_address: data_reader_address.clone(),
+                            subscriber: subscriber.clone(),
+                            subscriber_mask_listener: subscriber_mask_listener.clone(),
+                            participant_mask_listener: participant_mask_listener.clone(),
+                            executor_handle: executor_handle.clone(),
+                            timer_handle: timer_handle.clone(),
+                        },
+                    },
+                )?;
+                return Ok(());
+            }
+        }
+
+        let complete_data = self.complete_coherent_set(writer_guid, received_data);
+        self.add_complete_data(
+            writer_guid,
+            complete_data,
+            data_reader_address,
+            subscriber,
+            subscriber_mask_listener,
+            participant_mask_listener,
+            executor_handle,
+            timer_handle,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn add_complete_data(
+        &mut self,
+        writer_guid: Guid,
+        complete_data: Vec<ReceivedData>,
+        data_reader_address: &ActorAddress<DataReaderActor>,
+        subscriber: &SubscriberAsync,
+        subscriber_mask_listener: &(
+            Option<MpscSender<SubscriberListenerMessage>>,
+            Vec<StatusKind>,
+        ),
+        participant_mask_listener: &(
+            Option<MpscSender<ParticipantListenerMessage>>,
+            Vec<StatusKind>,
+        ),
+        executor_handle: &ExecutorHandle,
+        timer_handle: &TimerHandle,
+    ) -> DdsResult<()> {
+        for received_data in complete_data {
+            match self.convert_received_data_to_cache_change(
+                writer_guid,
+                received_data.inline_qos,
+                received_data.data.clone(),
+                received_data.source_timestamp,
+                received_data.reception_timestamp,
+            ) {
+                Ok(Some(change)) => {
+                    self.add_change(
+                        change,
+                        data_reader_address,
+                        subscriber,
+                        subscriber_mask_listener,
+                        participant_mask_listener,
+                        executor_handle,
+                        timer_handle,
+                    )?;
+                }
+                Ok(None) => (),
+                Err(e) => debug!(
+                    "Received invalid data on reader with GUID {guid:?}. Error: {err:?}.
+                     Message writer GUID: {writer_guid:?}
+                     Data submessage payload: {payload:?}",
+                    guid = self.rtps_reader.guid(),
+                    err = e,
+                    writer_guid = writer_guid,
+                    payload = received_data.data,
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the received data which can be added to the history cache. The data
+    /// belonging to a coherent set of the writer is held back until the set is complete.
+    fn complete_coherent_set(
+        &mut self,
+        writer_guid: Guid,
+        received_data: ReceivedData,
+    ) -> Vec<ReceivedData> {
+        let writer_handle = InstanceHandle::new(writer_guid.into());
+        let coherent_set = received_data
+            .inline_qos
+            .parameter()
+            .iter()
+            .find(|&x| x.parameter_id() == PID_COHERENT_SET)
+            .and_then(|p| {
+                let mut deserializer = Xcdr1LeDeserializer::new(p.value());
+                CoherentSet::deserialize(&mut deserializer).ok()
+            });
+
+        let mut complete_data = Vec::new();
+        // The set is complete once data which is not part of it is received
+        if self
+            .coherent_sets
+            .get(&writer_handle)
+            .is_some_and(|s| Some(s.coherent_set) != coherent_set)
+        {
+            if let Some(s) = self.coherent_sets.remove(&writer_handle) {
+                if !s.is_incomplete {
+                    complete_data.extend(s.data);
+                }
+            }
+        }
+
+        match coherent_set {
+            Some(COHERENT_SET_END) if received_data.data.is_empty() => (),
+            Some(coherent_set) if self.coherent_writer_list.contains(&writer_handle) => self
+                .coherent_sets
+                .entry(writer_handle)
+                .or_insert_with(|| PendingCoherentSet {
+                    coherent_set,
+                    data: Vec::new(),
+                    is_incomplete: false,
+                })
+                .data
+                .push(received_data),
+            _ => complete_data.push(received_data),
+        }
+
+        complete_data
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn on_data_frag_submessage_received(
         &mut self,
@@ -802,7 +1055,20 @@ impl DataReaderActor {
         heartbeat_submessage: &HeartbeatSubmessage,
         source_guid_prefix: GuidPrefix,
         message_sender_actor: &ActorAddress<MessageSenderActor>,
+        data_reader_address: &ActorAddress<DataReaderActor>,
+        executor_handle: &ExecutorHandle,
+        timer_handle: &TimerHandle,
     ) {
+        if heartbeat_submessage.liveliness_flag() {
+            let writer_guid = Guid::new(source_guid_prefix, heartbeat_submessage.writer_id());
+            self.assert_writer_liveliness(
+                InstanceHandle::new(writer_guid.into()),
+                data_reader_address,
+                executor_handle,
+                timer_handle,
+            );
+        }
+
         if self.qos.reliability.kind == ReliabilityQosPolicyKind::Reliable {
             let writer_guid = Guid::new(source_guid_prefix, heartbeat_submessage.writer_id());
 
@@ -887,7 +1153,10 @@ impl DataReaderActor {
         if &self.qos.latency_budget > writer_info.latency_budget() {
             incompatible_qos_policy_list.push(LATENCYBUDGET_QOS_POLICY_ID);
         }
-        if &self.qos.liveliness > writer_info.liveliness() {
+        // Offered kind >= requested kind and offered lease_duration <= requested lease_duration
+        if self.qos.liveliness.kind > writer_info.liveliness().kind
+            || self.qos.liveliness.lease_duration < writer_info.liveliness().lease_duration
+        {
             incompatible_qos_policy_list.push(LIVELINESS_QOS_POLICY_ID);
         }
         if self.qos.reliability.kind > writer_info.reliability().kind {
@@ -1264,7 +1533,7 @@ impl DataReaderActor {
         data: Data,
         source_timestamp: Option<rtps::messages::types::Time>,
         reception_timestamp: rtps::messages::types::Time,
-    ) -> DdsResult<ReaderCacheChange> {
+    ) -> DdsResult<Option<ReaderCacheChange>> {
         let change_kind = if let Some(p) = inline_qos
             .parameter()
             .iter()
@@ -1283,6 +1552,36 @@ impl DataReaderActor {
             Ok(ChangeKind::Alive)
         }?;
 
+        if let Some(content_filter) = &self.content_filter {
+            // The filter does not need to be evaluated again if the writer
+            // already applied the current one. A result for any other filter,
+            // e.g. one with outdated parameters, is ignored.
+            let writer_filter_result = inline_qos
+                .parameter()
+                .iter()
+                .find(|&x| x.parameter_id() == PID_CONTENT_FILTER_INFO)
+                .and_then(|p| {
+                    let mut deserializer = Xcdr1LeDeserializer::new(p.value());
+                    ContentFilterInfo::deserialize(&mut deserializer).ok()
+                })
+                .and_then(|content_filter_info| {
+                    content_filter_info.filter_result(
+                        &content_filter
+                            .as_discovered_content_filter_property()
+                            .signature(),
+                    )
+                });
+            if change_kind == ChangeKind::Alive
+                && !writer_filter_result.unwrap_or_else(|| {
+                    content_filter
+                        .filter
+                        .accepts(data.as_ref(), self.type_support.as_ref())
+                })
+            {
+                return Ok(None);
+            }
+        }
+
         let instance_handle = build_instance_handle(
             &self.type_support,
             change_kind,
@@ -1290,12 +1589,22 @@ impl DataReaderActor {
             inline_qos.parameter(),
         )?;
 
+        let writer_handle = InstanceHandle::new(writer_guid.into());
         match change_kind {
             ChangeKind::Alive | ChangeKind::AliveFiltered => {
-                self.instances
-                    .entry(instance_handle)
-                    .or_insert_with(InstanceState::new)
-                    .update_state(change_kind);
+                // The key of the first sample is kept to provide the key value of the instance
+                let instance = match self.instances.entry(instance_handle) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        let serialized_key = get_serialized_key_from_serialized_foo(
+                            data.as_ref(),
+                            self.type_support.as_ref(),
+                        )?;
+                        entry.insert(InstanceState::new(serialized_key.into()))
+                    }
+                };
+                instance.update_state(change_kind);
+                instance.writer_list.insert(writer_handle);
                 Ok(())
             }
             ChangeKind::NotAliveDisposed
@@ -1304,6 +1613,9 @@ impl DataReaderActor {
                 match self.instances.get_mut(&instance_handle) {
                     Some(instance) => {
                         instance.update_state(change_kind);
+                        if change_kind != ChangeKind::NotAliveDisposed {
+                            instance.writer_list.remove(&writer_handle);
+                        }
                         Ok(())
                     }
                     None => Err(DdsError::Error(
@@ -1313,7 +1625,7 @@ impl DataReaderActor {
             }
         }?;
 
-        Ok(ReaderCacheChange {
+        Ok(Some(ReaderCacheChange {
             rtps_cache_change: RtpsCacheChange {
                 kind: change_kind,
                 writer_guid,
@@ -1328,7 +1640,7 @@ impl DataReaderActor {
                 .most_recent_no_writers_generation_count,
             reception_timestamp,
             source_timestamp,
-        })
+        }))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1348,6 +1660,26 @@ impl DataReaderActor {
         executor_handle: &ExecutorHandle,
         timer_handle: &TimerHandle,
     ) -> DdsResult<()> {
+        // The samples received by the readers of the related topics of a MultiTopic
+        // are joined by the reader of the MultiTopic instead of being stored
+        if let Some(multi_topic_reader) = &self.multi_topic_reader {
+            multi_topic_reader.send_actor_mail(AddMultiTopicSample {
+                topic_name: self.topic_name.clone(),
+                instance_handle: change.instance_handle(),
+                kind: change.rtps_cache_change.kind,
+                data: change.rtps_cache_change.data_value,
+                source_timestamp: change.source_timestamp,
+                reception_timestamp: change.reception_timestamp,
+                data_reader_address: multi_topic_reader.clone(),
+                subscriber: subscriber.clone(),
+                subscriber_mask_listener: subscriber_mask_listener.clone(),
+                participant_mask_listener: participant_mask_listener.clone(),
+                executor_handle: executor_handle.clone(),
+                timer_handle: timer_handle.clone(),
+            })?;
+            return Ok(());
+        }
+
         // For exclusive access if the writer is not the allowed to write the sample do an early return
         if self.qos.ownership.kind == OwnershipQosPolicyKind::Exclusive {
             // Get the InstanceHandle of the data writer owning this instance
@@ -1390,6 +1722,12 @@ impl DataReaderActor {
                 }
             }
         }
+        self.start_autopurge_task(
+            change.instance_handle(),
+            data_reader_address.clone(),
+            executor_handle,
+            timer_handle,
+        );
 
         if self.is_sample_of_interest_based_on_time(&change) {
             if self.is_max_samples_limit_reached(&change) {
@@ -1543,6 +1881,7 @@ impl DataReaderActor {
         view_states: &[ViewStateKind],
         instance_states: &[InstanceStateKind],
         specific_instance_handle: Option<InstanceHandle>,
+        query: Option<&ContentFilter>,
     ) -> DdsResult<Vec<IndexedSample>> {
         if let Some(h) = specific_instance_handle {
             if !self.instances.contains_key(&h) {
@@ -1552,16 +1891,13 @@ impl DataReaderActor {
 
         let mut indexed_samples = Vec::new();
 
-        let instances = &self.instances;
         let mut instances_in_collection = HashMap::new();
         for (index, cache_change) in self
             .changes
             .iter()
             .enumerate()
             .filter(|(_, cc)| {
-                sample_states.contains(&cc.sample_state)
-                    && view_states.contains(&instances[&cc.instance_handle()].view_state)
-                    && instance_states.contains(&instances[&cc.instance_handle()].instance_state)
+                self.is_sample_selected(cc, sample_states, view_states, instance_states, query)
                     && if let Some(h) = specific_instance_handle {
                         h == cc.instance_handle()
                     } else {
@@ -1572,7 +1908,9 @@ impl DataReaderActor {
         {
             instances_in_collection
                 .entry(cache_change.instance_handle())
-                .or_insert_with(InstanceState::new);
+                .or_insert_with(|| {
+                    InstanceState::new(cache_change.rtps_cache_change.data_value.clone())
+                });
 
             instances_in_collection
                 .get_mut(&cache_change.instance_handle())
@@ -1681,6 +2019,43 @@ impl DataReaderActor {
         }
     }
 
+    fn is_sample_selected(
+        &self,
+        cache_change: &ReaderCacheChange,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        query: Option<&ContentFilter>,
+    ) -> bool {
+        let instance = &self.instances[&cache_change.instance_handle()];
+        sample_states.contains(&cache_change.sample_state)
+            && view_states.contains(&instance.view_state)
+            && instance_states.contains(&instance.instance_state)
+            && match query {
+                Some(q) => match cache_change.rtps_cache_change.kind {
+                    ChangeKind::Alive | ChangeKind::AliveFiltered => q.accepts(
+                        cache_change.rtps_cache_change.data_value.as_ref(),
+                        self.type_support.as_ref(),
+                    ),
+                    ChangeKind::NotAliveDisposed
+                    | ChangeKind::NotAliveUnregistered
+                    | ChangeKind::NotAliveDisposedUnregistered => false,
+                },
+                None => true,
+            }
+    }
+
+    fn get_read_condition(&self, condition_id: u64) -> DdsResult<ReadConditionState> {
+        self.read_conditions
+            .get(&condition_id)
+            .cloned()
+            .ok_or_else(|| {
+                DdsError::PreconditionNotMet(
+                    "Condition does not belong to this DataReader".to_string(),
+                )
+            })
+    }
+
     fn next_instance(&self, previous_handle: Option<InstanceHandle>) -> Option<InstanceHandle> {
         match previous_handle {
             Some(p) => self.instances.keys().filter(|&h| h > &p).min().cloned(),
@@ -1847,6 +2222,183 @@ impl DataReaderActor {
         Ok(())
     }
 
+    fn start_autopurge_task(
+        &mut self,
+        instance_handle: InstanceHandle,
+        data_reader_address: ActorAddress<DataReaderActor>,
+        executor_handle: &ExecutorHandle,
+        timer_handle: &TimerHandle,
+    ) {
+        if let Some(t) = self.instance_autopurge_task.remove(&instance_handle) {
+            t.abort();
+        }
+
+        let autopurge_delay = match self.instances.get(&instance_handle) {
+            Some(instance) => match instance.instance_state {
+                InstanceStateKind::Alive => DurationKind::Infinite,
+                InstanceStateKind::NotAliveDisposed => {
+                    self.qos
+                        .reader_data_lifecycle
+                        .autopurge_disposed_samples_delay
+                }
+                InstanceStateKind::NotAliveNoWriters => {
+                    self.qos
+                        .reader_data_lifecycle
+                        .autopurge_nowriter_samples_delay
+                }
+            },
+            None => DurationKind::Infinite,
+        };
+
+        if let DurationKind::Finite(autopurge_delay) = autopurge_delay {
+            let timer_handle = timer_handle.clone();
+            let autopurge_task = executor_handle.spawn(async move {
+                timer_handle.sleep(autopurge_delay.into()).await;
+                data_reader_address
+                    .send_actor_mail(PurgeInstance { instance_handle })
+                    .ok();
+            });
+            self.instance_autopurge_task
+                .insert(instance_handle, autopurge_task);
+        }
+    }
+
+    fn assert_writer_liveliness(
+        &mut self,
+        writer_handle: InstanceHandle,
+        data_reader_address: &ActorAddress<DataReaderActor>,
+        executor_handle: &ExecutorHandle,
+        timer_handle: &TimerHandle,
+    ) {
+        let lease_duration = match self.matched_publication_list.get(&writer_handle) {
+            Some(publication) => publication.liveliness().lease_duration,
+            None => return,
+        };
+
+        if let Some(t) = self.writer_liveliness_task.remove(&writer_handle) {
+            t.abort();
+        }
+
+        if self.alive_writer_list.insert(writer_handle) {
+            if self.not_alive_writer_list.remove(&writer_handle) {
+                self.liveliness_changed_status.not_alive_count -= 1;
+                self.liveliness_changed_status.not_alive_count_change -= 1;
+            }
+            self.liveliness_changed_status.alive_count += 1;
+            self.liveliness_changed_status.alive_count_change += 1;
+            self.liveliness_changed_status.last_publication_handle = writer_handle;
+            self.status_condition
+                .send_actor_mail(AddCommunicationState {
+                    state: StatusKind::LivelinessChanged,
+                });
+        }
+
+        if let DurationKind::Finite(lease_duration) = lease_duration {
+            let data_reader_address = data_reader_address.clone();
+            let timer_handle = timer_handle.clone();
+            let writer_liveliness_task = executor_handle.spawn(async move {
+                timer_handle.sleep(lease_duration.into()).await;
+                data_reader_address
+                    .send_actor_mail(ExpireWriterLiveliness { writer_handle })
+                    .ok();
+            });
+            self.writer_liveliness_task
+                .insert(writer_handle, writer_liveliness_task);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn remove_instance_writer(
+        &mut self,
+        writer_handle: InstanceHandle,
+        data_reader_address: &ActorAddress<DataReaderActor>,
+        subscriber: &SubscriberAsync,
+        subscriber_mask_listener: &(
+            Option<MpscSender<SubscriberListenerMessage>>,
+            Vec<StatusKind>,
+        ),
+        participant_mask_listener: &(
+            Option<MpscSender<ParticipantListenerMessage>>,
+            Vec<StatusKind>,
+        ),
+    ) -> DdsResult<()> {
+        // The alive instances which are left without any writer become NOT_ALIVE_NO_WRITERS
+        let mut no_writers_instance_list = Vec::new();
+        for (instance_handle, instance) in self.instances.iter_mut() {
+            if instance.writer_list.remove(&writer_handle)
+                && instance.writer_list.is_empty()
+                && instance.instance_state == InstanceStateKind::Alive
+            {
+                no_writers_instance_list.push(*instance_handle);
+            }
+        }
+
+        let unix_time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Clock time is before Unix epoch start");
+        let reception_timestamp =
+            infrastructure::time::Time::new(unix_time.as_secs() as i32, unix_time.subsec_nanos())
+                .into();
+        let executor_handle = subscriber.get_participant().executor_handle().clone();
+        let timer_handle = subscriber.get_participant().timer_handle().clone();
+        for instance_handle in no_writers_instance_list {
+            let instance = self
+                .instances
+                .get_mut(&instance_handle)
+                .expect("Instance must exist");
+            instance.update_state(ChangeKind::NotAliveUnregistered);
+            let change = ReaderCacheChange {
+                rtps_cache_change: RtpsCacheChange {
+                    kind: ChangeKind::NotAliveUnregistered,
+                    writer_guid: <[u8; 16]>::from(writer_handle).into(),
+                    instance_handle: instance_handle.into(),
+                    data_value: instance.serialized_key.clone(),
+                    inline_qos: ParameterList::empty(),
+                },
+                sample_state: SampleStateKind::NotRead,
+                disposed_generation_count: instance.most_recent_disposed_generation_count,
+                no_writers_generation_count: instance.most_recent_no_writers_generation_count,
+                reception_timestamp,
+                source_timestamp: Some(reception_timestamp),
+            };
+            self.add_change(
+                change,
+                data_reader_address,
+                subscriber,
+                subscriber_mask_listener,
+                participant_mask_listener,
+                &executor_handle,
+                &timer_handle,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn remove_writer_liveliness(&mut self, writer_handle: InstanceHandle) {
+        if let Some(t) = self.writer_liveliness_task.remove(&writer_handle) {
+            t.abort();
+        }
+
+        // A writer which is no longer matched is neither alive nor not alive
+        let was_alive = self.alive_writer_list.remove(&writer_handle);
+        let was_not_alive = self.not_alive_writer_list.remove(&writer_handle);
+        if was_alive {
+            self.liveliness_changed_status.alive_count -= 1;
+            self.liveliness_changed_status.alive_count_change -= 1;
+        }
+        if was_not_alive {
+            self.liveliness_changed_status.not_alive_count -= 1;
+            self.liveliness_changed_status.not_alive_count_change -= 1;
+        }
+        if was_alive || was_not_alive {
+            self.liveliness_changed_status.last_publication_handle = writer_handle;
+            self.status_condition
+                .send_actor_mail(AddCommunicationState {
+                    state: StatusKind::LivelinessChanged,
+                });
+        }
+    }
+
     fn send_message(&mut self, message_sender_actor: &ActorAddress<MessageSenderActor>) {
         match &mut self.rtps_reader {
             RtpsReaderKind::Stateful(r) => r.send_message(message_sender_actor),
@@ -1873,6 +2425,7 @@ impl MailHandler<Read> for DataReaderActor {
             message.view_states,
             message.instance_states,
             message.specific_instance_handle,
+            None,
         )
     }
 }
@@ -1895,6 +2448,7 @@ impl MailHandler<Take> for DataReaderActor {
             message.view_states,
             message.instance_states,
             message.specific_instance_handle,
+            None,
         )
     }
 }
@@ -1990,6 +2544,9 @@ impl MailHandler<AsDiscoveredReaderData> for DataReaderActor {
                 xml_type: message.xml_type,
                 representation: self.qos.representation.clone(),
             },
+            self.content_filter
+                .as_ref()
+                .map(|f| f.as_discovered_content_filter_property()),
         ))
     }
 }
@@ -2119,6 +2676,7 @@ impl MailHandler<TakeNextInstance> for DataReaderActor {
                 message.view_states,
                 message.instance_states,
                 Some(next_handle),
+                None,
             ),
             None => Err(DdsError::NoData),
         }
@@ -2167,56 +2725,284 @@ impl MailHandler<ReadNextInstance> for DataReaderActor {
                 message.view_states,
                 message.instance_states,
                 Some(next_handle),
+                None,
             ),
             None => Err(DdsError::NoData),
         }
     }
 }
 
-pub struct AddMatchedWriter {
-    pub discovered_writer_data: DiscoveredWriterData,
-    pub default_unicast_locator_list: Vec<Locator>,
-    pub default_multicast_locator_list: Vec<Locator>,
-    pub data_reader_address: ActorAddress<DataReaderActor>,
-    pub subscriber: SubscriberAsync,
-    pub subscriber_qos: SubscriberQos,
-    pub subscriber_mask_listener: (
-        Option<MpscSender<SubscriberListenerMessage>>,
-        Vec<StatusKind>,
-    ),
-    pub participant_mask_listener: (
-        Option<MpscSender<ParticipantListenerMessage>>,
-        Vec<StatusKind>,
-    ),
+pub struct CreateReadCondition {
+    pub sample_states: Vec<SampleStateKind>,
+    pub view_states: Vec<ViewStateKind>,
+    pub instance_states: Vec<InstanceStateKind>,
+    pub query: Option<ContentFilter>,
 }
-impl Mail for AddMatchedWriter {
+impl Mail for CreateReadCondition {
+    type Result = DdsResult<u64>;
+}
+impl MailHandler<CreateReadCondition> for DataReaderActor {
+    fn handle(&mut self, message: CreateReadCondition) -> <CreateReadCondition as Mail>::Result {
+        if let Some(query) = &message.query {
+            query.expression.validate(
+                &get_member_paths(self.type_support.as_ref())?,
+                &query.parameters,
+            )?;
+        }
+        let condition_id = self.read_condition_counter;
+        self.read_condition_counter += 1;
+        self.read_conditions.insert(
+            condition_id,
+            ReadConditionState {
+                sample_states: message.sample_states,
+                view_states: message.view_states,
+                instance_states: message.instance_states,
+                query: message.query,
+            },
+        );
+        Ok(condition_id)
+    }
+}
+
+pub struct DeleteReadCondition {
+    pub condition_id: u64,
+}
+impl Mail for DeleteReadCondition {
     type Result = DdsResult<()>;
 }
-impl MailHandler<AddMatchedWriter> for DataReaderActor {
-    fn handle(&mut self, message: AddMatchedWriter) -> <AddMatchedWriter as Mail>::Result {
-        let type_name = self.type_name.clone();
-        let topic_name = self.topic_name.clone();
-        let publication_builtin_topic_data = &message.discovered_writer_data.dds_publication_data;
-        if publication_builtin_topic_data.topic_name() == topic_name
-            && publication_builtin_topic_data.get_type_name() == type_name
-        {
-            tracing::trace!(
-                topic_name = topic_name,
-                type_name = type_name,
-                "Writer with matched topic and type found",
-            );
-            let instance_handle = InstanceHandle::new(
-                message
-                    .discovered_writer_data
-                    .dds_publication_data
-                    .key
-                    .value,
-            );
-            let incompatible_qos_policy_list = self
-                .get_discovered_writer_incompatible_qos_policy_list(
-                    &message.discovered_writer_data,
-                    &message.subscriber_qos,
-                );
+impl MailHandler<DeleteReadCondition> for DataReaderActor {
+    fn handle(&mut self, message: DeleteReadCondition) -> <DeleteReadCondition as Mail>::Result {
+        self.read_conditions
+            .remove(&message.condition_id)
+            .map(|_| ())
+            .ok_or_else(|| {
+                DdsError::PreconditionNotMet(
+                    "Condition does not belong to this DataReader".to_string(),
+                )
+            })
+    }
+}
+
+pub struct GetReadConditionTriggerValue {
+    pub condition_id: u64,
+}
+impl Mail for GetReadConditionTriggerValue {
+    type Result = DdsResult<bool>;
+}
+impl MailHandler<GetReadConditionTriggerValue> for DataReaderActor {
+    fn handle(
+        &mut self,
+        message: GetReadConditionTriggerValue,
+    ) -> <GetReadConditionTriggerValue as Mail>::Result {
+        let read_condition = self.get_read_condition(message.condition_id)?;
+        Ok(self.changes.iter().any(|cc| {
+            self.is_sample_selected(
+                cc,
+                &read_condition.sample_states,
+                &read_condition.view_states,
+                &read_condition.instance_states,
+                read_condition.query.as_ref(),
+            )
+        }))
+    }
+}
+
+pub struct GetQueryParameters {
+    pub condition_id: u64,
+}
+impl Mail for GetQueryParameters {
+    type Result = DdsResult<Vec<String>>;
+}
+impl MailHandler<GetQueryParameters> for DataReaderActor {
+    fn handle(&mut self, message: GetQueryParameters) -> <GetQueryParameters as Mail>::Result {
+        Ok(self
+            .get_read_condition(message.condition_id)?
+            .query
+            .map(|q| q.parameters)
+            .unwrap_or_default())
+    }
+}
+
+pub struct SetQueryParameters {
+    pub condition_id: u64,
+    pub query_parameters: Vec<String>,
+}
+impl Mail for SetQueryParameters {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<SetQueryParameters> for DataReaderActor {
+    fn handle(&mut self, message: SetQueryParameters) -> <SetQueryParameters as Mail>::Result {
+        let member_paths = get_member_paths(self.type_support.as_ref())?;
+        let query = self
+            .read_conditions
+            .get_mut(&message.condition_id)
+            .and_then(|c| c.query.as_mut())
+            .ok_or_else(|| {
+                DdsError::PreconditionNotMet(
+                    "Query condition does not belong to this DataReader".to_string(),
+                )
+            })?;
+        query
+            .expression
+            .validate(&member_paths, &message.query_parameters)?;
+        query.parameters = message.query_parameters;
+        Ok(())
+    }
+}
+
+pub struct ReadWCondition {
+    pub max_samples: i32,
+    pub condition_id: u64,
+}
+impl Mail for ReadWCondition {
+    type Result = DdsResult<Vec<(Option<Data>, SampleInfo)>>;
+}
+impl MailHandler<ReadWCondition> for DataReaderActor {
+    fn handle(&mut self, message: ReadWCondition) -> <ReadWCondition as Mail>::Result {
+        let read_condition = self.get_read_condition(message.condition_id)?;
+        self.read(
+            message.max_samples,
+            read_condition.sample_states,
+            read_condition.view_states,
+            read_condition.instance_states,
+            None,
+            read_condition.query.as_ref(),
+        )
+    }
+}
+
+pub struct TakeWCondition {
+    pub max_samples: i32,
+    pub condition_id: u64,
+}
+impl Mail for TakeWCondition {
+    type Result = DdsResult<Vec<(Option<Data>, SampleInfo)>>;
+}
+impl MailHandler<TakeWCondition> for DataReaderActor {
+    fn handle(&mut self, message: TakeWCondition) -> <TakeWCondition as Mail>::Result {
+        let read_condition = self.get_read_condition(message.condition_id)?;
+        self.take(
+            message.max_samples,
+            read_condition.sample_states,
+            read_condition.view_states,
+            read_condition.instance_states,
+            None,
+            read_condition.query.as_ref(),
+        )
+    }
+}
+
+pub struct ReadNextInstanceWCondition {
+    pub max_samples: i32,
+    pub previous_handle: Option<InstanceHandle>,
+    pub condition_id: u64,
+}
+impl Mail for ReadNextInstanceWCondition {
+    type Result = DdsResult<Vec<(Option<Data>, SampleInfo)>>;
+}
+impl MailHandler<ReadNextInstanceWCondition> for DataReaderActor {
+    fn handle(
+        &mut self,
+        message: ReadNextInstanceWCondition,
+    ) -> <ReadNextInstanceWCondition as Mail>::Result {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        let read_condition = self.get_read_condition(message.condition_id)?;
+        match self.next_instance(message.previous_handle) {
+            Some(next_handle) => self.read(
+                message.max_samples,
+                read_condition.sample_states,
+                read_condition.view_states,
+                read_condition.instance_states,
+                Some(next_handle),
+                read_condition.query.as_ref(),
+            ),
+            None => Err(DdsError::NoData),
+        }
+    }
+}
+
+pub struct TakeNextInstanceWCondition {
+    pub max_samples: i32,
+    pub previous_handle: Option<InstanceHandle>,
+    pub condition_id: u64,
+}
+impl Mail for TakeNextInstanceWCondition {
+    type Result = DdsResult<Vec<(Option<Data>, SampleInfo)>>;
+}
+impl MailHandler<TakeNextInstanceWCondition> for DataReaderActor {
+    fn handle(
+        &mut self,
+        message: TakeNextInstanceWCondition,
+    ) -> <TakeNextInstanceWCondition as Mail>::Result {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        let read_condition = self.get_read_condition(message.condition_id)?;
+        match self.next_instance(message.previous_handle) {
+            Some(next_handle) => self.take(
+                message.max_samples,
+                read_condition.sample_states,
+                read_condition.view_states,
+                read_condition.instance_states,
+                Some(next_handle),
+                read_condition.query.as_ref(),
+            ),
+            None => Err(DdsError::NoData),
+        }
+    }
+}
+
+pub struct AddMatchedWriter {
+    pub discovered_writer_data: DiscoveredWriterData,
+    pub default_unicast_locator_list: Vec<Locator>,
+    pub default_multicast_locator_list: Vec<Locator>,
+    pub data_reader_address: ActorAddress<DataReaderActor>,
+    pub subscriber: SubscriberAsync,
+    pub subscriber_qos: SubscriberQos,
+    pub subscriber_mask_listener: (
+        Option<MpscSender<SubscriberListenerMessage>>,
+        Vec<StatusKind>,
+    ),
+    pub participant_mask_listener: (
+        Option<MpscSender<ParticipantListenerMessage>>,
+        Vec<StatusKind>,
+    ),
+}
+impl Mail for AddMatchedWriter {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<AddMatchedWriter> for DataReaderActor {
+    fn handle(&mut self, message: AddMatchedWriter) -> <AddMatchedWriter as Mail>::Result {
+        let type_name = self.type_name.clone();
+        let topic_name = self.topic_name.clone();
+        let publication_builtin_topic_data = &message.discovered_writer_data.dds_publication_data;
+        // The reader of a MultiTopic only receives the samples joined from the
+        // readers of the related topics
+        if self.multi_topic.is_none()
+            && publication_builtin_topic_data.topic_name() == topic_name
+            && publication_builtin_topic_data.get_type_name() == type_name
+        {
+            tracing::trace!(
+                topic_name = topic_name,
+                type_name = type_name,
+                "Writer with matched topic and type found",
+            );
+            let instance_handle = InstanceHandle::new(
+                message
+                    .discovered_writer_data
+                    .dds_publication_data
+                    .key
+                    .value,
+            );
+            let incompatible_qos_policy_list = self
+                .get_discovered_writer_incompatible_qos_policy_list(
+                    &message.discovered_writer_data,
+                    &message.subscriber_qos,
+                );
             if incompatible_qos_policy_list.is_empty() {
                 let unicast_locator_list = if message
                     .discovered_writer_data
@@ -2271,6 +3057,30 @@ impl MailHandler<AddMatchedWriter> for DataReaderActor {
                     RtpsReaderKind::Stateful(r) => r.matched_writer_add(writer_proxy),
                     RtpsReaderKind::Stateless(_) => (),
                 }
+                if message.subscriber_qos.presentation.coherent_access {
+                    self.coherent_writer_list.insert(instance_handle);
+                    if message.subscriber_qos.presentation.access_scope
+                        == PresentationQosPolicyAccessScopeKind::Group
+                    {
+                        let writer_proxy = &message.discovered_writer_data.writer_proxy;
+                        let group = InstanceHandle::new(
+                            Guid::new(
+                                writer_proxy.remote_writer_guid.prefix(),
+                                writer_proxy.remote_group_entity_id,
+                            )
+                            .into(),
+                        );
+                        self.group_coherent_writer_list
+                            .insert(instance_handle, group);
+                        message.subscriber.subscriber_address().send_actor_mail(
+                            subscriber_actor::AddGroupCoherentWriter {
+                                group,
+                                reader_handle: self.get_instance_handle(),
+                                writer_handle: instance_handle,
+                            },
+                        )?;
+                    }
+                }
 
                 let insert_matched_publication_result = self
                     .matched_publication_list
@@ -2338,6 +3148,30 @@ impl MailHandler<RemoveMatchedWriter> for DataReaderActor {
                 RtpsReaderKind::Stateful(r) => r.matched_writer_remove(w.key().value.into()),
                 RtpsReaderKind::Stateless(_) => (),
             }
+            self.remove_writer_liveliness(message.discovered_writer_handle);
+            self.remove_instance_writer(
+                message.discovered_writer_handle,
+                &message.data_reader_address,
+                &message.subscriber,
+                &message.subscriber_mask_listener,
+                &message.participant_mask_listener,
+            )?;
+            // A coherent set which is not complete is never made available
+            self.coherent_writer_list
+                .remove(&message.discovered_writer_handle);
+            self.coherent_sets.remove(&message.discovered_writer_handle);
+            if self
+                .group_coherent_writer_list
+                .remove(&message.discovered_writer_handle)
+                .is_some()
+            {
+                message.subscriber.subscriber_address().send_actor_mail(
+                    subscriber_actor::RemoveGroupCoherentWriter {
+                        reader_handle: self.get_instance_handle(),
+                        writer_handle: message.discovered_writer_handle,
+                    },
+                )?;
+            }
 
             self.on_subscription_matched(
                 message.discovered_writer_handle,
@@ -2351,6 +3185,91 @@ impl MailHandler<RemoveMatchedWriter> for DataReaderActor {
     }
 }
 
+pub struct ReleaseGroupCoherentSet {
+    pub writer_guid: Guid,
+    pub group_coherent_set: SequenceNumber,
+    pub is_incomplete: bool,
+    pub data_reader_address: ActorAddress<DataReaderActor>,
+    pub subscriber: SubscriberAsync,
+    pub subscriber_mask_listener: (
+        Option<MpscSender<SubscriberListenerMessage>>,
+        Vec<StatusKind>,
+    ),
+    pub participant_mask_listener: (
+        Option<MpscSender<ParticipantListenerMessage>>,
+        Vec<StatusKind>,
+    ),
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
+}
+impl Mail for ReleaseGroupCoherentSet {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<ReleaseGroupCoherentSet> for DataReaderActor {
+    fn handle(
+        &mut self,
+        message: ReleaseGroupCoherentSet,
+    ) -> <ReleaseGroupCoherentSet as Mail>::Result {
+        let mut complete_data = Vec::new();
+        self.group_coherent_sets.retain_mut(|s| {
+            if s.writer_guid == message.writer_guid
+                && s.group_coherent_set == message.group_coherent_set
+            {
+                complete_data.append(&mut s.data);
+                false
+            } else {
+                true
+            }
+        });
+
+        // A group coherent set missing some of its samples is never made available
+        if message.is_incomplete {
+            return Ok(());
+        }
+        self.add_complete_data(
+            message.writer_guid,
+            complete_data,
+            &message.data_reader_address,
+            &message.subscriber,
+            &message.subscriber_mask_listener,
+            &message.participant_mask_listener,
+            &message.executor_handle,
+            &message.timer_handle,
+        )
+    }
+}
+
+pub struct GetSampleGroupSequenceNumbers {
+    pub sample_states: Vec<SampleStateKind>,
+    pub view_states: Vec<ViewStateKind>,
+    pub instance_states: Vec<InstanceStateKind>,
+}
+impl Mail for GetSampleGroupSequenceNumbers {
+    type Result = Vec<Option<SequenceNumber>>;
+}
+impl MailHandler<GetSampleGroupSequenceNumbers> for DataReaderActor {
+    fn handle(
+        &mut self,
+        message: GetSampleGroupSequenceNumbers,
+    ) -> <GetSampleGroupSequenceNumbers as Mail>::Result {
+        self.changes
+            .iter()
+            .filter(|cc| {
+                self.is_sample_selected(
+                    cc,
+                    &message.sample_states,
+                    &message.view_states,
+                    &message.instance_states,
+                    None,
+                )
+            })
+            .map(|cc| {
+                find_group_sequence_number(&cc.rtps_cache_change.inline_qos, PID_GROUP_SEQ_NUM)
+            })
+            .collect()
+    }
+}
+
 pub struct GetTopicName;
 impl Mail for GetTopicName {
     type Result = DdsResult<String>;
@@ -2361,6 +3280,134 @@ impl MailHandler<GetTopicName> for DataReaderActor {
     }
 }
 
+pub struct GetContentFilteredTopicName;
+impl Mail for GetContentFilteredTopicName {
+    type Result = Option<String>;
+}
+impl MailHandler<GetContentFilteredTopicName> for DataReaderActor {
+    fn handle(
+        &mut self,
+        _: GetContentFilteredTopicName,
+    ) -> <GetContentFilteredTopicName as Mail>::Result {
+        self.content_filter
+            .as_ref()
+            .map(|f| f.content_filtered_topic_name.clone())
+    }
+}
+
+pub struct SetContentFilterParameters {
+    pub expression_parameters: Vec<String>,
+}
+impl Mail for SetContentFilterParameters {
+    type Result = ();
+}
+impl MailHandler<SetContentFilterParameters> for DataReaderActor {
+    fn handle(
+        &mut self,
+        message: SetContentFilterParameters,
+    ) -> <SetContentFilterParameters as Mail>::Result {
+        if let Some(content_filter) = &mut self.content_filter {
+            content_filter.filter.parameters = message.expression_parameters;
+        }
+    }
+}
+
+pub struct GetMultiTopicName;
+impl Mail for GetMultiTopicName {
+    type Result = Option<String>;
+}
+impl MailHandler<GetMultiTopicName> for DataReaderActor {
+    fn handle(&mut self, _: GetMultiTopicName) -> <GetMultiTopicName as Mail>::Result {
+        self.multi_topic
+            .as_ref()
+            .map(|m| m.multi_topic_name().to_string())
+    }
+}
+
+pub struct SetMultiTopicParameters {
+    pub expression_parameters: Vec<String>,
+}
+impl Mail for SetMultiTopicParameters {
+    type Result = ();
+}
+impl MailHandler<SetMultiTopicParameters> for DataReaderActor {
+    fn handle(
+        &mut self,
+        message: SetMultiTopicParameters,
+    ) -> <SetMultiTopicParameters as Mail>::Result {
+        if let Some(multi_topic) = &mut self.multi_topic {
+            multi_topic.set_expression_parameters(message.expression_parameters);
+        }
+    }
+}
+
+pub struct AddMultiTopicSample {
+    pub topic_name: String,
+    pub instance_handle: InstanceHandle,
+    pub kind: ChangeKind,
+    pub data: Data,
+    pub source_timestamp: Option<rtps::messages::types::Time>,
+    pub reception_timestamp: rtps::messages::types::Time,
+    pub data_reader_address: ActorAddress<DataReaderActor>,
+    pub subscriber: SubscriberAsync,
+    pub subscriber_mask_listener: (
+        Option<MpscSender<SubscriberListenerMessage>>,
+        Vec<StatusKind>,
+    ),
+    pub participant_mask_listener: (
+        Option<MpscSender<ParticipantListenerMessage>>,
+        Vec<StatusKind>,
+    ),
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
+}
+impl Mail for AddMultiTopicSample {
+    type Result = ();
+}
+impl MailHandler<AddMultiTopicSample> for DataReaderActor {
+    fn handle(&mut self, message: AddMultiTopicSample) -> <AddMultiTopicSample as Mail>::Result {
+        let joined_samples = match &mut self.multi_topic {
+            Some(multi_topic) => match message.kind {
+                ChangeKind::Alive | ChangeKind::AliveFiltered => multi_topic.add_sample(
+                    &message.topic_name,
+                    message.instance_handle,
+                    message.data.as_ref(),
+                ),
+                ChangeKind::NotAliveDisposed
+                | ChangeKind::NotAliveUnregistered
+                | ChangeKind::NotAliveDisposedUnregistered => {
+                    multi_topic.remove_instance(&message.topic_name, &message.instance_handle);
+                    Vec::new()
+                }
+            },
+            None => Vec::new(),
+        };
+
+        for joined_sample in joined_samples {
+            // Joined samples are not sent by any writer
+            if let Ok(Some(change)) = self.convert_received_data_to_cache_change(
+                GUID_UNKNOWN,
+                ParameterList::empty(),
+                Data::new(joined_sample.into()),
+                message.source_timestamp,
+                message.reception_timestamp,
+            ) {
+                if let Err(e) = self.add_change(
+                    change,
+                    &message.data_reader_address,
+                    &message.subscriber,
+                    &message.subscriber_mask_listener,
+                    &message.participant_mask_listener,
+                    &message.executor_handle,
+                    &message.timer_handle,
+                ) {
+                    debug!("Error adding joined MultiTopic sample: {:?}", e);
+                }
+            }
+        }
+    }
+}
+
 pub struct GetTypeName;
 impl Mail for GetTypeName {
     type Result = DdsResult<String>;
@@ -2472,6 +3519,9 @@ pub struct ProcessHeartbeatSubmessage {
     pub heartbeat_submessage: HeartbeatSubmessage,
     pub source_guid_prefix: GuidPrefix,
     pub message_sender_actor: ActorAddress<MessageSenderActor>,
+    pub data_reader_address: ActorAddress<DataReaderActor>,
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
 }
 impl Mail for ProcessHeartbeatSubmessage {
     type Result = ();
@@ -2485,6 +3535,9 @@ impl MailHandler<ProcessHeartbeatSubmessage> for DataReaderActor {
             &message.heartbeat_submessage,
             message.source_guid_prefix,
             &message.message_sender_actor,
+            &message.data_reader_address,
+            &message.executor_handle,
+            &message.timer_handle,
         );
     }
 }
@@ -2547,10 +3600,117 @@ impl MailHandler<GetRequestedDeadlineMissedStatus> for DataReaderActor {
         &mut self,
         _: GetRequestedDeadlineMissedStatus,
     ) -> <GetRequestedDeadlineMissedStatus as Mail>::Result {
+        self.status_condition
+            .send_actor_mail(status_condition_actor::RemoveCommunicationState {
+                state: StatusKind::RequestedDeadlineMissed,
+            });
+
         self.read_requested_deadline_missed_status()
     }
 }
 
+pub struct GetRequestedIncompatibleQosStatus;
+impl Mail for GetRequestedIncompatibleQosStatus {
+    type Result = RequestedIncompatibleQosStatus;
+}
+impl MailHandler<GetRequestedIncompatibleQosStatus> for DataReaderActor {
+    fn handle(
+        &mut self,
+        _: GetRequestedIncompatibleQosStatus,
+    ) -> <GetRequestedIncompatibleQosStatus as Mail>::Result {
+        self.status_condition
+            .send_actor_mail(status_condition_actor::RemoveCommunicationState {
+                state: StatusKind::RequestedIncompatibleQos,
+            });
+
+        self.requested_incompatible_qos_status.read_and_reset()
+    }
+}
+
+pub struct GetSampleLostStatus;
+impl Mail for GetSampleLostStatus {
+    type Result = SampleLostStatus;
+}
+impl MailHandler<GetSampleLostStatus> for DataReaderActor {
+    fn handle(&mut self, _: GetSampleLostStatus) -> <GetSampleLostStatus as Mail>::Result {
+        self.status_condition
+            .send_actor_mail(status_condition_actor::RemoveCommunicationState {
+                state: StatusKind::SampleLost,
+            });
+
+        self.sample_lost_status.read_and_reset()
+    }
+}
+
+pub struct GetSampleRejectedStatus;
+impl Mail for GetSampleRejectedStatus {
+    type Result = SampleRejectedStatus;
+}
+impl MailHandler<GetSampleRejectedStatus> for DataReaderActor {
+    fn handle(&mut self, _: GetSampleRejectedStatus) -> <GetSampleRejectedStatus as Mail>::Result {
+        self.status_condition
+            .send_actor_mail(status_condition_actor::RemoveCommunicationState {
+                state: StatusKind::SampleRejected,
+            });
+
+        self.sample_rejected_status.read_and_reset()
+    }
+}
+
+pub struct GetLivelinessChangedStatus;
+impl Mail for GetLivelinessChangedStatus {
+    type Result = LivelinessChangedStatus;
+}
+impl MailHandler<GetLivelinessChangedStatus> for DataReaderActor {
+    fn handle(
+        &mut self,
+        _: GetLivelinessChangedStatus,
+    ) -> <GetLivelinessChangedStatus as Mail>::Result {
+        self.status_condition
+            .send_actor_mail(status_condition_actor::RemoveCommunicationState {
+                state: StatusKind::LivelinessChanged,
+            });
+
+        self.liveliness_changed_status.read_and_reset()
+    }
+}
+
+pub struct LookupInstance {
+    pub instance_handle: InstanceHandle,
+}
+impl Mail for LookupInstance {
+    type Result = Option<InstanceHandle>;
+}
+impl MailHandler<LookupInstance> for DataReaderActor {
+    fn handle(&mut self, message: LookupInstance) -> <LookupInstance as Mail>::Result {
+        self.instances
+            .contains_key(&message.instance_handle)
+            .then_some(message.instance_handle)
+    }
+}
+
+pub struct GetKeyValue {
+    pub handle: InstanceHandle,
+    pub serialized_key_holder: Data,
+}
+impl Mail for GetKeyValue {
+    type Result = DdsResult<Data>;
+}
+impl MailHandler<GetKeyValue> for DataReaderActor {
+    fn handle(&mut self, message: GetKeyValue) -> <GetKeyValue as Mail>::Result {
+        let instance = self
+            .instances
+            .get(&message.handle)
+            .ok_or(DdsError::BadParameter)?;
+        Ok(set_serialized_key_in_serialized_foo(
+            message.serialized_key_holder.as_ref(),
+            instance.serialized_key.as_ref(),
+            self.type_support.as_ref(),
+        )?
+        .into())
+    }
+}
+
 pub struct GetTopicAddress;
 impl Mail for GetTopicAddress {
     type Result = ActorAddress<TopicActor>;
@@ -2561,6 +3721,92 @@ impl MailHandler<GetTopicAddress> for DataReaderActor {
     }
 }
 
+pub struct PurgeInstance {
+    pub instance_handle: InstanceHandle,
+}
+impl Mail for PurgeInstance {
+    type Result = ();
+}
+impl MailHandler<PurgeInstance> for DataReaderActor {
+    fn handle(&mut self, message: PurgeInstance) -> <PurgeInstance as Mail>::Result {
+        self.instance_autopurge_task
+            .remove(&message.instance_handle);
+
+        // The instance might have become alive again since the purge was scheduled
+        if let Some(instance) = self.instances.get(&message.instance_handle) {
+            if instance.instance_state != InstanceStateKind::Alive {
+                self.instances.remove(&message.instance_handle);
+                self.instance_ownership.remove(&message.instance_handle);
+                self.changes
+                    .retain(|cc| cc.instance_handle() != message.instance_handle);
+            }
+        }
+    }
+}
+
+pub struct AssertDiscoveredParticipantLiveliness {
+    pub participant_guid_prefix: GuidPrefix,
+    pub liveliness_kind: LivelinessQosPolicyKind,
+    pub data_reader_address: ActorAddress<DataReaderActor>,
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
+}
+impl Mail for AssertDiscoveredParticipantLiveliness {
+    type Result = ();
+}
+impl MailHandler<AssertDiscoveredParticipantLiveliness> for DataReaderActor {
+    fn handle(
+        &mut self,
+        message: AssertDiscoveredParticipantLiveliness,
+    ) -> <AssertDiscoveredParticipantLiveliness as Mail>::Result {
+        let writer_handle_list: Vec<InstanceHandle> = self
+            .matched_publication_list
+            .iter()
+            .filter(|(_, p)| {
+                Guid::from(p.key().value).prefix() == message.participant_guid_prefix
+                    && p.liveliness().kind == message.liveliness_kind
+            })
+            .map(|(&h, _)| h)
+            .collect();
+        for writer_handle in writer_handle_list {
+            self.assert_writer_liveliness(
+                writer_handle,
+                &message.data_reader_address,
+                &message.executor_handle,
+                &message.timer_handle,
+            );
+        }
+    }
+}
+
+pub struct ExpireWriterLiveliness {
+    pub writer_handle: InstanceHandle,
+}
+impl Mail for ExpireWriterLiveliness {
+    type Result = ();
+}
+impl MailHandler<ExpireWriterLiveliness> for DataReaderActor {
+    fn handle(
+        &mut self,
+        message: ExpireWriterLiveliness,
+    ) -> <ExpireWriterLiveliness as Mail>::Result {
+        self.writer_liveliness_task.remove(&message.writer_handle);
+
+        if self.alive_writer_list.remove(&message.writer_handle) {
+            self.not_alive_writer_list.insert(message.writer_handle);
+            self.liveliness_changed_status.alive_count -= 1;
+            self.liveliness_changed_status.alive_count_change -= 1;
+            self.liveliness_changed_status.not_alive_count += 1;
+            self.liveliness_changed_status.not_alive_count_change += 1;
+            self.liveliness_changed_status.last_publication_handle = message.writer_handle;
+            self.status_condition
+                .send_actor_mail(AddCommunicationState {
+                    state: StatusKind::LivelinessChanged,
+                });
+        }
+    }
+}
+
 pub struct RemoveInstanceOwnership {
     pub instance: InstanceHandle,
 }
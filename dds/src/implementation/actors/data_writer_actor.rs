@@ -13,37 +13,59 @@ use crate::{
     dds_async::{publisher::PublisherAsync, topic::TopicAsync},
     implementation::{
         actor::{Actor, ActorAddress, Mail, MailHandler},
+        content_filter::{ContentFilter, FilterExpression},
         data_representation_builtin_endpoints::{
-            discovered_reader_data::DiscoveredReaderData,
+            discovered_reader_data::{
+                ContentFilterProperty, DiscoveredReaderData, FilterSignature,
+                DDSSQL_FILTER_CLASS_NAME,
+            },
             discovered_writer_data::{DiscoveredWriterData, WriterProxy},
         },
+        data_representation_inline_qos::{
+            parameter_id_values::{
+                PID_COHERENT_SET, PID_CONTENT_FILTER_INFO, PID_GROUP_COHERENT_SET, PID_KEY_HASH,
+                PID_STATUS_INFO,
+            },
+            types::{
+                CoherentSet, ContentFilterInfo, GroupSequenceNumber, COHERENT_SET_END,
+                STATUS_INFO_DISPOSED_UNREGISTERED, STATUS_INFO_UNREGISTERED,
+            },
+        },
         runtime::{
             executor::{block_on, ExecutorHandle, TaskHandle},
             mpsc::{mpsc_channel, MpscSender},
             timer::TimerHandle,
         },
+        xtypes_glue::{
+            key_and_instance_handle::set_serialized_key_in_serialized_foo,
+            member_values::get_member_paths,
+        },
     },
     infrastructure::{
         error::{DdsError, DdsResult},
         instance::{InstanceHandle, HANDLE_NIL},
         qos::{DataWriterQos, PublisherQos},
         qos_policy::{
-            DurabilityQosPolicyKind, HistoryQosPolicyKind, Length, QosPolicyId,
-            ReliabilityQosPolicyKind, TopicDataQosPolicy, DATA_REPRESENTATION_QOS_POLICY_ID,
-            DEADLINE_QOS_POLICY_ID, DESTINATIONORDER_QOS_POLICY_ID, DURABILITY_QOS_POLICY_ID,
-            INVALID_QOS_POLICY_ID, LATENCYBUDGET_QOS_POLICY_ID, LIVELINESS_QOS_POLICY_ID,
-            OWNERSHIP_QOS_POLICY_ID, PRESENTATION_QOS_POLICY_ID, RELIABILITY_QOS_POLICY_ID,
-            XCDR_DATA_REPRESENTATION,
+            DurabilityQosPolicyKind, HistoryQosPolicyKind, Length, LivelinessQosPolicyKind,
+            QosPolicyId, ReliabilityQosPolicyKind, TopicDataQosPolicy,
+            DATA_REPRESENTATION_QOS_POLICY_ID, DEADLINE_QOS_POLICY_ID,
+            DESTINATIONORDER_QOS_POLICY_ID, DURABILITY_QOS_POLICY_ID, INVALID_QOS_POLICY_ID,
+            LATENCYBUDGET_QOS_POLICY_ID, LIVELINESS_QOS_POLICY_ID, OWNERSHIP_QOS_POLICY_ID,
+            PRESENTATION_QOS_POLICY_ID, RELIABILITY_QOS_POLICY_ID, XCDR_DATA_REPRESENTATION,
         },
         status::{
-            OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, PublicationMatchedStatus,
-            QosPolicyCount, StatusKind,
+            LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
+            PublicationMatchedStatus, QosPolicyCount, StatusKind,
         },
         time::{Duration, DurationKind, Time},
     },
+    persistent_storage::PersistentStorage,
     rtps::{
         messages::{
-            submessage_elements::{Data, ParameterList, SequenceNumberSet, SerializedDataFragment},
+            overall_structure::{Endianness, TryReadFromBytes, WriteIntoBytes},
+            submessage_elements::{
+                Data, Parameter, ParameterList, SequenceNumberSet, SerializedDataFragment,
+            },
             submessages::{
                 ack_nack::AckNackSubmessage, data_frag::DataFragSubmessage, gap::GapSubmessage,
                 info_destination::InfoDestinationSubmessage,
@@ -54,17 +76,26 @@ use crate::{
         reader_proxy::RtpsReaderProxy,
         types::{
             ChangeKind, EntityId, Guid, GuidPrefix, Locator, ReliabilityKind, SequenceNumber,
-            ENTITYID_UNKNOWN, GUID_UNKNOWN, USER_DEFINED_UNKNOWN,
+            BUILT_IN_UNKNOWN, ENTITYID_UNKNOWN, GUID_UNKNOWN, USER_DEFINED_WRITER_GROUP,
         },
         writer::RtpsWriter,
         writer_history_cache::RtpsWriterCacheChange,
     },
+    xtypes::{
+        dynamic_type::DynamicType, serialize::XTypesSerialize, xcdr_serializer::Xcdr1LeSerializer,
+    },
 };
 use std::{
     collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
     thread::JoinHandle,
 };
 
+struct ReaderContentFilter {
+    filter: ContentFilter,
+    signature: FilterSignature,
+}
+
 struct MatchedSubscriptions {
     matched_subscription_list: HashMap<InstanceHandle, SubscriptionBuiltinTopicData>,
     total_count: i32,
@@ -244,14 +275,20 @@ impl DataWriterListenerThread {
     }
 }
 
+// Size of the log below which it is never compacted while the writer runs
+const MIN_COMPACTED_PERSISTENT_STORAGE_RECORD_COUNT: usize = 32;
+
 pub struct DataWriterActor {
     rtps_writer: RtpsWriter,
     reader_locators: Vec<RtpsReaderLocator>,
+    participant_reader_locators: HashMap<InstanceHandle, Vec<Locator>>,
     matched_readers: Vec<RtpsReaderProxy>,
+    reader_content_filters: HashMap<InstanceHandle, ReaderContentFilter>,
     topic_address: ActorAddress<TopicActor>,
     topic_name: String,
     type_name: String,
     topic_status_condition: ActorAddress<StatusConditionActor>,
+    type_support: Arc<dyn DynamicType + Send + Sync>,
     matched_subscriptions: MatchedSubscriptions,
     incompatible_subscriptions: IncompatibleSubscriptions,
     enabled: bool,
@@ -261,9 +298,23 @@ pub struct DataWriterActor {
     changes: HashMap<crate::rtps::behavior_types::InstanceHandle, VecDeque<RtpsWriterCacheChange>>,
     max_seq_num: Option<SequenceNumber>,
     qos: DataWriterQos,
-    registered_instance_list: HashSet<InstanceHandle>,
+    registered_instance_list: HashMap<InstanceHandle, Data>,
     offered_deadline_missed_status: OfferedDeadlineMissedStatus,
     instance_deadline_missed_task: HashMap<InstanceHandle, TaskHandle>,
+    liveliness_lost_status: LivelinessLostStatus,
+    liveliness_lost_task: Option<TaskHandle>,
+    coherent_set_open: bool,
+    coherent_set: Option<CoherentSet>,
+    // Changes without data marking the end of the coherent sets. They are sent like
+    // the changes of the history but they are not part of it.
+    coherent_set_end_list: VecDeque<RtpsWriterCacheChange>,
+    publications_suspended: bool,
+    persistent_storage: Option<Arc<dyn PersistentStorage>>,
+    // The log is compacted only by a writer which replayed it since otherwise
+    // it also keeps the changes served by the durability service
+    is_persistent_storage_compacted: bool,
+    persistent_storage_record_count: usize,
+    persistent_storage_compacted_record_count: usize,
 }
 
 impl DataWriterActor {
@@ -274,6 +325,7 @@ impl DataWriterActor {
         topic_name: String,
         type_name: String,
         topic_status_condition: ActorAddress<StatusConditionActor>,
+        type_support: Arc<dyn DynamicType + Send + Sync>,
         listener: Option<Box<dyn AnyDataWriterListener + Send>>,
         status_kind: Vec<StatusKind>,
         qos: DataWriterQos,
@@ -285,11 +337,14 @@ impl DataWriterActor {
         DataWriterActor {
             rtps_writer,
             reader_locators: Vec::new(),
+            participant_reader_locators: HashMap::new(),
             matched_readers: Vec::new(),
+            reader_content_filters: HashMap::new(),
             topic_address,
             topic_name,
             type_name,
             topic_status_condition,
+            type_support,
             matched_subscriptions: MatchedSubscriptions::new(),
             incompatible_subscriptions: IncompatibleSubscriptions::new(),
             enabled: false,
@@ -299,9 +354,65 @@ impl DataWriterActor {
             changes: HashMap::new(),
             max_seq_num: None,
             qos,
-            registered_instance_list: HashSet::new(),
+            registered_instance_list: HashMap::new(),
             offered_deadline_missed_status: OfferedDeadlineMissedStatus::default(),
             instance_deadline_missed_task: HashMap::new(),
+            liveliness_lost_status: LivelinessLostStatus::default(),
+            liveliness_lost_task: None,
+            coherent_set_open: false,
+            coherent_set: None,
+            coherent_set_end_list: VecDeque::new(),
+            publications_suspended: false,
+            persistent_storage: None,
+            is_persistent_storage_compacted: false,
+            persistent_storage_record_count: 0,
+            persistent_storage_compacted_record_count: 0,
+        }
+    }
+
+    fn compact_persistent_storage(&mut self) -> DdsResult<()> {
+        let Some(persistent_storage) = &self.persistent_storage else {
+            return Ok(());
+        };
+        let mut history_changes: Vec<_> = self.changes.values().flatten().collect();
+        history_changes.sort_by_key(|c| c.sequence_number());
+        let serialized_changes: Vec<_> = history_changes
+            .into_iter()
+            .map(|c| {
+                let mut serialized_change = Vec::new();
+                c.write_into_bytes(&mut serialized_change);
+                (c.instance_handle().into(), serialized_change)
+            })
+            .collect();
+        persistent_storage.replace(&self.topic_name, &serialized_changes)?;
+        self.persistent_storage_record_count = serialized_changes.len();
+        self.persistent_storage_compacted_record_count = serialized_changes.len();
+        Ok(())
+    }
+
+    fn assert_liveliness(
+        &mut self,
+        writer_address: ActorAddress<DataWriterActor>,
+        executor_handle: &ExecutorHandle,
+        timer_handle: TimerHandle,
+    ) {
+        if let Some(t) = self.liveliness_lost_task.take() {
+            t.abort();
+        }
+
+        // With automatic liveliness the service asserts it on behalf of the writer
+        if self.qos.liveliness.kind == LivelinessQosPolicyKind::Automatic {
+            return;
+        }
+
+        if let DurationKind::Finite(lease_duration) = self.qos.liveliness.lease_duration {
+            let lease_duration = std::time::Duration::from(lease_duration);
+            self.liveliness_lost_task = Some(executor_handle.spawn(async move {
+                timer_handle.sleep(lease_duration).await;
+                writer_address
+                    .send_actor_mail(IncrementLivelinessLostStatus)
+                    .ok();
+            }));
         }
     }
 
@@ -318,14 +429,100 @@ impl DataWriterActor {
         InstanceHandle::new(self.rtps_writer.guid().into())
     }
 
+    fn remove_disposed_instances(&mut self) {
+        self.changes
+            .retain(|_, instance_changes| match instance_changes.back() {
+                Some(last_change) => !is_disposed_change(last_change),
+                None => false,
+            });
+    }
+
+    fn apply_durability_service_limits(&mut self) {
+        let durability_service = self.qos.durability_service.clone();
+        let limit = |length: Length| match length {
+            Length::Unlimited => usize::MAX,
+            Length::Limited(l) => l as usize,
+        };
+        let max_samples_per_instance = match durability_service.history_kind {
+            HistoryQosPolicyKind::KeepLast(depth) => {
+                limit(durability_service.max_samples_per_instance).min(depth as usize)
+            }
+            HistoryQosPolicyKind::KeepAll => limit(durability_service.max_samples_per_instance),
+        };
+        for instance_changes in self.changes.values_mut() {
+            while instance_changes.len() > max_samples_per_instance {
+                instance_changes.pop_front();
+            }
+        }
+
+        let mut instance_list: Vec<_> = self
+            .changes
+            .iter()
+            .filter_map(|(handle, c)| c.back().map(|c| (*handle, c.sequence_number())))
+            .collect();
+        instance_list.sort_by_key(|(_, sequence_number)| std::cmp::Reverse(*sequence_number));
+        for (handle, _) in instance_list
+            .into_iter()
+            .skip(limit(durability_service.max_instances))
+        {
+            self.changes.remove(&handle);
+        }
+
+        let mut change_list: Vec<_> = self
+            .changes
+            .iter()
+            .flat_map(|(handle, c)| c.iter().map(|c| (*handle, c.sequence_number())))
+            .collect();
+        change_list.sort_by_key(|(_, sequence_number)| *sequence_number);
+        let number_of_changes_to_remove = change_list
+            .len()
+            .saturating_sub(limit(durability_service.max_samples));
+        for (handle, _) in change_list.into_iter().take(number_of_changes_to_remove) {
+            if let Some(instance_changes) = self.changes.get_mut(&handle) {
+                instance_changes.pop_front();
+            }
+        }
+        self.changes.retain(|_, c| !c.is_empty());
+    }
+
     fn send_message(&mut self, message_sender_actor: ActorAddress<MessageSenderActor>) {
-        self.send_message_to_reader_locators(&message_sender_actor);
+        // While the publications are suspended the new changes stay in the history and are sent
+        // when the publications are resumed. The changes already sent are still repaired.
+        if !self.publications_suspended {
+            self.send_message_to_reader_locators(&message_sender_actor);
+        }
         self.send_message_to_reader_proxies(&message_sender_actor);
     }
 
     fn matched_reader_remove(&mut self, a_reader_guid: Guid) {
         self.matched_readers
-            .retain(|x| x.remote_reader_guid() != a_reader_guid)
+            .retain(|x| x.remote_reader_guid() != a_reader_guid);
+        self.reader_content_filters
+            .remove(&InstanceHandle::new(a_reader_guid.into()));
+    }
+
+    fn create_reader_content_filter(
+        &self,
+        content_filter: &ContentFilterProperty,
+    ) -> Option<ReaderContentFilter> {
+        // Filters which can not be evaluated by this writer are left to the reader
+        if content_filter.filter_class_name != DDSSQL_FILTER_CLASS_NAME {
+            return None;
+        }
+        let expression = FilterExpression::parse(&content_filter.filter_expression).ok()?;
+        expression
+            .validate(
+                &get_member_paths(self.type_support.as_ref()).ok()?,
+                &content_filter.expression_parameters,
+            )
+            .ok()?;
+        Some(ReaderContentFilter {
+            filter: ContentFilter {
+                expression,
+                parameters: content_filter.expression_parameters.clone(),
+            },
+            signature: content_filter.signature(),
+        })
     }
 
     fn on_acknack_submessage_received(
@@ -426,14 +623,83 @@ impl DataWriterActor {
         &mut self,
         message_sender_actor: &ActorAddress<MessageSenderActor>,
     ) {
+        let change_list: Vec<_> = self
+            .changes
+            .values()
+            .flatten()
+            .chain(&self.coherent_set_end_list)
+            .collect();
+        let seq_num_min = self
+            .changes
+            .values()
+            .flatten()
+            .map(|cc| cc.sequence_number())
+            .min();
+
+        // The readers of user-defined data which receive on the same multicast locators and which
+        // were sent the same changes are sent a single copy of the unsent changes on those locators
+        if self.rtps_writer.guid().entity_id().entity_kind() & BUILT_IN_UNKNOWN == 0
+            && !self.publications_suspended
+        {
+            let mut multicast_reader_proxy_group_list: Vec<Vec<&mut RtpsReaderProxy>> = Vec::new();
+            for reader_proxy in self.matched_readers.iter_mut().filter(|rp| {
+                !rp.multicast_locator_list().is_empty()
+                    && !self
+                        .reader_content_filters
+                        .contains_key(&InstanceHandle::new(rp.remote_reader_guid().into()))
+            }) {
+                match multicast_reader_proxy_group_list.iter_mut().find(|g| {
+                    g[0].multicast_locator_list() == reader_proxy.multicast_locator_list()
+                        && g[0].highest_sent_seq_num() == reader_proxy.highest_sent_seq_num()
+                        && g[0].first_relevant_sample_seq_num()
+                            == reader_proxy.first_relevant_sample_seq_num()
+                }) {
+                    Some(reader_proxy_group) => reader_proxy_group.push(reader_proxy),
+                    None => multicast_reader_proxy_group_list.push(vec![reader_proxy]),
+                }
+            }
+            for reader_proxy_group in multicast_reader_proxy_group_list {
+                send_message_to_multicast_reader_proxies(
+                    reader_proxy_group,
+                    self.rtps_writer.guid().entity_id(),
+                    &change_list,
+                    seq_num_min,
+                    self.max_seq_num,
+                    self.rtps_writer.data_max_size_serialized(),
+                    message_sender_actor,
+                );
+            }
+        }
+
         for reader_proxy in &mut self.matched_readers {
+            let reader_content_filter = self
+                .reader_content_filters
+                .get(&InstanceHandle::new(
+                    reader_proxy.remote_reader_guid().into(),
+                ))
+                .map(|f| (f, self.type_support.as_ref() as &dyn DynamicType));
+            // While suspended only the changes already sent to the reader are made available
+            let (reader_change_list, seq_num_max) = if self.publications_suspended {
+                let highest_sent_seq_num = reader_proxy.highest_sent_seq_num();
+                (
+                    change_list
+                        .iter()
+                        .filter(|cc| cc.sequence_number() <= highest_sent_seq_num)
+                        .copied()
+                        .collect(),
+                    self.max_seq_num.map(|sn| sn.min(highest_sent_seq_num)),
+                )
+            } else {
+                (change_list.clone(), self.max_seq_num)
+            };
             match (&self.qos.reliability.kind, reader_proxy.reliability()) {
                 (ReliabilityQosPolicyKind::BestEffort, ReliabilityKind::BestEffort)
                 | (ReliabilityQosPolicyKind::Reliable, ReliabilityKind::BestEffort) => {
                     send_message_to_reader_proxy_best_effort(
                         reader_proxy,
+                        reader_content_filter,
                         self.rtps_writer.guid().entity_id(),
-                        &self.changes,
+                        &reader_change_list,
                         self.rtps_writer.data_max_size_serialized(),
                         message_sender_actor,
                     )
@@ -441,14 +707,11 @@ impl DataWriterActor {
                 (ReliabilityQosPolicyKind::Reliable, ReliabilityKind::Reliable) => {
                     send_message_to_reader_proxy_reliable(
                         reader_proxy,
+                        reader_content_filter,
                         self.rtps_writer.guid().entity_id(),
-                        &self.changes,
-                        self.changes
-                            .values()
-                            .flatten()
-                            .map(|cc| cc.sequence_number())
-                            .min(),
-                        self.max_seq_num,
+                        &reader_change_list,
+                        seq_num_min,
+                        seq_num_max,
                         self.rtps_writer.data_max_size_serialized(),
                         self.rtps_writer.heartbeat_period().into(),
                         message_sender_actor,
@@ -459,6 +722,57 @@ impl DataWriterActor {
                 }
             }
         }
+
+        // The end of a coherent set is no longer needed once all the matched readers received it,
+        // except for the last one which completes the set sent to the readers matched later
+        let last_coherent_set_end_seq_num = self
+            .coherent_set_end_list
+            .back()
+            .map(|cc| cc.sequence_number());
+        let matched_readers = &self.matched_readers;
+        self.coherent_set_end_list.retain(|cc| {
+            let seq_num = cc.sequence_number();
+            Some(seq_num) == last_coherent_set_end_seq_num
+                || matched_readers.iter().any(|rp| {
+                    rp.highest_sent_seq_num() < seq_num
+                        || (rp.reliability() == ReliabilityKind::Reliable
+                            && rp.unacked_changes(Some(seq_num)))
+                })
+        });
+    }
+
+    fn send_liveliness_heartbeat(
+        &mut self,
+        message_sender_actor: &ActorAddress<MessageSenderActor>,
+    ) {
+        let writer_id = self.rtps_writer.guid().entity_id();
+        let first_sn = self
+            .changes
+            .values()
+            .flatten()
+            .map(|cc| cc.sequence_number())
+            .min()
+            .unwrap_or(1);
+        let last_sn = self.max_seq_num.unwrap_or(0);
+        for reader_proxy in &mut self.matched_readers {
+            // The changes held back while suspended are not announced
+            let last_sn = if self.publications_suspended {
+                last_sn.min(reader_proxy.highest_sent_seq_num())
+            } else {
+                last_sn
+            };
+            let heartbeat_submessage = Box::new(
+                reader_proxy
+                    .heartbeat_machine()
+                    .generate_new_liveliness_heartbeat(writer_id, first_sn, last_sn),
+            );
+            message_sender_actor
+                .send_actor_mail(message_sender_actor::WriteMessage {
+                    submessages: vec![heartbeat_submessage],
+                    destination_locator_list: reader_proxy.unicast_locator_list().to_vec(),
+                })
+                .ok();
+        }
     }
 
     fn on_nack_frag_submessage_received(
@@ -722,6 +1036,11 @@ impl MailHandler<GetOfferedIncompatibleQosStatus> for DataWriterActor {
         &mut self,
         _: GetOfferedIncompatibleQosStatus,
     ) -> <GetOfferedIncompatibleQosStatus as Mail>::Result {
+        self.status_condition
+            .send_actor_mail(status_condition_actor::RemoveCommunicationState {
+                state: StatusKind::OfferedIncompatibleQos,
+            });
+
         self.incompatible_subscriptions
             .get_offered_incompatible_qos_status()
     }
@@ -754,6 +1073,13 @@ impl MailHandler<Enable> for DataWriterActor {
     fn handle(&mut self, message: Enable) -> <Enable as Mail>::Result {
         self.enabled = true;
 
+        // The lease of a writer with manual liveliness starts when it is enabled
+        self.assert_liveliness(
+            message.data_writer_address.clone(),
+            &message.executor_handle,
+            message.timer_handle.clone(),
+        );
+
         if self.qos.reliability.kind == ReliabilityQosPolicyKind::Reliable {
             let half_heartbeat_period =
                 std::time::Duration::from(Duration::from(self.rtps_writer.heartbeat_period())) / 2;
@@ -835,6 +1161,7 @@ impl MailHandler<SetQos> for DataWriterActor {
 
 pub struct RegisterInstanceWTimestamp {
     pub instance_handle: InstanceHandle,
+    pub serialized_key: Data,
 }
 impl Mail for RegisterInstanceWTimestamp {
     type Result = DdsResult<Option<InstanceHandle>>;
@@ -846,11 +1173,11 @@ impl MailHandler<RegisterInstanceWTimestamp> for DataWriterActor {
     ) -> <RegisterInstanceWTimestamp as Mail>::Result {
         if !self
             .registered_instance_list
-            .contains(&message.instance_handle)
+            .contains_key(&message.instance_handle)
         {
             if self.registered_instance_list.len() < self.qos.resource_limits.max_instances {
                 self.registered_instance_list
-                    .insert(message.instance_handle);
+                    .insert(message.instance_handle, message.serialized_key);
             } else {
                 return Err(DdsError::OutOfResources);
             }
@@ -874,7 +1201,7 @@ impl MailHandler<LookupInstance> for DataWriterActor {
         Ok(
             if self
                 .registered_instance_list
-                .contains(&message.instance_handle)
+                .contains_key(&message.instance_handle)
             {
                 Some(message.instance_handle)
             } else {
@@ -884,6 +1211,211 @@ impl MailHandler<LookupInstance> for DataWriterActor {
     }
 }
 
+pub struct GetKeyValue {
+    pub handle: InstanceHandle,
+    pub serialized_key_holder: Data,
+}
+impl Mail for GetKeyValue {
+    type Result = DdsResult<Data>;
+}
+impl MailHandler<GetKeyValue> for DataWriterActor {
+    fn handle(&mut self, message: GetKeyValue) -> <GetKeyValue as Mail>::Result {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        let serialized_key = self
+            .registered_instance_list
+            .get(&message.handle)
+            .ok_or(DdsError::BadParameter)?;
+        Ok(set_serialized_key_in_serialized_foo(
+            message.serialized_key_holder.as_ref(),
+            serialized_key.as_ref(),
+            self.type_support.as_ref(),
+        )?
+        .into())
+    }
+}
+
+pub struct NewUnregisterAllInstancesChanges {
+    pub group_inline_qos: Vec<Parameter>,
+    pub timestamp: Time,
+}
+impl Mail for NewUnregisterAllInstancesChanges {
+    type Result = DdsResult<Vec<RtpsWriterCacheChange>>;
+}
+impl MailHandler<NewUnregisterAllInstancesChanges> for DataWriterActor {
+    fn handle(
+        &mut self,
+        message: NewUnregisterAllInstancesChanges,
+    ) -> <NewUnregisterAllInstancesChanges as Mail>::Result {
+        let status_info = if self
+            .qos
+            .writer_data_lifecycle
+            .autodispose_unregistered_instances
+        {
+            STATUS_INFO_DISPOSED_UNREGISTERED
+        } else {
+            STATUS_INFO_UNREGISTERED
+        };
+        let mut serialized_status_info = Vec::new();
+        let mut serializer = Xcdr1LeSerializer::new(&mut serialized_status_info);
+        XTypesSerialize::serialize(&status_info, &mut serializer)?;
+        let serialized_status_info: Arc<[u8]> = Arc::from(serialized_status_info);
+
+        let mut change_list = Vec::new();
+        for (instance_handle, serialized_key) in &self.registered_instance_list {
+            let pid_status_info = Parameter::new(PID_STATUS_INFO, serialized_status_info.clone());
+            let pid_key_hash = Parameter::new(PID_KEY_HASH, Arc::from(*instance_handle.as_ref()));
+            let inline_qos = ParameterList::new(
+                [
+                    vec![pid_status_info, pid_key_hash],
+                    message.group_inline_qos.clone(),
+                ]
+                .concat(),
+            );
+            change_list.push(self.rtps_writer.new_change(
+                ChangeKind::NotAliveUnregistered,
+                serialized_key.clone(),
+                inline_qos,
+                (*instance_handle).into(),
+                message.timestamp.into(),
+            ));
+        }
+        Ok(change_list)
+    }
+}
+
+pub struct HandOverToDurabilityService {
+    pub writer_address: ActorAddress<DataWriterActor>,
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
+}
+impl Mail for HandOverToDurabilityService {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<HandOverToDurabilityService> for DataWriterActor {
+    fn handle(
+        &mut self,
+        message: HandOverToDurabilityService,
+    ) -> <HandOverToDurabilityService as Mail>::Result {
+        // The application no longer uses the writer so its listener and the
+        // periodic tasks related to the written instances are stopped
+        if let Some(listener) = self.data_writer_listener_thread.take() {
+            listener.join()?;
+        }
+        self.status_kind.clear();
+        if let Some(t) = self.liveliness_lost_task.take() {
+            t.abort();
+        }
+        for (_, t) in self.instance_deadline_missed_task.drain() {
+            t.abort();
+        }
+
+        // The history kept by the service is limited by the durability service policy
+        self.apply_durability_service_limits();
+
+        match self.qos.durability_service.service_cleanup_delay {
+            DurationKind::Finite(service_cleanup_delay)
+                if service_cleanup_delay == Duration::new(0, 0) =>
+            {
+                self.remove_disposed_instances()
+            }
+            DurationKind::Finite(service_cleanup_delay) => {
+                let timer_handle = message.timer_handle.clone();
+                let writer_address = message.writer_address.clone();
+                message.executor_handle.spawn(async move {
+                    timer_handle.sleep(service_cleanup_delay.into()).await;
+                    writer_address.send_actor_mail(RemoveDisposedInstances).ok();
+                });
+            }
+            DurationKind::Infinite => (),
+        }
+        Ok(())
+    }
+}
+
+pub struct RemoveDisposedInstances;
+impl Mail for RemoveDisposedInstances {
+    type Result = ();
+}
+impl MailHandler<RemoveDisposedInstances> for DataWriterActor {
+    fn handle(&mut self, _: RemoveDisposedInstances) -> <RemoveDisposedInstances as Mail>::Result {
+        self.remove_disposed_instances()
+    }
+}
+
+pub struct AttachPersistentStorage {
+    pub persistent_storage: Arc<dyn PersistentStorage>,
+    pub replay_stored_changes: bool,
+}
+impl Mail for AttachPersistentStorage {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<AttachPersistentStorage> for DataWriterActor {
+    fn handle(
+        &mut self,
+        message: AttachPersistentStorage,
+    ) -> <AttachPersistentStorage as Mail>::Result {
+        if message.replay_stored_changes {
+            let mut stored_changes = Vec::new();
+            for (_, serialized_change) in message.persistent_storage.read(&self.topic_name)? {
+                stored_changes.push(RtpsWriterCacheChange::try_read_from_bytes(
+                    &mut serialized_change.as_slice(),
+                    &Endianness::LittleEndian,
+                )?);
+            }
+
+            // The stored samples are written again by this writer. Disposed instances
+            // are not replayed and the coherent sets of the previous writer are dropped
+            for stored_change in stored_changes {
+                let handle = stored_change.instance_handle();
+                if is_disposed_change(&stored_change) {
+                    self.changes.remove(&handle);
+                } else if stored_change.kind() == ChangeKind::Alive
+                    && !stored_change.data_value().is_empty()
+                {
+                    let inline_qos = ParameterList::new(
+                        stored_change
+                            .inline_qos()
+                            .parameter()
+                            .iter()
+                            .filter(|p| {
+                                p.parameter_id() != PID_COHERENT_SET
+                                    && p.parameter_id() != PID_GROUP_COHERENT_SET
+                            })
+                            .cloned()
+                            .collect(),
+                    );
+                    let change = self.rtps_writer.new_change(
+                        ChangeKind::Alive,
+                        stored_change.data_value().clone(),
+                        inline_qos,
+                        handle,
+                        stored_change.timestamp(),
+                    );
+                    self.max_seq_num = Some(change.sequence_number());
+                    let instance_changes = self.changes.entry(handle).or_default();
+                    if let HistoryQosPolicyKind::KeepLast(depth) = self.qos.history.kind {
+                        if instance_changes.len() == depth as usize {
+                            instance_changes.pop_front();
+                        }
+                    }
+                    instance_changes.push_back(change);
+                }
+            }
+            self.apply_durability_service_limits();
+        }
+        self.persistent_storage = Some(message.persistent_storage);
+        self.is_persistent_storage_compacted = message.replay_stored_changes;
+        if self.is_persistent_storage_compacted {
+            // The storage is compacted to the replayed changes
+            self.compact_persistent_storage()?;
+        }
+        Ok(())
+    }
+}
+
 pub struct AreAllChangesAcknowledge;
 impl Mail for AreAllChangesAcknowledge {
     type Result = bool;
@@ -960,7 +1492,11 @@ impl MailHandler<AsDiscoveredWriterData> for DataWriterActor {
             },
             writer_proxy: WriterProxy {
                 remote_writer_guid: self.rtps_writer.guid(),
-                remote_group_entity_id: EntityId::new([0; 3], USER_DEFINED_UNKNOWN),
+                // The first byte of the writer entity key is the one of its publisher
+                remote_group_entity_id: EntityId::new(
+                    [self.rtps_writer.guid().entity_id().entity_key()[0], 0, 0],
+                    USER_DEFINED_WRITER_GROUP,
+                ),
                 unicast_locator_list,
                 multicast_locator_list,
                 data_max_size_serialized: Default::default(),
@@ -1135,14 +1671,32 @@ impl MailHandler<AddMatchedReader> for DataWriterActor {
                     first_relevant_sample_seq_num,
                 );
 
+                let reader_guid = reader_proxy.remote_reader_guid();
                 if !self
                     .matched_readers
                     .iter()
-                    .any(|x| x.remote_reader_guid() == reader_proxy.remote_reader_guid())
+                    .any(|x| x.remote_reader_guid() == reader_guid)
                 {
                     self.matched_readers.push(reader_proxy)
                 }
 
+                match message
+                    .discovered_reader_data
+                    .content_filter()
+                    .and_then(|cf| self.create_reader_content_filter(cf))
+                {
+                    Some(reader_content_filter) => {
+                        self.reader_content_filters.insert(
+                            InstanceHandle::new(reader_guid.into()),
+                            reader_content_filter,
+                        );
+                    }
+                    None => {
+                        self.reader_content_filters
+                            .remove(&InstanceHandle::new(reader_guid.into()));
+                    }
+                }
+
                 if !self
                     .matched_subscriptions
                     .get_matched_subscriptions()
@@ -1226,6 +1780,55 @@ impl MailHandler<RemoveMatchedReader> for DataWriterActor {
     }
 }
 
+pub struct AddParticipantReaderLocators {
+    pub participant_handle: InstanceHandle,
+    pub locator_list: Vec<Locator>,
+}
+impl Mail for AddParticipantReaderLocators {
+    type Result = ();
+}
+impl MailHandler<AddParticipantReaderLocators> for DataWriterActor {
+    fn handle(
+        &mut self,
+        message: AddParticipantReaderLocators,
+    ) -> <AddParticipantReaderLocators as Mail>::Result {
+        // Only the locators which are not yet in use are owned by the participant
+        // so that removing the participant doesn't remove the configured locators
+        let mut locator_list = Vec::new();
+        for locator in message.locator_list {
+            if !self.reader_locators.iter().any(|r| r.locator() == locator) {
+                self.reader_locator_add(RtpsReaderLocator::new(locator, false));
+                locator_list.push(locator);
+            }
+        }
+        self.participant_reader_locators
+            .entry(message.participant_handle)
+            .or_default()
+            .extend(locator_list);
+    }
+}
+
+pub struct RemoveParticipantReaderLocators {
+    pub participant_handle: InstanceHandle,
+}
+impl Mail for RemoveParticipantReaderLocators {
+    type Result = ();
+}
+impl MailHandler<RemoveParticipantReaderLocators> for DataWriterActor {
+    fn handle(
+        &mut self,
+        message: RemoveParticipantReaderLocators,
+    ) -> <RemoveParticipantReaderLocators as Mail>::Result {
+        if let Some(locator_list) = self
+            .participant_reader_locators
+            .remove(&message.participant_handle)
+        {
+            self.reader_locators
+                .retain(|r| !locator_list.contains(&r.locator()));
+        }
+    }
+}
+
 pub struct ProcessAckNackSubmessage {
     pub acknack_submessage: AckNackSubmessage,
     pub source_guid_prefix: GuidPrefix,
@@ -1309,16 +1912,98 @@ impl Mail for NewChange {
 }
 impl MailHandler<NewChange> for DataWriterActor {
     fn handle(&mut self, message: NewChange) -> <NewChange as Mail>::Result {
+        let mut inline_qos = message.inline_qos;
+        if self.coherent_set_open {
+            // The coherent set is identified by the sequence number of its first change
+            let next_sequence_number = self.rtps_writer.next_sequence_number();
+            let coherent_set = *self
+                .coherent_set
+                .get_or_insert_with(|| CoherentSet::new(next_sequence_number));
+            let mut parameter = inline_qos.parameter().to_vec();
+            parameter.push(coherent_set_parameter(&coherent_set));
+            inline_qos = ParameterList::new(parameter);
+        }
+
         self.rtps_writer.new_change(
             message.kind,
             message.data,
-            message.inline_qos,
+            inline_qos,
             message.handle.into(),
             message.timestamp.into(),
         )
     }
 }
 
+pub struct BeginCoherentSet;
+impl Mail for BeginCoherentSet {
+    type Result = ();
+}
+impl MailHandler<BeginCoherentSet> for DataWriterActor {
+    fn handle(&mut self, _: BeginCoherentSet) -> <BeginCoherentSet as Mail>::Result {
+        self.coherent_set_open = true;
+    }
+}
+
+pub struct SuspendPublications;
+impl Mail for SuspendPublications {
+    type Result = ();
+}
+impl MailHandler<SuspendPublications> for DataWriterActor {
+    fn handle(&mut self, _: SuspendPublications) -> <SuspendPublications as Mail>::Result {
+        self.publications_suspended = true;
+    }
+}
+
+pub struct ResumePublications {
+    pub message_sender_actor: ActorAddress<MessageSenderActor>,
+}
+impl Mail for ResumePublications {
+    type Result = ();
+}
+impl MailHandler<ResumePublications> for DataWriterActor {
+    fn handle(&mut self, message: ResumePublications) -> <ResumePublications as Mail>::Result {
+        self.publications_suspended = false;
+        self.send_message(message.message_sender_actor);
+    }
+}
+
+pub struct EndCoherentSet {
+    pub now: Time,
+    pub message_sender_actor: ActorAddress<MessageSenderActor>,
+    pub group_coherent_set: Option<GroupSequenceNumber>,
+}
+impl Mail for EndCoherentSet {
+    type Result = ();
+}
+impl MailHandler<EndCoherentSet> for DataWriterActor {
+    fn handle(&mut self, message: EndCoherentSet) -> <EndCoherentSet as Mail>::Result {
+        self.coherent_set_open = false;
+        if self.coherent_set.take().is_some() || message.group_coherent_set.is_some() {
+            // The end of the set is marked with a change without data whose coherent set is
+            // SEQUENCENUMBER_UNKNOWN. It is kept out of the history so that it is neither an
+            // instance nor a sample for the history, durability and resource limits.
+            let mut parameter = vec![coherent_set_parameter(&COHERENT_SET_END)];
+            if let Some(group_coherent_set) = &message.group_coherent_set {
+                parameter.push(group_coherent_set_parameter(group_coherent_set));
+            }
+            let change = self.rtps_writer.new_change(
+                ChangeKind::Alive,
+                Data::default(),
+                ParameterList::new(parameter),
+                self.get_instance_handle().into(),
+                message.now.into(),
+            );
+            let seq_num = change.sequence_number();
+            if seq_num > self.max_seq_num.unwrap_or(0) {
+                self.max_seq_num = Some(seq_num)
+            }
+            self.coherent_set_end_list.push_back(change);
+
+            self.send_message(message.message_sender_actor);
+        }
+    }
+}
+
 pub struct AddChange {
     pub change: RtpsWriterCacheChange,
     pub now: Time,
@@ -1341,6 +2026,32 @@ impl Mail for AddChange {
 }
 impl MailHandler<AddChange> for DataWriterActor {
     fn handle(&mut self, message: AddChange) -> <AddChange as Mail>::Result {
+        // Writing a sample implicitly asserts the liveliness of the writer
+        self.assert_liveliness(
+            message.writer_address.clone(),
+            &message.executor_handle,
+            message.timer_handle.clone(),
+        );
+
+        if let Some(persistent_storage) = &self.persistent_storage {
+            let mut serialized_change = Vec::new();
+            message.change.write_into_bytes(&mut serialized_change);
+            if let Err(e) = persistent_storage.append(
+                &self.topic_name,
+                message.change.instance_handle().into(),
+                &serialized_change,
+            ) {
+                tracing::warn!("Failed to store change in persistent storage: {:?}", e);
+            }
+            self.persistent_storage_record_count += 1;
+        }
+
+        // The handle of an unregistered instance is no longer valid for the writer
+        if message.change.kind() == ChangeKind::NotAliveUnregistered {
+            self.registered_instance_list
+                .remove(&message.change.instance_handle().into());
+        }
+
         let instance_changes = self
             .changes
             .entry(message.change.instance_handle())
@@ -1512,6 +2223,19 @@ impl MailHandler<AddChange> for DataWriterActor {
             instance_changes.push_back(message.change);
         }
 
+        // The changes evicted from the history are removed from the storage once the log
+        // has grown to twice its size after the last compaction
+        if self.is_persistent_storage_compacted
+            && self.persistent_storage_record_count
+                > 2 * self
+                    .persistent_storage_compacted_record_count
+                    .max(MIN_COMPACTED_PERSISTENT_STORAGE_RECORD_COUNT)
+        {
+            if let Err(e) = self.compact_persistent_storage() {
+                tracing::warn!("Failed to compact persistent storage: {:?}", e);
+            }
+        }
+
         self.send_message(message.message_sender_actor);
     }
 }
@@ -1668,6 +2392,94 @@ impl MailHandler<GetOfferedDeadlineMissedStatus> for DataWriterActor {
     }
 }
 
+pub struct AssertLiveliness {
+    pub writer_address: ActorAddress<DataWriterActor>,
+    pub message_sender_actor: ActorAddress<MessageSenderActor>,
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
+}
+impl Mail for AssertLiveliness {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<AssertLiveliness> for DataWriterActor {
+    fn handle(&mut self, message: AssertLiveliness) -> <AssertLiveliness as Mail>::Result {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        self.assert_liveliness(
+            message.writer_address,
+            &message.executor_handle,
+            message.timer_handle,
+        );
+        // The liveliness of a MANUAL_BY_TOPIC writer is asserted to the matched readers
+        // by a HEARTBEAT with the liveliness flag set
+        if self.qos.liveliness.kind == LivelinessQosPolicyKind::ManualByTopic {
+            self.send_liveliness_heartbeat(&message.message_sender_actor);
+        }
+        Ok(())
+    }
+}
+
+pub struct AssertManualByParticipantLiveliness {
+    pub writer_address: ActorAddress<DataWriterActor>,
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
+}
+impl Mail for AssertManualByParticipantLiveliness {
+    type Result = ();
+}
+impl MailHandler<AssertManualByParticipantLiveliness> for DataWriterActor {
+    fn handle(
+        &mut self,
+        message: AssertManualByParticipantLiveliness,
+    ) -> <AssertManualByParticipantLiveliness as Mail>::Result {
+        if self.enabled && self.qos.liveliness.kind == LivelinessQosPolicyKind::ManualByParticipant
+        {
+            self.assert_liveliness(
+                message.writer_address,
+                &message.executor_handle,
+                message.timer_handle,
+            );
+        }
+    }
+}
+
+pub struct IncrementLivelinessLostStatus;
+impl Mail for IncrementLivelinessLostStatus {
+    type Result = ();
+}
+impl MailHandler<IncrementLivelinessLostStatus> for DataWriterActor {
+    fn handle(
+        &mut self,
+        _: IncrementLivelinessLostStatus,
+    ) -> <IncrementLivelinessLostStatus as Mail>::Result {
+        self.liveliness_lost_status.total_count += 1;
+        self.liveliness_lost_status.total_count_change += 1;
+        self.status_condition
+            .send_actor_mail(AddCommunicationState {
+                state: StatusKind::LivelinessLost,
+            });
+    }
+}
+
+pub struct GetLivelinessLostStatus;
+impl Mail for GetLivelinessLostStatus {
+    type Result = LivelinessLostStatus;
+}
+impl MailHandler<GetLivelinessLostStatus> for DataWriterActor {
+    fn handle(&mut self, _: GetLivelinessLostStatus) -> <GetLivelinessLostStatus as Mail>::Result {
+        self.status_condition
+            .send_actor_mail(status_condition_actor::RemoveCommunicationState {
+                state: StatusKind::LivelinessLost,
+            });
+
+        let status = self.liveliness_lost_status.clone();
+        self.liveliness_lost_status.total_count_change = 0;
+        status
+    }
+}
+
 fn get_discovered_reader_incompatible_qos_policy_list(
     writer_qos: &DataWriterQos,
     discovered_reader_data: &SubscriptionBuiltinTopicData,
@@ -1691,7 +2503,10 @@ fn get_discovered_reader_incompatible_qos_policy_list(
     if &writer_qos.latency_budget < discovered_reader_data.latency_budget() {
         incompatible_qos_policy_list.push(LATENCYBUDGET_QOS_POLICY_ID);
     }
-    if &writer_qos.liveliness < discovered_reader_data.liveliness() {
+    // Offered kind >= requested kind and offered lease_duration <= requested lease_duration
+    if writer_qos.liveliness.kind < discovered_reader_data.liveliness().kind
+        || writer_qos.liveliness.lease_duration > discovered_reader_data.liveliness().lease_duration
+    {
         incompatible_qos_policy_list.push(LIVELINESS_QOS_POLICY_ID);
     }
     if writer_qos.reliability.kind < discovered_reader_data.reliability().kind {
@@ -1722,10 +2537,87 @@ fn get_discovered_reader_incompatible_qos_policy_list(
     incompatible_qos_policy_list
 }
 
+fn is_disposed_change(change: &RtpsWriterCacheChange) -> bool {
+    match change.kind() {
+        ChangeKind::NotAliveDisposed | ChangeKind::NotAliveDisposedUnregistered => true,
+        // An unregistered instance is also disposed if the status info says so
+        ChangeKind::NotAliveUnregistered => change
+            .inline_qos()
+            .parameter()
+            .iter()
+            .find(|p| p.parameter_id() == PID_STATUS_INFO)
+            .and_then(|p| p.value().get(3))
+            .is_some_and(|flags| flags & 0b1 != 0),
+        ChangeKind::Alive | ChangeKind::AliveFiltered => false,
+    }
+}
+
+fn coherent_set_parameter(coherent_set: &CoherentSet) -> Parameter {
+    let mut serialized_coherent_set = Vec::new();
+    let mut serializer = Xcdr1LeSerializer::new(&mut serialized_coherent_set);
+    XTypesSerialize::serialize(coherent_set, &mut serializer)
+        .expect("Coherent set serialization must succeed");
+    Parameter::new(PID_COHERENT_SET, Arc::from(serialized_coherent_set))
+}
+
+fn group_coherent_set_parameter(group_coherent_set: &GroupSequenceNumber) -> Parameter {
+    let mut serialized_group_coherent_set = Vec::new();
+    let mut serializer = Xcdr1LeSerializer::new(&mut serialized_group_coherent_set);
+    XTypesSerialize::serialize(group_coherent_set, &mut serializer)
+        .expect("Group coherent set serialization must succeed");
+    Parameter::new(
+        PID_GROUP_COHERENT_SET,
+        Arc::from(serialized_group_coherent_set),
+    )
+}
+
+/// Inline QoS with which the change is sent to the reader or `None` if the change
+/// does not pass the content filter of the reader.
+fn filtered_inline_qos(
+    cache_change: &RtpsWriterCacheChange,
+    reader_content_filter: Option<(&ReaderContentFilter, &dyn DynamicType)>,
+) -> Option<ParameterList> {
+    // Changes without data, like the end of a coherent set, are not filtered
+    match reader_content_filter {
+        Some((reader_content_filter, type_support))
+            if cache_change.kind() == ChangeKind::Alive
+                && !cache_change.data_value().is_empty() =>
+        {
+            let filter_result = reader_content_filter
+                .filter
+                .accepts(cache_change.data_value().as_ref(), type_support);
+            // The filter is evaluated with the expression parameters last announced by the
+            // reader. Samples written before a change of parameters is announced are
+            // therefore filtered with the previous ones.
+            if !filter_result {
+                return None;
+            }
+            // Tell the reader the result of the filter so that it can skip evaluating it
+            // if it still uses the same one
+            let content_filter_info = ContentFilterInfo {
+                filter_result: vec![if filter_result { 1 << 31 } else { 0 }],
+                filter_signatures: vec![reader_content_filter.signature],
+            };
+            let mut serialized_content_filter_info = Vec::new();
+            let mut serializer = Xcdr1LeSerializer::new(&mut serialized_content_filter_info);
+            let mut parameter = cache_change.inline_qos().parameter().to_vec();
+            if XTypesSerialize::serialize(&content_filter_info, &mut serializer).is_ok() {
+                parameter.push(Parameter::new(
+                    PID_CONTENT_FILTER_INFO,
+                    Arc::from(serialized_content_filter_info),
+                ));
+            }
+            Some(ParameterList::new(parameter))
+        }
+        _ => Some(cache_change.inline_qos().clone()),
+    }
+}
+
 fn send_message_to_reader_proxy_best_effort(
     reader_proxy: &mut RtpsReaderProxy,
+    reader_content_filter: Option<(&ReaderContentFilter, &dyn DynamicType)>,
     writer_id: EntityId,
-    changes: &HashMap<crate::rtps::behavior_types::InstanceHandle, VecDeque<RtpsWriterCacheChange>>,
+    changes: &[&RtpsWriterCacheChange],
     data_max_size_serialized: usize,
     message_sender_actor: &ActorAddress<MessageSenderActor>,
 ) {
@@ -1754,7 +2646,7 @@ fn send_message_to_reader_proxy_best_effort(
     // }
     // the_reader_proxy.higuest_sent_seq_num := a_change_seq_num;
     while let Some(next_unsent_change_seq_num) =
-        reader_proxy.next_unsent_change(changes.values().flatten())
+        reader_proxy.next_unsent_change(changes.iter().copied())
     {
         if next_unsent_change_seq_num > reader_proxy.highest_sent_seq_num() + 1 {
             let gap_start_sequence_number = reader_proxy.highest_sent_seq_num() + 1;
@@ -1774,10 +2666,10 @@ fn send_message_to_reader_proxy_best_effort(
                 .ok();
 
             reader_proxy.set_highest_sent_seq_num(next_unsent_change_seq_num);
-        } else if let Some(cache_change) = changes
-            .values()
-            .flatten()
+        } else if let Some((cache_change, inline_qos)) = changes
+            .iter()
             .find(|cc| cc.sequence_number() == next_unsent_change_seq_num)
+            .and_then(|cc| Some((cc, filtered_inline_qos(cc, reader_content_filter)?)))
         {
             let number_of_fragments = cache_change
                 .data_value()
@@ -1798,9 +2690,10 @@ fn send_message_to_reader_proxy_best_effort(
 
                     let inline_qos_flag = true;
                     let key_flag = match cache_change.kind() {
-                        ChangeKind::Alive => false,
-                        ChangeKind::NotAliveDisposed | ChangeKind::NotAliveUnregistered => true,
-                        _ => todo!(),
+                        ChangeKind::Alive | ChangeKind::AliveFiltered => false,
+                        ChangeKind::NotAliveDisposed
+                        | ChangeKind::NotAliveUnregistered
+                        | ChangeKind::NotAliveDisposedUnregistered => true,
                     };
                     let non_standard_payload_flag = false;
                     let reader_id = reader_proxy.remote_reader_guid().entity_id();
@@ -1810,7 +2703,7 @@ fn send_message_to_reader_proxy_best_effort(
                     let fragments_in_submessage = 1;
                     let fragment_size = data_max_size_serialized as u16;
                     let data_size = cache_change.data_value().len() as u32;
-                    let inline_qos = cache_change.inline_qos().clone();
+                    let inline_qos = inline_qos.clone();
 
                     let start = frag_index * data_max_size_serialized;
                     let end = std::cmp::min(
@@ -1853,9 +2746,10 @@ fn send_message_to_reader_proxy_best_effort(
                     cache_change.timestamp(),
                 ));
 
-                let data_submessage = Box::new(
-                    cache_change.as_data_submessage(reader_proxy.remote_reader_guid().entity_id()),
-                );
+                let data_submessage = Box::new(cache_change.as_data_submessage_with_inline_qos(
+                    reader_proxy.remote_reader_guid().entity_id(),
+                    inline_qos,
+                ));
 
                 message_sender_actor
                     .send_actor_mail(message_sender_actor::WriteMessage {
@@ -1882,11 +2776,162 @@ fn send_message_to_reader_proxy_best_effort(
     }
 }
 
+/// Send the unsent changes to a group of reader proxies which receive on the same multicast locators
+/// and which were sent the same changes. Each change is sent once to the multicast locators and the
+/// reliable readers are then sent a HEARTBEAT on their unicast locators so that they can acknowledge
+/// the changes or request the missing ones.
+#[allow(clippy::too_many_arguments)]
+fn send_message_to_multicast_reader_proxies(
+    mut reader_proxy_group: Vec<&mut RtpsReaderProxy>,
+    writer_id: EntityId,
+    changes: &[&RtpsWriterCacheChange],
+    seq_num_min: Option<SequenceNumber>,
+    seq_num_max: Option<SequenceNumber>,
+    data_max_size_serialized: usize,
+    message_sender_actor: &ActorAddress<MessageSenderActor>,
+) {
+    let multicast_locator_list = reader_proxy_group[0].multicast_locator_list().to_vec();
+    let first_relevant_sample_seq_num = reader_proxy_group[0].first_relevant_sample_seq_num();
+    let mut is_change_sent = false;
+    while let Some(next_unsent_change_seq_num) =
+        reader_proxy_group[0].next_unsent_change(changes.iter().copied())
+    {
+        let highest_sent_seq_num = reader_proxy_group[0].highest_sent_seq_num();
+        if next_unsent_change_seq_num > highest_sent_seq_num + 1 {
+            message_sender_actor
+                .send_actor_mail(message_sender_actor::WriteMessage {
+                    submessages: vec![Box::new(GapSubmessage::new(
+                        ENTITYID_UNKNOWN,
+                        writer_id,
+                        highest_sent_seq_num + 1,
+                        SequenceNumberSet::new(next_unsent_change_seq_num, []),
+                    ))],
+                    destination_locator_list: multicast_locator_list.clone(),
+                })
+                .ok();
+        }
+
+        match changes
+            .iter()
+            .find(|cc| cc.sequence_number() == next_unsent_change_seq_num)
+        {
+            Some(cache_change) if next_unsent_change_seq_num > first_relevant_sample_seq_num => {
+                let number_of_fragments = cache_change
+                    .data_value()
+                    .len()
+                    .div_ceil(data_max_size_serialized);
+
+                // Either send a DATAFRAG submessages or send a single DATA submessage
+                if number_of_fragments > 1 {
+                    for frag_index in 0..number_of_fragments {
+                        let info_timestamp = Box::new(InfoTimestampSubmessage::new(
+                            false,
+                            cache_change.timestamp(),
+                        ));
+
+                        let key_flag = match cache_change.kind() {
+                            ChangeKind::Alive | ChangeKind::AliveFiltered => false,
+                            ChangeKind::NotAliveDisposed
+                            | ChangeKind::NotAliveUnregistered
+                            | ChangeKind::NotAliveDisposedUnregistered => true,
+                        };
+                        let start = frag_index * data_max_size_serialized;
+                        let end = std::cmp::min(
+                            (frag_index + 1) * data_max_size_serialized,
+                            cache_change.data_value().len(),
+                        );
+
+                        let data_frag = Box::new(DataFragSubmessage::new(
+                            true,
+                            false,
+                            key_flag,
+                            ENTITYID_UNKNOWN,
+                            cache_change.writer_guid().entity_id(),
+                            cache_change.sequence_number(),
+                            (frag_index + 1) as u32,
+                            1,
+                            data_max_size_serialized as u16,
+                            cache_change.data_value().len() as u32,
+                            cache_change.inline_qos().clone(),
+                            SerializedDataFragment::new(
+                                cache_change.data_value().clone(),
+                                start..end,
+                            ),
+                        ));
+
+                        message_sender_actor
+                            .send_actor_mail(message_sender_actor::WriteMessage {
+                                submessages: vec![info_timestamp, data_frag],
+                                destination_locator_list: multicast_locator_list.clone(),
+                            })
+                            .ok();
+                    }
+                } else {
+                    let info_timestamp = Box::new(InfoTimestampSubmessage::new(
+                        false,
+                        cache_change.timestamp(),
+                    ));
+
+                    let data_submessage =
+                        Box::new(cache_change.as_data_submessage(ENTITYID_UNKNOWN));
+
+                    message_sender_actor
+                        .send_actor_mail(message_sender_actor::WriteMessage {
+                            submessages: vec![info_timestamp, data_submessage],
+                            destination_locator_list: multicast_locator_list.clone(),
+                        })
+                        .ok();
+                }
+            }
+            _ => {
+                message_sender_actor
+                    .send_actor_mail(message_sender_actor::WriteMessage {
+                        submessages: vec![Box::new(GapSubmessage::new(
+                            ENTITYID_UNKNOWN,
+                            writer_id,
+                            next_unsent_change_seq_num,
+                            SequenceNumberSet::new(next_unsent_change_seq_num + 1, []),
+                        ))],
+                        destination_locator_list: multicast_locator_list.clone(),
+                    })
+                    .ok();
+            }
+        }
+
+        for reader_proxy in reader_proxy_group.iter_mut() {
+            reader_proxy.set_highest_sent_seq_num(next_unsent_change_seq_num);
+        }
+        is_change_sent = true;
+    }
+
+    if is_change_sent {
+        let first_sn = seq_num_min.unwrap_or(1);
+        let last_sn = seq_num_max.unwrap_or(0);
+        for reader_proxy in reader_proxy_group
+            .into_iter()
+            .filter(|rp| rp.reliability() == ReliabilityKind::Reliable)
+        {
+            let heartbeat_submessage = Box::new(
+                reader_proxy
+                    .heartbeat_machine()
+                    .generate_new_heartbeat(writer_id, first_sn, last_sn),
+            );
+            message_sender_actor
+                .send_actor_mail(message_sender_actor::WriteMessage {
+                    submessages: vec![heartbeat_submessage],
+                    destination_locator_list: reader_proxy.unicast_locator_list().to_vec(),
+                })
+                .ok();
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn send_message_to_reader_proxy_reliable(
     reader_proxy: &mut RtpsReaderProxy,
+    reader_content_filter: Option<(&ReaderContentFilter, &dyn DynamicType)>,
     writer_id: EntityId,
-    changes: &HashMap<crate::rtps::behavior_types::InstanceHandle, VecDeque<RtpsWriterCacheChange>>,
+    changes: &[&RtpsWriterCacheChange],
     seq_num_min: Option<SequenceNumber>,
     seq_num_max: Option<SequenceNumber>,
     data_max_size_serialized: usize,
@@ -1894,9 +2939,9 @@ fn send_message_to_reader_proxy_reliable(
     message_sender_actor: &ActorAddress<MessageSenderActor>,
 ) {
     // Top part of the state machine - Figure 8.19 RTPS standard
-    if reader_proxy.unsent_changes(changes.values().flatten()) {
+    if reader_proxy.unsent_changes(changes.iter().copied()) {
         while let Some(next_unsent_change_seq_num) =
-            reader_proxy.next_unsent_change(changes.values().flatten())
+            reader_proxy.next_unsent_change(changes.iter().copied())
         {
             if next_unsent_change_seq_num > reader_proxy.highest_sent_seq_num() + 1 {
                 let gap_start_sequence_number = reader_proxy.highest_sent_seq_num() + 1;
@@ -1923,6 +2968,7 @@ fn send_message_to_reader_proxy_reliable(
             } else {
                 send_change_message_reader_proxy_reliable(
                     reader_proxy,
+                    reader_content_filter,
                     writer_id,
                     changes,
                     seq_num_min,
@@ -1966,6 +3012,7 @@ fn send_message_to_reader_proxy_reliable(
             // should be full-filled by next_requested_change()
             send_change_message_reader_proxy_reliable(
                 reader_proxy,
+                reader_content_filter,
                 writer_id,
                 changes,
                 seq_num_min,
@@ -1981,8 +3028,9 @@ fn send_message_to_reader_proxy_reliable(
 #[allow(clippy::too_many_arguments)]
 fn send_change_message_reader_proxy_reliable(
     reader_proxy: &mut RtpsReaderProxy,
+    reader_content_filter: Option<(&ReaderContentFilter, &dyn DynamicType)>,
     writer_id: EntityId,
-    changes: &HashMap<crate::rtps::behavior_types::InstanceHandle, VecDeque<RtpsWriterCacheChange>>,
+    changes: &[&RtpsWriterCacheChange],
     seq_num_min: Option<SequenceNumber>,
     seq_num_max: Option<SequenceNumber>,
     data_max_size_serialized: usize,
@@ -1990,11 +3038,13 @@ fn send_change_message_reader_proxy_reliable(
     message_sender_actor: &ActorAddress<MessageSenderActor>,
 ) {
     match changes
-        .values()
-        .flatten()
+        .iter()
         .find(|cc| cc.sequence_number() == change_seq_num)
+        .and_then(|cc| Some((cc, filtered_inline_qos(cc, reader_content_filter)?)))
     {
-        Some(cache_change) if change_seq_num > reader_proxy.first_relevant_sample_seq_num() => {
+        Some((cache_change, inline_qos))
+            if change_seq_num > reader_proxy.first_relevant_sample_seq_num() =>
+        {
             let number_of_fragments = cache_change
                 .data_value()
                 .len()
@@ -2014,9 +3064,10 @@ fn send_change_message_reader_proxy_reliable(
 
                     let inline_qos_flag = true;
                     let key_flag = match cache_change.kind() {
-                        ChangeKind::Alive => false,
-                        ChangeKind::NotAliveDisposed | ChangeKind::NotAliveUnregistered => true,
-                        _ => todo!(),
+                        ChangeKind::Alive | ChangeKind::AliveFiltered => false,
+                        ChangeKind::NotAliveDisposed
+                        | ChangeKind::NotAliveUnregistered
+                        | ChangeKind::NotAliveDisposedUnregistered => true,
                     };
                     let non_standard_payload_flag = false;
                     let reader_id = reader_proxy.remote_reader_guid().entity_id();
@@ -2026,7 +3077,7 @@ fn send_change_message_reader_proxy_reliable(
                     let fragments_in_submessage = 1;
                     let fragment_size = data_max_size_serialized as u16;
                     let data_size = cache_change.data_value().len() as u32;
-                    let inline_qos = cache_change.inline_qos().clone();
+                    let inline_qos = inline_qos.clone();
 
                     let start = frag_index * data_max_size_serialized;
                     let end = std::cmp::min(
@@ -2069,9 +3120,10 @@ fn send_change_message_reader_proxy_reliable(
                     cache_change.timestamp(),
                 ));
 
-                let data_submessage = Box::new(
-                    cache_change.as_data_submessage(reader_proxy.remote_reader_guid().entity_id()),
-                );
+                let data_submessage = Box::new(cache_change.as_data_submessage_with_inline_qos(
+                    reader_proxy.remote_reader_guid().entity_id(),
+                    inline_qos,
+                ));
 
                 let first_sn = seq_num_min.unwrap_or(1);
                 let last_sn = seq_num_max.unwrap_or(0);
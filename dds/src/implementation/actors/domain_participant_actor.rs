@@ -1,6 +1,8 @@
 use super::{
     data_writer_actor::DataWriterActor,
-    domain_participant_factory_actor::{sedp_data_reader_qos, sedp_data_writer_qos},
+    domain_participant_factory_actor::{
+        port_user_multicast, sedp_data_reader_qos, sedp_data_writer_qos,
+    },
     message_sender_actor::MessageSenderActor,
     publisher_actor::{self, PublisherActor},
     status_condition_actor::StatusConditionActor,
@@ -28,25 +30,33 @@ use crate::{
             data_reader_actor::DataReaderActor, subscriber_actor::SubscriberActor,
             topic_actor::TopicActor,
         },
+        content_filter::ContentFilterProperty,
         data_representation_builtin_endpoints::{
             discovered_reader_data::{DiscoveredReaderData, ReaderProxy},
             discovered_topic_data::DiscoveredTopicData,
             discovered_writer_data::{DiscoveredWriterData, WriterProxy},
+            participant_message_data::{
+                ParticipantMessageData, DCPS_PARTICIPANT_MESSAGE,
+                PARTICIPANT_MESSAGE_DATA_KIND_AUTOMATIC_LIVELINESS_UPDATE,
+                PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE,
+            },
             spdp_discovered_participant_data::{ParticipantProxy, SpdpDiscoveredParticipantData},
         },
+        multi_topic::MultiTopicProperty,
         runtime::{
-            executor::{block_on, Executor, ExecutorHandle},
+            executor::{block_on, Executor, ExecutorHandle, TaskHandle},
             mpsc::{mpsc_channel, MpscSender},
             timer::{TimerDriver, TimerHandle},
         },
+        udp_transport::{get_multicast_socket, join_multicast_group, spawn_receive_thread},
     },
     infrastructure::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DomainParticipantQos, PublisherQos, QosKind, SubscriberQos, TopicQos},
         qos_policy::{
-            HistoryQosPolicy, LifespanQosPolicy, ResourceLimitsQosPolicy,
-            TransportPriorityQosPolicy,
+            DurabilityQosPolicyKind, HistoryQosPolicy, LifespanQosPolicy, LivelinessQosPolicyKind,
+            ResourceLimitsQosPolicy, TransportMulticastQosPolicy, TransportPriorityQosPolicy,
         },
         status::{
             LivelinessChangedStatus, LivelinessLostStatus, OfferedDeadlineMissedStatus,
@@ -56,13 +66,17 @@ use crate::{
         },
         time::{Duration, Time},
     },
+    persistent_storage::PersistentStorage,
     rtps::{
         discovery_types::{
-            BuiltinEndpointQos, BuiltinEndpointSet, ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
+            BuiltinEndpointQos, BuiltinEndpointSet,
+            ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER,
+            ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER,
+            ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
             ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
             ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
             ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR, ENTITYID_SEDP_BUILTIN_TOPICS_ANNOUNCER,
-            ENTITYID_SEDP_BUILTIN_TOPICS_DETECTOR,
+            ENTITYID_SEDP_BUILTIN_TOPICS_DETECTOR, ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER,
         },
         group::RtpsGroup,
         message_receiver::MessageReceiver,
@@ -72,7 +86,7 @@ use crate::{
         },
         participant::RtpsParticipant,
         types::{
-            EntityId, Guid, Locator, BUILT_IN_READER_GROUP, BUILT_IN_WRITER_GROUP,
+            EntityId, Guid, GuidPrefix, Locator, BUILT_IN_READER_GROUP, BUILT_IN_WRITER_GROUP,
             ENTITYID_PARTICIPANT, ENTITYID_UNKNOWN, USER_DEFINED_READER_GROUP, USER_DEFINED_TOPIC,
             USER_DEFINED_WRITER_GROUP,
         },
@@ -80,8 +94,10 @@ use crate::{
     subscription::sample_info::{
         InstanceStateKind, SampleStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE,
     },
+    transport::TransportReceiver,
     xtypes::dynamic_type::DynamicType,
 };
+use network_interface::{Addr, NetworkInterface};
 use std::{
     collections::{hash_map::Entry, HashMap, HashSet},
     sync::Arc,
@@ -90,11 +106,12 @@ use std::{
 };
 use tracing::warn;
 
-pub const BUILT_IN_TOPIC_NAME_LIST: [&str; 4] = [
+pub const BUILT_IN_TOPIC_NAME_LIST: [&str; 5] = [
     DCPS_PARTICIPANT,
     DCPS_TOPIC,
     DCPS_PUBLICATION,
     DCPS_SUBSCRIPTION,
+    DCPS_PARTICIPANT_MESSAGE,
 ];
 
 pub enum ListenerKind {
@@ -391,23 +408,42 @@ pub struct DomainParticipantActor {
     user_defined_publisher_list: HashMap<InstanceHandle, Actor<PublisherActor>>,
     user_defined_publisher_counter: u8,
     default_publisher_qos: PublisherQos,
+    durability_service_publisher_list: HashMap<InstanceHandle, Actor<PublisherActor>>,
+    durability_service_persistent_topic_list: HashSet<String>,
+    persistent_storage: Option<Arc<dyn PersistentStorage>>,
     topic_list: HashMap<String, (Actor<TopicActor>, ActorAddress<StatusConditionActor>)>,
     user_defined_topic_counter: u8,
     default_topic_qos: TopicQos,
+    content_filtered_topic_list: HashMap<String, ContentFilterProperty>,
+    multi_topic_list: HashMap<
+        String,
+        (
+            MultiTopicProperty,
+            Actor<TopicActor>,
+            ActorAddress<StatusConditionActor>,
+        ),
+    >,
     manual_liveliness_count: Count,
     lease_duration: Duration,
     discovered_participant_list: HashMap<InstanceHandle, SpdpDiscoveredParticipantData>,
+    discovered_participant_lease_task: HashMap<InstanceHandle, TaskHandle>,
     discovered_topic_list: HashMap<InstanceHandle, TopicBuiltinTopicData>,
     enabled: bool,
     ignored_participants: HashSet<InstanceHandle>,
     ignored_publications: HashSet<InstanceHandle>,
     ignored_subcriptions: HashSet<InstanceHandle>,
     ignored_topic_list: HashSet<InstanceHandle>,
+    ignored_topic_name_list: HashSet<String>,
     data_max_size_serialized: usize,
     participant_listener_thread: Option<ParticipantListenerThread>,
     status_kind: Vec<StatusKind>,
     status_condition: Actor<StatusConditionActor>,
     message_sender_actor: Actor<MessageSenderActor>,
+    interface_list: Vec<NetworkInterface>,
+    user_defined_multicast_socket_list: HashMap<(i32, u32), std::net::UdpSocket>,
+    joined_multicast_locator_list: Vec<Locator>,
+    automatic_liveliness_writer_list: HashMap<InstanceHandle, std::time::Duration>,
+    automatic_liveliness_task: Option<TaskHandle>,
     executor: Executor,
     timer_driver: TimerDriver,
 }
@@ -420,12 +456,15 @@ impl DomainParticipantActor {
         domain_tag: String,
         domain_participant_qos: DomainParticipantQos,
         data_max_size_serialized: usize,
+        lease_duration: Duration,
+        persistent_storage: Option<Arc<dyn PersistentStorage>>,
         listener: Option<Box<dyn DomainParticipantListenerAsync + Send>>,
         status_kind: Vec<StatusKind>,
         topic_list: HashMap<String, (Actor<TopicActor>, ActorAddress<StatusConditionActor>)>,
         builtin_data_writer_list: Vec<DataWriterActor>,
         builtin_data_reader_list: Vec<DataReaderActor>,
         message_sender_actor: MessageSenderActor,
+        interface_list: Vec<NetworkInterface>,
         executor: Executor,
         timer_driver: TimerDriver,
     ) -> (
@@ -434,7 +473,6 @@ impl DomainParticipantActor {
         ActorAddress<SubscriberActor>,
         ActorAddress<StatusConditionActor>,
     ) {
-        let lease_duration = Duration::new(100, 0);
         let guid_prefix = rtps_participant.guid().prefix();
         let executor_handle = executor.handle();
 
@@ -484,23 +522,35 @@ impl DomainParticipantActor {
                 user_defined_publisher_list: HashMap::new(),
                 user_defined_publisher_counter: 0,
                 default_publisher_qos: PublisherQos::default(),
+                durability_service_publisher_list: HashMap::new(),
+                durability_service_persistent_topic_list: HashSet::new(),
+                persistent_storage,
                 topic_list,
                 user_defined_topic_counter: 0,
                 default_topic_qos: TopicQos::default(),
+                content_filtered_topic_list: HashMap::new(),
+                multi_topic_list: HashMap::new(),
                 manual_liveliness_count: 0,
                 lease_duration,
                 discovered_participant_list: HashMap::new(),
+                discovered_participant_lease_task: HashMap::new(),
                 discovered_topic_list: HashMap::new(),
                 enabled: false,
                 ignored_participants: HashSet::new(),
                 ignored_publications: HashSet::new(),
                 ignored_subcriptions: HashSet::new(),
                 ignored_topic_list: HashSet::new(),
+                ignored_topic_name_list: HashSet::new(),
                 data_max_size_serialized,
                 participant_listener_thread,
                 status_kind,
                 status_condition,
                 message_sender_actor: Actor::spawn(message_sender_actor, &executor_handle),
+                interface_list,
+                user_defined_multicast_socket_list: HashMap::new(),
+                joined_multicast_locator_list: Vec::new(),
+                automatic_liveliness_writer_list: HashMap::new(),
+                automatic_liveliness_task: None,
                 executor,
                 timer_driver,
             },
@@ -510,6 +560,61 @@ impl DomainParticipantActor {
         )
     }
 
+    fn renew_discovered_participant_lease(
+        &mut self,
+        discovered_participant_handle: InstanceHandle,
+        lease_duration: Duration,
+        participant: DomainParticipantAsync,
+    ) {
+        if let Some(t) = self
+            .discovered_participant_lease_task
+            .remove(&discovered_participant_handle)
+        {
+            t.abort();
+        }
+
+        let timer_handle = self.timer_driver.handle();
+        let participant_address = participant.participant_address().clone();
+        let lease_task = self.executor.handle().spawn(async move {
+            timer_handle.sleep(lease_duration.into()).await;
+            participant_address
+                .send_actor_mail(RemoveDiscoveredParticipant {
+                    handle: discovered_participant_handle,
+                    participant,
+                })
+                .ok();
+        });
+        self.discovered_participant_lease_task
+            .insert(discovered_participant_handle, lease_task);
+    }
+
+    fn restart_automatic_liveliness_task(&mut self, participant: DomainParticipantAsync) {
+        if let Some(t) = self.automatic_liveliness_task.take() {
+            t.abort();
+        }
+
+        // The liveliness of all the AUTOMATIC writers is asserted with a single message sent
+        // often enough to keep alive the writer with the shortest lease duration
+        if let Some(lease_duration) = self.automatic_liveliness_writer_list.values().min() {
+            let period = *lease_duration / 3;
+            let timer_handle = self.timer_driver.handle();
+            self.automatic_liveliness_task = Some(self.executor.handle().spawn(async move {
+                loop {
+                    let r = participant
+                        .announce_participant_liveliness(
+                            PARTICIPANT_MESSAGE_DATA_KIND_AUTOMATIC_LIVELINESS_UPDATE,
+                        )
+                        .await;
+                    if r.is_err() {
+                        break;
+                    }
+
+                    timer_handle.sleep(period).await;
+                }
+            }));
+        }
+    }
+
     fn lookup_discovered_topic(
         &mut self,
         topic_name: String,
@@ -521,6 +626,7 @@ impl DomainParticipantActor {
                 let qos = TopicQos {
                     topic_data: discovered_topic_data.topic_data().clone(),
                     durability: discovered_topic_data.durability().clone(),
+                    durability_service: Default::default(),
                     deadline: discovered_topic_data.deadline().clone(),
                     latency_budget: discovered_topic_data.latency_budget().clone(),
                     liveliness: discovered_topic_data.liveliness().clone(),
@@ -549,6 +655,16 @@ impl DomainParticipantActor {
         Ok(None)
     }
 
+    fn create_user_defined_topic_guid(
+        rtps_participant: &RtpsParticipant,
+        user_defined_topic_counter: &mut u8,
+    ) -> Guid {
+        let topic_counter = *user_defined_topic_counter;
+        *user_defined_topic_counter += 1;
+        let entity_id = EntityId::new([topic_counter, 0, 0], USER_DEFINED_TOPIC);
+        Guid::new(rtps_participant.guid().prefix(), entity_id)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn create_user_defined_topic(
         &mut self,
@@ -560,15 +676,27 @@ impl DomainParticipantActor {
         type_support: Arc<dyn DynamicType + Send + Sync>,
         executor_handle: ExecutorHandle,
     ) -> DdsResult<(ActorAddress<TopicActor>, ActorAddress<StatusConditionActor>)> {
+        if self.content_filtered_topic_list.contains_key(&topic_name) {
+            return Err(DdsError::PreconditionNotMet(format!(
+                "ContentFilteredTopic with name {} already exists",
+                topic_name
+            )));
+        }
+        if self.multi_topic_list.contains_key(&topic_name) {
+            return Err(DdsError::PreconditionNotMet(format!(
+                "MultiTopic with name {} already exists",
+                topic_name
+            )));
+        }
         if let Entry::Vacant(e) = self.topic_list.entry(topic_name.clone()) {
             let qos = match qos {
                 QosKind::Default => self.default_topic_qos.clone(),
                 QosKind::Specific(q) => q,
             };
-            let topic_counter = self.user_defined_topic_counter;
-            self.user_defined_topic_counter += 1;
-            let entity_id = EntityId::new([topic_counter, 0, 0], USER_DEFINED_TOPIC);
-            let guid = Guid::new(self.rtps_participant.guid().prefix(), entity_id);
+            let guid = Self::create_user_defined_topic_guid(
+                &self.rtps_participant,
+                &mut self.user_defined_topic_counter,
+            );
 
             let (topic, topic_status_condition) = TopicActor::new(
                 guid,
@@ -610,6 +738,54 @@ impl DomainParticipantActor {
             .expect("Clock time is before Unix epoch start");
         infrastructure::time::Time::new(unix_time.as_secs() as i32, unix_time.subsec_nanos())
     }
+
+    fn remove_matched_writer(
+        &self,
+        discovered_writer_handle: InstanceHandle,
+        participant: &DomainParticipantAsync,
+    ) {
+        for subscriber in self.user_defined_subscriber_list.values() {
+            let subscriber_address = subscriber.address();
+            let participant_mask_listener = (
+                self.participant_listener_thread
+                    .as_ref()
+                    .map(|l| l.sender().clone()),
+                self.status_kind.clone(),
+            );
+            subscriber.send_actor_mail(subscriber_actor::RemoveMatchedWriter {
+                discovered_writer_handle,
+                subscriber_address,
+                participant: participant.clone(),
+                participant_mask_listener,
+            });
+        }
+    }
+
+    fn remove_matched_reader(
+        &self,
+        discovered_reader_handle: InstanceHandle,
+        participant: &DomainParticipantAsync,
+    ) {
+        for publisher in self
+            .user_defined_publisher_list
+            .values()
+            .chain(self.durability_service_publisher_list.values())
+        {
+            let publisher_address = publisher.address();
+            let participant_mask_listener = (
+                self.participant_listener_thread
+                    .as_ref()
+                    .map(|l| l.sender().clone()),
+                self.status_kind.clone(),
+            );
+            publisher.send_actor_mail(publisher_actor::RemoveMatchedReader {
+                discovered_reader_handle,
+                publisher_address,
+                participant: participant.clone(),
+                participant_mask_listener,
+            });
+        }
+    }
 }
 
 pub struct CreateUserDefinedPublisher {
@@ -659,6 +835,75 @@ impl MailHandler<CreateUserDefinedPublisher> for DomainParticipantActor {
     }
 }
 
+pub struct AddDurabilityServiceWriter {
+    pub publisher_qos: PublisherQos,
+    pub data_writer_handle: InstanceHandle,
+    pub data_writer: Actor<DataWriterActor>,
+    pub topic_name: String,
+    pub durability_kind: DurabilityQosPolicyKind,
+    pub executor_handle: ExecutorHandle,
+}
+impl Mail for AddDurabilityServiceWriter {
+    type Result = ();
+}
+impl MailHandler<AddDurabilityServiceWriter> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: AddDurabilityServiceWriter,
+    ) -> <AddDurabilityServiceWriter as Mail>::Result {
+        // Each writer kept by the durability service gets its own internal publisher
+        // so that it keeps matching readers with the partition of the deleted publisher
+        let publisher_counter = self.user_defined_publisher_counter;
+        self.user_defined_publisher_counter += 1;
+        let entity_id = EntityId::new([publisher_counter, 0, 0], USER_DEFINED_WRITER_GROUP);
+        let guid = Guid::new(self.rtps_participant.guid().prefix(), entity_id);
+        let mut publisher = PublisherActor::new(
+            message.publisher_qos,
+            RtpsGroup::new(guid),
+            None,
+            vec![],
+            vec![],
+            &message.executor_handle,
+        );
+        publisher.add_data_writer(message.data_writer_handle, message.data_writer);
+        let publisher_actor = Actor::spawn(publisher, &message.executor_handle);
+        publisher_actor.send_actor_mail(publisher_actor::Enable);
+
+        self.durability_service_publisher_list
+            .insert(InstanceHandle::new(guid.into()), publisher_actor);
+        if message.durability_kind == DurabilityQosPolicyKind::Persistent {
+            self.durability_service_persistent_topic_list
+                .insert(message.topic_name);
+        }
+    }
+}
+
+pub struct IsPersistentTopicInDurabilityService {
+    pub topic_name: String,
+}
+impl Mail for IsPersistentTopicInDurabilityService {
+    type Result = bool;
+}
+impl MailHandler<IsPersistentTopicInDurabilityService> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: IsPersistentTopicInDurabilityService,
+    ) -> <IsPersistentTopicInDurabilityService as Mail>::Result {
+        self.durability_service_persistent_topic_list
+            .contains(&message.topic_name)
+    }
+}
+
+pub struct GetPersistentStorage;
+impl Mail for GetPersistentStorage {
+    type Result = Option<Arc<dyn PersistentStorage>>;
+}
+impl MailHandler<GetPersistentStorage> for DomainParticipantActor {
+    fn handle(&mut self, _: GetPersistentStorage) -> <GetPersistentStorage as Mail>::Result {
+        self.persistent_storage.clone()
+    }
+}
+
 pub struct DeleteUserDefinedPublisher {
     pub handle: InstanceHandle,
 }
@@ -762,6 +1007,16 @@ impl MailHandler<GetSubscriberList> for DomainParticipantActor {
     }
 }
 
+pub struct GetTopicList;
+impl Mail for GetTopicList {
+    type Result = Vec<ActorAddress<TopicActor>>;
+}
+impl MailHandler<GetTopicList> for DomainParticipantActor {
+    fn handle(&mut self, _: GetTopicList) -> <GetTopicList as Mail>::Result {
+        self.topic_list.values().map(|(t, _)| t.address()).collect()
+    }
+}
+
 pub struct CreateUserDefinedTopic {
     pub topic_name: String,
     pub type_name: String,
@@ -843,6 +1098,240 @@ impl MailHandler<LookupTopicdescription> for DomainParticipantActor {
     }
 }
 
+pub struct CreateContentFilteredTopic {
+    pub content_filter: ContentFilterProperty,
+}
+impl Mail for CreateContentFilteredTopic {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<CreateContentFilteredTopic> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: CreateContentFilteredTopic,
+    ) -> <CreateContentFilteredTopic as Mail>::Result {
+        let name = message.content_filter.content_filtered_topic_name.clone();
+        if self.topic_list.contains_key(&name) {
+            return Err(DdsError::PreconditionNotMet(format!(
+                "Topic with name {} already exists",
+                name
+            )));
+        }
+        if self.multi_topic_list.contains_key(&name) {
+            return Err(DdsError::PreconditionNotMet(format!(
+                "MultiTopic with name {} already exists",
+                name
+            )));
+        }
+        if let Entry::Vacant(e) = self.content_filtered_topic_list.entry(name) {
+            e.insert(message.content_filter);
+            Ok(())
+        } else {
+            Err(DdsError::PreconditionNotMet(format!(
+                "ContentFilteredTopic with name {} already exists",
+                message.content_filter.content_filtered_topic_name
+            )))
+        }
+    }
+}
+
+pub struct DeleteContentFilteredTopic {
+    pub name: String,
+}
+impl Mail for DeleteContentFilteredTopic {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<DeleteContentFilteredTopic> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: DeleteContentFilteredTopic,
+    ) -> <DeleteContentFilteredTopic as Mail>::Result {
+        self.content_filtered_topic_list
+            .remove(&message.name)
+            .map(|_| ())
+            .ok_or(DdsError::AlreadyDeleted)
+    }
+}
+
+pub struct GetContentFilteredTopic {
+    pub name: String,
+}
+impl Mail for GetContentFilteredTopic {
+    type Result = DdsResult<ContentFilterProperty>;
+}
+impl MailHandler<GetContentFilteredTopic> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: GetContentFilteredTopic,
+    ) -> <GetContentFilteredTopic as Mail>::Result {
+        self.content_filtered_topic_list
+            .get(&message.name)
+            .cloned()
+            .ok_or(DdsError::AlreadyDeleted)
+    }
+}
+
+pub struct SetContentFilteredTopicParameters {
+    pub name: String,
+    pub expression_parameters: Vec<String>,
+}
+impl Mail for SetContentFilteredTopicParameters {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<SetContentFilteredTopicParameters> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: SetContentFilteredTopicParameters,
+    ) -> <SetContentFilteredTopicParameters as Mail>::Result {
+        let content_filter = self
+            .content_filtered_topic_list
+            .get_mut(&message.name)
+            .ok_or(DdsError::AlreadyDeleted)?;
+        content_filter.filter.parameters = message.expression_parameters;
+        Ok(())
+    }
+}
+
+pub struct HasContentFilteredTopics {
+    pub related_topic_name: String,
+}
+impl Mail for HasContentFilteredTopics {
+    type Result = bool;
+}
+impl MailHandler<HasContentFilteredTopics> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: HasContentFilteredTopics,
+    ) -> <HasContentFilteredTopics as Mail>::Result {
+        self.content_filtered_topic_list
+            .values()
+            .any(|f| f.related_topic_name == message.related_topic_name)
+    }
+}
+
+pub struct CreateMultiTopic {
+    pub multi_topic: MultiTopicProperty,
+    pub type_support: Arc<dyn DynamicType + Send + Sync>,
+    pub executor_handle: ExecutorHandle,
+}
+impl Mail for CreateMultiTopic {
+    type Result = DdsResult<(ActorAddress<TopicActor>, ActorAddress<StatusConditionActor>)>;
+}
+impl MailHandler<CreateMultiTopic> for DomainParticipantActor {
+    fn handle(&mut self, message: CreateMultiTopic) -> <CreateMultiTopic as Mail>::Result {
+        let name = message.multi_topic.multi_topic_name.clone();
+        if self.topic_list.contains_key(&name) {
+            return Err(DdsError::PreconditionNotMet(format!(
+                "Topic with name {} already exists",
+                name
+            )));
+        }
+        if self.content_filtered_topic_list.contains_key(&name) {
+            return Err(DdsError::PreconditionNotMet(format!(
+                "ContentFilteredTopic with name {} already exists",
+                name
+            )));
+        }
+        if let Entry::Vacant(e) = self.multi_topic_list.entry(name) {
+            // The MultiTopic has a topic of its own type which is used by the DataReaders
+            // created from it. This topic is neither announced nor listed as a user topic.
+            let guid = Self::create_user_defined_topic_guid(
+                &self.rtps_participant,
+                &mut self.user_defined_topic_counter,
+            );
+            let (topic, topic_status_condition) = TopicActor::new(
+                guid,
+                self.default_topic_qos.clone(),
+                message.multi_topic.type_name.clone(),
+                &message.multi_topic.multi_topic_name,
+                None,
+                message.type_support,
+                &message.executor_handle,
+            );
+            let topic_actor = Actor::spawn(topic, &message.executor_handle);
+            let topic_address = topic_actor.address();
+            e.insert((
+                message.multi_topic,
+                topic_actor,
+                topic_status_condition.clone(),
+            ));
+            Ok((topic_address, topic_status_condition))
+        } else {
+            Err(DdsError::PreconditionNotMet(format!(
+                "MultiTopic with name {} already exists",
+                message.multi_topic.multi_topic_name
+            )))
+        }
+    }
+}
+
+pub struct DeleteMultiTopic {
+    pub name: String,
+}
+impl Mail for DeleteMultiTopic {
+    type Result = DdsResult<Actor<TopicActor>>;
+}
+impl MailHandler<DeleteMultiTopic> for DomainParticipantActor {
+    fn handle(&mut self, message: DeleteMultiTopic) -> <DeleteMultiTopic as Mail>::Result {
+        self.multi_topic_list
+            .remove(&message.name)
+            .map(|(_, topic_actor, _)| topic_actor)
+            .ok_or(DdsError::AlreadyDeleted)
+    }
+}
+
+pub struct GetMultiTopic {
+    pub name: String,
+}
+impl Mail for GetMultiTopic {
+    type Result = DdsResult<MultiTopicProperty>;
+}
+impl MailHandler<GetMultiTopic> for DomainParticipantActor {
+    fn handle(&mut self, message: GetMultiTopic) -> <GetMultiTopic as Mail>::Result {
+        self.multi_topic_list
+            .get(&message.name)
+            .map(|(multi_topic, _, _)| multi_topic.clone())
+            .ok_or(DdsError::AlreadyDeleted)
+    }
+}
+
+pub struct SetMultiTopicParameters {
+    pub name: String,
+    pub expression_parameters: Vec<String>,
+}
+impl Mail for SetMultiTopicParameters {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<SetMultiTopicParameters> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: SetMultiTopicParameters,
+    ) -> <SetMultiTopicParameters as Mail>::Result {
+        let (multi_topic, _, _) = self
+            .multi_topic_list
+            .get_mut(&message.name)
+            .ok_or(DdsError::AlreadyDeleted)?;
+        multi_topic.expression_parameters = message.expression_parameters;
+        Ok(())
+    }
+}
+
+pub struct HasMultiTopics {
+    pub related_topic_name: String,
+}
+impl Mail for HasMultiTopics {
+    type Result = bool;
+}
+impl MailHandler<HasMultiTopics> for DomainParticipantActor {
+    fn handle(&mut self, message: HasMultiTopics) -> <HasMultiTopics as Mail>::Result {
+        self.multi_topic_list.values().any(|(multi_topic, _, _)| {
+            multi_topic
+                .expression
+                .topic_names
+                .contains(&message.related_topic_name)
+        })
+    }
+}
+
 pub struct GetInstanceHandle;
 impl Mail for GetInstanceHandle {
     type Result = InstanceHandle;
@@ -875,6 +1364,86 @@ impl MailHandler<IsEnabled> for DomainParticipantActor {
     }
 }
 
+pub struct AssertLiveliness;
+impl Mail for AssertLiveliness {
+    type Result = ();
+}
+impl MailHandler<AssertLiveliness> for DomainParticipantActor {
+    fn handle(&mut self, _: AssertLiveliness) -> <AssertLiveliness as Mail>::Result {
+        self.manual_liveliness_count += 1;
+        for publisher in self.user_defined_publisher_list.values() {
+            publisher.send_actor_mail(publisher_actor::AssertManualByParticipantLiveliness {
+                executor_handle: self.executor.handle(),
+                timer_handle: self.timer_driver.handle(),
+            });
+        }
+    }
+}
+
+pub struct RegisterAutomaticLivelinessWriter {
+    pub writer_handle: InstanceHandle,
+    pub lease_duration: std::time::Duration,
+    pub participant: DomainParticipantAsync,
+}
+impl Mail for RegisterAutomaticLivelinessWriter {
+    type Result = ();
+}
+impl MailHandler<RegisterAutomaticLivelinessWriter> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: RegisterAutomaticLivelinessWriter,
+    ) -> <RegisterAutomaticLivelinessWriter as Mail>::Result {
+        self.automatic_liveliness_writer_list
+            .insert(message.writer_handle, message.lease_duration);
+        self.restart_automatic_liveliness_task(message.participant);
+    }
+}
+
+pub struct UnregisterAutomaticLivelinessWriter {
+    pub writer_handle: InstanceHandle,
+    pub participant: DomainParticipantAsync,
+}
+impl Mail for UnregisterAutomaticLivelinessWriter {
+    type Result = ();
+}
+impl MailHandler<UnregisterAutomaticLivelinessWriter> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: UnregisterAutomaticLivelinessWriter,
+    ) -> <UnregisterAutomaticLivelinessWriter as Mail>::Result {
+        if self
+            .automatic_liveliness_writer_list
+            .remove(&message.writer_handle)
+            .is_some()
+        {
+            self.restart_automatic_liveliness_task(message.participant);
+        }
+    }
+}
+
+pub struct AssertDiscoveredParticipantLiveliness {
+    pub participant_guid_prefix: GuidPrefix,
+    pub liveliness_kind: LivelinessQosPolicyKind,
+}
+impl Mail for AssertDiscoveredParticipantLiveliness {
+    type Result = ();
+}
+impl MailHandler<AssertDiscoveredParticipantLiveliness> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: AssertDiscoveredParticipantLiveliness,
+    ) -> <AssertDiscoveredParticipantLiveliness as Mail>::Result {
+        for subscriber in self.user_defined_subscriber_list.values() {
+            subscriber.send_actor_mail(subscriber_actor::AssertDiscoveredParticipantLiveliness {
+                participant_guid_prefix: message.participant_guid_prefix,
+                liveliness_kind: message.liveliness_kind,
+                executor_handle: self.executor.handle(),
+                timer_handle: self.timer_driver.handle(),
+            });
+        }
+    }
+}
+
 pub struct IgnoreParticipant {
     pub handle: InstanceHandle,
 }
@@ -926,6 +1495,47 @@ impl MailHandler<IgnorePublication> for DomainParticipantActor {
     }
 }
 
+pub struct IgnoreTopic {
+    pub handle: InstanceHandle,
+    // Name of the topic announced with this handle which is not yet discovered by the participant
+    pub topic_name: Option<String>,
+    pub participant: DomainParticipantAsync,
+}
+impl Mail for IgnoreTopic {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<IgnoreTopic> for DomainParticipantActor {
+    fn handle(&mut self, message: IgnoreTopic) -> <IgnoreTopic as Mail>::Result {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        self.ignored_topic_list.insert(message.handle);
+        let topic_name = match self.discovered_topic_list.get(&message.handle) {
+            Some(topic_data) => Some(topic_data.name().to_owned()),
+            None => message.topic_name,
+        };
+        if let Some(topic_name) = topic_name {
+            // Topics are discovered through the endpoints using them so all the
+            // entries with the same name as the ignored one are removed. The endpoints
+            // already matched on the topic are identified by the handle of their entry.
+            let discovered_handle_list: Vec<InstanceHandle> = self
+                .discovered_topic_list
+                .iter()
+                .filter(|(_, t)| t.name() == topic_name)
+                .map(|(&handle, _)| handle)
+                .collect();
+            for handle in discovered_handle_list {
+                self.discovered_topic_list.remove(&handle);
+                self.remove_matched_writer(handle, &message.participant);
+                self.remove_matched_reader(handle, &message.participant);
+            }
+            self.ignored_topic_name_list.insert(topic_name);
+        }
+        Ok(())
+    }
+}
+
 pub struct IsEmpty;
 impl Mail for IsEmpty {
     type Result = bool;
@@ -942,6 +1552,8 @@ impl MailHandler<IsEmpty> for DomainParticipantActor {
         self.user_defined_publisher_list.is_empty()
             && self.user_defined_subscriber_list.is_empty()
             && no_user_defined_topics
+            && self.content_filtered_topic_list.is_empty()
+            && self.multi_topic_list.is_empty()
     }
 }
 
@@ -985,6 +1597,82 @@ impl MailHandler<GetDefaultMulticastLocatorList> for DomainParticipantActor {
     }
 }
 
+pub struct JoinTransportMulticastGroups {
+    pub transport_multicast: TransportMulticastQosPolicy,
+    pub participant: DomainParticipantAsync,
+}
+impl Mail for JoinTransportMulticastGroups {
+    type Result = DdsResult<Vec<Locator>>;
+}
+impl MailHandler<JoinTransportMulticastGroups> for DomainParticipantActor {
+    fn handle(
+        &mut self,
+        message: JoinTransportMulticastGroups,
+    ) -> <JoinTransportMulticastGroups as Mail>::Result {
+        if message
+            .transport_multicast
+            .value
+            .iter()
+            .any(|s| !s.receive_address.is_multicast())
+        {
+            return Err(DdsError::BadParameter);
+        }
+
+        let mut multicast_locator_list = Vec::new();
+        for settings in &message.transport_multicast.value {
+            let is_ip_version_used =
+                self.interface_list
+                    .iter()
+                    .flat_map(|i| i.addr.iter())
+                    .any(|a| match a {
+                        Addr::V4(_) => settings.receive_address.is_ipv4(),
+                        Addr::V6(_) => settings.receive_address.is_ipv6(),
+                    });
+            if !is_ip_version_used {
+                warn!(
+                    "Multicast group {} ignored since its IP version is not in use",
+                    settings.receive_address
+                );
+                continue;
+            }
+
+            let port = settings
+                .receive_port
+                .unwrap_or_else(|| port_user_multicast(self.domain_id));
+            let multicast_locator =
+                Locator::from_ip_addr_and_port(settings.receive_address, port as u32);
+            // A single socket per port receives the data of all the groups joined on that port
+            if !self
+                .joined_multicast_locator_list
+                .contains(&multicast_locator)
+            {
+                let socket = match self
+                    .user_defined_multicast_socket_list
+                    .entry((multicast_locator.kind(), multicast_locator.port()))
+                {
+                    Entry::Occupied(e) => e.into_mut(),
+                    Entry::Vacant(e) => {
+                        let socket = get_multicast_socket(multicast_locator.kind(), port)?;
+                        let transport_receiver = TransportReceiver::new();
+                        transport_receiver.set_participant(message.participant.clone());
+                        spawn_receive_thread(
+                            socket.try_clone()?,
+                            false,
+                            transport_receiver,
+                            Default::default(),
+                        );
+                        e.insert(socket)
+                    }
+                };
+                join_multicast_group(socket, &multicast_locator, &self.interface_list);
+                self.joined_multicast_locator_list.push(multicast_locator);
+            }
+            multicast_locator_list.push(multicast_locator);
+        }
+        Ok(multicast_locator_list)
+    }
+}
+
 pub struct GetMetatrafficUnicastLocatorList;
 impl Mail for GetMetatrafficUnicastLocatorList {
     type Result = Vec<Locator>;
@@ -1072,10 +1760,24 @@ impl MailHandler<DrainTopicList> for DomainParticipantActor {
                 drained_topic_list.push(removed_topic);
             }
         }
+        self.content_filtered_topic_list.clear();
         drained_topic_list
     }
 }
 
+pub struct DrainMultiTopicList;
+impl Mail for DrainMultiTopicList {
+    type Result = Vec<Actor<TopicActor>>;
+}
+impl MailHandler<DrainMultiTopicList> for DomainParticipantActor {
+    fn handle(&mut self, _: DrainMultiTopicList) -> <DrainMultiTopicList as Mail>::Result {
+        self.multi_topic_list
+            .drain()
+            .map(|(_, (_, topic_actor, _))| topic_actor)
+            .collect()
+    }
+}
+
 pub struct SetDefaultPublisherQos {
     pub qos: QosKind<PublisherQos>,
 }
@@ -1426,6 +2128,8 @@ impl MailHandler<ProcessMetatrafficRtpsMessage> for DomainParticipantActor {
                             heartbeat_submessage,
                             source_guid_prefix: message_receiver.source_guid_prefix(),
                             message_sender_actor: self.message_sender_actor.address(),
+                            executor_handle: message.executor_handle.clone(),
+                            timer_handle: self.timer_driver.handle(),
                         },
                     );
                 }
@@ -1552,6 +2256,8 @@ impl MailHandler<ProcessUserDefinedRtpsMessage> for DomainParticipantActor {
                                 heartbeat_submessage: heartbeat_submessage.clone(),
                                 source_guid_prefix: message_receiver.source_guid_prefix(),
                                 message_sender_actor: self.message_sender_actor.address(),
+                                executor_handle: message.executor_handle.clone(),
+                                timer_handle: self.timer_driver.handle(),
                             },
                         );
                     }
@@ -1568,7 +2274,11 @@ impl MailHandler<ProcessUserDefinedRtpsMessage> for DomainParticipantActor {
                     }
                 }
                 RtpsSubmessageReadKind::AckNack(acknack_submessage) => {
-                    for user_defined_publisher_actor in self.user_defined_publisher_list.values() {
+                    for user_defined_publisher_actor in self
+                        .user_defined_publisher_list
+                        .values()
+                        .chain(self.durability_service_publisher_list.values())
+                    {
                         user_defined_publisher_actor.send_actor_mail(
                             publisher_actor::ProcessAckNackSubmessage {
                                 acknack_submessage: acknack_submessage.clone(),
@@ -1579,7 +2289,11 @@ impl MailHandler<ProcessUserDefinedRtpsMessage> for DomainParticipantActor {
                     }
                 }
                 RtpsSubmessageReadKind::NackFrag(nackfrag_submessage) => {
-                    for user_defined_publisher_actor in self.user_defined_publisher_list.values() {
+                    for user_defined_publisher_actor in self
+                        .user_defined_publisher_list
+                        .values()
+                        .chain(self.durability_service_publisher_list.values())
+                    {
                         user_defined_publisher_actor.send_actor_mail(
                             publisher_actor::ProcessNackFragSubmessage {
                                 nackfrag_submessage: nackfrag_submessage.clone(),
@@ -1741,6 +2455,14 @@ impl MailHandler<AddDiscoveredParticipant> for DomainParticipantActor {
         let is_participant_discovered = self
             .discovered_participant_list
             .contains_key(&discovered_participant_handle);
+        if is_domain_id_matching && is_domain_tag_matching && !is_participant_ignored {
+            // Every announcement of the participant renews its lease
+            self.renew_discovered_participant_lease(
+                discovered_participant_handle,
+                message.discovered_participant_data.lease_duration,
+                message.participant.clone(),
+            );
+        }
         if is_domain_id_matching
             && is_domain_tag_matching
             && !is_participant_ignored
@@ -1770,6 +2492,49 @@ impl MailHandler<AddDiscoveredParticipant> for DomainParticipantActor {
                 &message.discovered_participant_data,
                 message.participant.clone(),
             )?;
+            self.add_matched_participant_message_reader(
+                &message.discovered_participant_data,
+                message.participant.clone(),
+            )?;
+            self.add_matched_participant_message_writer(
+                &message.discovered_participant_data,
+                message.participant.clone(),
+            )?;
+
+            // A participant which doesn't share the multicast discovery group with this one
+            // is announced by unicast so that it also discovers this participant
+            if self
+                .rtps_participant
+                .metatraffic_multicast_locator_list()
+                .is_empty()
+                || message
+                    .discovered_participant_data
+                    .participant_proxy
+                    .metatraffic_multicast_locator_list
+                    .is_empty()
+            {
+                self.builtin_publisher.send_actor_mail(
+                    publisher_actor::AddParticipantReaderLocators {
+                        data_writer_handle: InstanceHandle::new(
+                            Guid::new(
+                                self.rtps_participant.guid().prefix(),
+                                ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER,
+                            )
+                            .into(),
+                        ),
+                        participant_handle: discovered_participant_handle,
+                        locator_list: message
+                            .discovered_participant_data
+                            .participant_proxy
+                            .metatraffic_unicast_locator_list
+                            .clone(),
+                    },
+                );
+                let participant = message.participant.clone();
+                self.executor.handle().spawn(async move {
+                    participant.announce_participant().await.ok();
+                });
+            }
 
             self.discovered_participant_list.insert(
                 InstanceHandle::new(
@@ -1788,6 +2553,7 @@ impl MailHandler<AddDiscoveredParticipant> for DomainParticipantActor {
 
 pub struct RemoveDiscoveredParticipant {
     pub handle: InstanceHandle,
+    pub participant: DomainParticipantAsync,
 }
 impl Mail for RemoveDiscoveredParticipant {
     type Result = ();
@@ -1797,7 +2563,76 @@ impl MailHandler<RemoveDiscoveredParticipant> for DomainParticipantActor {
         &mut self,
         message: RemoveDiscoveredParticipant,
     ) -> <RemoveDiscoveredParticipant as Mail>::Result {
-        self.discovered_participant_list.remove(&message.handle);
+        if let Some(t) = self
+            .discovered_participant_lease_task
+            .remove(&message.handle)
+        {
+            t.abort();
+        }
+
+        if let Some(discovered_participant_data) =
+            self.discovered_participant_list.remove(&message.handle)
+        {
+            let guid_prefix = discovered_participant_data.participant_proxy.guid_prefix;
+            self.builtin_publisher.send_actor_mail(
+                publisher_actor::RemoveParticipantReaderLocators {
+                    data_writer_handle: InstanceHandle::new(
+                        Guid::new(
+                            self.rtps_participant.guid().prefix(),
+                            ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER,
+                        )
+                        .into(),
+                    ),
+                    participant_handle: message.handle,
+                },
+            );
+            let participant_mask_listener = (
+                self.participant_listener_thread
+                    .as_ref()
+                    .map(|l| l.sender().clone()),
+                self.status_kind.clone(),
+            );
+
+            // Removing the builtin writers of the participant makes the discovered endpoints
+            // NOT_ALIVE_NO_WRITERS so that they are removed when the discovery data is processed
+            for writer_entity_id in [
+                ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
+                ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+                ENTITYID_SEDP_BUILTIN_TOPICS_ANNOUNCER,
+                ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER,
+            ] {
+                self.builtin_subscriber
+                    .send_actor_mail(subscriber_actor::RemoveMatchedWriter {
+                        discovered_writer_handle: InstanceHandle::new(
+                            Guid::new(guid_prefix, writer_entity_id).into(),
+                        ),
+                        subscriber_address: self.builtin_subscriber.address(),
+                        participant: message.participant.clone(),
+                        participant_mask_listener: participant_mask_listener.clone(),
+                    });
+            }
+            for reader_entity_id in [
+                ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
+                ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+                ENTITYID_SEDP_BUILTIN_TOPICS_DETECTOR,
+                ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER,
+            ] {
+                self.builtin_publisher
+                    .send_actor_mail(publisher_actor::RemoveMatchedReader {
+                        discovered_reader_handle: InstanceHandle::new(
+                            Guid::new(guid_prefix, reader_entity_id).into(),
+                        ),
+                        publisher_address: self.builtin_publisher.address(),
+                        participant: message.participant.clone(),
+                        participant_mask_listener: participant_mask_listener.clone(),
+                    });
+            }
+
+            let participant = message.participant;
+            self.executor.handle().spawn(async move {
+                process_discovery_data(participant).await.ok();
+            });
+        }
     }
 }
 
@@ -1828,7 +2663,13 @@ impl MailHandler<AddMatchedWriter> for DomainParticipantActor {
                 .key()
                 .value,
         ));
-        if !is_publication_ignored && !is_participant_ignored {
+        let is_topic_ignored = self.ignored_topic_name_list.contains(
+            message
+                .discovered_writer_data
+                .dds_publication_data
+                .topic_name(),
+        );
+        if !is_publication_ignored && !is_participant_ignored && !is_topic_ignored {
             if let Some(discovered_participant_data) =
                 self.discovered_participant_list.get(&InstanceHandle::new(
                     Guid::new(
@@ -1960,21 +2801,7 @@ impl Mail for RemoveMatchedWriter {
 }
 impl MailHandler<RemoveMatchedWriter> for DomainParticipantActor {
     fn handle(&mut self, message: RemoveMatchedWriter) -> <RemoveMatchedWriter as Mail>::Result {
-        for subscriber in self.user_defined_subscriber_list.values() {
-            let subscriber_address = subscriber.address();
-            let participant_mask_listener = (
-                self.participant_listener_thread
-                    .as_ref()
-                    .map(|l| l.sender().clone()),
-                self.status_kind.clone(),
-            );
-            subscriber.send_actor_mail(subscriber_actor::RemoveMatchedWriter {
-                discovered_writer_handle: message.discovered_writer_handle,
-                subscriber_address,
-                participant: message.participant.clone(),
-                participant_mask_listener,
-            });
-        }
+        self.remove_matched_writer(message.discovered_writer_handle, &message.participant);
         Ok(())
     }
 }
@@ -2006,7 +2833,13 @@ impl MailHandler<AddMatchedReader> for DomainParticipantActor {
                 .key()
                 .value,
         ));
-        if !is_subscription_ignored && !is_participant_ignored {
+        let is_topic_ignored = self.ignored_topic_name_list.contains(
+            message
+                .discovered_reader_data
+                .subscription_builtin_topic_data()
+                .topic_name(),
+        );
+        if !is_subscription_ignored && !is_participant_ignored && !is_topic_ignored {
             if let Some(discovered_participant_data) =
                 self.discovered_participant_list.get(&InstanceHandle::new(
                     Guid::new(
@@ -2029,7 +2862,11 @@ impl MailHandler<AddMatchedReader> for DomainParticipantActor {
                     .default_multicast_locator_list
                     .to_vec();
 
-                for publisher in self.user_defined_publisher_list.values() {
+                for publisher in self
+                    .user_defined_publisher_list
+                    .values()
+                    .chain(self.durability_service_publisher_list.values())
+                {
                     let publisher_address = publisher.address();
                     let participant_mask_listener = (
                         self.participant_listener_thread
@@ -2137,21 +2974,7 @@ impl Mail for RemoveMatchedReader {
 }
 impl MailHandler<RemoveMatchedReader> for DomainParticipantActor {
     fn handle(&mut self, message: RemoveMatchedReader) -> <RemoveMatchedReader as Mail>::Result {
-        for publisher in self.user_defined_publisher_list.values() {
-            let publisher_address = publisher.address();
-            let participant_mask_listener = (
-                self.participant_listener_thread
-                    .as_ref()
-                    .map(|l| l.sender().clone()),
-                self.status_kind.clone(),
-            );
-            publisher.send_actor_mail(publisher_actor::RemoveMatchedReader {
-                discovered_reader_handle: message.discovered_reader_handle,
-                publisher_address,
-                participant: message.participant.clone(),
-                participant_mask_listener,
-            });
-        }
+        self.remove_matched_reader(message.discovered_reader_handle, &message.participant);
         Ok(())
     }
 }
@@ -2171,7 +2994,13 @@ impl MailHandler<AddMatchedTopic> for DomainParticipantActor {
                 .key()
                 .value,
         );
-        let is_topic_ignored = self.ignored_topic_list.contains(&handle);
+        let is_topic_ignored = self.ignored_topic_list.contains(&handle)
+            || self.ignored_topic_name_list.contains(
+                message
+                    .discovered_topic_data
+                    .topic_builtin_topic_data
+                    .name(),
+            );
         if !is_topic_ignored {
             for (topic, _) in self.topic_list.values() {
                 topic.send_actor_mail(topic_actor::ProcessDiscoveredTopic {
@@ -2287,7 +3116,7 @@ impl DomainParticipantActor {
                 representation: sedp_data_reader_qos().representation,
             };
             let discovered_reader_data =
-                DiscoveredReaderData::new(reader_proxy, subscription_builtin_topic_data);
+                DiscoveredReaderData::new(reader_proxy, subscription_builtin_topic_data, None);
             self.builtin_publisher
                 .send_actor_mail(publisher_actor::AddMatchedReader {
                     discovered_reader_data,
@@ -2437,7 +3266,7 @@ impl DomainParticipantActor {
                 representation: sedp_data_reader_qos().representation,
             };
             let discovered_reader_data =
-                DiscoveredReaderData::new(reader_proxy, subscription_builtin_topic_data);
+                DiscoveredReaderData::new(reader_proxy, subscription_builtin_topic_data, None);
             self.builtin_publisher
                 .send_actor_mail(publisher_actor::AddMatchedReader {
                     discovered_reader_data,
@@ -2587,7 +3416,7 @@ impl DomainParticipantActor {
                 representation: sedp_data_reader_qos().representation,
             };
             let discovered_reader_data =
-                DiscoveredReaderData::new(reader_proxy, subscription_builtin_topic_data);
+                DiscoveredReaderData::new(reader_proxy, subscription_builtin_topic_data, None);
             self.builtin_publisher
                 .send_actor_mail(publisher_actor::AddMatchedReader {
                     discovered_reader_data,
@@ -2682,13 +3511,163 @@ impl DomainParticipantActor {
         }
         Ok(())
     }
+
+    fn add_matched_participant_message_reader(
+        &self,
+        discovered_participant_data: &SpdpDiscoveredParticipantData,
+        participant: DomainParticipantAsync,
+    ) -> DdsResult<()> {
+        if discovered_participant_data
+            .participant_proxy
+            .available_builtin_endpoints
+            .has(BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_READER)
+        {
+            let remote_reader_guid = Guid::new(
+                discovered_participant_data.participant_proxy.guid_prefix,
+                ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER,
+            );
+            let remote_group_entity_id = ENTITYID_UNKNOWN;
+            let expects_inline_qos = false;
+            let reader_proxy = ReaderProxy {
+                remote_reader_guid,
+                remote_group_entity_id,
+                unicast_locator_list: discovered_participant_data
+                    .participant_proxy
+                    .metatraffic_unicast_locator_list
+                    .to_vec(),
+                multicast_locator_list: discovered_participant_data
+                    .participant_proxy
+                    .metatraffic_multicast_locator_list
+                    .to_vec(),
+                expects_inline_qos,
+            };
+            let subscription_builtin_topic_data = SubscriptionBuiltinTopicData {
+                key: BuiltInTopicKey {
+                    value: remote_reader_guid.into(),
+                },
+                participant_key: BuiltInTopicKey::default(),
+                topic_name: DCPS_PARTICIPANT_MESSAGE.to_owned(),
+                type_name: "ParticipantMessageData".to_owned(),
+                durability: sedp_data_reader_qos().durability,
+                deadline: sedp_data_reader_qos().deadline,
+                latency_budget: sedp_data_reader_qos().latency_budget,
+                liveliness: sedp_data_reader_qos().liveliness,
+                reliability: sedp_data_reader_qos().reliability,
+                ownership: sedp_data_reader_qos().ownership,
+                destination_order: sedp_data_reader_qos().destination_order,
+                user_data: sedp_data_reader_qos().user_data,
+                time_based_filter: sedp_data_reader_qos().time_based_filter,
+                presentation: Default::default(),
+                partition: Default::default(),
+                topic_data: Default::default(),
+                group_data: Default::default(),
+                xml_type: Default::default(),
+                representation: sedp_data_reader_qos().representation,
+            };
+            let discovered_reader_data =
+                DiscoveredReaderData::new(reader_proxy, subscription_builtin_topic_data, None);
+            self.builtin_publisher
+                .send_actor_mail(publisher_actor::AddMatchedReader {
+                    discovered_reader_data,
+                    default_unicast_locator_list: vec![],
+                    default_multicast_locator_list: vec![],
+                    publisher_address: self.builtin_publisher.address(),
+                    participant,
+                    participant_mask_listener: (
+                        self.participant_listener_thread
+                            .as_ref()
+                            .map(|l| l.sender().clone()),
+                        self.status_kind.clone(),
+                    ),
+                    message_sender_actor: self.message_sender_actor.address(),
+                });
+        }
+        Ok(())
+    }
+
+    fn add_matched_participant_message_writer(
+        &self,
+        discovered_participant_data: &SpdpDiscoveredParticipantData,
+        participant: DomainParticipantAsync,
+    ) -> DdsResult<()> {
+        if discovered_participant_data
+            .participant_proxy
+            .available_builtin_endpoints
+            .has(BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_WRITER)
+        {
+            let remote_writer_guid = Guid::new(
+                discovered_participant_data.participant_proxy.guid_prefix,
+                ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER,
+            );
+            let remote_group_entity_id = ENTITYID_UNKNOWN;
+            let data_max_size_serialized = Default::default();
+
+            let writer_proxy = WriterProxy {
+                remote_writer_guid,
+                remote_group_entity_id,
+                unicast_locator_list: discovered_participant_data
+                    .participant_proxy
+                    .metatraffic_unicast_locator_list
+                    .to_vec(),
+                multicast_locator_list: discovered_participant_data
+                    .participant_proxy
+                    .metatraffic_multicast_locator_list
+                    .to_vec(),
+                data_max_size_serialized,
+            };
+            let dds_publication_data = PublicationBuiltinTopicData {
+                key: BuiltInTopicKey {
+                    value: remote_writer_guid.into(),
+                },
+                participant_key: BuiltInTopicKey::default(),
+                topic_name: DCPS_PARTICIPANT_MESSAGE.to_owned(),
+                type_name: "ParticipantMessageData".to_owned(),
+                durability: sedp_data_writer_qos().durability,
+                deadline: sedp_data_writer_qos().deadline,
+                latency_budget: sedp_data_writer_qos().latency_budget,
+                liveliness: sedp_data_writer_qos().liveliness,
+                reliability: sedp_data_writer_qos().reliability,
+                lifespan: sedp_data_writer_qos().lifespan,
+                user_data: sedp_data_writer_qos().user_data,
+                ownership: sedp_data_writer_qos().ownership,
+                ownership_strength: sedp_data_writer_qos().ownership_strength,
+                destination_order: sedp_data_writer_qos().destination_order,
+                presentation: Default::default(),
+                partition: Default::default(),
+                topic_data: Default::default(),
+                group_data: Default::default(),
+                xml_type: Default::default(),
+                representation: sedp_data_writer_qos().representation,
+            };
+            let discovered_writer_data = DiscoveredWriterData {
+                dds_publication_data,
+                writer_proxy,
+            };
+            self.builtin_subscriber
+                .send_actor_mail(subscriber_actor::AddMatchedWriter {
+                    discovered_writer_data,
+                    default_unicast_locator_list: vec![],
+                    default_multicast_locator_list: vec![],
+                    subscriber_address: self.builtin_subscriber.address(),
+                    participant,
+                    participant_mask_listener: (
+                        self.participant_listener_thread
+                            .as_ref()
+                            .map(|l| l.sender().clone()),
+                        self.status_kind.clone(),
+                    ),
+                });
+        }
+        Ok(())
+    }
 }
 
 async fn process_discovery_data(participant: DomainParticipantAsync) -> DdsResult<()> {
     process_spdp_participant_discovery(&participant).await?;
     process_sedp_publications_discovery(&participant).await?;
     process_sedp_subscriptions_discovery(&participant).await?;
-    process_sedp_topics_discovery(&participant).await
+    process_sedp_topics_discovery(&participant).await?;
+    process_participant_messages(&participant).await
 }
 
 async fn process_spdp_participant_discovery(participant: &DomainParticipantAsync) -> DdsResult<()> {
@@ -2728,6 +3707,7 @@ async fn process_spdp_participant_discovery(participant: &DomainParticipantAsync
                             .participant_address()
                             .send_actor_mail(RemoveDiscoveredParticipant {
                                 handle: discovered_participant_sample.sample_info().instance_handle,
+                                participant: participant.clone(),
                             })?
                             .receive_reply()
                             .await;
@@ -2774,7 +3754,7 @@ async fn process_sedp_publications_discovery(
                             e
                         ),
                     },
-                    InstanceStateKind::NotAliveDisposed => {
+                    InstanceStateKind::NotAliveDisposed | InstanceStateKind::NotAliveNoWriters => {
                         participant
                             .participant_address()
                             .send_actor_mail(RemoveMatchedWriter {
@@ -2784,9 +3764,6 @@ async fn process_sedp_publications_discovery(
                                 participant: participant.clone(),
                             })?;
                     }
-                    InstanceStateKind::NotAliveNoWriters => {
-                        todo!()
-                    }
                 }
             }
         }
@@ -2828,7 +3805,7 @@ async fn process_sedp_subscriptions_discovery(
                             e
                         ),
                     },
-                    InstanceStateKind::NotAliveDisposed => {
+                    InstanceStateKind::NotAliveDisposed | InstanceStateKind::NotAliveNoWriters => {
                         participant
                             .participant_address()
                             .send_actor_mail(RemoveMatchedReader {
@@ -2838,9 +3815,6 @@ async fn process_sedp_subscriptions_discovery(
                                 participant: participant.clone(),
                             })?;
                     }
-                    InstanceStateKind::NotAliveNoWriters => {
-                        todo!()
-                    }
                 }
             }
         }
@@ -2885,3 +3859,44 @@ async fn process_sedp_topics_discovery(participant: &DomainParticipantAsync) ->
     }
     Ok(())
 }
+
+async fn process_participant_messages(participant: &DomainParticipantAsync) -> DdsResult<()> {
+    let builtin_subscriber = participant.get_builtin_subscriber();
+    if let Some(participant_message_reader) = builtin_subscriber
+        .lookup_datareader::<ParticipantMessageData>(DCPS_PARTICIPANT_MESSAGE)
+        .await?
+    {
+        if let Ok(participant_message_sample_list) = participant_message_reader
+            .take(
+                i32::MAX,
+                &[SampleStateKind::NotRead],
+                ANY_VIEW_STATE,
+                ANY_INSTANCE_STATE,
+            )
+            .await
+        {
+            for participant_message_sample in participant_message_sample_list {
+                if let Ok(participant_message_data) = participant_message_sample.data() {
+                    let liveliness_kind = match participant_message_data.kind {
+                        PARTICIPANT_MESSAGE_DATA_KIND_AUTOMATIC_LIVELINESS_UPDATE => {
+                            LivelinessQosPolicyKind::Automatic
+                        }
+                        PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE => {
+                            LivelinessQosPolicyKind::ManualByParticipant
+                        }
+                        // Vendor specific messages are not understood
+                        _ => continue,
+                    };
+                    participant.participant_address().send_actor_mail(
+                        AssertDiscoveredParticipantLiveliness {
+                            participant_guid_prefix: participant_message_data
+                                .participant_guid_prefix,
+                            liveliness_kind,
+                        },
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,9 +1,6 @@
 use super::{
-    data_reader_actor::DataReaderActor,
-    data_writer_actor::DataWriterActor,
-    domain_participant_actor::{self},
-    message_sender_actor::MessageSenderActor,
-    status_condition_actor::StatusConditionActor,
+    data_reader_actor::DataReaderActor, data_writer_actor::DataWriterActor,
+    message_sender_actor::MessageSenderActor, status_condition_actor::StatusConditionActor,
     topic_actor::TopicActor,
 };
 use crate::{
@@ -21,12 +18,14 @@ use crate::{
             discovered_reader_data::DiscoveredReaderData,
             discovered_topic_data::DiscoveredTopicData,
             discovered_writer_data::DiscoveredWriterData,
+            participant_message_data::{ParticipantMessageData, DCPS_PARTICIPANT_MESSAGE},
             spdp_discovered_participant_data::SpdpDiscoveredParticipantData,
         },
         runtime::{
             executor::{Executor, ExecutorHandle},
             timer::TimerDriver,
         },
+        udp_transport::UdpTransportFactory,
     },
     infrastructure::{
         error::{DdsError, DdsResult},
@@ -42,9 +41,12 @@ use crate::{
         status::StatusKind,
         time::{Duration, DurationKind, DURATION_ZERO_NSEC, DURATION_ZERO_SEC},
     },
+    persistent_storage::{AppendOnlyLogStorage, PersistentStorage},
     rtps::{
         behavior_types::DURATION_ZERO,
         discovery_types::{
+            ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER,
+            ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER,
             ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
             ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
             ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
@@ -53,31 +55,35 @@ use crate::{
             ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER,
         },
         endpoint::RtpsEndpoint,
-        messages::overall_structure::RtpsMessageRead,
         participant::RtpsParticipant,
         reader::{RtpsReader, RtpsReaderKind, RtpsStatefulReader, RtpsStatelessReader},
         reader_locator::RtpsReaderLocator,
         types::{
-            EntityId, Guid, GuidPrefix, Locator, TopicKind, BUILT_IN_TOPIC, LOCATOR_KIND_UDP_V4,
-            PROTOCOLVERSION, VENDOR_ID_S2E,
+            EntityId, Guid, GuidPrefix, Locator, TopicKind, BUILT_IN_TOPIC, LOCATOR_KIND_INVALID,
+            LOCATOR_KIND_RESERVED, LOCATOR_KIND_UDP_V4, LOCATOR_KIND_UDP_V6, PROTOCOLVERSION,
+            VENDOR_ID_S2E,
         },
         writer::RtpsWriter,
     },
     topic_definition::type_support::TypeSupport,
+    transport::{Transport, TransportFactory, TransportLocators, TransportReceiver},
 };
 use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
-use socket2::Socket;
 use std::{
-    collections::HashMap,
-    net::{IpAddr, Ipv4Addr, SocketAddr},
+    collections::{BTreeMap, HashMap},
+    net::IpAddr,
     sync::{
         atomic::{AtomicU32, Ordering},
         Arc, OnceLock,
     },
 };
-use tracing::{info, warn};
+use tracing::warn;
 
-const MAX_DATAGRAM_SIZE: usize = 65507;
+pub(crate) const MAX_DATAGRAM_SIZE: usize = 65507;
+// Room left in a datagram around a fragment of a sample for the RTPS header, the
+// INFO_DST and INFO_TS preceding the DATA_FRAG and its header and inline QoS
+const FRAGMENT_DATAGRAM_OVERHEAD: usize = 128;
+const MIN_FRAGMENT_SIZE: usize = 8;
 
 #[derive(Default)]
 pub struct DomainParticipantFactoryActor {
@@ -85,6 +91,8 @@ pub struct DomainParticipantFactoryActor {
     qos: DomainParticipantFactoryQos,
     default_participant_qos: DomainParticipantQos,
     configuration: DustDdsConfiguration,
+    persistent_storage: Option<Arc<dyn PersistentStorage>>,
+    transport_factory_list: HashMap<i32, Arc<dyn TransportFactory>>,
 }
 
 impl DomainParticipantFactoryActor {
@@ -98,8 +106,10 @@ impl DomainParticipantFactoryActor {
         c.fetch_add(1, Ordering::Acquire)
     }
 
-    fn create_new_guid_prefix(&mut self) -> GuidPrefix {
-        let interface_address = NetworkInterface::show()
+    /// Network interfaces selected by the configuration with only the addresses of the IP versions in use
+    fn get_interface_list(&self) -> Vec<NetworkInterface> {
+        let ip_version_mode = self.configuration.ip_version_mode();
+        NetworkInterface::show()
             .expect("Could not scan interfaces")
             .into_iter()
             .filter(|x| {
@@ -109,16 +119,31 @@ impl DomainParticipantFactoryActor {
                     true
                 }
             })
-            .flat_map(|i| {
-                i.addr
-                    .into_iter()
-                    .filter(|a| matches!(a, Addr::V4(v4) if !v4.ip.is_loopback()))
+            .map(|mut i| {
+                i.addr.retain(|a| match a {
+                    Addr::V4(_) => ip_version_mode.uses_ipv4(),
+                    Addr::V6(_) => ip_version_mode.uses_ipv6(),
+                });
+                i
             })
-            .next();
+            .collect()
+    }
+
+    fn create_new_guid_prefix(&mut self, interface_list: &[NetworkInterface]) -> GuidPrefix {
+        // IPv4 addresses are preferred for the Host ID and the last
+        // 4 bytes of an IPv6 address are used otherwise
+        let interface_address = interface_list
+            .iter()
+            .flat_map(|i| i.addr.iter())
+            .filter(|a| !a.ip().is_loopback())
+            .min_by_key(|a| matches!(a, Addr::V6(_)));
         let host_id = if let Some(interface) = interface_address {
             match interface.ip() {
                 IpAddr::V4(a) => a.octets(),
-                IpAddr::V6(_) => unimplemented!("IPv6 not yet implemented"),
+                IpAddr::V6(a) => {
+                    let octets = a.octets();
+                    [octets[12], octets[13], octets[14], octets[15]]
+                }
             }
         } else {
             warn!("Failed to get Host ID from IP address, use 0 instead");
@@ -228,6 +253,26 @@ impl DomainParticipantFactoryActor {
             ),
         );
 
+        let participant_message_entity_id = EntityId::new([0, 0, 4], BUILT_IN_TOPIC);
+        let participant_message_topic_guid = Guid::new(guid_prefix, participant_message_entity_id);
+        let (participant_message_topic, participant_message_topic_status_condition) =
+            TopicActor::new(
+                participant_message_topic_guid,
+                TopicQos::default(),
+                "ParticipantMessageData".to_string(),
+                DCPS_PARTICIPANT_MESSAGE,
+                None,
+                Arc::new(ParticipantMessageData::get_type()),
+                handle,
+            );
+        topic_list.insert(
+            DCPS_PARTICIPANT_MESSAGE.to_owned(),
+            (
+                Actor::spawn(participant_message_topic, handle),
+                participant_message_topic_status_condition,
+            ),
+        );
+
         topic_list
     }
 
@@ -265,6 +310,9 @@ impl DomainParticipantFactoryActor {
             spdp_reader_qos,
             None,
             vec![],
+            None,
+            None,
+            None,
             handle,
         );
 
@@ -280,6 +328,9 @@ impl DomainParticipantFactoryActor {
             sedp_data_reader_qos(),
             None,
             vec![],
+            None,
+            None,
+            None,
             handle,
         );
 
@@ -295,6 +346,9 @@ impl DomainParticipantFactoryActor {
             sedp_data_reader_qos(),
             None,
             vec![],
+            None,
+            None,
+            None,
             handle,
         );
 
@@ -310,6 +364,28 @@ impl DomainParticipantFactoryActor {
             sedp_data_reader_qos(),
             None,
             vec![],
+            None,
+            None,
+            None,
+            handle,
+        );
+
+        // The Writer Liveliness Protocol uses the same QoS as the SEDP endpoints
+        let participant_message_reader_guid =
+            Guid::new(guid_prefix, ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER);
+        let participant_message_reader = DataReaderActor::new(
+            create_builtin_stateful_reader(participant_message_reader_guid),
+            topic_list[DCPS_PARTICIPANT_MESSAGE].0.address(),
+            DCPS_PARTICIPANT_MESSAGE.to_string(),
+            "ParticipantMessageData".to_string(),
+            topic_list[DCPS_PARTICIPANT_MESSAGE].1.clone(),
+            Arc::new(ParticipantMessageData::get_type()),
+            sedp_data_reader_qos(),
+            None,
+            vec![],
+            None,
+            None,
+            None,
             handle,
         );
 
@@ -318,13 +394,14 @@ impl DomainParticipantFactoryActor {
             sedp_builtin_topics_reader,
             sedp_builtin_publications_reader,
             sedp_builtin_subscriptions_reader,
+            participant_message_reader,
         ]
     }
 
     fn create_builtin_writers(
         &self,
         guid_prefix: GuidPrefix,
-        domain_id: DomainId,
+        spdp_discovery_locator_list: &[Locator],
         topic_list: &HashMap<String, (Actor<TopicActor>, ActorAddress<StatusConditionActor>)>,
         handle: &ExecutorHandle,
     ) -> Vec<DataWriterActor> {
@@ -352,18 +429,13 @@ impl DomainParticipantFactoryActor {
             DCPS_PARTICIPANT.to_string(),
             "SpdpDiscoveredParticipantData".to_string(),
             topic_list[DCPS_PARTICIPANT].1.clone(),
+            Arc::new(SpdpDiscoveredParticipantData::get_type()),
             None,
             vec![],
             spdp_writer_qos,
             handle,
         );
 
-        let spdp_discovery_locator_list = [Locator::new(
-            LOCATOR_KIND_UDP_V4,
-            port_builtin_multicast(domain_id) as u32,
-            DEFAULT_MULTICAST_LOCATOR_ADDRESS,
-        )];
-
         for reader_locator in spdp_discovery_locator_list
             .iter()
             .map(|&locator| RtpsReaderLocator::new(locator, false))
@@ -379,6 +451,7 @@ impl DomainParticipantFactoryActor {
             DCPS_TOPIC.to_string(),
             "DiscoveredTopicData".to_string(),
             topic_list[DCPS_TOPIC].1.clone(),
+            Arc::new(DiscoveredTopicData::get_type()),
             None,
             vec![],
             sedp_data_writer_qos(),
@@ -393,6 +466,7 @@ impl DomainParticipantFactoryActor {
             DCPS_PUBLICATION.to_string(),
             "DiscoveredWriterData".to_string(),
             topic_list[DCPS_PUBLICATION].1.clone(),
+            Arc::new(DiscoveredWriterData::get_type()),
             None,
             vec![],
             sedp_data_writer_qos(),
@@ -407,6 +481,23 @@ impl DomainParticipantFactoryActor {
             DCPS_SUBSCRIPTION.to_string(),
             "DiscoveredReaderData".to_string(),
             topic_list[DCPS_SUBSCRIPTION].1.clone(),
+            Arc::new(DiscoveredReaderData::get_type()),
+            None,
+            vec![],
+            sedp_data_writer_qos(),
+            handle,
+        );
+
+        // The Writer Liveliness Protocol uses the same QoS as the SEDP endpoints
+        let participant_message_writer_guid =
+            Guid::new(guid_prefix, ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER);
+        let participant_message_writer = DataWriterActor::new(
+            create_builtin_stateful_writer(participant_message_writer_guid),
+            topic_list[DCPS_PARTICIPANT_MESSAGE].0.address(),
+            DCPS_PARTICIPANT_MESSAGE.to_string(),
+            "ParticipantMessageData".to_string(),
+            topic_list[DCPS_PARTICIPANT_MESSAGE].1.clone(),
+            Arc::new(ParticipantMessageData::get_type()),
             None,
             vec![],
             sedp_data_writer_qos(),
@@ -418,27 +509,17 @@ impl DomainParticipantFactoryActor {
             sedp_builtin_topics_writer,
             sedp_builtin_publications_writer,
             sedp_builtin_subscriptions_writer,
+            participant_message_writer,
         ]
     }
 }
 
-pub fn read_message(
-    socket: &mut std::net::UdpSocket,
-    buf: &mut [u8],
-) -> DdsResult<RtpsMessageRead> {
-    let (bytes, _) = socket.recv_from(buf)?;
-    if bytes > 0 {
-        Ok(RtpsMessageRead::try_from(&buf[0..bytes])?)
-    } else {
-        Err(DdsError::NoData)
-    }
-}
-
 pub struct CreateParticipant {
     pub domain_id: DomainId,
     pub qos: QosKind<DomainParticipantQos>,
     pub listener: Option<Box<dyn DomainParticipantListenerAsync + Send>>,
     pub status_kind: Vec<StatusKind>,
+    pub transport_factory_list: Vec<(i32, Arc<dyn TransportFactory>)>,
 }
 impl Mail for CreateParticipant {
     type Result = DdsResult<ActorAddress<DomainParticipantActor>>;
@@ -453,11 +534,83 @@ impl MailHandler<CreateParticipant> for DomainParticipantFactoryActor {
             QosKind::Specific(q) => q,
         };
 
-        let guid_prefix = self.create_new_guid_prefix();
+        let ip_version_mode = self.configuration.ip_version_mode();
+        let interface_list = self.get_interface_list();
+        let guid_prefix = self.create_new_guid_prefix(&interface_list);
+
+        // The UDP locators of the IP versions in use have a built-in transport which can be replaced by the
+        // transport factories registered on the participant factory and by the ones of this participant
+        let mut transport_factory_list: BTreeMap<i32, Arc<dyn TransportFactory>> = BTreeMap::new();
+        if ip_version_mode.uses_ipv4() {
+            transport_factory_list.insert(
+                LOCATOR_KIND_UDP_V4,
+                Arc::new(UdpTransportFactory::new(
+                    LOCATOR_KIND_UDP_V4,
+                    &interface_list,
+                    self.configuration.clone(),
+                )),
+            );
+        }
+        if ip_version_mode.uses_ipv6() {
+            transport_factory_list.insert(
+                LOCATOR_KIND_UDP_V6,
+                Arc::new(UdpTransportFactory::new(
+                    LOCATOR_KIND_UDP_V6,
+                    &interface_list,
+                    self.configuration.clone(),
+                )),
+            );
+        }
+        transport_factory_list.extend(
+            self.transport_factory_list
+                .iter()
+                .map(|(&locator_kind, f)| (locator_kind, f.clone())),
+        );
+        for (locator_kind, transport_factory) in message.transport_factory_list {
+            if !is_transport_locator_kind(locator_kind) {
+                return Err(DdsError::BadParameter);
+            }
+            transport_factory_list.insert(locator_kind, transport_factory);
+        }
 
-        let socket = std::net::UdpSocket::bind("0.0.0.0:0000")?;
+        let transport_receiver = TransportReceiver::new();
+        let mut transport_list: HashMap<i32, Arc<dyn Transport>> = HashMap::new();
+        let mut transport_locators = TransportLocators::default();
+        for (locator_kind, transport_factory) in transport_factory_list {
+            let (transport, locators) = match transport_factory
+                .create_transport(message.domain_id, transport_receiver.clone())
+            {
+                Ok(t) => t,
+                Err(e) => {
+                    // The transports already created are not used by any participant
+                    for transport in transport_list.values() {
+                        transport.close();
+                    }
+                    return Err(e);
+                }
+            };
+            transport_list.insert(locator_kind, Arc::from(transport));
+            transport_locators
+                .default_unicast_locator_list
+                .extend(locators.default_unicast_locator_list);
+            transport_locators
+                .metatraffic_unicast_locator_list
+                .extend(locators.metatraffic_unicast_locator_list);
+            transport_locators
+                .metatraffic_multicast_locator_list
+                .extend(locators.metatraffic_multicast_locator_list);
+        }
+        // The fragments of the samples must fit in the datagrams of all the transports
+        let data_max_size_serialized = transport_list
+            .values()
+            .map(|t| {
+                t.max_datagram_size()
+                    .saturating_sub(FRAGMENT_DATAGRAM_OVERHEAD)
+            })
+            .fold(self.configuration.fragment_size(), usize::min)
+            .max(MIN_FRAGMENT_SIZE);
         let message_sender_actor =
-            MessageSenderActor::new(socket, PROTOCOLVERSION, VENDOR_ID_S2E, guid_prefix);
+            MessageSenderActor::new(transport_list, PROTOCOLVERSION, VENDOR_ID_S2E, guid_prefix);
 
         let mut rtps_participant = RtpsParticipant::new(
             guid_prefix,
@@ -470,69 +623,67 @@ impl MailHandler<CreateParticipant> for DomainParticipantFactoryActor {
         );
         let participant_guid = rtps_participant.guid();
 
+        // The participant is announced on the metatraffic multicast locators of its transports and by unicast to the
+        // initial peers, either on the given port or on the ports of the range of participant ids
+        let mut spdp_discovery_locator_list = transport_locators
+            .metatraffic_multicast_locator_list
+            .clone();
+        for initial_peer in self.configuration.initial_peers() {
+            let is_ip_version_used = match initial_peer.address() {
+                IpAddr::V4(_) => ip_version_mode.uses_ipv4(),
+                IpAddr::V6(_) => ip_version_mode.uses_ipv6(),
+            };
+            if !is_ip_version_used {
+                warn!(
+                    "Initial peer {} ignored since its IP version is not in use",
+                    initial_peer.address()
+                );
+                continue;
+            }
+            match initial_peer.port() {
+                Some(port) => spdp_discovery_locator_list.push(Locator::from_ip_addr_and_port(
+                    initial_peer.address(),
+                    port as u32,
+                )),
+                None => spdp_discovery_locator_list.extend(
+                    self.configuration
+                        .initial_peers_participant_id_range()
+                        .clone()
+                        .map(|participant_id| {
+                            Locator::from_ip_addr_and_port(
+                                initial_peer.address(),
+                                port_builtin_unicast(message.domain_id, participant_id) as u32,
+                            )
+                        }),
+                ),
+            }
+        }
+
         let topic_list = self.create_builtin_topics(guid_prefix, &executor.handle());
         let builtin_data_writer_list = self.create_builtin_writers(
             guid_prefix,
-            message.domain_id,
+            &spdp_discovery_locator_list,
             &topic_list,
             &executor_handle,
         );
         let builtin_data_reader_list =
             self.create_builtin_readers(guid_prefix, &topic_list, &executor_handle);
 
-        // Open socket for unicast user-defined data
-        let interface_address_list = NetworkInterface::show()
-            .expect("Could not scan interfaces")
-            .into_iter()
-            .filter(|x| {
-                if let Some(if_name) = self.configuration.interface_name() {
-                    &x.name == if_name
-                } else {
-                    true
-                }
-            })
-            .flat_map(|i| {
-                i.addr.into_iter().filter(|a| match a {
-                    #[rustfmt::skip]
-                    Addr::V4(_) => true,
-                    _ => false,
-                })
-            });
-
-        let default_unicast_socket =
-            socket2::Socket::new(socket2::Domain::IPV4, socket2::Type::DGRAM, None)?;
-        default_unicast_socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)).into())?;
-        default_unicast_socket.set_nonblocking(false)?;
-        if let Some(buffer_size) = self.configuration.udp_receive_buffer_size() {
-            default_unicast_socket.set_recv_buffer_size(buffer_size)?;
-        }
-        let mut default_unicast_socket = std::net::UdpSocket::from(default_unicast_socket);
-        let user_defined_unicast_port = default_unicast_socket.local_addr()?.port().into();
-        let default_unicast_locator_list: Vec<Locator> = interface_address_list
-            .clone()
-            .map(|a| Locator::from_ip_and_port(&a, user_defined_unicast_port))
-            .collect();
-        rtps_participant.set_default_unicast_locator_list(default_unicast_locator_list);
-
-        // Open socket for unicast metatraffic data
-        let mut metatrafic_unicast_socket =
-            std::net::UdpSocket::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))?;
-        metatrafic_unicast_socket.set_nonblocking(false)?;
-        let metattrafic_unicast_locator_port =
-            metatrafic_unicast_socket.local_addr()?.port().into();
-        let metatraffic_unicast_locator_list: Vec<Locator> = interface_address_list
-            .clone()
-            .map(|a| Locator::from_ip_and_port(&a, metattrafic_unicast_locator_port))
-            .collect();
-        rtps_participant.set_metatraffic_unicast_locator_list(metatraffic_unicast_locator_list);
-
-        // Open socket for multicast metatraffic data
-        let metatraffic_multicast_locator_list = vec![Locator::new(
-            LOCATOR_KIND_UDP_V4,
-            port_builtin_multicast(message.domain_id) as u32,
-            DEFAULT_MULTICAST_LOCATOR_ADDRESS,
-        )];
-        rtps_participant.set_metatraffic_multicast_locator_list(metatraffic_multicast_locator_list);
+        rtps_participant
+            .set_default_unicast_locator_list(transport_locators.default_unicast_locator_list);
+        rtps_participant.set_metatraffic_unicast_locator_list(
+            transport_locators.metatraffic_unicast_locator_list,
+        );
+        rtps_participant.set_metatraffic_multicast_locator_list(
+            transport_locators.metatraffic_multicast_locator_list,
+        );
+
+        // A storage set by the application takes precedence over the configured directory
+        let persistent_storage = self.persistent_storage.clone().or_else(|| {
+            self.configuration
+                .persistent_storage_directory()
+                .map(|d| Arc::new(AppendOnlyLogStorage::new(d)) as Arc<dyn PersistentStorage>)
+        });
 
         let timer_driver = TimerDriver::new();
         let timer_handle = timer_driver.handle();
@@ -547,13 +698,16 @@ impl MailHandler<CreateParticipant> for DomainParticipantFactoryActor {
             message.domain_id,
             self.configuration.domain_tag().to_string(),
             domain_participant_qos,
-            self.configuration.fragment_size(),
+            data_max_size_serialized,
+            self.configuration.participant_lease_duration().into(),
+            persistent_storage,
             message.listener,
             message.status_kind,
             topic_list,
             builtin_data_writer_list,
             builtin_data_reader_list,
             message_sender_actor,
+            interface_list,
             executor,
             timer_driver,
         );
@@ -567,27 +721,7 @@ impl MailHandler<CreateParticipant> for DomainParticipantFactoryActor {
             executor_handle.clone(),
             timer_handle.clone(),
         );
-
-        let participant_address_clone = participant_actor.address();
-        let participant_clone = participant.clone();
-
-        std::thread::spawn(move || {
-            let mut buf = Box::new([0; MAX_DATAGRAM_SIZE]);
-            loop {
-                if let Ok(message) = read_message(&mut default_unicast_socket, buf.as_mut_slice()) {
-                    let r = participant_address_clone.send_actor_mail(
-                        domain_participant_actor::ProcessUserDefinedRtpsMessage {
-                            rtps_message: message,
-                            participant: participant_clone.clone(),
-                            executor_handle: participant_clone.executor_handle().clone(),
-                        },
-                    );
-                    if r.is_err() {
-                        break;
-                    }
-                }
-            }
-        });
+        transport_receiver.set_participant(participant.clone());
 
         // Start the regular participant announcement task
         let participant_clone = participant.clone();
@@ -605,56 +739,6 @@ impl MailHandler<CreateParticipant> for DomainParticipantFactoryActor {
             }
         });
 
-        let participant_address_clone = participant_actor.address();
-        let participant_clone = participant.clone();
-
-        std::thread::spawn(move || {
-            let mut buf = Box::new([0; MAX_DATAGRAM_SIZE]);
-            loop {
-                if let Ok(message) =
-                    read_message(&mut metatrafic_unicast_socket, buf.as_mut_slice())
-                {
-                    let r = participant_address_clone.send_actor_mail(
-                        domain_participant_actor::ProcessMetatrafficRtpsMessage {
-                            rtps_message: message,
-                            participant: participant_clone.clone(),
-                            executor_handle: participant_clone.executor_handle().clone(),
-                        },
-                    );
-
-                    if r.is_err() {
-                        break;
-                    }
-                }
-            }
-        });
-
-        let participant_address_clone = participant_actor.address();
-        let participant_clone = participant.clone();
-        let mut socket = get_multicast_socket(
-            DEFAULT_MULTICAST_LOCATOR_ADDRESS,
-            port_builtin_multicast(message.domain_id),
-            interface_address_list,
-        )?;
-        std::thread::spawn(move || {
-            let mut buf = Box::new([0; MAX_DATAGRAM_SIZE]);
-            loop {
-                if let Ok(message) = read_message(&mut socket, buf.as_mut_slice()) {
-                    let r = participant_address_clone.send_actor_mail(
-                        domain_participant_actor::ProcessMetatrafficRtpsMessage {
-                            rtps_message: message,
-                            participant: participant_clone.clone(),
-                            executor_handle: participant_clone.executor_handle().clone(),
-                        },
-                    );
-
-                    if r.is_err() {
-                        break;
-                    }
-                }
-            }
-        });
-
         let participant_address = participant_actor.address();
         self.domain_participant_list.insert(
             InstanceHandle::new(participant_guid.into()),
@@ -770,6 +854,36 @@ impl MailHandler<SetConfiguration> for DomainParticipantFactoryActor {
     }
 }
 
+pub struct SetPersistentStorage {
+    pub persistent_storage: Option<Arc<dyn PersistentStorage>>,
+}
+impl Mail for SetPersistentStorage {
+    type Result = ();
+}
+impl MailHandler<SetPersistentStorage> for DomainParticipantFactoryActor {
+    fn handle(&mut self, message: SetPersistentStorage) -> <SetPersistentStorage as Mail>::Result {
+        self.persistent_storage = message.persistent_storage;
+    }
+}
+
+pub struct RegisterTransport {
+    pub locator_kind: i32,
+    pub transport_factory: Arc<dyn TransportFactory>,
+}
+impl Mail for RegisterTransport {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<RegisterTransport> for DomainParticipantFactoryActor {
+    fn handle(&mut self, message: RegisterTransport) -> <RegisterTransport as Mail>::Result {
+        if !is_transport_locator_kind(message.locator_kind) {
+            return Err(DdsError::BadParameter);
+        }
+        self.transport_factory_list
+            .insert(message.locator_kind, message.transport_factory);
+        Ok(())
+    }
+}
+
 pub struct GetConfiguration;
 impl Mail for GetConfiguration {
     type Result = DustDdsConfiguration;
@@ -780,68 +894,41 @@ impl MailHandler<GetConfiguration> for DomainParticipantFactoryActor {
     }
 }
 
-type LocatorAddress = [u8; 16];
-// As of 9.6.1.4.1  Default multicast address
-const DEFAULT_MULTICAST_LOCATOR_ADDRESS: LocatorAddress =
-    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 239, 255, 0, 1];
-
 const PB: i32 = 7400;
 const DG: i32 = 250;
+const PG: i32 = 2;
 #[allow(non_upper_case_globals)]
 const d0: i32 = 0;
+#[allow(non_upper_case_globals)]
+const d1: i32 = 10;
+#[allow(non_upper_case_globals)]
+const d2: i32 = 1;
+#[allow(non_upper_case_globals)]
+const d3: i32 = 11;
 const DEFAULT_HEARTBEAT_PERIOD: Duration = Duration::new(2, 0);
 const DEFAULT_NACK_RESPONSE_DELAY: Duration = Duration::new(0, 200);
 const DEFAULT_NACK_SUPPRESSION_DURATION: Duration =
     Duration::new(DURATION_ZERO_SEC, DURATION_ZERO_NSEC);
 
-fn port_builtin_multicast(domain_id: DomainId) -> u16 {
+pub(crate) fn port_builtin_multicast(domain_id: DomainId) -> u16 {
     (PB + DG * domain_id + d0) as u16
 }
 
-fn get_multicast_socket(
-    multicast_address: LocatorAddress,
-    port: u16,
-    interface_address_list: impl IntoIterator<Item = Addr>,
-) -> std::io::Result<std::net::UdpSocket> {
-    let socket_addr = SocketAddr::from((Ipv4Addr::UNSPECIFIED, port));
-
-    let socket = Socket::new(
-        socket2::Domain::IPV4,
-        socket2::Type::DGRAM,
-        Some(socket2::Protocol::UDP),
-    )?;
-
-    socket.set_reuse_address(true)?;
-    #[cfg(target_family = "unix")]
-    socket.set_reuse_port(true)?;
-    socket.set_nonblocking(false)?;
-    socket.set_read_timeout(Some(std::time::Duration::from_millis(50)))?;
-
-    socket.bind(&socket_addr.into())?;
-    let addr = Ipv4Addr::new(
-        multicast_address[12],
-        multicast_address[13],
-        multicast_address[14],
-        multicast_address[15],
-    );
-    for interface_addr in interface_address_list {
-        match interface_addr {
-            Addr::V4(a) => {
-                let r = socket.join_multicast_v4(&addr, &a.ip);
-                if let Err(e) = r {
-                    info!(
-                        "Failed to join multicast group on address {} with error {}",
-                        a.ip, e
-                    )
-                }
-            }
-            Addr::V6(_) => (),
-        }
-    }
+pub(crate) fn port_user_multicast(domain_id: DomainId) -> u16 {
+    (PB + DG * domain_id + d2) as u16
+}
 
-    socket.set_multicast_loop_v4(true)?;
+pub(crate) fn port_builtin_unicast(domain_id: DomainId, participant_id: u32) -> u16 {
+    (PB + DG * domain_id + d1 + PG * participant_id as i32) as u16
+}
+
+pub(crate) fn port_user_unicast(domain_id: DomainId, participant_id: u32) -> u16 {
+    (PB + DG * domain_id + d3 + PG * participant_id as i32) as u16
+}
 
-    Ok(socket.into())
+/// Locator kinds for which a transport can be registered
+fn is_transport_locator_kind(locator_kind: i32) -> bool {
+    ![LOCATOR_KIND_INVALID, LOCATOR_KIND_RESERVED].contains(&locator_kind)
 }
 
 fn create_builtin_stateless_reader(guid: Guid) -> RtpsReaderKind {
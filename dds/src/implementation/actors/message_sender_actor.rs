@@ -1,38 +1,135 @@
-use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
-use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, ToSocketAddrs};
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     implementation::actor::{Mail, MailHandler},
     rtps::{
-        messages::overall_structure::{RtpsMessageHeader, RtpsMessageWrite, Submessage},
-        types::{
-            GuidPrefix, Locator, ProtocolVersion, VendorId, LOCATOR_KIND_UDP_V4,
-            LOCATOR_KIND_UDP_V6,
+        messages::{
+            overall_structure::{
+                write_into_bytes_vec, write_submessage_into_bytes_vec, RtpsMessageHeader,
+                RtpsMessageWrite, Submessage,
+            },
+            submessages::{
+                info_destination::InfoDestinationSubmessage,
+                info_timestamp::InfoTimestampSubmessage,
+            },
+            types::{INFO_DST, TIME_INVALID},
         },
+        types::{GuidPrefix, Locator, ProtocolVersion, VendorId, GUIDPREFIX_UNKNOWN},
     },
+    transport::Transport,
 };
 
+// The datagrams of a batch fit in the MTU of an Ethernet link over IPv4 and IPv6 so that
+// they are not fragmented by the IP layer, since losing one IP fragment drops the whole datagram
+const MAX_BATCH_DATAGRAM_SIZE: usize = 1452;
+
+// Serialized messages grouped by their destination locators
+type MessageBatch = Vec<(Vec<Locator>, Vec<Vec<u8>>)>;
+
 pub struct MessageSenderActor {
-    socket: std::net::UdpSocket,
+    transport_list: HashMap<i32, Arc<dyn Transport>>,
     protocol_version: ProtocolVersion,
     vendor_id: VendorId,
     guid_prefix: GuidPrefix,
+    // Messages kept to be sent together by a sender created for a batch
+    batch: Option<MessageBatch>,
 }
 
 impl MessageSenderActor {
     pub fn new(
-        socket: std::net::UdpSocket,
+        transport_list: HashMap<i32, Arc<dyn Transport>>,
         protocol_version: ProtocolVersion,
         vendor_id: VendorId,
         guid_prefix: GuidPrefix,
     ) -> Self {
         Self {
-            socket,
+            transport_list,
             protocol_version,
             vendor_id,
             guid_prefix,
+            batch: None,
         }
     }
+
+    fn send_datagram(&self, buf: &[u8], destination_locator_list: &[Locator]) {
+        for destination_locator in destination_locator_list {
+            // Locators of a kind without transport are skipped
+            if let Some(transport) = self.transport_list.get(&destination_locator.kind()) {
+                transport.send(buf, destination_locator);
+            }
+        }
+    }
+
+    fn send_batch(&mut self) {
+        let Some(batch) = self.batch.as_mut().map(std::mem::take) else {
+            return;
+        };
+        let header = write_into_bytes_vec(RtpsMessageHeader::new(
+            self.protocol_version,
+            self.vendor_id,
+            self.guid_prefix,
+        ));
+        for (destination_locator_list, message_list) in batch {
+            // The datagrams must also fit in the transports of all the destination locators
+            let max_datagram_size = destination_locator_list
+                .iter()
+                .filter_map(|l| self.transport_list.get(&l.kind()))
+                .map(|t| t.max_datagram_size())
+                .fold(MAX_BATCH_DATAGRAM_SIZE, usize::min);
+            for datagram in pack_datagrams(&header, message_list, max_datagram_size) {
+                self.send_datagram(&datagram, &destination_locator_list);
+            }
+        }
+    }
+}
+
+impl Drop for MessageSenderActor {
+    fn drop(&mut self) {
+        // The messages of a batch are sent even if the batch is abandoned before being sent
+        self.send_batch();
+    }
+}
+
+/// Packs the serialized submessages of each message into as few datagrams as possible
+/// without exceeding `max_datagram_size`. Messages following another one in a datagram
+/// are preceded by an INFO_TS invalidating the timestamp and, if they do not start with
+/// an INFO_DST, by an INFO_DST to GUIDPREFIX_UNKNOWN so that they do not inherit the
+/// timestamp or the destination of the message preceding them.
+fn pack_datagrams(
+    header: &[u8],
+    message_list: Vec<Vec<u8>>,
+    max_datagram_size: usize,
+) -> Vec<Vec<u8>> {
+    let info_ts_invalid =
+        write_submessage_into_bytes_vec(&InfoTimestampSubmessage::new(true, TIME_INVALID));
+    let info_dst_unknown =
+        write_submessage_into_bytes_vec(&InfoDestinationSubmessage::new(GUIDPREFIX_UNKNOWN));
+
+    let mut datagram_list = Vec::new();
+    let mut datagram = header.to_vec();
+    for message in message_list {
+        let starts_with_info_dst = message.first() == Some(&INFO_DST);
+        if datagram.len() > header.len() {
+            let prefix_len = if starts_with_info_dst {
+                info_ts_invalid.len()
+            } else {
+                info_ts_invalid.len() + info_dst_unknown.len()
+            };
+            if datagram.len() + prefix_len + message.len() > max_datagram_size {
+                datagram_list.push(std::mem::replace(&mut datagram, header.to_vec()));
+            } else {
+                datagram.extend_from_slice(&info_ts_invalid);
+                if !starts_with_info_dst {
+                    datagram.extend_from_slice(&info_dst_unknown);
+                }
+            }
+        }
+        datagram.extend_from_slice(&message);
+    }
+    if datagram.len() > header.len() {
+        datagram_list.push(datagram);
+    }
+    datagram_list
 }
 
 pub struct WriteMessage {
@@ -44,80 +141,120 @@ impl Mail for WriteMessage {
 }
 impl MailHandler<WriteMessage> for MessageSenderActor {
     fn handle(&mut self, message: WriteMessage) -> <WriteMessage as Mail>::Result {
+        if let Some(batch) = self.batch.as_mut() {
+            let serialized_message = message
+                .submessages
+                .iter()
+                .flat_map(|s| write_submessage_into_bytes_vec(s.as_ref()))
+                .collect();
+            match batch
+                .iter_mut()
+                .find(|(l, _)| l == &message.destination_locator_list)
+            {
+                Some((_, message_list)) => message_list.push(serialized_message),
+                None => batch.push((message.destination_locator_list, vec![serialized_message])),
+            }
+            return;
+        }
+
         let header =
             RtpsMessageHeader::new(self.protocol_version, self.vendor_id, self.guid_prefix);
         let rtpmessage = RtpsMessageWrite::new(&header, &message.submessages);
-        let buf = rtpmessage.buffer();
-
-        for destination_locator in message.destination_locator_list {
-            if UdpLocator(destination_locator).is_multicast() {
-                let socket2: socket2::Socket = self.socket.try_clone().unwrap().into();
-                let interface_addresses = NetworkInterface::show();
-                let interface_addresses: Vec<_> = interface_addresses
-                    .expect("Could not scan interfaces")
-                    .into_iter()
-                    .flat_map(|i| {
-                        i.addr.into_iter().filter_map(|a| match a {
-                            Addr::V4(v4) => Some(v4.ip),
-                            _ => None,
-                        })
-                    })
-                    .collect();
-                for address in interface_addresses {
-                    if socket2.set_multicast_if_v4(&address).is_ok() {
-                        self.socket
-                            .send_to(buf, UdpLocator(destination_locator))
-                            .ok();
-                    }
-                }
-            } else {
-                self.socket
-                    .send_to(buf, UdpLocator(destination_locator))
-                    .ok();
-            }
-        }
+        self.send_datagram(rtpmessage.buffer(), &message.destination_locator_list);
     }
 }
 
-struct UdpLocator(Locator);
-
-impl ToSocketAddrs for UdpLocator {
-    type Iter = std::option::IntoIter<SocketAddr>;
-
-    fn to_socket_addrs(&self) -> std::io::Result<Self::Iter> {
-        let locator_address = self.0.address();
-        match self.0.kind() {
-            LOCATOR_KIND_UDP_V4 => {
-                let address = SocketAddrV4::new(
-                    Ipv4Addr::new(
-                        locator_address[12],
-                        locator_address[13],
-                        locator_address[14],
-                        locator_address[15],
-                    ),
-                    self.0.port() as u16,
-                );
-                Ok(Some(SocketAddr::V4(address)).into_iter())
-            }
-            LOCATOR_KIND_UDP_V6 => todo!(),
-            _ => Err(std::io::ErrorKind::InvalidInput.into()),
+pub struct NewBatch;
+impl Mail for NewBatch {
+    type Result = MessageSenderActor;
+}
+impl MailHandler<NewBatch> for MessageSenderActor {
+    fn handle(&mut self, _: NewBatch) -> <NewBatch as Mail>::Result {
+        MessageSenderActor {
+            transport_list: self.transport_list.clone(),
+            protocol_version: self.protocol_version,
+            vendor_id: self.vendor_id,
+            guid_prefix: self.guid_prefix,
+            batch: Some(Vec::new()),
         }
     }
 }
 
-impl UdpLocator {
-    fn is_multicast(&self) -> bool {
-        let locator_address = self.0.address();
-        match self.0.kind() {
-            LOCATOR_KIND_UDP_V4 => Ipv4Addr::new(
-                locator_address[12],
-                locator_address[13],
-                locator_address[14],
-                locator_address[15],
-            )
-            .is_multicast(),
-            LOCATOR_KIND_UDP_V6 => Ipv6Addr::from(locator_address).is_multicast(),
-            _ => false,
+pub struct SendBatch;
+impl Mail for SendBatch {
+    type Result = ();
+}
+impl MailHandler<SendBatch> for MessageSenderActor {
+    fn handle(&mut self, _: SendBatch) -> <SendBatch as Mail>::Result {
+        self.send_batch();
+    }
+}
+
+pub struct CloseTransports;
+impl Mail for CloseTransports {
+    type Result = ();
+}
+impl MailHandler<CloseTransports> for MessageSenderActor {
+    fn handle(&mut self, _: CloseTransports) -> <CloseTransports as Mail>::Result {
+        for (_, transport) in self.transport_list.drain() {
+            transport.close();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pack_datagrams_resets_destination_and_timestamp_between_messages() {
+        let header = vec![0; 20];
+        let info_dst = write_submessage_into_bytes_vec(&InfoDestinationSubmessage::new([1; 12]));
+        let pad = vec![1, 0, 0, 0];
+
+        let datagram_list = pack_datagrams(
+            &header,
+            vec![pad.clone(), pad.clone(), info_dst.clone()],
+            MAX_BATCH_DATAGRAM_SIZE,
+        );
+
+        let info_ts_invalid =
+            write_submessage_into_bytes_vec(&InfoTimestampSubmessage::new(true, TIME_INVALID));
+        let info_dst_unknown =
+            write_submessage_into_bytes_vec(&InfoDestinationSubmessage::new(GUIDPREFIX_UNKNOWN));
+        let expected_datagram = [
+            header.as_slice(),
+            &pad,
+            &info_ts_invalid,
+            &info_dst_unknown,
+            &pad,
+            &info_ts_invalid,
+            &info_dst,
+        ]
+        .concat();
+        assert_eq!(datagram_list, vec![expected_datagram]);
+    }
+
+    #[test]
+    fn pack_datagrams_splits_messages_exceeding_maximum_size() {
+        let header = vec![0; 20];
+        let info_dst = write_submessage_into_bytes_vec(&InfoDestinationSubmessage::new([1; 12]));
+
+        let info_ts_invalid =
+            write_submessage_into_bytes_vec(&InfoTimestampSubmessage::new(true, TIME_INVALID));
+
+        let datagram_list = pack_datagrams(
+            &header,
+            vec![info_dst.clone(), info_dst.clone(), info_dst.clone()],
+            header.len() + 2 * info_dst.len() + info_ts_invalid.len(),
+        );
+
+        assert_eq!(
+            datagram_list,
+            vec![
+                [header.as_slice(), &info_dst, &info_ts_invalid, &info_dst].concat(),
+                [header.as_slice(), &info_dst].concat(),
+            ]
+        );
+    }
+}
@@ -14,36 +14,48 @@ use crate::{
     implementation::{
         actor::{Actor, ActorAddress, Mail, MailHandler},
         data_representation_builtin_endpoints::discovered_reader_data::DiscoveredReaderData,
+        data_representation_inline_qos::{
+            parameter_id_values::{PID_GROUP_COHERENT_SET, PID_GROUP_SEQ_NUM},
+            types::GroupSequenceNumber,
+        },
         runtime::{
             executor::{block_on, ExecutorHandle},
             mpsc::{mpsc_channel, MpscSender},
+            timer::TimerHandle,
         },
     },
     infrastructure::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DataWriterQos, PublisherQos, QosKind},
-        qos_policy::PartitionQosPolicy,
+        qos_policy::{PartitionQosPolicy, PresentationQosPolicyAccessScopeKind},
         status::{
             LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
             PublicationMatchedStatus, StatusKind,
         },
-        time::Duration,
+        time::{Duration, Time},
     },
     rtps::{
         behavior_types::DURATION_ZERO,
         endpoint::RtpsEndpoint,
         group::RtpsGroup,
-        messages::submessages::{ack_nack::AckNackSubmessage, nack_frag::NackFragSubmessage},
+        messages::{
+            submessage_elements::Parameter,
+            submessages::{ack_nack::AckNackSubmessage, nack_frag::NackFragSubmessage},
+            types::ParameterId,
+        },
         types::{
-            EntityId, Guid, GuidPrefix, Locator, TopicKind, USER_DEFINED_WRITER_NO_KEY,
-            USER_DEFINED_WRITER_WITH_KEY,
+            EntityId, Guid, GuidPrefix, Locator, SequenceNumber, TopicKind,
+            USER_DEFINED_WRITER_NO_KEY, USER_DEFINED_WRITER_WITH_KEY,
         },
         writer::RtpsWriter,
     },
+    xtypes::{
+        dynamic_type::DynamicType, serialize::XTypesSerialize, xcdr_serializer::Xcdr1LeSerializer,
+    },
 };
 use fnmatch_regex::glob_to_regex;
-use std::{collections::HashMap, thread::JoinHandle};
+use std::{collections::HashMap, sync::Arc, thread::JoinHandle};
 use tracing::warn;
 
 pub enum PublisherListenerOperation {
@@ -123,6 +135,10 @@ pub struct PublisherActor {
     publisher_listener_thread: Option<PublisherListenerThread>,
     status_kind: Vec<StatusKind>,
     status_condition: Actor<StatusConditionActor>,
+    coherent_changes_depth: u32,
+    group_sequence_number: SequenceNumber,
+    group_coherent_set: Option<SequenceNumber>,
+    suspended_publications_depth: u32,
 }
 
 impl PublisherActor {
@@ -149,6 +165,10 @@ impl PublisherActor {
             publisher_listener_thread,
             status_kind,
             status_condition: Actor::spawn(StatusConditionActor::default(), handle),
+            coherent_changes_depth: 0,
+            group_sequence_number: 0,
+            group_coherent_set: None,
+            suspended_publications_depth: 0,
         }
     }
 
@@ -156,12 +176,26 @@ impl PublisherActor {
         self.status_condition.address()
     }
 
+    pub fn add_data_writer(
+        &mut self,
+        data_writer_handle: InstanceHandle,
+        data_writer: Actor<DataWriterActor>,
+    ) {
+        self.data_writer_list
+            .insert(data_writer_handle, data_writer);
+    }
+
     fn get_unique_writer_id(&mut self) -> u8 {
         let counter = self.user_defined_data_writer_counter;
         self.user_defined_data_writer_counter += 1;
         counter
     }
 
+    fn is_group_access_scope(&self) -> bool {
+        self.qos.presentation.access_scope == PresentationQosPolicyAccessScopeKind::Group
+            && (self.qos.presentation.coherent_access || self.qos.presentation.ordered_access)
+    }
+
     fn is_partition_matched(&self, discovered_partition_qos_policy: &PartitionQosPolicy) -> bool {
         let is_any_name_matched = discovered_partition_qos_policy
             .name
@@ -217,6 +251,7 @@ pub struct CreateDatawriter {
     pub topic_name: String,
     pub type_name: String,
     pub topic_status_condition: ActorAddress<StatusConditionActor>,
+    pub type_support: Arc<dyn DynamicType + Send + Sync>,
     pub has_key: bool,
     pub data_max_size_serialized: usize,
     pub qos: QosKind<DataWriterQos>,
@@ -272,12 +307,19 @@ impl MailHandler<CreateDatawriter> for PublisherActor {
             message.topic_name,
             message.type_name,
             message.topic_status_condition,
+            message.type_support,
             message.a_listener,
             message.mask,
             qos,
             &message.executor_handle,
         );
         let data_writer_actor = Actor::spawn(data_writer, &message.executor_handle);
+        if self.coherent_changes_depth > 0 && self.qos.presentation.coherent_access {
+            data_writer_actor.send_actor_mail(data_writer_actor::BeginCoherentSet);
+        }
+        if self.suspended_publications_depth > 0 {
+            data_writer_actor.send_actor_mail(data_writer_actor::SuspendPublications);
+        }
         let data_writer_address = data_writer_actor.address();
         self.data_writer_list
             .insert(InstanceHandle::new(guid.into()), data_writer_actor);
@@ -445,6 +487,125 @@ impl MailHandler<GetDataWriterList> for PublisherActor {
     }
 }
 
+pub struct SuspendPublications;
+impl Mail for SuspendPublications {
+    type Result = ();
+}
+impl MailHandler<SuspendPublications> for PublisherActor {
+    fn handle(&mut self, _: SuspendPublications) -> <SuspendPublications as Mail>::Result {
+        if self.suspended_publications_depth == 0 {
+            for data_writer in self.data_writer_list.values() {
+                data_writer.send_actor_mail(data_writer_actor::SuspendPublications);
+            }
+        }
+        self.suspended_publications_depth += 1;
+    }
+}
+
+pub struct ResumePublications;
+impl Mail for ResumePublications {
+    type Result = DdsResult<Vec<ActorAddress<DataWriterActor>>>;
+}
+impl MailHandler<ResumePublications> for PublisherActor {
+    fn handle(&mut self, _: ResumePublications) -> <ResumePublications as Mail>::Result {
+        if self.suspended_publications_depth == 0 {
+            return Err(DdsError::PreconditionNotMet(
+                "No matching suspend_publications".to_string(),
+            ));
+        }
+
+        self.suspended_publications_depth -= 1;
+        // The writers are returned to be resumed by the caller so that all their
+        // pending messages can be sent together
+        if self.suspended_publications_depth == 0 {
+            Ok(self
+                .data_writer_list
+                .values()
+                .map(|x| x.address())
+                .collect())
+        } else {
+            Ok(Vec::new())
+        }
+    }
+}
+
+pub struct BeginCoherentChanges;
+impl Mail for BeginCoherentChanges {
+    type Result = ();
+}
+impl MailHandler<BeginCoherentChanges> for PublisherActor {
+    fn handle(&mut self, _: BeginCoherentChanges) -> <BeginCoherentChanges as Mail>::Result {
+        // Nested calls are part of the outermost coherent set
+        if self.coherent_changes_depth == 0 && self.qos.presentation.coherent_access {
+            for data_writer in self.data_writer_list.values() {
+                data_writer.send_actor_mail(data_writer_actor::BeginCoherentSet);
+            }
+        }
+        self.coherent_changes_depth += 1;
+    }
+}
+
+pub struct EndCoherentChanges {
+    pub now: Time,
+    pub message_sender_actor: ActorAddress<MessageSenderActor>,
+}
+impl Mail for EndCoherentChanges {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<EndCoherentChanges> for PublisherActor {
+    fn handle(&mut self, message: EndCoherentChanges) -> <EndCoherentChanges as Mail>::Result {
+        if self.coherent_changes_depth == 0 {
+            return Err(DdsError::PreconditionNotMet(
+                "No matching begin_coherent_changes".to_string(),
+            ));
+        }
+
+        self.coherent_changes_depth -= 1;
+        if self.coherent_changes_depth == 0 && self.qos.presentation.coherent_access {
+            // All the writers mark the end of a group coherent set, even if they didn't
+            // write any change, so that the subscriber knows when the set is complete
+            let group_coherent_set = self.group_coherent_set.take().map(GroupSequenceNumber::new);
+            for data_writer in self.data_writer_list.values() {
+                data_writer.send_actor_mail(data_writer_actor::EndCoherentSet {
+                    now: message.now,
+                    message_sender_actor: message.message_sender_actor.clone(),
+                    group_coherent_set,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct GetGroupInlineQos;
+impl Mail for GetGroupInlineQos {
+    type Result = Vec<Parameter>;
+}
+impl MailHandler<GetGroupInlineQos> for PublisherActor {
+    fn handle(&mut self, _: GetGroupInlineQos) -> <GetGroupInlineQos as Mail>::Result {
+        if !self.is_group_access_scope() {
+            return Vec::new();
+        }
+
+        self.group_sequence_number += 1;
+        let mut parameter = vec![group_sequence_number_parameter(
+            PID_GROUP_SEQ_NUM,
+            self.group_sequence_number,
+        )];
+        if self.coherent_changes_depth > 0 && self.qos.presentation.coherent_access {
+            // The group coherent set is identified by the group sequence number of its first change
+            let group_coherent_set = *self
+                .group_coherent_set
+                .get_or_insert(self.group_sequence_number);
+            parameter.push(group_sequence_number_parameter(
+                PID_GROUP_COHERENT_SET,
+                group_coherent_set,
+            ));
+        }
+        parameter
+    }
+}
+
 pub struct ProcessAckNackSubmessage {
     pub acknack_submessage: AckNackSubmessage,
     pub source_guid_prefix: GuidPrefix,
@@ -468,6 +629,30 @@ impl MailHandler<ProcessAckNackSubmessage> for PublisherActor {
     }
 }
 
+pub struct AssertManualByParticipantLiveliness {
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
+}
+impl Mail for AssertManualByParticipantLiveliness {
+    type Result = ();
+}
+impl MailHandler<AssertManualByParticipantLiveliness> for PublisherActor {
+    fn handle(
+        &mut self,
+        message: AssertManualByParticipantLiveliness,
+    ) -> <AssertManualByParticipantLiveliness as Mail>::Result {
+        for data_writer_actor in self.data_writer_list.values() {
+            data_writer_actor.send_actor_mail(
+                data_writer_actor::AssertManualByParticipantLiveliness {
+                    writer_address: data_writer_actor.address(),
+                    executor_handle: message.executor_handle.clone(),
+                    timer_handle: message.timer_handle.clone(),
+                },
+            );
+        }
+    }
+}
+
 pub struct ProcessNackFragSubmessage {
     pub nackfrag_submessage: NackFragSubmessage,
     pub source_guid_prefix: GuidPrefix,
@@ -537,6 +722,35 @@ impl MailHandler<AddMatchedReader> for PublisherActor {
                     message_sender_actor: message.message_sender_actor.clone(),
                 });
             }
+        } else {
+            // A reader which was matched before a change of partition is no longer matched
+            let discovered_reader_handle = InstanceHandle::new(
+                message
+                    .discovered_reader_data
+                    .subscription_builtin_topic_data()
+                    .key()
+                    .value,
+            );
+            for data_writer in self.data_writer_list.values() {
+                let data_writer_address = data_writer.address();
+                let publisher_mask_listener = (
+                    self.publisher_listener_thread
+                        .as_ref()
+                        .map(|l| l.sender().clone()),
+                    self.status_kind.clone(),
+                );
+                data_writer.send_actor_mail(data_writer_actor::RemoveMatchedReader {
+                    discovered_reader_handle,
+                    data_writer_address,
+                    publisher: PublisherAsync::new(
+                        message.publisher_address.clone(),
+                        self.status_condition.address(),
+                        message.participant.clone(),
+                    ),
+                    publisher_mask_listener,
+                    participant_mask_listener: message.participant_mask_listener.clone(),
+                });
+            }
         }
         Ok(())
     }
@@ -580,6 +794,48 @@ impl MailHandler<RemoveMatchedReader> for PublisherActor {
     }
 }
 
+pub struct AddParticipantReaderLocators {
+    pub data_writer_handle: InstanceHandle,
+    pub participant_handle: InstanceHandle,
+    pub locator_list: Vec<Locator>,
+}
+impl Mail for AddParticipantReaderLocators {
+    type Result = ();
+}
+impl MailHandler<AddParticipantReaderLocators> for PublisherActor {
+    fn handle(
+        &mut self,
+        message: AddParticipantReaderLocators,
+    ) -> <AddParticipantReaderLocators as Mail>::Result {
+        if let Some(data_writer) = self.data_writer_list.get(&message.data_writer_handle) {
+            data_writer.send_actor_mail(data_writer_actor::AddParticipantReaderLocators {
+                participant_handle: message.participant_handle,
+                locator_list: message.locator_list,
+            });
+        }
+    }
+}
+
+pub struct RemoveParticipantReaderLocators {
+    pub data_writer_handle: InstanceHandle,
+    pub participant_handle: InstanceHandle,
+}
+impl Mail for RemoveParticipantReaderLocators {
+    type Result = ();
+}
+impl MailHandler<RemoveParticipantReaderLocators> for PublisherActor {
+    fn handle(
+        &mut self,
+        message: RemoveParticipantReaderLocators,
+    ) -> <RemoveParticipantReaderLocators as Mail>::Result {
+        if let Some(data_writer) = self.data_writer_list.get(&message.data_writer_handle) {
+            data_writer.send_actor_mail(data_writer_actor::RemoveParticipantReaderLocators {
+                participant_handle: message.participant_handle,
+            });
+        }
+    }
+}
+
 pub struct GetStatuscondition;
 impl Mail for GetStatuscondition {
     type Result = ActorAddress<StatusConditionActor>;
@@ -635,3 +891,17 @@ impl PublisherQos {
         }
     }
 }
+
+fn group_sequence_number_parameter(
+    parameter_id: ParameterId,
+    group_sequence_number: SequenceNumber,
+) -> Parameter {
+    let mut serialized_group_sequence_number = Vec::new();
+    let mut serializer = Xcdr1LeSerializer::new(&mut serialized_group_sequence_number);
+    XTypesSerialize::serialize(
+        &GroupSequenceNumber::new(group_sequence_number),
+        &mut serializer,
+    )
+    .expect("Group sequence number serialization must succeed");
+    Parameter::new(parameter_id, Arc::from(serialized_group_sequence_number))
+}
@@ -87,6 +87,22 @@ impl MailHandler<SetEnabledStatuses> for StatusConditionActor {
     }
 }
 
+pub struct GetStatusChanges;
+impl Mail for GetStatusChanges {
+    type Result = Vec<StatusKind>;
+}
+impl MailHandler<GetStatusChanges> for StatusConditionActor {
+    fn handle(&mut self, _: GetStatusChanges) -> <GetStatusChanges as Mail>::Result {
+        let mut status_changes = Vec::new();
+        for status in &self.status_changes {
+            if !status_changes.contains(status) {
+                status_changes.push(*status);
+            }
+        }
+        status_changes
+    }
+}
+
 pub struct GetTriggerValue;
 impl Mail for GetTriggerValue {
     type Result = bool;
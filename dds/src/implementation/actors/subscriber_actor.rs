@@ -14,7 +14,9 @@ use crate::{
     implementation::{
         actor::{Actor, ActorAddress, Mail, MailHandler},
         actors::status_condition_actor::StatusConditionActor,
+        content_filter::ContentFilterProperty,
         data_representation_builtin_endpoints::discovered_writer_data::DiscoveredWriterData,
+        multi_topic::MultiTopicJoin,
         runtime::{
             executor::{block_on, ExecutorHandle},
             mpsc::{mpsc_channel, MpscSender},
@@ -25,7 +27,7 @@ use crate::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DataReaderQos, QosKind, SubscriberQos},
-        qos_policy::PartitionQosPolicy,
+        qos_policy::{LivelinessQosPolicyKind, PartitionQosPolicy},
         status::{
             LivelinessChangedStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
             SampleLostStatus, SampleRejectedStatus, StatusKind, SubscriptionMatchedStatus,
@@ -42,8 +44,8 @@ use crate::{
         },
         reader::{RtpsReader, RtpsReaderKind, RtpsStatefulReader},
         types::{
-            EntityId, Guid, GuidPrefix, Locator, TopicKind, USER_DEFINED_READER_NO_KEY,
-            USER_DEFINED_READER_WITH_KEY,
+            EntityId, Guid, GuidPrefix, Locator, SequenceNumber, TopicKind,
+            USER_DEFINED_READER_NO_KEY, USER_DEFINED_READER_WITH_KEY,
         },
     },
     xtypes::dynamic_type::DynamicType,
@@ -135,16 +137,29 @@ impl SubscriberListenerThread {
     }
 }
 
+struct GroupCoherentSetPart {
+    reader_handle: InstanceHandle,
+    release: data_reader_actor::ReleaseGroupCoherentSet,
+}
+
 pub struct SubscriberActor {
     qos: SubscriberQos,
     rtps_group: RtpsGroup,
     data_reader_list: HashMap<InstanceHandle, Actor<DataReaderActor>>,
+    // Readers of the related topics of the MultiTopic readers indexed by the handle
+    // of the MultiTopic reader to which they forward the received samples
+    multi_topic_source_list: HashMap<InstanceHandle, Vec<Actor<DataReaderActor>>>,
     enabled: bool,
     user_defined_data_reader_counter: u8,
     default_data_reader_qos: DataReaderQos,
     status_condition: Actor<StatusConditionActor>,
     subscriber_listener_thread: Option<SubscriberListenerThread>,
     status_kind: Vec<StatusKind>,
+    access_depth: u32,
+    // Pairs of reader and matched writer handles indexed by the handle of the
+    // remote publisher to which the writers belong
+    group_coherent_writer_list: HashMap<InstanceHandle, Vec<(InstanceHandle, InstanceHandle)>>,
+    group_coherent_sets: HashMap<(InstanceHandle, SequenceNumber), Vec<GroupCoherentSetPart>>,
 }
 
 impl SubscriberActor {
@@ -168,17 +183,72 @@ impl SubscriberActor {
                 qos,
                 rtps_group,
                 data_reader_list,
+                multi_topic_source_list: HashMap::new(),
                 enabled: false,
                 user_defined_data_reader_counter: 0,
                 default_data_reader_qos: Default::default(),
                 status_condition,
                 subscriber_listener_thread,
                 status_kind,
+                access_depth: 0,
+                group_coherent_writer_list: HashMap::new(),
+                group_coherent_sets: HashMap::new(),
             },
             status_condition_address,
         )
     }
 
+    fn data_reader_and_source_list(&self) -> impl Iterator<Item = &Actor<DataReaderActor>> {
+        self.data_reader_list
+            .values()
+            .chain(self.multi_topic_source_list.values().flatten())
+    }
+
+    fn is_group_coherent_set_complete(
+        &self,
+        group: &InstanceHandle,
+        group_coherent_set: SequenceNumber,
+    ) -> bool {
+        let parts = &self.group_coherent_sets[&(*group, group_coherent_set)];
+        self.group_coherent_writer_list
+            .get(group)
+            .into_iter()
+            .flatten()
+            .all(|(reader_handle, writer_handle)| {
+                parts.iter().any(|p| {
+                    &p.reader_handle == reader_handle
+                        && &InstanceHandle::new(p.release.writer_guid.into()) == writer_handle
+                })
+            })
+    }
+
+    fn release_group_coherent_sets(&mut self) {
+        // The data is not modified while the application is accessing it
+        if self.access_depth > 0 {
+            return;
+        }
+
+        let mut complete_sets: Vec<_> = self
+            .group_coherent_sets
+            .keys()
+            .filter(|(group, group_coherent_set)| {
+                self.is_group_coherent_set_complete(group, *group_coherent_set)
+            })
+            .copied()
+            .collect();
+        complete_sets.sort_by_key(|(_, group_coherent_set)| *group_coherent_set);
+        for key in complete_sets {
+            if let Some(parts) = self.group_coherent_sets.remove(&key) {
+                let is_incomplete = parts.iter().any(|p| p.release.is_incomplete);
+                for mut part in parts {
+                    part.release.is_incomplete = is_incomplete;
+                    let data_reader_address = part.release.data_reader_address.clone();
+                    data_reader_address.send_actor_mail(part.release).ok();
+                }
+            }
+        }
+    }
+
     fn get_unique_reader_id(&mut self) -> u8 {
         let counter = self.user_defined_data_reader_counter;
         self.user_defined_data_reader_counter += 1;
@@ -247,6 +317,9 @@ pub struct CreateDatareader {
     pub mask: Vec<StatusKind>,
     pub default_unicast_locator_list: Vec<Locator>,
     pub default_multicast_locator_list: Vec<Locator>,
+    pub content_filter: Option<ContentFilterProperty>,
+    pub multi_topic: Option<MultiTopicJoin>,
+    pub multi_topic_reader: Option<InstanceHandle>,
     pub executor_handle: ExecutorHandle,
 }
 impl Mail for CreateDatareader {
@@ -294,6 +367,16 @@ impl MailHandler<CreateDatareader> for SubscriberActor {
             false,
         )));
 
+        let multi_topic_reader = match message.multi_topic_reader {
+            Some(handle) => Some(
+                self.data_reader_list
+                    .get(&handle)
+                    .ok_or(DdsError::AlreadyDeleted)?
+                    .address(),
+            ),
+            None => None,
+        };
+
         let status_kind = message.mask.to_vec();
         let data_reader = DataReaderActor::new(
             rtps_reader,
@@ -305,13 +388,25 @@ impl MailHandler<CreateDatareader> for SubscriberActor {
             qos,
             message.a_listener,
             status_kind,
+            message.content_filter,
+            message.multi_topic,
+            multi_topic_reader,
             &message.executor_handle,
         );
 
         let reader_actor = Actor::spawn(data_reader, &message.executor_handle);
         let reader_address = reader_actor.address();
-        self.data_reader_list
-            .insert(InstanceHandle::new(guid.into()), reader_actor);
+        match message.multi_topic_reader {
+            Some(handle) => self
+                .multi_topic_source_list
+                .entry(handle)
+                .or_default()
+                .push(reader_actor),
+            None => {
+                self.data_reader_list
+                    .insert(InstanceHandle::new(guid.into()), reader_actor);
+            }
+        }
 
         Ok(reader_address)
     }
@@ -326,6 +421,10 @@ impl Mail for DeleteDatareader {
 impl MailHandler<DeleteDatareader> for SubscriberActor {
     fn handle(&mut self, message: DeleteDatareader) -> <DeleteDatareader as Mail>::Result {
         if let Some(removed_reader) = self.data_reader_list.remove(&message.handle) {
+            for writer_list in self.group_coherent_writer_list.values_mut() {
+                writer_list.retain(|(reader_handle, _)| reader_handle != &message.handle);
+            }
+            self.release_group_coherent_sets();
             Ok(removed_reader)
         } else {
             Err(DdsError::PreconditionNotMet(
@@ -345,6 +444,41 @@ impl MailHandler<DrainDataReaderList> for SubscriberActor {
     }
 }
 
+pub struct GetMultiTopicSourceReaderList {
+    pub handle: InstanceHandle,
+}
+impl Mail for GetMultiTopicSourceReaderList {
+    type Result = Vec<ActorAddress<DataReaderActor>>;
+}
+impl MailHandler<GetMultiTopicSourceReaderList> for SubscriberActor {
+    fn handle(
+        &mut self,
+        message: GetMultiTopicSourceReaderList,
+    ) -> <GetMultiTopicSourceReaderList as Mail>::Result {
+        self.multi_topic_source_list
+            .get(&message.handle)
+            .map(|l| l.iter().map(|dr| dr.address()).collect())
+            .unwrap_or_default()
+    }
+}
+
+pub struct RemoveMultiTopicSourceReaderList {
+    pub handle: InstanceHandle,
+}
+impl Mail for RemoveMultiTopicSourceReaderList {
+    type Result = Vec<Actor<DataReaderActor>>;
+}
+impl MailHandler<RemoveMultiTopicSourceReaderList> for SubscriberActor {
+    fn handle(
+        &mut self,
+        message: RemoveMultiTopicSourceReaderList,
+    ) -> <RemoveMultiTopicSourceReaderList as Mail>::Result {
+        self.multi_topic_source_list
+            .remove(&message.handle)
+            .unwrap_or_default()
+    }
+}
+
 pub struct GetGuid;
 impl Mail for GetGuid {
     type Result = Guid;
@@ -515,7 +649,7 @@ impl MailHandler<ProcessDataSubmessage> for SubscriberActor {
         &mut self,
         message: ProcessDataSubmessage,
     ) -> <ProcessDataSubmessage as Mail>::Result {
-        for data_reader_actor in self.data_reader_list.values() {
+        for data_reader_actor in self.data_reader_and_source_list() {
             let subscriber_mask_listener = (
                 self.subscriber_listener_thread
                     .as_ref()
@@ -564,7 +698,7 @@ impl MailHandler<ProcessDataFragSubmessage> for SubscriberActor {
         &mut self,
         message: ProcessDataFragSubmessage,
     ) -> <ProcessDataFragSubmessage as Mail>::Result {
-        for data_reader_actor in self.data_reader_list.values() {
+        for data_reader_actor in self.data_reader_and_source_list() {
             let subscriber_mask_listener = (
                 self.subscriber_listener_thread
                     .as_ref()
@@ -600,7 +734,7 @@ impl Mail for ProcessGapSubmessage {
 }
 impl MailHandler<ProcessGapSubmessage> for SubscriberActor {
     fn handle(&mut self, message: ProcessGapSubmessage) -> <ProcessGapSubmessage as Mail>::Result {
-        for data_reader_actor in self.data_reader_list.values() {
+        for data_reader_actor in self.data_reader_and_source_list() {
             data_reader_actor.send_actor_mail(data_reader_actor::ProcessGapSubmessage {
                 gap_submessage: message.gap_submessage.clone(),
                 source_guid_prefix: message.source_guid_prefix,
@@ -613,6 +747,8 @@ pub struct ProcessHeartbeatSubmessage {
     pub heartbeat_submessage: HeartbeatSubmessage,
     pub source_guid_prefix: GuidPrefix,
     pub message_sender_actor: ActorAddress<MessageSenderActor>,
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
 }
 impl Mail for ProcessHeartbeatSubmessage {
     type Result = ();
@@ -622,11 +758,14 @@ impl MailHandler<ProcessHeartbeatSubmessage> for SubscriberActor {
         &mut self,
         message: ProcessHeartbeatSubmessage,
     ) -> <ProcessHeartbeatSubmessage as Mail>::Result {
-        for data_reader_actor in self.data_reader_list.values() {
+        for data_reader_actor in self.data_reader_and_source_list() {
             data_reader_actor.send_actor_mail(data_reader_actor::ProcessHeartbeatSubmessage {
                 heartbeat_submessage: message.heartbeat_submessage.clone(),
                 source_guid_prefix: message.source_guid_prefix,
                 message_sender_actor: message.message_sender_actor.clone(),
+                data_reader_address: data_reader_actor.address(),
+                executor_handle: message.executor_handle.clone(),
+                timer_handle: message.timer_handle.clone(),
             });
         }
     }
@@ -644,7 +783,7 @@ impl MailHandler<ProcessHeartbeatFragSubmessage> for SubscriberActor {
         &mut self,
         message: ProcessHeartbeatFragSubmessage,
     ) -> <ProcessHeartbeatFragSubmessage as Mail>::Result {
-        for data_reader_actor in self.data_reader_list.values() {
+        for data_reader_actor in self.data_reader_and_source_list() {
             data_reader_actor.send_actor_mail(data_reader_actor::ProcessHeartbeatFragSubmessage {
                 heartbeat_frag_submessage: message.heartbeat_frag_submessage.clone(),
                 source_guid_prefix: message.source_guid_prefix,
@@ -653,6 +792,34 @@ impl MailHandler<ProcessHeartbeatFragSubmessage> for SubscriberActor {
     }
 }
 
+pub struct AssertDiscoveredParticipantLiveliness {
+    pub participant_guid_prefix: GuidPrefix,
+    pub liveliness_kind: LivelinessQosPolicyKind,
+    pub executor_handle: ExecutorHandle,
+    pub timer_handle: TimerHandle,
+}
+impl Mail for AssertDiscoveredParticipantLiveliness {
+    type Result = ();
+}
+impl MailHandler<AssertDiscoveredParticipantLiveliness> for SubscriberActor {
+    fn handle(
+        &mut self,
+        message: AssertDiscoveredParticipantLiveliness,
+    ) -> <AssertDiscoveredParticipantLiveliness as Mail>::Result {
+        for data_reader_actor in self.data_reader_and_source_list() {
+            data_reader_actor.send_actor_mail(
+                data_reader_actor::AssertDiscoveredParticipantLiveliness {
+                    participant_guid_prefix: message.participant_guid_prefix,
+                    liveliness_kind: message.liveliness_kind,
+                    data_reader_address: data_reader_actor.address(),
+                    executor_handle: message.executor_handle.clone(),
+                    timer_handle: message.timer_handle.clone(),
+                },
+            );
+        }
+    }
+}
+
 pub struct AddMatchedWriter {
     pub discovered_writer_data: DiscoveredWriterData,
     pub default_unicast_locator_list: Vec<Locator>,
@@ -675,7 +842,7 @@ impl MailHandler<AddMatchedWriter> for SubscriberActor {
                 .dds_publication_data
                 .partition(),
         ) {
-            for data_reader in self.data_reader_list.values() {
+            for data_reader in self.data_reader_and_source_list() {
                 let subscriber_mask_listener = (
                     self.subscriber_listener_thread
                         .as_ref()
@@ -699,6 +866,35 @@ impl MailHandler<AddMatchedWriter> for SubscriberActor {
                     participant_mask_listener: message.participant_mask_listener.clone(),
                 });
             }
+        } else {
+            // A writer which was matched before a change of partition is no longer matched
+            let discovered_writer_handle = InstanceHandle::new(
+                message
+                    .discovered_writer_data
+                    .dds_publication_data
+                    .key()
+                    .value,
+            );
+            for data_reader in self.data_reader_and_source_list() {
+                let data_reader_address = data_reader.address();
+                let subscriber_mask_listener = (
+                    self.subscriber_listener_thread
+                        .as_ref()
+                        .map(|l| l.sender().clone()),
+                    self.status_kind.clone(),
+                );
+                data_reader.send_actor_mail(data_reader_actor::RemoveMatchedWriter {
+                    discovered_writer_handle,
+                    data_reader_address,
+                    subscriber: SubscriberAsync::new(
+                        message.subscriber_address.clone(),
+                        self.status_condition.address(),
+                        message.participant.clone(),
+                    ),
+                    subscriber_mask_listener,
+                    participant_mask_listener: message.participant_mask_listener.clone(),
+                });
+            }
         }
         Ok(())
     }
@@ -718,7 +914,7 @@ impl Mail for RemoveMatchedWriter {
 }
 impl MailHandler<RemoveMatchedWriter> for SubscriberActor {
     fn handle(&mut self, message: RemoveMatchedWriter) -> <RemoveMatchedWriter as Mail>::Result {
-        for data_reader in self.data_reader_list.values() {
+        for data_reader in self.data_reader_and_source_list() {
             let data_reader_address = data_reader.address();
             let subscriber_mask_listener = (
                 self.subscriber_listener_thread
@@ -743,6 +939,100 @@ impl MailHandler<RemoveMatchedWriter> for SubscriberActor {
     }
 }
 
+pub struct AddGroupCoherentWriter {
+    pub group: InstanceHandle,
+    pub reader_handle: InstanceHandle,
+    pub writer_handle: InstanceHandle,
+}
+impl Mail for AddGroupCoherentWriter {
+    type Result = ();
+}
+impl MailHandler<AddGroupCoherentWriter> for SubscriberActor {
+    fn handle(
+        &mut self,
+        message: AddGroupCoherentWriter,
+    ) -> <AddGroupCoherentWriter as Mail>::Result {
+        let writer_list = self
+            .group_coherent_writer_list
+            .entry(message.group)
+            .or_default();
+        if !writer_list.contains(&(message.reader_handle, message.writer_handle)) {
+            writer_list.push((message.reader_handle, message.writer_handle));
+        }
+    }
+}
+
+pub struct RemoveGroupCoherentWriter {
+    pub reader_handle: InstanceHandle,
+    pub writer_handle: InstanceHandle,
+}
+impl Mail for RemoveGroupCoherentWriter {
+    type Result = ();
+}
+impl MailHandler<RemoveGroupCoherentWriter> for SubscriberActor {
+    fn handle(
+        &mut self,
+        message: RemoveGroupCoherentWriter,
+    ) -> <RemoveGroupCoherentWriter as Mail>::Result {
+        for writer_list in self.group_coherent_writer_list.values_mut() {
+            writer_list.retain(|&x| x != (message.reader_handle, message.writer_handle));
+        }
+        self.release_group_coherent_sets();
+    }
+}
+
+pub struct AddGroupCoherentSetPart {
+    pub group: InstanceHandle,
+    pub reader_handle: InstanceHandle,
+    pub release: data_reader_actor::ReleaseGroupCoherentSet,
+}
+impl Mail for AddGroupCoherentSetPart {
+    type Result = ();
+}
+impl MailHandler<AddGroupCoherentSetPart> for SubscriberActor {
+    fn handle(
+        &mut self,
+        message: AddGroupCoherentSetPart,
+    ) -> <AddGroupCoherentSetPart as Mail>::Result {
+        self.group_coherent_sets
+            .entry((message.group, message.release.group_coherent_set))
+            .or_default()
+            .push(GroupCoherentSetPart {
+                reader_handle: message.reader_handle,
+                release: message.release,
+            });
+        self.release_group_coherent_sets();
+    }
+}
+
+pub struct BeginAccess;
+impl Mail for BeginAccess {
+    type Result = ();
+}
+impl MailHandler<BeginAccess> for SubscriberActor {
+    fn handle(&mut self, _: BeginAccess) -> <BeginAccess as Mail>::Result {
+        self.access_depth += 1;
+    }
+}
+
+pub struct EndAccess;
+impl Mail for EndAccess {
+    type Result = DdsResult<()>;
+}
+impl MailHandler<EndAccess> for SubscriberActor {
+    fn handle(&mut self, _: EndAccess) -> <EndAccess as Mail>::Result {
+        if self.access_depth == 0 {
+            return Err(DdsError::PreconditionNotMet(
+                "No matching begin_access".to_string(),
+            ));
+        }
+
+        self.access_depth -= 1;
+        self.release_group_coherent_sets();
+        Ok(())
+    }
+}
+
 pub struct SetListener {
     pub listener: Option<Box<dyn SubscriberListenerAsync + Send>>,
     pub status_kind: Vec<StatusKind>,
@@ -0,0 +1,921 @@
+use crate::{
+    implementation::{
+        data_representation_builtin_endpoints::discovered_reader_data::{
+            self, DDSSQL_FILTER_CLASS_NAME,
+        },
+        xtypes_glue::member_values::{
+            get_member_values_from_serialized_foo, MemberValue, MemberValues,
+        },
+    },
+    infrastructure::error::{DdsError, DdsResult},
+    xtypes::dynamic_type::DynamicType,
+};
+use std::cmp::Ordering;
+
+// Implementation of the subset of the SQL grammar used by the DDS specification
+// (Annex B - Syntax for Queries and Filters) for the filter expression of the
+// ContentFilteredTopic, the query expression of the QueryCondition and the
+// subscription expression of the MultiTopic:
+//
+// TopicExpression ::= SelectFrom [ Where ] [ ";" ]
+// SelectFrom ::= "SELECT" Aggregation "FROM" Selection
+// Aggregation ::= "*" | SubjectFieldSpec { "," SubjectFieldSpec }
+// SubjectFieldSpec ::= FIELDNAME | FIELDNAME "AS" IDENTIFIER | FIELDNAME IDENTIFIER
+// Selection ::= TOPICNAME | TOPICNAME NaturalJoin JoinItem
+// JoinItem ::= TOPICNAME | TOPICNAME NaturalJoin JoinItem | "(" TOPICNAME NaturalJoin JoinItem ")"
+// NaturalJoin ::= "INNER JOIN" | "INNER NATURAL JOIN" | "NATURAL JOIN" | "NATURAL INNER JOIN"
+// Where ::= "WHERE" Condition
+// Condition ::= Predicate
+//             | Condition "AND" Condition
+//             | Condition "OR" Condition
+//             | "NOT" Condition
+//             | "(" Condition ")"
+// Predicate ::= ComparisonPredicate | BetweenPredicate
+// ComparisonPredicate ::= Operand RelOp Operand
+// BetweenPredicate ::= FIELDNAME "BETWEEN" Operand "AND" Operand
+//                    | FIELDNAME "NOT BETWEEN" Operand "AND" Operand
+// RelOp ::= "=" | ">" | ">=" | "<" | "<=" | "<>" | "!=" | "LIKE"
+// Operand ::= FIELDNAME | INTEGERVALUE | FLOATVALUE | STRING | BOOLEANVALUE | PARAMETER
+// PARAMETER ::= "%" [0-99]
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Identifier(String),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Parameter(usize),
+    RelOp(RelOp),
+    LeftParenthesis,
+    RightParenthesis,
+    And,
+    Or,
+    Not,
+    Between,
+    True,
+    False,
+    Select,
+    From,
+    Where,
+    As,
+    Natural,
+    Inner,
+    Join,
+    Asterisk,
+    Comma,
+    Semicolon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RelOp {
+    Equal,
+    NotEqual,
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Like,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Field(String),
+    Literal(MemberValue),
+    Parameter(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+    Not(Box<Condition>),
+    Comparison {
+        left: Operand,
+        op: RelOp,
+        right: Operand,
+    },
+    Between {
+        field: String,
+        low: Operand,
+        high: Operand,
+    },
+}
+
+fn tokenize(expression: &str) -> DdsResult<Vec<Token>> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LeftParenthesis);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RightParenthesis);
+                i += 1;
+            }
+            '*' => {
+                tokens.push(Token::Asterisk);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            ';' => {
+                tokens.push(Token::Semicolon);
+                i += 1;
+            }
+            '=' => {
+                tokens.push(Token::RelOp(RelOp::Equal));
+                i += 1;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::RelOp(RelOp::NotEqual));
+                i += 2;
+            }
+            '<' => match chars.get(i + 1) {
+                Some('=') => {
+                    tokens.push(Token::RelOp(RelOp::LessOrEqual));
+                    i += 2;
+                }
+                Some('>') => {
+                    tokens.push(Token::RelOp(RelOp::NotEqual));
+                    i += 2;
+                }
+                _ => {
+                    tokens.push(Token::RelOp(RelOp::Less));
+                    i += 1;
+                }
+            },
+            '>' => match chars.get(i + 1) {
+                Some('=') => {
+                    tokens.push(Token::RelOp(RelOp::GreaterOrEqual));
+                    i += 2;
+                }
+                _ => {
+                    tokens.push(Token::RelOp(RelOp::Greater));
+                    i += 1;
+                }
+            },
+            '\'' | '`' | '"' => {
+                // Strings are delimited by single quotes. The DDS specification also allows
+                // the opening quote to be a backtick.
+                let closing_quote = if c == '"' { '"' } else { '\'' };
+                let start = i + 1;
+                let end = chars[start..]
+                    .iter()
+                    .position(|&x| x == closing_quote)
+                    .ok_or(DdsError::BadParameter)?
+                    + start;
+                tokens.push(Token::String(chars[start..end].iter().collect()));
+                i = end + 1;
+            }
+            '%' => {
+                let start = i + 1;
+                let end = chars[start..]
+                    .iter()
+                    .position(|x| !x.is_ascii_digit())
+                    .map_or(chars.len(), |p| p + start);
+                let index = chars[start..end]
+                    .iter()
+                    .collect::<String>()
+                    .parse::<usize>()
+                    .map_err(|_| DdsError::BadParameter)?;
+                if index > 99 {
+                    return Err(DdsError::BadParameter);
+                }
+                tokens.push(Token::Parameter(index));
+                i = end;
+            }
+            c if c.is_ascii_digit()
+                || ((c == '-' || c == '+' || c == '.')
+                    && chars.get(i + 1).is_some_and(|x| x.is_ascii_digit())) =>
+            {
+                let start = i;
+                i += 1;
+                while i < chars.len()
+                    && (chars[i].is_ascii_alphanumeric()
+                        || chars[i] == '.'
+                        || ((chars[i] == '-' || chars[i] == '+')
+                            && matches!(chars[i - 1], 'e' | 'E')))
+                {
+                    i += 1;
+                }
+                let number: String = chars[start..i].iter().collect();
+                tokens.push(parse_number(&number)?);
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+                {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let token = match word.to_ascii_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    "BETWEEN" => Token::Between,
+                    "LIKE" => Token::RelOp(RelOp::Like),
+                    "TRUE" => Token::True,
+                    "FALSE" => Token::False,
+                    "SELECT" => Token::Select,
+                    "FROM" => Token::From,
+                    "WHERE" => Token::Where,
+                    "AS" => Token::As,
+                    "NATURAL" => Token::Natural,
+                    "INNER" => Token::Inner,
+                    "JOIN" => Token::Join,
+                    _ => Token::Identifier(word),
+                };
+                tokens.push(token);
+            }
+            _ => return Err(DdsError::BadParameter),
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_number(number: &str) -> DdsResult<Token> {
+    let (negative, digits) = match number.strip_prefix('-') {
+        Some(d) => (true, d),
+        None => (false, number.strip_prefix('+').unwrap_or(number)),
+    };
+    if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        let value = i64::from_str_radix(hex, 16).map_err(|_| DdsError::BadParameter)?;
+        return Ok(Token::Integer(if negative { -value } else { value }));
+    }
+    if let Ok(value) = number.parse::<i64>() {
+        Ok(Token::Integer(value))
+    } else {
+        number
+            .parse::<f64>()
+            .map(Token::Float)
+            .map_err(|_| DdsError::BadParameter)
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn expect(&mut self, token: Token) -> DdsResult<()> {
+        if self.next() == Some(token) {
+            Ok(())
+        } else {
+            Err(DdsError::BadParameter)
+        }
+    }
+
+    fn parse_or(&mut self) -> DdsResult<Condition> {
+        let mut condition = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            condition = Condition::Or(Box::new(condition), Box::new(self.parse_and()?));
+        }
+        Ok(condition)
+    }
+
+    fn parse_and(&mut self) -> DdsResult<Condition> {
+        let mut condition = self.parse_not()?;
+        while self.peek() == Some(&Token::And) {
+            self.next();
+            condition = Condition::And(Box::new(condition), Box::new(self.parse_not()?));
+        }
+        Ok(condition)
+    }
+
+    fn parse_not(&mut self) -> DdsResult<Condition> {
+        if self.peek() == Some(&Token::Not) {
+            self.next();
+            Ok(Condition::Not(Box::new(self.parse_not()?)))
+        } else {
+            self.parse_primary()
+        }
+    }
+
+    fn parse_primary(&mut self) -> DdsResult<Condition> {
+        if self.peek() == Some(&Token::LeftParenthesis) {
+            self.next();
+            let condition = self.parse_or()?;
+            self.expect(Token::RightParenthesis)?;
+            return Ok(condition);
+        }
+
+        let left = self.parse_operand()?;
+        match self.next() {
+            Some(Token::RelOp(op)) => {
+                let right = self.parse_operand()?;
+                Ok(Condition::Comparison { left, op, right })
+            }
+            Some(Token::Between) => self.parse_between(left),
+            Some(Token::Not) => {
+                self.expect(Token::Between)?;
+                Ok(Condition::Not(Box::new(self.parse_between(left)?)))
+            }
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+
+    fn parse_between(&mut self, left: Operand) -> DdsResult<Condition> {
+        let Operand::Field(field) = left else {
+            return Err(DdsError::BadParameter);
+        };
+        let low = self.parse_operand()?;
+        self.expect(Token::And)?;
+        let high = self.parse_operand()?;
+        Ok(Condition::Between { field, low, high })
+    }
+
+    fn parse_operand(&mut self) -> DdsResult<Operand> {
+        match self.next() {
+            Some(Token::Identifier(name)) => Ok(Operand::Field(name)),
+            Some(Token::Integer(v)) => Ok(Operand::Literal(MemberValue::Int(v))),
+            Some(Token::Float(v)) => Ok(Operand::Literal(MemberValue::Float(v))),
+            Some(Token::String(v)) => Ok(Operand::Literal(MemberValue::String(v))),
+            Some(Token::True) => Ok(Operand::Literal(MemberValue::Boolean(true))),
+            Some(Token::False) => Ok(Operand::Literal(MemberValue::Boolean(false))),
+            Some(Token::Parameter(i)) => Ok(Operand::Parameter(i)),
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+
+    fn parse_identifier(&mut self) -> DdsResult<String> {
+        match self.next() {
+            Some(Token::Identifier(name)) => Ok(name),
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+
+    fn parse_topic_expression(&mut self) -> DdsResult<SubscriptionExpression> {
+        self.expect(Token::Select)?;
+        let selected_fields = if self.peek() == Some(&Token::Asterisk) {
+            self.next();
+            None
+        } else {
+            let mut selected_fields = vec![self.parse_subject_field_spec()?];
+            while self.peek() == Some(&Token::Comma) {
+                self.next();
+                selected_fields.push(self.parse_subject_field_spec()?);
+            }
+            Some(selected_fields)
+        };
+
+        self.expect(Token::From)?;
+        let mut topic_names = Vec::new();
+        self.parse_join_item(&mut topic_names)?;
+
+        let condition = if self.peek() == Some(&Token::Where) {
+            self.next();
+            Some(FilterExpression {
+                condition: self.parse_or()?,
+            })
+        } else {
+            None
+        };
+        if self.peek() == Some(&Token::Semicolon) {
+            self.next();
+        }
+
+        Ok(SubscriptionExpression {
+            selected_fields,
+            topic_names,
+            condition,
+        })
+    }
+
+    fn parse_subject_field_spec(&mut self) -> DdsResult<SelectedField> {
+        let field = self.parse_identifier()?;
+        let name = match self.peek() {
+            Some(Token::As) => {
+                self.next();
+                self.parse_identifier()?
+            }
+            Some(Token::Identifier(_)) => self.parse_identifier()?,
+            _ => field.clone(),
+        };
+        Ok(SelectedField { field, name })
+    }
+
+    fn parse_join_item(&mut self, topic_names: &mut Vec<String>) -> DdsResult<()> {
+        if self.peek() == Some(&Token::LeftParenthesis) {
+            self.next();
+            self.parse_join_item(topic_names)?;
+            self.expect(Token::RightParenthesis)?;
+        } else {
+            topic_names.push(self.parse_identifier()?);
+        }
+        if self.parse_natural_join()? {
+            self.parse_join_item(topic_names)?;
+        }
+        Ok(())
+    }
+
+    fn parse_natural_join(&mut self) -> DdsResult<bool> {
+        match self.peek() {
+            Some(Token::Inner) => {
+                self.next();
+                if self.peek() == Some(&Token::Natural) {
+                    self.next();
+                }
+            }
+            Some(Token::Natural) => {
+                self.next();
+                if self.peek() == Some(&Token::Inner) {
+                    self.next();
+                }
+            }
+            _ => return Ok(false),
+        }
+        self.expect(Token::Join)?;
+        Ok(true)
+    }
+}
+
+/// Parsed representation of a filter or query expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterExpression {
+    condition: Condition,
+}
+
+impl FilterExpression {
+    pub fn parse(expression: &str) -> DdsResult<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(expression)?,
+            position: 0,
+        };
+        let condition = parser.parse_or()?;
+        if parser.peek().is_some() {
+            return Err(DdsError::BadParameter);
+        }
+        Ok(Self { condition })
+    }
+
+    /// Checks that all the fields referenced by the expression are part of
+    /// `member_paths` and that there is a value for all the referenced parameters.
+    pub fn validate(&self, member_paths: &[String], parameters: &[String]) -> DdsResult<()> {
+        fn validate_operand(
+            operand: &Operand,
+            member_paths: &[String],
+            parameters: &[String],
+        ) -> DdsResult<()> {
+            match operand {
+                Operand::Field(name) if !member_paths.contains(name) => Err(DdsError::BadParameter),
+                Operand::Parameter(i) if *i >= parameters.len() => Err(DdsError::BadParameter),
+                _ => Ok(()),
+            }
+        }
+
+        fn validate_condition(
+            condition: &Condition,
+            member_paths: &[String],
+            parameters: &[String],
+        ) -> DdsResult<()> {
+            match condition {
+                Condition::And(a, b) | Condition::Or(a, b) => {
+                    validate_condition(a, member_paths, parameters)?;
+                    validate_condition(b, member_paths, parameters)
+                }
+                Condition::Not(c) => validate_condition(c, member_paths, parameters),
+                Condition::Comparison { left, right, .. } => {
+                    validate_operand(left, member_paths, parameters)?;
+                    validate_operand(right, member_paths, parameters)
+                }
+                Condition::Between { field, low, high } => {
+                    validate_operand(&Operand::Field(field.clone()), member_paths, parameters)?;
+                    validate_operand(low, member_paths, parameters)?;
+                    validate_operand(high, member_paths, parameters)
+                }
+            }
+        }
+
+        validate_condition(&self.condition, member_paths, parameters)
+    }
+
+    /// Evaluates the expression for a sample given by its member values. Comparisons
+    /// with members that are not available or with incompatible types evaluate to false.
+    pub fn evaluate(&self, member_values: &MemberValues, parameters: &[String]) -> bool {
+        evaluate_condition(&self.condition, member_values, parameters)
+    }
+}
+
+/// Field of the related topics selected by a subscription expression together with
+/// the name of the member of the resulting type to which it is assigned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectedField {
+    pub field: String,
+    pub name: String,
+}
+
+/// Parsed representation of the subscription expression of a MultiTopic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubscriptionExpression {
+    /// Selected fields or `None` if all the fields are selected ("*").
+    pub selected_fields: Option<Vec<SelectedField>>,
+    pub topic_names: Vec<String>,
+    pub condition: Option<FilterExpression>,
+}
+
+impl SubscriptionExpression {
+    pub fn parse(expression: &str) -> DdsResult<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(expression)?,
+            position: 0,
+        };
+        let subscription_expression = parser.parse_topic_expression()?;
+        if parser.peek().is_some() {
+            return Err(DdsError::BadParameter);
+        }
+        Ok(subscription_expression)
+    }
+
+    /// Path of the member of the joined related topics from which the value of the
+    /// member of the resulting type with the given path is taken.
+    pub fn source_member_path(&self, member_path: &str) -> Option<String> {
+        match &self.selected_fields {
+            None => Some(member_path.to_owned()),
+            Some(selected_fields) => selected_fields.iter().find_map(|f| {
+                if member_path == f.name {
+                    Some(f.field.clone())
+                } else {
+                    member_path
+                        .strip_prefix(f.name.as_str())
+                        .and_then(|nested_path| nested_path.strip_prefix('.'))
+                        .map(|nested_path| format!("{}.{}", f.field, nested_path))
+                }
+            }),
+        }
+    }
+}
+
+/// Filter expression together with the current values of its parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentFilter {
+    pub expression: FilterExpression,
+    pub parameters: Vec<String>,
+}
+
+impl ContentFilter {
+    /// Evaluates the filter on a serialized sample. Samples which can not be
+    /// deserialized using the type information are not accepted.
+    pub fn accepts(&self, serialized_data: &[u8], type_support: &dyn DynamicType) -> bool {
+        match get_member_values_from_serialized_foo(serialized_data, type_support) {
+            Ok(member_values) => self.expression.evaluate(&member_values, &self.parameters),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Description of the filter of a ContentFilteredTopic as used by the
+/// DataReaders created from it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentFilterProperty {
+    pub content_filtered_topic_name: String,
+    pub related_topic_name: String,
+    pub filter_expression: String,
+    pub filter: ContentFilter,
+}
+
+impl ContentFilterProperty {
+    /// Representation of the filter announced to the remote DataWriters
+    /// through the discovery of the DataReader.
+    pub fn as_discovered_content_filter_property(
+        &self,
+    ) -> discovered_reader_data::ContentFilterProperty {
+        discovered_reader_data::ContentFilterProperty {
+            content_filtered_topic_name: self.content_filtered_topic_name.clone(),
+            related_topic_name: self.related_topic_name.clone(),
+            filter_class_name: DDSSQL_FILTER_CLASS_NAME.to_string(),
+            filter_expression: self.filter_expression.clone(),
+            expression_parameters: self.filter.parameters.clone(),
+        }
+    }
+}
+
+fn evaluate_condition(
+    condition: &Condition,
+    member_values: &MemberValues,
+    parameters: &[String],
+) -> bool {
+    match condition {
+        Condition::And(a, b) => {
+            evaluate_condition(a, member_values, parameters)
+                && evaluate_condition(b, member_values, parameters)
+        }
+        Condition::Or(a, b) => {
+            evaluate_condition(a, member_values, parameters)
+                || evaluate_condition(b, member_values, parameters)
+        }
+        Condition::Not(c) => !evaluate_condition(c, member_values, parameters),
+        Condition::Comparison { left, op, right } => {
+            match (
+                resolve_operand(left, member_values, parameters),
+                resolve_operand(right, member_values, parameters),
+            ) {
+                (Some(l), Some(r)) => compare(&l, *op, &r),
+                _ => false,
+            }
+        }
+        Condition::Between { field, low, high } => {
+            match (
+                member_values.get(field),
+                resolve_operand(low, member_values, parameters),
+                resolve_operand(high, member_values, parameters),
+            ) {
+                (Some(v), Some(l), Some(h)) => {
+                    compare(v, RelOp::GreaterOrEqual, &l) && compare(v, RelOp::LessOrEqual, &h)
+                }
+                _ => false,
+            }
+        }
+    }
+}
+
+fn resolve_operand(
+    operand: &Operand,
+    member_values: &MemberValues,
+    parameters: &[String],
+) -> Option<MemberValue> {
+    match operand {
+        Operand::Field(name) => member_values.get(name).cloned(),
+        Operand::Literal(value) => Some(value.clone()),
+        Operand::Parameter(i) => parameters.get(*i).map(|p| parse_parameter(p)),
+    }
+}
+
+/// Parameters are given as strings which are interpreted as a literal if possible.
+/// Otherwise the parameter is used as a (possibly quoted) string value.
+fn parse_parameter(parameter: &str) -> MemberValue {
+    match tokenize(parameter).as_deref() {
+        Ok([Token::Integer(v)]) => MemberValue::Int(*v),
+        Ok([Token::Float(v)]) => MemberValue::Float(*v),
+        Ok([Token::String(v)]) => MemberValue::String(v.clone()),
+        Ok([Token::True]) => MemberValue::Boolean(true),
+        Ok([Token::False]) => MemberValue::Boolean(false),
+        _ => MemberValue::String(parameter.to_string()),
+    }
+}
+
+pub fn compare_values(left: &MemberValue, right: &MemberValue) -> Option<Ordering> {
+    match (left, right) {
+        (MemberValue::Boolean(l), MemberValue::Boolean(r)) => Some(l.cmp(r)),
+        (MemberValue::Int(l), MemberValue::Int(r)) => Some(l.cmp(r)),
+        (MemberValue::UInt(l), MemberValue::UInt(r)) => Some(l.cmp(r)),
+        (MemberValue::Int(l), MemberValue::UInt(r)) => Some((*l as i128).cmp(&(*r as i128))),
+        (MemberValue::UInt(l), MemberValue::Int(r)) => Some((*l as i128).cmp(&(*r as i128))),
+        (MemberValue::Float(l), MemberValue::Float(r)) => l.partial_cmp(r),
+        (MemberValue::Float(l), MemberValue::Int(r)) => l.partial_cmp(&(*r as f64)),
+        (MemberValue::Float(l), MemberValue::UInt(r)) => l.partial_cmp(&(*r as f64)),
+        (MemberValue::Int(l), MemberValue::Float(r)) => (*l as f64).partial_cmp(r),
+        (MemberValue::UInt(l), MemberValue::Float(r)) => (*l as f64).partial_cmp(r),
+        (MemberValue::String(l), MemberValue::String(r)) => Some(l.cmp(r)),
+        (MemberValue::Char(l), MemberValue::Char(r)) => Some(l.cmp(r)),
+        (MemberValue::Char(l), MemberValue::String(r)) => Some(l.to_string().cmp(r)),
+        (MemberValue::String(l), MemberValue::Char(r)) => Some(l.cmp(&r.to_string())),
+        _ => None,
+    }
+}
+
+fn compare(left: &MemberValue, op: RelOp, right: &MemberValue) -> bool {
+    if op == RelOp::Like {
+        return match (left, right) {
+            (MemberValue::String(value), MemberValue::String(pattern)) => is_like(value, pattern),
+            _ => false,
+        };
+    }
+    match compare_values(left, right) {
+        Some(ordering) => match op {
+            RelOp::Equal => ordering == Ordering::Equal,
+            RelOp::NotEqual => ordering != Ordering::Equal,
+            RelOp::Greater => ordering == Ordering::Greater,
+            RelOp::GreaterOrEqual => ordering != Ordering::Less,
+            RelOp::Less => ordering == Ordering::Less,
+            RelOp::LessOrEqual => ordering != Ordering::Greater,
+            RelOp::Like => unreachable!("Like operator handled before"),
+        },
+        None => false,
+    }
+}
+
+/// Matches a value with an SQL LIKE pattern where '%' matches any sequence of
+/// characters and '_' matches exactly one character.
+///
+/// On a mismatch only the last '%' seen is retried with one more character, which
+/// keeps the matching linear in the value length times the pattern length whatever
+/// the number of '%' in the pattern.
+fn is_like(value: &str, pattern: &str) -> bool {
+    let value: Vec<char> = value.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    let (mut value_index, mut pattern_index) = (0, 0);
+    // Pattern position after the last '%' and the value position it currently matches up to
+    let mut last_wildcard: Option<(usize, usize)> = None;
+    while value_index < value.len() {
+        match pattern.get(pattern_index) {
+            Some('%') => {
+                pattern_index += 1;
+                last_wildcard = Some((pattern_index, value_index));
+            }
+            Some(&c) if c == '_' || c == value[value_index] => {
+                pattern_index += 1;
+                value_index += 1;
+            }
+            _ => match last_wildcard {
+                Some((wildcard_pattern_index, wildcard_value_index)) => {
+                    pattern_index = wildcard_pattern_index;
+                    value_index = wildcard_value_index + 1;
+                    last_wildcard = Some((wildcard_pattern_index, value_index));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[pattern_index..].iter().all(|&c| c == '%')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> MemberValues {
+        MemberValues::from([
+            ("id".to_string(), MemberValue::UInt(3)),
+            ("temperature".to_string(), MemberValue::Float(41.5)),
+            (
+                "name".to_string(),
+                MemberValue::String("sensor_a".to_string()),
+            ),
+            ("position.x".to_string(), MemberValue::Int(-2)),
+            ("active".to_string(), MemberValue::Boolean(true)),
+        ])
+    }
+
+    fn evaluate(expression: &str, parameters: &[&str]) -> bool {
+        let parameters: Vec<String> = parameters.iter().map(|p| p.to_string()).collect();
+        FilterExpression::parse(expression)
+            .unwrap()
+            .evaluate(&sample(), &parameters)
+    }
+
+    #[test]
+    fn comparison_operators() {
+        assert!(evaluate("temperature > 40", &[]));
+        assert!(!evaluate("temperature < 40", &[]));
+        assert!(evaluate("id = 3", &[]));
+        assert!(evaluate("id <> 4", &[]));
+        assert!(evaluate("id != 4", &[]));
+        assert!(evaluate("id >= 3 AND id <= 3", &[]));
+        assert!(evaluate("position.x < 0", &[]));
+        assert!(evaluate("name = 'sensor_a'", &[]));
+        assert!(evaluate("active = TRUE", &[]));
+        assert!(evaluate("3 = id", &[]));
+    }
+
+    #[test]
+    fn logical_operators_and_precedence() {
+        assert!(evaluate("id = 1 OR id = 3 AND temperature > 40", &[]));
+        assert!(!evaluate("(id = 1 OR id = 3) AND temperature > 50", &[]));
+        assert!(evaluate("NOT id = 1", &[]));
+        assert!(!evaluate("NOT (id = 3 OR id = 1)", &[]));
+        assert!(evaluate("id = 3 and not temperature < 0", &[]));
+    }
+
+    #[test]
+    fn between_and_like() {
+        assert!(evaluate("temperature BETWEEN 40 AND 42", &[]));
+        assert!(!evaluate("temperature NOT BETWEEN 40 AND 42", &[]));
+        assert!(evaluate("temperature BETWEEN 40 AND 42 AND id = 3", &[]));
+        assert!(evaluate("name LIKE 'sensor%'", &[]));
+        assert!(evaluate("name LIKE 'sensor__'", &[]));
+        assert!(!evaluate("name LIKE 'sensor_'", &[]));
+        assert!(evaluate("name LIKE '%or_%'", &[]));
+        assert!(evaluate("name LIKE '%%a'", &[]));
+        assert!(!evaluate("name LIKE '%b%'", &[]));
+    }
+
+    #[test]
+    fn like_with_many_wildcards_does_not_backtrack_exponentially() {
+        let value = "a".repeat(2000);
+        assert!(!is_like(&value, "%a%a%a%a%a%a%a%a%a%a%a%a%b"));
+        assert!(is_like(&value, "%a%a%a%a%a%a%a%a%a%a%a%a%"));
+        assert!(is_like("", "%%"));
+        assert!(!is_like("", "_"));
+    }
+
+    #[test]
+    fn parameters() {
+        assert!(evaluate("temperature > %0", &["40"]));
+        assert!(!evaluate("temperature > %0", &["41.6"]));
+        assert!(evaluate("name = %1 AND id = %0", &["3", "'sensor_a'"]));
+        assert!(evaluate("name LIKE %0", &["sensor%"]));
+        assert!(evaluate("id BETWEEN %0 AND %1", &["0x01", "5"]));
+    }
+
+    #[test]
+    fn unknown_fields_do_not_match() {
+        assert!(!evaluate("pressure > 1", &[]));
+        assert!(evaluate("NOT pressure > 1", &[]));
+        assert!(!evaluate("name > 1", &[]));
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        assert!(FilterExpression::parse("").is_err());
+        assert!(FilterExpression::parse("id =").is_err());
+        assert!(FilterExpression::parse("id = 3 AND").is_err());
+        assert!(FilterExpression::parse("(id = 3").is_err());
+        assert!(FilterExpression::parse("name = 'abc").is_err());
+        assert!(FilterExpression::parse("3 BETWEEN 1 AND 5").is_err());
+        assert!(FilterExpression::parse("id = 3 id = 4").is_err());
+        assert!(FilterExpression::parse("id = %100").is_err());
+    }
+
+    #[test]
+    fn subscription_expression() {
+        let expression = SubscriptionExpression::parse(
+            "SELECT id, temperature AS t, position p FROM Sensor NATURAL JOIN (Position INNER JOIN Name) WHERE t > %0;",
+        )
+        .unwrap();
+        assert_eq!(
+            expression.selected_fields,
+            Some(vec![
+                SelectedField {
+                    field: "id".to_string(),
+                    name: "id".to_string()
+                },
+                SelectedField {
+                    field: "temperature".to_string(),
+                    name: "t".to_string()
+                },
+                SelectedField {
+                    field: "position".to_string(),
+                    name: "p".to_string()
+                },
+            ])
+        );
+        assert_eq!(expression.topic_names, vec!["Sensor", "Position", "Name"]);
+        assert!(expression.condition.is_some());
+        assert_eq!(
+            expression.source_member_path("t").as_deref(),
+            Some("temperature")
+        );
+        assert_eq!(
+            expression.source_member_path("p.x").as_deref(),
+            Some("position.x")
+        );
+        assert_eq!(expression.source_member_path("name"), None);
+
+        let expression = SubscriptionExpression::parse("select * from Sensor").unwrap();
+        assert_eq!(expression.selected_fields, None);
+        assert_eq!(expression.topic_names, vec!["Sensor"]);
+        assert_eq!(expression.condition, None);
+        assert_eq!(
+            expression.source_member_path("name").as_deref(),
+            Some("name")
+        );
+
+        assert!(SubscriptionExpression::parse("SELECT FROM Sensor").is_err());
+        assert!(SubscriptionExpression::parse("SELECT * FROM Sensor JOIN Name").is_err());
+        assert!(SubscriptionExpression::parse("SELECT * FROM Sensor NATURAL JOIN").is_err());
+        assert!(SubscriptionExpression::parse("SELECT * FROM Sensor WHERE").is_err());
+        assert!(SubscriptionExpression::parse("id > 1").is_err());
+    }
+
+    #[test]
+    fn validate_fields_and_parameters() {
+        let member_paths = vec!["id".to_string(), "position.x".to_string()];
+        let expression = FilterExpression::parse("id > %1 OR position.x = %0").unwrap();
+        assert!(expression
+            .validate(&member_paths, &["1".to_string(), "2".to_string()])
+            .is_ok());
+        assert_eq!(
+            expression.validate(&member_paths, &["1".to_string()]),
+            Err(DdsError::BadParameter)
+        );
+        assert_eq!(
+            FilterExpression::parse("temperature > 1")
+                .unwrap()
+                .validate(&member_paths, &[]),
+            Err(DdsError::BadParameter)
+        );
+    }
+}
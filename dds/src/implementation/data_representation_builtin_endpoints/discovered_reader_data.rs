@@ -1,11 +1,11 @@
 use super::{
     parameter_id_values::{
-        DEFAULT_EXPECTS_INLINE_QOS, PID_DATA_REPRESENTATION, PID_DEADLINE, PID_DESTINATION_ORDER,
-        PID_DURABILITY, PID_ENDPOINT_GUID, PID_EXPECTS_INLINE_QOS, PID_GROUP_DATA,
-        PID_GROUP_ENTITYID, PID_LATENCY_BUDGET, PID_LIVELINESS, PID_MULTICAST_LOCATOR,
-        PID_OWNERSHIP, PID_PARTICIPANT_GUID, PID_PARTITION, PID_PRESENTATION, PID_RELIABILITY,
-        PID_TIME_BASED_FILTER, PID_TOPIC_DATA, PID_TOPIC_NAME, PID_TYPE_NAME,
-        PID_TYPE_REPRESENTATION, PID_UNICAST_LOCATOR, PID_USER_DATA,
+        DEFAULT_EXPECTS_INLINE_QOS, PID_CONTENT_FILTER_PROPERTY, PID_DATA_REPRESENTATION,
+        PID_DEADLINE, PID_DESTINATION_ORDER, PID_DURABILITY, PID_ENDPOINT_GUID,
+        PID_EXPECTS_INLINE_QOS, PID_GROUP_DATA, PID_GROUP_ENTITYID, PID_LATENCY_BUDGET,
+        PID_LIVELINESS, PID_MULTICAST_LOCATOR, PID_OWNERSHIP, PID_PARTICIPANT_GUID, PID_PARTITION,
+        PID_PRESENTATION, PID_RELIABILITY, PID_TIME_BASED_FILTER, PID_TOPIC_DATA, PID_TOPIC_NAME,
+        PID_TYPE_NAME, PID_TYPE_REPRESENTATION, PID_UNICAST_LOCATOR, PID_USER_DATA,
     },
     payload_serializer_deserializer::{
         parameter_list_deserializer::ParameterListCdrDeserializer,
@@ -19,8 +19,47 @@ use crate::{
     },
     rtps::types::{EntityId, Guid, Locator},
     topic_definition::type_support::{DdsDeserialize, DdsSerialize, TypeSupport},
+    xtypes::{deserialize::XTypesDeserialize, serialize::XTypesSerialize},
 };
 
+pub const DDSSQL_FILTER_CLASS_NAME: &str = "DDSSQL";
+
+pub type FilterSignature = [i32; 4];
+
+#[derive(Debug, PartialEq, Eq, Clone, XTypesSerialize, XTypesDeserialize)]
+pub struct ContentFilterProperty {
+    pub content_filtered_topic_name: String,
+    pub related_topic_name: String,
+    pub filter_class_name: String,
+    pub filter_expression: String,
+    pub expression_parameters: Vec<String>,
+}
+
+impl ContentFilterProperty {
+    /// MD5 of the filter description which is used to identify the filter in the
+    /// PID_CONTENT_FILTER_INFO sent by the writers that evaluate it.
+    pub fn signature(&self) -> FilterSignature {
+        let mut context = md5::Context::new();
+        context.consume(self.content_filtered_topic_name.as_bytes());
+        context.consume([0]);
+        context.consume(self.related_topic_name.as_bytes());
+        context.consume([0]);
+        context.consume(self.filter_class_name.as_bytes());
+        context.consume([0]);
+        context.consume(self.filter_expression.as_bytes());
+        for parameter in &self.expression_parameters {
+            context.consume([0]);
+            context.consume(parameter.as_bytes());
+        }
+        let digest = context.compute();
+        let mut signature = [0; 4];
+        for (s, chunk) in signature.iter_mut().zip(digest.0.chunks_exact(4)) {
+            *s = i32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        signature
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ReaderProxy {
     pub(crate) remote_reader_guid: Guid,
@@ -34,6 +73,7 @@ pub struct ReaderProxy {
 pub struct DiscoveredReaderData {
     subscription_builtin_topic_data: SubscriptionBuiltinTopicData,
     reader_proxy: ReaderProxy,
+    content_filter: Option<ContentFilterProperty>,
 }
 impl TypeSupport for DiscoveredReaderData {
     fn get_type_name() -> &'static str {
@@ -213,6 +253,10 @@ impl DdsSerialize for DiscoveredReaderData {
             &DEFAULT_EXPECTS_INLINE_QOS,
         )?;
 
+        if let Some(content_filter) = &self.content_filter {
+            serializer.write(PID_CONTENT_FILTER_PROPERTY, content_filter)?;
+        }
+
         serializer.write_sentinel()?;
         Ok(serializer.writer)
     }
@@ -274,6 +318,9 @@ impl<'de> DdsDeserialize<'de> for DiscoveredReaderData {
                 expects_inline_qos: pl_deserializer
                     .read_with_default(PID_EXPECTS_INLINE_QOS, DEFAULT_EXPECTS_INLINE_QOS)?,
             },
+            content_filter: pl_deserializer
+                .read_collection(PID_CONTENT_FILTER_PROPERTY)?
+                .pop(),
         })
     }
 }
@@ -282,10 +329,12 @@ impl DiscoveredReaderData {
     pub fn new(
         reader_proxy: ReaderProxy,
         subscription_builtin_topic_data: SubscriptionBuiltinTopicData,
+        content_filter: Option<ContentFilterProperty>,
     ) -> Self {
         Self {
             reader_proxy,
             subscription_builtin_topic_data,
+            content_filter,
         }
     }
 
@@ -296,6 +345,10 @@ impl DiscoveredReaderData {
     pub fn subscription_builtin_topic_data(&self) -> &SubscriptionBuiltinTopicData {
         &self.subscription_builtin_topic_data
     }
+
+    pub fn content_filter(&self) -> Option<&ContentFilterProperty> {
+        self.content_filter.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -346,6 +399,7 @@ mod tests {
                 multicast_locator_list: vec![],
                 expects_inline_qos: false,
             },
+            content_filter: None,
         };
 
         let expected = vec![
@@ -389,6 +443,7 @@ mod tests {
                 multicast_locator_list: vec![],
                 expects_inline_qos: false,
             },
+            content_filter: None,
             subscription_builtin_topic_data: SubscriptionBuiltinTopicData {
                 key: BuiltInTopicKey {
                     value: [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0],
@@ -441,4 +496,77 @@ mod tests {
         let result = DiscoveredReaderData::deserialize_data(&mut data).unwrap();
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn serialize_deserialize_with_content_filter() {
+        let data = DiscoveredReaderData::new(
+            ReaderProxy {
+                remote_reader_guid: Guid::new(
+                    [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0],
+                    EntityId::new([4, 0, 0], USER_DEFINED_UNKNOWN),
+                ),
+                remote_group_entity_id: EntityId::new([21, 22, 23], BUILT_IN_WRITER_WITH_KEY),
+                unicast_locator_list: vec![],
+                multicast_locator_list: vec![],
+                expects_inline_qos: false,
+            },
+            SubscriptionBuiltinTopicData {
+                key: BuiltInTopicKey {
+                    value: [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0],
+                },
+                participant_key: BuiltInTopicKey {
+                    value: [6, 0, 0, 0, 7, 0, 0, 0, 8, 0, 0, 0, 9, 0, 0, 0],
+                },
+                topic_name: "ab".to_string(),
+                type_name: "cd".to_string(),
+                durability: Default::default(),
+                deadline: Default::default(),
+                latency_budget: Default::default(),
+                liveliness: Default::default(),
+                reliability: DEFAULT_RELIABILITY_QOS_POLICY_DATA_READER_AND_TOPICS,
+                ownership: Default::default(),
+                destination_order: Default::default(),
+                user_data: Default::default(),
+                time_based_filter: Default::default(),
+                presentation: Default::default(),
+                partition: Default::default(),
+                topic_data: Default::default(),
+                group_data: Default::default(),
+                xml_type: Default::default(),
+                representation: Default::default(),
+            },
+            Some(ContentFilterProperty {
+                content_filtered_topic_name: "FilteredTopic".to_string(),
+                related_topic_name: "ab".to_string(),
+                filter_class_name: DDSSQL_FILTER_CLASS_NAME.to_string(),
+                filter_expression: "x > %0".to_string(),
+                expression_parameters: vec!["10".to_string()],
+            }),
+        );
+
+        let serialized_data = data.serialize_data().unwrap();
+        let result = DiscoveredReaderData::deserialize_data(&serialized_data).unwrap();
+        assert_eq!(result, data);
+    }
+
+    #[test]
+    fn content_filter_signature_depends_on_parameters() {
+        let content_filter = ContentFilterProperty {
+            content_filtered_topic_name: "FilteredTopic".to_string(),
+            related_topic_name: "ab".to_string(),
+            filter_class_name: DDSSQL_FILTER_CLASS_NAME.to_string(),
+            filter_expression: "x > %0".to_string(),
+            expression_parameters: vec!["10".to_string()],
+        };
+        let other_content_filter = ContentFilterProperty {
+            expression_parameters: vec!["20".to_string()],
+            ..content_filter.clone()
+        };
+
+        assert_eq!(
+            content_filter.signature(),
+            content_filter.clone().signature()
+        );
+        assert_ne!(content_filter.signature(), other_content_filter.signature());
+    }
 }
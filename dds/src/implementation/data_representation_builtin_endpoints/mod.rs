@@ -1,6 +1,7 @@
 pub mod discovered_reader_data;
 pub mod discovered_topic_data;
 pub mod discovered_writer_data;
+pub mod participant_message_data;
 pub mod parameter_id_values;
 pub mod spdp_discovered_participant_data;
 mod payload_serializer_deserializer;
\ No newline at end of file
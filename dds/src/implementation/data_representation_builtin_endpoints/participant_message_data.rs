@@ -0,0 +1,68 @@
+use crate::topic_definition::type_support::DdsType;
+
+/// Topic name of the built-in topic used by the Writer Liveliness Protocol
+pub const DCPS_PARTICIPANT_MESSAGE: &str = "DCPSParticipantMessage";
+
+pub type ParticipantMessageKind = [u8; 4];
+
+pub const PARTICIPANT_MESSAGE_DATA_KIND_AUTOMATIC_LIVELINESS_UPDATE: ParticipantMessageKind =
+    [0x00, 0x00, 0x00, 0x01];
+pub const PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE: ParticipantMessageKind =
+    [0x00, 0x00, 0x00, 0x02];
+
+// Data type of the BuiltinParticipantMessageWriter and BuiltinParticipantMessageReader
+// as specified in 9.6.2.1 Data Representation for the ParticipantMessageData Built-in Endpoints
+#[derive(Debug, PartialEq, Eq, Clone, DdsType)]
+pub struct ParticipantMessageData {
+    #[dust_dds(key)]
+    pub(crate) participant_guid_prefix: [u8; 12],
+    #[dust_dds(key)]
+    pub(crate) kind: [u8; 4],
+    pub(crate) data: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topic_definition::type_support::{DdsDeserialize, DdsSerialize};
+
+    #[test]
+    fn serialize_participant_message_data() {
+        let data = ParticipantMessageData {
+            participant_guid_prefix: [1; 12],
+            kind: PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE,
+            data: vec![7],
+        };
+        let expected = vec![
+            0x00, 0x01, 0x00, 0x03, // CDR_LE | options (padding length)
+            1, 1, 1, 1, // participant_guid_prefix
+            1, 1, 1, 1, // participant_guid_prefix
+            1, 1, 1, 1, // participant_guid_prefix
+            0, 0, 0, 2, // kind
+            1, 0, 0, 0, // data length
+            7, 0, 0, 0, // data | padding
+        ];
+        assert_eq!(data.serialize_data().unwrap(), expected);
+    }
+
+    #[test]
+    fn deserialize_participant_message_data() {
+        let expected = ParticipantMessageData {
+            participant_guid_prefix: [3; 12],
+            kind: PARTICIPANT_MESSAGE_DATA_KIND_AUTOMATIC_LIVELINESS_UPDATE,
+            data: vec![],
+        };
+        let data = [
+            0x00, 0x00, 0x00, 0x00, // CDR_BE | options
+            3, 3, 3, 3, // participant_guid_prefix
+            3, 3, 3, 3, // participant_guid_prefix
+            3, 3, 3, 3, // participant_guid_prefix
+            0, 0, 0, 1, // kind
+            0, 0, 0, 0, // data length
+        ];
+        assert_eq!(
+            ParticipantMessageData::deserialize_data(&data).unwrap(),
+            expected
+        );
+    }
+}
@@ -1,11 +1,11 @@
 use crate::rtps::messages::types::ParameterId;
 
-pub const _PID_CONTENT_FILTER_INFO: ParameterId = 0x0055;
-pub const _PID_COHERENT_SET: ParameterId = 0x0056;
+pub const PID_CONTENT_FILTER_INFO: ParameterId = 0x0055;
+pub const PID_COHERENT_SET: ParameterId = 0x0056;
 pub const _PID_DIRECTED_WRITE: ParameterId = 0x0057;
 pub const _PID_ORIGINAL_WRITER_INFO: ParameterId = 0x0061;
-pub const _PID_GROUP_COHERENT_SET: ParameterId = 0x0063;
-pub const _PID_GROUP_SEQ_NUM: ParameterId = 0x0064;
+pub const PID_GROUP_COHERENT_SET: ParameterId = 0x0063;
+pub const PID_GROUP_SEQ_NUM: ParameterId = 0x0064;
 pub const _PID_WRITER_GROUP_INFO: ParameterId = 0x0065;
 pub const _PID_SECURE_WRITER_GROUP_INFO: ParameterId = 0x0066;
 pub const PID_KEY_HASH: ParameterId = 0x0070;
@@ -1,6 +1,6 @@
 use dust_dds_derive::XTypesDeserialize;
 
-use crate::xtypes::serialize::XTypesSerialize;
+use crate::{rtps::types::SequenceNumber, xtypes::serialize::XTypesSerialize};
 
 #[derive(Clone, Copy, PartialEq, Eq, XTypesSerialize, XTypesDeserialize)]
 pub struct KeyHash(pub [u8; 16]);
@@ -11,3 +11,59 @@ pub const STATUS_INFO_DISPOSED: StatusInfo = StatusInfo([0, 0, 0, 0b00000001]);
 pub const STATUS_INFO_UNREGISTERED: StatusInfo = StatusInfo([0, 0, 0, 0b0000010]);
 pub const STATUS_INFO_DISPOSED_UNREGISTERED: StatusInfo = StatusInfo([0, 0, 0, 0b00000011]);
 pub const _STATUS_INFO_FILTERED: StatusInfo = StatusInfo([0, 0, 0, 0b0000100]);
+
+#[derive(Clone, PartialEq, Eq, Debug, XTypesSerialize, XTypesDeserialize)]
+pub struct ContentFilterInfo {
+    pub filter_result: Vec<i32>,
+    pub filter_signatures: Vec<[i32; 4]>,
+}
+
+impl ContentFilterInfo {
+    /// Result of the filter with the given signature or `None` if the
+    /// writer did not evaluate that filter.
+    pub fn filter_result(&self, signature: &[i32; 4]) -> Option<bool> {
+        let index = self.filter_signatures.iter().position(|s| s == signature)?;
+        let bitmap = self.filter_result.get(index / 32)?;
+        Some(bitmap & (1 << (31 - index % 32)) != 0)
+    }
+}
+
+/// Sequence number of the first sample of the coherent set to which a sample belongs.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, XTypesSerialize, XTypesDeserialize)]
+pub struct CoherentSet {
+    high: i32,
+    low: u32,
+}
+/// SEQUENCENUMBER_UNKNOWN, sent by the writer in a change without data to mark the end of a coherent set
+pub const COHERENT_SET_END: CoherentSet = CoherentSet { high: -1, low: 0 };
+
+impl CoherentSet {
+    pub fn new(sequence_number: SequenceNumber) -> Self {
+        Self {
+            high: (sequence_number >> 32) as i32,
+            low: sequence_number as u32,
+        }
+    }
+}
+
+/// Sequence number assigned by the publisher to a sample written by any of its writers.
+/// It is used both for the group sequence number of a sample and to identify the
+/// group coherent set by the group sequence number of its first sample.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, XTypesSerialize, XTypesDeserialize)]
+pub struct GroupSequenceNumber {
+    high: i32,
+    low: u32,
+}
+
+impl GroupSequenceNumber {
+    pub fn new(sequence_number: SequenceNumber) -> Self {
+        Self {
+            high: (sequence_number >> 32) as i32,
+            low: sequence_number as u32,
+        }
+    }
+
+    pub fn value(&self) -> SequenceNumber {
+        ((self.high as i64) << 32) + self.low as i64
+    }
+}
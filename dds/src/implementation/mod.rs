@@ -1,6 +1,9 @@
 pub mod actor;
 pub mod actors;
+pub mod content_filter;
 pub mod data_representation_builtin_endpoints;
 pub mod data_representation_inline_qos;
+pub mod multi_topic;
 pub mod runtime;
+pub mod udp_transport;
 pub mod xtypes_glue;
\ No newline at end of file
@@ -0,0 +1,436 @@
+use super::{
+    content_filter::{compare_values, SubscriptionExpression},
+    xtypes_glue::member_values::{
+        get_member_paths, get_member_values_from_serialized_foo, serialize_member_values,
+        MemberValues,
+    },
+};
+use crate::{
+    infrastructure::{
+        error::{DdsError, DdsResult},
+        instance::InstanceHandle,
+    },
+    xtypes::dynamic_type::DynamicType,
+};
+use std::{cmp::Ordering, collections::HashMap, sync::Arc};
+
+/// Description of a MultiTopic as kept by the DomainParticipant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiTopicProperty {
+    pub multi_topic_name: String,
+    pub type_name: String,
+    pub subscription_expression: String,
+    pub expression: SubscriptionExpression,
+    pub expression_parameters: Vec<String>,
+}
+
+struct RelatedTopic {
+    topic_name: String,
+    type_support: Arc<dyn DynamicType + Send + Sync>,
+    member_paths: Vec<String>,
+    // Members compared with the samples of the other related topics. These are the
+    // members of the topic which have the same name as a member of another related topic.
+    join_member_paths: Vec<String>,
+    samples: HashMap<InstanceHandle, MemberValues>,
+}
+
+/// State of the join of the related topics of a MultiTopic used by the
+/// DataReader created from it. The last sample of every instance of the related
+/// topics is kept to compute the joined samples when a new sample is received.
+pub struct MultiTopicJoin {
+    multi_topic_name: String,
+    expression: SubscriptionExpression,
+    expression_parameters: Vec<String>,
+    type_support: Arc<dyn DynamicType + Send + Sync>,
+    member_paths: Vec<String>,
+    related_topic_list: Vec<RelatedTopic>,
+}
+
+impl MultiTopicJoin {
+    /// Creates the join of the related topics after checking that the subscription
+    /// expression can be used to construct samples of the MultiTopic type. The type
+    /// support of the related topics must be given in the order in which the topics
+    /// appear in the subscription expression.
+    pub fn new(
+        property: &MultiTopicProperty,
+        type_support: Arc<dyn DynamicType + Send + Sync>,
+        related_type_support_list: Vec<Arc<dyn DynamicType + Send + Sync>>,
+    ) -> DdsResult<Self> {
+        let topic_names = &property.expression.topic_names;
+        if related_type_support_list.len() != topic_names.len()
+            || topic_names
+                .iter()
+                .enumerate()
+                .any(|(i, name)| topic_names[..i].contains(name))
+        {
+            return Err(DdsError::BadParameter);
+        }
+
+        let mut related_topic_list = Vec::new();
+        for (topic_name, related_type_support) in topic_names.iter().zip(related_type_support_list)
+        {
+            related_topic_list.push(RelatedTopic {
+                topic_name: topic_name.clone(),
+                member_paths: get_member_paths(related_type_support.as_ref())?,
+                type_support: related_type_support,
+                join_member_paths: Vec::new(),
+                samples: HashMap::new(),
+            });
+        }
+        for index in 0..related_topic_list.len() {
+            let join_member_paths = related_topic_list[index]
+                .member_paths
+                .iter()
+                .filter(|p| {
+                    related_topic_list
+                        .iter()
+                        .enumerate()
+                        .any(|(i, t)| i != index && t.member_paths.contains(p))
+                })
+                .cloned()
+                .collect();
+            related_topic_list[index].join_member_paths = join_member_paths;
+        }
+
+        let related_member_paths: Vec<String> = related_topic_list
+            .iter()
+            .flat_map(|t| t.member_paths.iter().cloned())
+            .collect();
+        if let Some(selected_fields) = &property.expression.selected_fields {
+            for selected_field in selected_fields {
+                let nested_prefix = format!("{}.", selected_field.field);
+                if !related_member_paths
+                    .iter()
+                    .any(|p| p == &selected_field.field || p.starts_with(&nested_prefix))
+                {
+                    return Err(DdsError::BadParameter);
+                }
+            }
+        }
+
+        let member_paths = get_member_paths(type_support.as_ref())?;
+        for member_path in &member_paths {
+            match property.expression.source_member_path(member_path) {
+                Some(source) if related_member_paths.contains(&source) => (),
+                _ => return Err(DdsError::BadParameter),
+            }
+        }
+
+        if let Some(condition) = &property.expression.condition {
+            condition.validate(&related_member_paths, &property.expression_parameters)?;
+        }
+
+        Ok(Self {
+            multi_topic_name: property.multi_topic_name.clone(),
+            expression: property.expression.clone(),
+            expression_parameters: property.expression_parameters.clone(),
+            type_support,
+            member_paths,
+            related_topic_list,
+        })
+    }
+
+    pub fn multi_topic_name(&self) -> &str {
+        &self.multi_topic_name
+    }
+
+    pub fn set_expression_parameters(&mut self, expression_parameters: Vec<String>) {
+        self.expression_parameters = expression_parameters;
+    }
+
+    /// Stores a sample received on one of the related topics and returns the
+    /// serialized samples of the MultiTopic type resulting from joining it with the
+    /// samples stored for the other related topics.
+    pub fn add_sample(
+        &mut self,
+        topic_name: &str,
+        instance_handle: InstanceHandle,
+        serialized_data: &[u8],
+    ) -> Vec<Vec<u8>> {
+        let Some(index) = self
+            .related_topic_list
+            .iter()
+            .position(|t| t.topic_name == topic_name)
+        else {
+            return Vec::new();
+        };
+        let related_topic = &mut self.related_topic_list[index];
+        let Ok(member_values) = get_member_values_from_serialized_foo(
+            serialized_data,
+            related_topic.type_support.as_ref(),
+        ) else {
+            return Vec::new();
+        };
+        related_topic
+            .samples
+            .insert(instance_handle, member_values.clone());
+
+        let mut joined_samples = Vec::new();
+        self.join(
+            0,
+            (index, &member_values),
+            MemberValues::new(),
+            &mut joined_samples,
+        );
+        joined_samples
+    }
+
+    /// Removes the sample of an instance of a related topic which is no longer alive
+    /// so that it is not used for future joins.
+    pub fn remove_instance(&mut self, topic_name: &str, instance_handle: &InstanceHandle) {
+        if let Some(related_topic) = self
+            .related_topic_list
+            .iter_mut()
+            .find(|t| t.topic_name == topic_name)
+        {
+            related_topic.samples.remove(instance_handle);
+        }
+    }
+
+    fn join(
+        &self,
+        index: usize,
+        new_sample: (usize, &MemberValues),
+        joined: MemberValues,
+        joined_samples: &mut Vec<Vec<u8>>,
+    ) {
+        let Some(related_topic) = self.related_topic_list.get(index) else {
+            if let Some(data) = self.project(&joined) {
+                joined_samples.push(data);
+            }
+            return;
+        };
+
+        let candidates: Vec<&MemberValues> = if index == new_sample.0 {
+            vec![new_sample.1]
+        } else {
+            related_topic.samples.values().collect()
+        };
+        for candidate in candidates {
+            let is_matched = related_topic.join_member_paths.iter().all(|p| {
+                match (joined.get(p), candidate.get(p)) {
+                    (Some(a), Some(b)) => compare_values(a, b) == Some(Ordering::Equal),
+                    _ => true,
+                }
+            });
+            if is_matched {
+                let mut candidate_joined = joined.clone();
+                candidate_joined.extend(candidate.iter().map(|(k, v)| (k.clone(), v.clone())));
+                self.join(index + 1, new_sample, candidate_joined, joined_samples);
+            }
+        }
+    }
+
+    fn project(&self, joined: &MemberValues) -> Option<Vec<u8>> {
+        if let Some(condition) = &self.expression.condition {
+            if !condition.evaluate(joined, &self.expression_parameters) {
+                return None;
+            }
+        }
+        let mut member_values = MemberValues::new();
+        for member_path in &self.member_paths {
+            let source = self.expression.source_member_path(member_path)?;
+            member_values.insert(member_path.clone(), joined.get(&source)?.clone());
+        }
+        serialize_member_values(&member_values, self.type_support.as_ref()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::xtypes_glue::member_values::MemberValue;
+    use crate::topic_definition::type_support::{serialize_rtps_xtypes_xcdr1_le, TypeSupport};
+    use dust_dds_derive::{TypeSupport, XTypesSerialize};
+
+    #[derive(TypeSupport, XTypesSerialize)]
+    struct Temperature {
+        #[dust_dds(key)]
+        sensor_id: u32,
+        value: f32,
+    }
+
+    #[derive(TypeSupport, XTypesSerialize)]
+    struct Location {
+        #[dust_dds(key)]
+        sensor_id: u32,
+        room: String,
+    }
+
+    #[derive(TypeSupport, XTypesSerialize)]
+    struct RoomSensorTemperature {
+        #[dust_dds(key)]
+        sensor_id: u32,
+        room: String,
+        value: f32,
+    }
+
+    #[derive(TypeSupport)]
+    struct RoomTemperature {
+        #[dust_dds(key)]
+        _room: String,
+        _temperature: f32,
+    }
+
+    fn property(
+        subscription_expression: &str,
+        expression_parameters: &[&str],
+    ) -> MultiTopicProperty {
+        MultiTopicProperty {
+            multi_topic_name: "RoomTemperature".to_string(),
+            type_name: "RoomTemperature".to_string(),
+            subscription_expression: subscription_expression.to_string(),
+            expression: SubscriptionExpression::parse(subscription_expression).unwrap(),
+            expression_parameters: expression_parameters
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+        }
+    }
+
+    fn join(property: &MultiTopicProperty) -> DdsResult<MultiTopicJoin> {
+        MultiTopicJoin::new(
+            property,
+            Arc::new(RoomTemperature::get_type()),
+            vec![
+                Arc::new(Temperature::get_type()),
+                Arc::new(Location::get_type()),
+            ],
+        )
+    }
+
+    fn handle(id: u8) -> InstanceHandle {
+        InstanceHandle::new([id; 16])
+    }
+
+    #[test]
+    fn join_on_shared_key() {
+        let mut join = join(&property(
+            "SELECT room AS _room, value AS _temperature FROM Temperature NATURAL JOIN Location WHERE value > %0",
+            &["10"],
+        ))
+        .unwrap();
+
+        let temperature = |sensor_id, value| {
+            serialize_rtps_xtypes_xcdr1_le(&Temperature { sensor_id, value }).unwrap()
+        };
+        let location = |sensor_id, room: &str| {
+            serialize_rtps_xtypes_xcdr1_le(&Location {
+                sensor_id,
+                room: room.to_string(),
+            })
+            .unwrap()
+        };
+
+        assert!(join
+            .add_sample("Temperature", handle(1), &temperature(1, 20.0))
+            .is_empty());
+        let joined = join.add_sample("Location", handle(2), &location(1, "Kitchen"));
+        assert_eq!(joined.len(), 1);
+        let member_values =
+            get_member_values_from_serialized_foo(&joined[0], &RoomTemperature::get_type())
+                .unwrap();
+        assert_eq!(
+            member_values["_room"],
+            MemberValue::String("Kitchen".to_string())
+        );
+        assert_eq!(member_values["_temperature"], MemberValue::Float(20.0));
+
+        // Different key value is not joined
+        assert!(join
+            .add_sample("Location", handle(3), &location(2, "Garage"))
+            .is_empty());
+        // Samples not fulfilling the condition are not joined
+        assert!(join
+            .add_sample("Temperature", handle(1), &temperature(1, 5.0))
+            .is_empty());
+        join.set_expression_parameters(vec!["0".to_string()]);
+        assert_eq!(
+            join.add_sample("Temperature", handle(1), &temperature(1, 5.0))
+                .len(),
+            1
+        );
+
+        join.remove_instance("Location", &handle(2));
+        assert!(join
+            .add_sample("Temperature", handle(1), &temperature(1, 25.0))
+            .is_empty());
+    }
+
+    #[test]
+    fn join_on_all_shared_members() {
+        let mut join = MultiTopicJoin::new(
+            &property(
+                "SELECT room AS _room, value AS _temperature FROM RoomSensorTemperature NATURAL JOIN Location",
+                &[],
+            ),
+            Arc::new(RoomTemperature::get_type()),
+            vec![
+                Arc::new(RoomSensorTemperature::get_type()),
+                Arc::new(Location::get_type()),
+            ],
+        )
+        .unwrap();
+
+        let temperature = serialize_rtps_xtypes_xcdr1_le(&RoomSensorTemperature {
+            sensor_id: 1,
+            room: "Kitchen".to_string(),
+            value: 20.0,
+        })
+        .unwrap();
+        let location = |room: &str| {
+            serialize_rtps_xtypes_xcdr1_le(&Location {
+                sensor_id: 1,
+                room: room.to_string(),
+            })
+            .unwrap()
+        };
+
+        assert!(join
+            .add_sample("RoomSensorTemperature", handle(1), &temperature)
+            .is_empty());
+        // Same key but different value of the shared non-key member is not joined
+        assert!(join
+            .add_sample("Location", handle(2), &location("Garage"))
+            .is_empty());
+        assert_eq!(
+            join.add_sample("Location", handle(2), &location("Kitchen"))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn invalid_subscription_expressions() {
+        // Result type member not selected
+        assert!(join(&property(
+            "SELECT room AS _room FROM Temperature NATURAL JOIN Location",
+            &[]
+        ))
+        .is_err());
+        // Unknown selected field
+        assert!(join(&property(
+            "SELECT room AS _room, value AS _temperature, humidity FROM Temperature NATURAL JOIN Location",
+            &[]
+        ))
+        .is_err());
+        // Missing parameter
+        assert!(join(&property(
+            "SELECT room AS _room, value AS _temperature FROM Temperature NATURAL JOIN Location WHERE value > %0",
+            &[]
+        ))
+        .is_err());
+        // Wrong number of related topics
+        assert!(join(&property(
+            "SELECT room AS _room, value AS _temperature FROM Temperature",
+            &[]
+        ))
+        .is_err());
+        // Repeated related topic
+        assert!(join(&property(
+            "SELECT room AS _room, value AS _temperature FROM Temperature NATURAL JOIN Temperature",
+            &[]
+        ))
+        .is_err());
+    }
+}
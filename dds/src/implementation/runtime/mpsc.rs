@@ -61,6 +61,14 @@ impl<T> Clone for MpscSender<T> {
     }
 }
 
+impl<T> PartialEq for MpscSender<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T> Eq for MpscSender<T> {}
+
 impl<T> std::fmt::Debug for MpscSender<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MpscSender")
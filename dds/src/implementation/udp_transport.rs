@@ -0,0 +1,474 @@
+use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
+use socket2::Socket;
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tracing::info;
+
+use crate::{
+    configuration::{DustDdsConfiguration, MAX_PARTICIPANT_ID},
+    domain::domain_participant_factory::DomainId,
+    implementation::actors::domain_participant_factory_actor::{
+        port_builtin_multicast, port_builtin_unicast, port_user_unicast, MAX_DATAGRAM_SIZE,
+    },
+    infrastructure::error::{DdsError, DdsResult},
+    rtps::{
+        messages::overall_structure::RtpsMessageRead,
+        types::{Locator, LOCATOR_KIND_UDP_V4, LOCATOR_KIND_UDP_V6},
+    },
+    transport::{Transport, TransportFactory, TransportLocators, TransportReceiver},
+};
+
+type LocatorAddress = [u8; 16];
+// As of 9.6.1.4.1  Default multicast address
+const DEFAULT_MULTICAST_LOCATOR_ADDRESS: LocatorAddress =
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 239, 255, 0, 1];
+// Link-local scope IPv6 group embedding the default IPv4 multicast address (ff02::ffff:239.255.0.1)
+const DEFAULT_MULTICAST_LOCATOR_ADDRESS_V6: LocatorAddress = [
+    0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 239, 255, 0, 1,
+];
+
+/// Built-in [`TransportFactory`] of the UDPv4 or UDPv6 locators
+pub struct UdpTransportFactory {
+    locator_kind: i32,
+    interface_list: Vec<NetworkInterface>,
+    configuration: DustDdsConfiguration,
+}
+
+impl UdpTransportFactory {
+    /// Factory of the transports of the locators of kind `locator_kind` which receive on the
+    /// addresses of that IP version of the interfaces of `interface_list`
+    pub fn new(
+        locator_kind: i32,
+        interface_list: &[NetworkInterface],
+        configuration: DustDdsConfiguration,
+    ) -> Self {
+        let interface_list = interface_list
+            .iter()
+            .cloned()
+            .map(|mut i| {
+                i.addr.retain(|a| match a {
+                    Addr::V4(_) => locator_kind == LOCATOR_KIND_UDP_V4,
+                    Addr::V6(_) => locator_kind == LOCATOR_KIND_UDP_V6,
+                });
+                i
+            })
+            .collect();
+        Self {
+            locator_kind,
+            interface_list,
+            configuration,
+        }
+    }
+}
+
+impl TransportFactory for UdpTransportFactory {
+    fn create_transport(
+        &self,
+        domain_id: DomainId,
+        transport_receiver: TransportReceiver,
+    ) -> DdsResult<(Box<dyn Transport>, TransportLocators)> {
+        let (unspecified_address, multicast_address) = match self.locator_kind {
+            LOCATOR_KIND_UDP_V4 => (
+                IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                DEFAULT_MULTICAST_LOCATOR_ADDRESS,
+            ),
+            LOCATOR_KIND_UDP_V6 => (
+                IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+                DEFAULT_MULTICAST_LOCATOR_ADDRESS_V6,
+            ),
+            _ => return Err(DdsError::BadParameter),
+        };
+        let socket = std::net::UdpSocket::bind(SocketAddr::from((unspecified_address, 0)))?;
+
+        // Open the sockets for unicast user-defined data and metatraffic on the well-known
+        // ports of the configured participant id or of the first one which is free on this host
+        let receive_buffer_size = self.configuration.udp_receive_buffer_size();
+        let (participant_id, default_unicast_socket, metatraffic_unicast_socket) =
+            match self.configuration.participant_id() {
+                Some(participant_id) => {
+                    let (default_unicast_socket, metatraffic_unicast_socket) = get_unicast_sockets(
+                        unspecified_address,
+                        domain_id,
+                        participant_id,
+                        receive_buffer_size,
+                    )?;
+                    (
+                        participant_id,
+                        default_unicast_socket,
+                        metatraffic_unicast_socket,
+                    )
+                }
+                None => (0..=MAX_PARTICIPANT_ID)
+                    .find_map(|participant_id| {
+                        get_unicast_sockets(
+                            unspecified_address,
+                            domain_id,
+                            participant_id,
+                            receive_buffer_size,
+                        )
+                        .ok()
+                        .map(
+                            |(default_unicast_socket, metatraffic_unicast_socket)| {
+                                (
+                                    participant_id,
+                                    default_unicast_socket,
+                                    metatraffic_unicast_socket,
+                                )
+                            },
+                        )
+                    })
+                    .ok_or(DdsError::OutOfResources)?,
+            };
+        info!(
+            "Participant on domain {} uses participant id {} on {}",
+            domain_id, participant_id, unspecified_address
+        );
+
+        // Link-local IPv6 addresses are not announced since they
+        // can't be used without the scope of the interface
+        let interface_address_list: Vec<Addr> = self
+            .interface_list
+            .iter()
+            .flat_map(|i| i.addr.iter().copied())
+            .filter(|a| match a {
+                Addr::V4(_) => true,
+                Addr::V6(v6) => !v6.ip.is_unicast_link_local(),
+            })
+            .collect();
+        let mut transport_locators = TransportLocators {
+            default_unicast_locator_list: interface_address_list
+                .iter()
+                .map(|a| {
+                    Locator::from_ip_and_port(
+                        a,
+                        port_user_unicast(domain_id, participant_id) as u32,
+                    )
+                })
+                .collect(),
+            metatraffic_unicast_locator_list: interface_address_list
+                .iter()
+                .map(|a| {
+                    Locator::from_ip_and_port(
+                        a,
+                        port_builtin_unicast(domain_id, participant_id) as u32,
+                    )
+                })
+                .collect(),
+            metatraffic_multicast_locator_list: Vec::new(),
+        };
+
+        let mut metatraffic_socket_list = vec![metatraffic_unicast_socket];
+        if self.configuration.enable_multicast() {
+            let multicast_locator = Locator::new(
+                self.locator_kind,
+                port_builtin_multicast(domain_id) as u32,
+                multicast_address,
+            );
+            let socket =
+                get_multicast_socket(multicast_locator.kind(), multicast_locator.port() as u16)?;
+            join_multicast_group(&socket, &multicast_locator, &self.interface_list);
+            metatraffic_socket_list.push(socket);
+            transport_locators
+                .metatraffic_multicast_locator_list
+                .push(multicast_locator);
+        }
+
+        let closed = Arc::new(AtomicBool::new(false));
+        spawn_receive_thread(
+            default_unicast_socket,
+            false,
+            transport_receiver.clone(),
+            closed.clone(),
+        );
+        for socket in metatraffic_socket_list {
+            spawn_receive_thread(socket, true, transport_receiver.clone(), closed.clone());
+        }
+
+        Ok((
+            Box::new(UdpTransport { socket, closed }),
+            transport_locators,
+        ))
+    }
+}
+
+/// Built-in [`Transport`] sending the messages to the UDPv4 or UDPv6 locators
+struct UdpTransport {
+    socket: std::net::UdpSocket,
+    // Stops the receive threads of the transport
+    closed: Arc<AtomicBool>,
+}
+
+impl Transport for UdpTransport {
+    fn send(&self, datagram: &[u8], locator: &Locator) {
+        let socket = &self.socket;
+        if UdpLocator(*locator).is_multicast() {
+            let socket2: socket2::Socket = socket.try_clone().unwrap().into();
+            let interface_list = NetworkInterface::show().expect("Could not scan interfaces");
+            if locator.kind() == LOCATOR_KIND_UDP_V4 {
+                let interface_addresses = interface_list.into_iter().flat_map(|i| {
+                    i.addr.into_iter().filter_map(|a| match a {
+                        Addr::V4(v4) => Some(v4.ip),
+                        _ => None,
+                    })
+                });
+                for address in interface_addresses {
+                    if socket2.set_multicast_if_v4(&address).is_ok() {
+                        socket.send_to(datagram, UdpLocator(*locator)).ok();
+                    }
+                }
+            } else {
+                // IPv6 multicast interfaces are selected by index
+                let interface_indexes = interface_list
+                    .into_iter()
+                    .filter(|i| i.addr.iter().any(|a| matches!(a, Addr::V6(_))))
+                    .map(|i| i.index);
+                for index in interface_indexes {
+                    if socket2.set_multicast_if_v6(index).is_ok() {
+                        socket.send_to(datagram, UdpLocator(*locator)).ok();
+                    }
+                }
+            }
+        } else {
+            socket.send_to(datagram, UdpLocator(*locator)).ok();
+        }
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+    }
+}
+
+fn read_message(socket: &mut std::net::UdpSocket, buf: &mut [u8]) -> DdsResult<RtpsMessageRead> {
+    let (bytes, _) = socket.recv_from(buf)?;
+    if bytes > 0 {
+        Ok(RtpsMessageRead::try_from(&buf[0..bytes])?)
+    } else {
+        Err(DdsError::NoData)
+    }
+}
+
+/// Spawn the thread delivering the messages received on `socket` to `transport_receiver` until
+/// `closed` is set or the participant is deleted
+pub(crate) fn spawn_receive_thread(
+    mut socket: std::net::UdpSocket,
+    is_metatraffic: bool,
+    transport_receiver: TransportReceiver,
+    closed: Arc<AtomicBool>,
+) {
+    std::thread::spawn(move || {
+        let mut buf = Box::new([0; MAX_DATAGRAM_SIZE]);
+        while !closed.load(Ordering::Acquire) {
+            if let Ok(message) = read_message(&mut socket, buf.as_mut_slice()) {
+                let r = if is_metatraffic {
+                    transport_receiver.receive_metatraffic(message)
+                } else {
+                    transport_receiver.receive_user_defined(message)
+                };
+                if r.is_err() {
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// Sockets for the unicast user-defined data and metatraffic of the participant with id `participant_id`.
+/// Fails if any of the ports is already in use.
+fn get_unicast_sockets(
+    ip_address: IpAddr,
+    domain_id: DomainId,
+    participant_id: u32,
+    receive_buffer_size: Option<usize>,
+) -> std::io::Result<(std::net::UdpSocket, std::net::UdpSocket)> {
+    let default_unicast_socket = get_unicast_socket(
+        SocketAddr::from((ip_address, port_user_unicast(domain_id, participant_id))),
+        receive_buffer_size,
+    )?;
+    let metatraffic_unicast_socket = get_unicast_socket(
+        SocketAddr::from((ip_address, port_builtin_unicast(domain_id, participant_id))),
+        None,
+    )?;
+    Ok((default_unicast_socket, metatraffic_unicast_socket))
+}
+
+fn get_unicast_socket(
+    socket_addr: SocketAddr,
+    receive_buffer_size: Option<usize>,
+) -> std::io::Result<std::net::UdpSocket> {
+    let socket = Socket::new(
+        socket2::Domain::for_address(socket_addr),
+        socket2::Type::DGRAM,
+        None,
+    )?;
+    if socket_addr.is_ipv6() {
+        // The IPv4 traffic is received on its own socket
+        socket.set_only_v6(true)?;
+    }
+    socket.bind(&socket_addr.into())?;
+    socket.set_nonblocking(false)?;
+    // The receive thread checks regularly if the transport is closed
+    socket.set_read_timeout(Some(std::time::Duration::from_millis(50)))?;
+    if let Some(buffer_size) = receive_buffer_size {
+        socket.set_recv_buffer_size(buffer_size)?;
+    }
+    Ok(socket.into())
+}
+
+/// Socket receiving the multicast traffic sent to `port` for the locators of kind `locator_kind`.
+/// The socket receives on a multicast group only after joining it with [`join_multicast_group`].
+pub(crate) fn get_multicast_socket(
+    locator_kind: i32,
+    port: u16,
+) -> std::io::Result<std::net::UdpSocket> {
+    let domain = if locator_kind == LOCATOR_KIND_UDP_V6 {
+        socket2::Domain::IPV6
+    } else {
+        socket2::Domain::IPV4
+    };
+
+    let socket = Socket::new(domain, socket2::Type::DGRAM, Some(socket2::Protocol::UDP))?;
+
+    socket.set_reuse_address(true)?;
+    #[cfg(target_family = "unix")]
+    socket.set_reuse_port(true)?;
+    socket.set_nonblocking(false)?;
+    socket.set_read_timeout(Some(std::time::Duration::from_millis(50)))?;
+
+    if domain == socket2::Domain::IPV6 {
+        socket.set_only_v6(true)?;
+        socket.bind(&SocketAddr::from((Ipv6Addr::UNSPECIFIED, port)).into())?;
+        socket.set_multicast_loop_v6(true)?;
+    } else {
+        socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)).into())?;
+        socket.set_multicast_loop_v4(true)?;
+    }
+
+    Ok(socket.into())
+}
+
+/// Join the group of `multicast_locator` on all the interfaces of `interface_list`
+/// with an address of the same IP version
+pub(crate) fn join_multicast_group(
+    socket: &std::net::UdpSocket,
+    multicast_locator: &Locator,
+    interface_list: &[NetworkInterface],
+) {
+    let multicast_address = multicast_locator.address();
+    if multicast_locator.kind() == LOCATOR_KIND_UDP_V6 {
+        let addr = Ipv6Addr::from(multicast_address);
+        // IPv6 multicast groups are joined on the interface index
+        for interface in interface_list
+            .iter()
+            .filter(|i| i.addr.iter().any(|a| matches!(a, Addr::V6(_))))
+        {
+            let r = socket.join_multicast_v6(&addr, interface.index);
+            if let Err(e) = r {
+                info!(
+                    "Failed to join multicast group on interface {} with error {}",
+                    interface.name, e
+                )
+            }
+        }
+    } else {
+        let addr = Ipv4Addr::new(
+            multicast_address[12],
+            multicast_address[13],
+            multicast_address[14],
+            multicast_address[15],
+        );
+        for interface_addr in interface_list.iter().flat_map(|i| i.addr.iter()) {
+            match interface_addr {
+                Addr::V4(a) => {
+                    let r = socket.join_multicast_v4(&addr, &a.ip);
+                    if let Err(e) = r {
+                        info!(
+                            "Failed to join multicast group on address {} with error {}",
+                            a.ip, e
+                        )
+                    }
+                }
+                Addr::V6(_) => (),
+            }
+        }
+    }
+}
+
+struct UdpLocator(Locator);
+
+impl ToSocketAddrs for UdpLocator {
+    type Iter = std::option::IntoIter<SocketAddr>;
+
+    fn to_socket_addrs(&self) -> std::io::Result<Self::Iter> {
+        let locator_address = self.0.address();
+        match self.0.kind() {
+            LOCATOR_KIND_UDP_V4 => {
+                let address = SocketAddrV4::new(
+                    Ipv4Addr::new(
+                        locator_address[12],
+                        locator_address[13],
+                        locator_address[14],
+                        locator_address[15],
+                    ),
+                    self.0.port() as u16,
+                );
+                Ok(Some(SocketAddr::V4(address)).into_iter())
+            }
+            LOCATOR_KIND_UDP_V6 => {
+                let address =
+                    SocketAddrV6::new(Ipv6Addr::from(locator_address), self.0.port() as u16, 0, 0);
+                Ok(Some(SocketAddr::V6(address)).into_iter())
+            }
+            _ => Err(std::io::ErrorKind::InvalidInput.into()),
+        }
+    }
+}
+
+impl UdpLocator {
+    fn is_multicast(&self) -> bool {
+        let locator_address = self.0.address();
+        match self.0.kind() {
+            LOCATOR_KIND_UDP_V4 => Ipv4Addr::new(
+                locator_address[12],
+                locator_address[13],
+                locator_address[14],
+                locator_address[15],
+            )
+            .is_multicast(),
+            LOCATOR_KIND_UDP_V6 => Ipv6Addr::from(locator_address).is_multicast(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn udp_locator_v6_to_socket_address() {
+        let address = Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 2);
+        let locator = Locator::new(LOCATOR_KIND_UDP_V6, 7410, address.octets());
+
+        let socket_address: Vec<SocketAddr> =
+            UdpLocator(locator).to_socket_addrs().unwrap().collect();
+
+        assert_eq!(
+            socket_address,
+            vec![SocketAddr::V6(SocketAddrV6::new(address, 7410, 0, 0))]
+        );
+        assert!(!UdpLocator(locator).is_multicast());
+    }
+
+    #[test]
+    fn udp_locator_v6_multicast() {
+        let address = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0xffff, 0xefff, 0x0001);
+        let locator = Locator::new(LOCATOR_KIND_UDP_V6, 7400, address.octets());
+
+        assert!(UdpLocator(locator).is_multicast());
+    }
+}
@@ -2,10 +2,13 @@ use crate::{
     infrastructure::instance::InstanceHandle,
     xtypes::{
         deserializer::{DeserializeSequence, XTypesDeserializer},
-        dynamic_type::{DynamicType, MemberDescriptor},
+        dynamic_type::{DynamicType, ExtensibilityKind, MemberDescriptor},
         error::XTypesError,
-        serialize::{Write, XTypesSerializer},
-        serializer::SerializeFinalStruct,
+        serialize::{Write, XTypesSerialize, XTypesSerializer},
+        serializer::{
+            SerializeAppendableStruct, SerializeCollection, SerializeFinalStruct,
+            SerializeMutableStruct,
+        },
         type_object::TypeIdentifier,
         xcdr_deserializer::{
             Xcdr1BeDeserializer, Xcdr1LeDeserializer, Xcdr2BeDeserializer, Xcdr2LeDeserializer,
@@ -216,7 +219,7 @@ where
     Ok(())
 }
 
-fn go_to_pid_le(mut reader: &[u8], pid: u32) -> Result<&[u8], XTypesError> {
+pub(super) fn go_to_pid_le(mut reader: &[u8], pid: u32) -> Result<&[u8], XTypesError> {
     const PID_SENTINEL: u16 = 1;
     loop {
         let current_pid = u16::from_le_bytes([reader[0], reader[1]]);
@@ -231,7 +234,7 @@ fn go_to_pid_le(mut reader: &[u8], pid: u32) -> Result<&[u8], XTypesError> {
     }
 }
 
-fn go_to_pid_be(mut reader: &[u8], pid: u32) -> Result<&[u8], XTypesError> {
+pub(super) fn go_to_pid_be(mut reader: &[u8], pid: u32) -> Result<&[u8], XTypesError> {
     const PID_SENTINEL: u16 = 1;
     loop {
         let current_pid = u16::from_be_bytes([reader[0], reader[1]]);
@@ -309,15 +312,15 @@ fn push_to_key_parameter_list_be(
     Ok(())
 }
 
-type RepresentationIdentifier = [u8; 2];
-const CDR_BE: RepresentationIdentifier = [0x00, 0x00];
-const CDR_LE: RepresentationIdentifier = [0x00, 0x01];
-const CDR2_BE: RepresentationIdentifier = [0x00, 0x06];
-const CDR2_LE: RepresentationIdentifier = [0x00, 0x07];
+pub(super) type RepresentationIdentifier = [u8; 2];
+pub(super) const CDR_BE: RepresentationIdentifier = [0x00, 0x00];
+pub(super) const CDR_LE: RepresentationIdentifier = [0x00, 0x01];
+pub(super) const CDR2_BE: RepresentationIdentifier = [0x00, 0x06];
+pub(super) const CDR2_LE: RepresentationIdentifier = [0x00, 0x07];
 const _D_CDR2_BE: RepresentationIdentifier = [0x00, 0x08];
 const _D_CDR2_LE: RepresentationIdentifier = [0x00, 0x09];
-const PL_CDR_BE: RepresentationIdentifier = [0x00, 0x02];
-const PL_CDR_LE: RepresentationIdentifier = [0x00, 0x03];
+pub(super) const PL_CDR_BE: RepresentationIdentifier = [0x00, 0x02];
+pub(super) const PL_CDR_LE: RepresentationIdentifier = [0x00, 0x03];
 
 pub fn get_instance_handle_from_serialized_key(
     mut data: &[u8],
@@ -379,6 +382,13 @@ pub fn get_instance_handle_from_serialized_foo(
     Ok(InstanceHandle::new(md5_collection.into_key()))
 }
 
+fn pad(collection: &mut Vec<u8>) {
+    let padding_len = collection.len().div_ceil(4) * 4 - collection.len();
+    const ZEROS: [u8; 4] = [0; 4];
+    collection.extend_from_slice(&ZEROS[..padding_len]);
+    collection[3] |= padding_len as u8;
+}
+
 pub fn get_serialized_key_from_serialized_foo(
     mut data: &[u8],
     dynamic_type: &dyn DynamicType,
@@ -403,10 +413,306 @@ pub fn get_serialized_key_from_serialized_foo(
             _ => panic!("representation_identifier not supported"),
         }
     }
-    let padding_len = ((collection.len() + 4 - 1) / 4 * 4) - collection.len();
-    const ZEROS: [u8; 4] = [0; 4];
-    collection.extend_from_slice(&ZEROS[..padding_len]);
-    collection[3] |= padding_len as u8;
+    pad(&mut collection);
+    Ok(collection)
+}
+
+/// Value of a sample member read according to its type identifier
+enum DynamicValue<'t> {
+    Boolean(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Uint8(u8),
+    Uint16(u16),
+    Uint32(u32),
+    Uint64(u64),
+    Float32(f32),
+    Float64(f64),
+    Char8(char),
+    String(String),
+    Sequence(Vec<DynamicValue<'t>>),
+    Array(Vec<DynamicValue<'t>>),
+    Struct(&'t dyn DynamicType, Vec<DynamicValue<'t>>),
+}
+
+impl XTypesSerialize for DynamicValue<'_> {
+    fn serialize(&self, serializer: impl XTypesSerializer) -> Result<(), XTypesError> {
+        match self {
+            DynamicValue::Boolean(v) => serializer.serialize_boolean(*v),
+            DynamicValue::Int8(v) => serializer.serialize_int8(*v),
+            DynamicValue::Int16(v) => serializer.serialize_int16(*v),
+            DynamicValue::Int32(v) => serializer.serialize_int32(*v),
+            DynamicValue::Int64(v) => serializer.serialize_int64(*v),
+            DynamicValue::Uint8(v) => serializer.serialize_uint8(*v),
+            DynamicValue::Uint16(v) => serializer.serialize_uint16(*v),
+            DynamicValue::Uint32(v) => serializer.serialize_uint32(*v),
+            DynamicValue::Uint64(v) => serializer.serialize_uint64(*v),
+            DynamicValue::Float32(v) => serializer.serialize_float32(*v),
+            DynamicValue::Float64(v) => serializer.serialize_float64(*v),
+            DynamicValue::Char8(v) => serializer.serialize_char8(*v),
+            DynamicValue::String(v) => serializer.serialize_string(v),
+            DynamicValue::Sequence(element_list) => {
+                let mut s = serializer.serialize_sequence(element_list.len())?;
+                for element in element_list {
+                    s.serialize_element(element)?;
+                }
+                Ok(())
+            }
+            DynamicValue::Array(element_list) => {
+                let mut s = serializer.serialize_array()?;
+                for element in element_list {
+                    s.serialize_element(element)?;
+                }
+                Ok(())
+            }
+            DynamicValue::Struct(dynamic_type, member_list) => {
+                let descriptor_list = dynamic_type
+                    .into_iter()
+                    .collect::<Result<Vec<_>, XTypesError>>()?;
+                match dynamic_type.get_descriptor()?.extensibility_kind {
+                    ExtensibilityKind::Final => {
+                        let mut s = serializer.serialize_final_struct()?;
+                        for (descriptor, member) in descriptor_list.iter().zip(member_list) {
+                            s.serialize_field(member, &descriptor.name)?;
+                        }
+                        Ok(())
+                    }
+                    ExtensibilityKind::Appendable => {
+                        let mut s = serializer.serialize_appendable_struct()?;
+                        for (descriptor, member) in descriptor_list.iter().zip(member_list) {
+                            s.serialize_field(member, &descriptor.name)?;
+                        }
+                        Ok(())
+                    }
+                    ExtensibilityKind::Mutable => {
+                        let mut s = serializer.serialize_mutable_struct()?;
+                        for (descriptor, member) in descriptor_list.iter().zip(member_list) {
+                            s.serialize_field(member, descriptor.id, &descriptor.name)?;
+                        }
+                        s.end()
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn deserialize_dynamic_value<'a, 't, T>(
+    type_identifier: &'t TypeIdentifier,
+    de: &mut T,
+) -> Result<DynamicValue<'t>, XTypesError>
+where
+    for<'b> &'b mut T: XTypesDeserializer<'a>,
+{
+    Ok(match type_identifier {
+        TypeIdentifier::TkBoolean => DynamicValue::Boolean(de.deserialize_boolean()?),
+        TypeIdentifier::TkInt8Type => DynamicValue::Int8(de.deserialize_int8()?),
+        TypeIdentifier::TkInt16Type => DynamicValue::Int16(de.deserialize_int16()?),
+        TypeIdentifier::TkInt32Type => DynamicValue::Int32(de.deserialize_int32()?),
+        TypeIdentifier::TkInt64Type => DynamicValue::Int64(de.deserialize_int64()?),
+        TypeIdentifier::TkUint8Type => DynamicValue::Uint8(de.deserialize_uint8()?),
+        TypeIdentifier::TkUint16Type => DynamicValue::Uint16(de.deserialize_uint16()?),
+        TypeIdentifier::TkUint32Type => DynamicValue::Uint32(de.deserialize_uint32()?),
+        TypeIdentifier::TkUint64Type => DynamicValue::Uint64(de.deserialize_uint64()?),
+        TypeIdentifier::TkFloat32Type => DynamicValue::Float32(de.deserialize_float32()?),
+        TypeIdentifier::TkFloat64Type => DynamicValue::Float64(de.deserialize_float64()?),
+        TypeIdentifier::TkChar8Type => DynamicValue::Char8(de.deserialize_char8()?),
+        TypeIdentifier::TiString8Small { .. } | TypeIdentifier::TiString8Large { .. } => {
+            DynamicValue::String(de.deserialize_string()?.to_owned())
+        }
+        TypeIdentifier::TiPlainSequenceSmall { seq_sdefn } => {
+            let len = de.deserialize_sequence()?.len();
+            DynamicValue::Sequence(
+                (0..len)
+                    .map(|_| deserialize_dynamic_value(&seq_sdefn.element_identifier, de))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+        TypeIdentifier::TiPlainSequenceLarge { seq_ldefn } => {
+            let len = de.deserialize_sequence()?.len();
+            DynamicValue::Sequence(
+                (0..len)
+                    .map(|_| deserialize_dynamic_value(&seq_ldefn.element_identifier, de))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+        TypeIdentifier::TiPlainArraySmall { array_sdefn } => {
+            let len: u32 = array_sdefn
+                .array_bound_seq
+                .iter()
+                .map(|&b| b as u32)
+                .product();
+            DynamicValue::Array(
+                (0..len)
+                    .map(|_| deserialize_dynamic_value(&array_sdefn.element_identifier, de))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+        TypeIdentifier::TiPlainArrayLarge { array_ldefn } => {
+            let len: u32 = array_ldefn.array_bound_seq.iter().product();
+            DynamicValue::Array(
+                (0..len)
+                    .map(|_| deserialize_dynamic_value(&array_ldefn.element_identifier, de))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+        TypeIdentifier::EkComplete { complete } => {
+            let dynamic_type = complete.as_ref();
+            let mut member_list = Vec::new();
+            for member_descriptor in dynamic_type.into_iter() {
+                member_list.push(deserialize_dynamic_value(member_descriptor?.type_, de)?);
+            }
+            DynamicValue::Struct(dynamic_type, member_list)
+        }
+        _ => return Err(XTypesError::InvalidData),
+    })
+}
+
+// Reads a member of a sample taking the key fields from the serialized key instead. The
+// members are visited in the same way as when the key is extracted from a sample.
+fn merge_dynamic_value<'a, 'k, 't, T, K>(
+    type_identifier: &'t TypeIdentifier,
+    is_key_field: bool,
+    de: &mut T,
+    key_de: &mut K,
+) -> Result<DynamicValue<'t>, XTypesError>
+where
+    for<'b> &'b mut T: XTypesDeserializer<'a>,
+    for<'b> &'b mut K: XTypesDeserializer<'k>,
+{
+    match type_identifier {
+        TypeIdentifier::EkComplete { complete } => Ok(DynamicValue::Struct(
+            complete.as_ref(),
+            merge_member_list(complete.as_ref(), de, key_de)?,
+        )),
+        TypeIdentifier::TiPlainArraySmall { array_sdefn } => {
+            let len: u32 = array_sdefn
+                .array_bound_seq
+                .iter()
+                .map(|&b| b as u32)
+                .product();
+            Ok(DynamicValue::Array(
+                (0..len)
+                    .map(|_| {
+                        merge_dynamic_value(
+                            &array_sdefn.element_identifier,
+                            is_key_field,
+                            de,
+                            key_de,
+                        )
+                    })
+                    .collect::<Result<_, _>>()?,
+            ))
+        }
+        TypeIdentifier::TiPlainArrayLarge { array_ldefn } => {
+            let len: u32 = array_ldefn.array_bound_seq.iter().product();
+            Ok(DynamicValue::Array(
+                (0..len)
+                    .map(|_| {
+                        merge_dynamic_value(
+                            &array_ldefn.element_identifier,
+                            is_key_field,
+                            de,
+                            key_de,
+                        )
+                    })
+                    .collect::<Result<_, _>>()?,
+            ))
+        }
+        _ if is_key_field => {
+            deserialize_dynamic_value(type_identifier, de)?;
+            deserialize_dynamic_value(type_identifier, key_de)
+        }
+        _ => deserialize_dynamic_value(type_identifier, de),
+    }
+}
+
+fn merge_member_list<'a, 'k, 't, T, K>(
+    dynamic_type: &'t dyn DynamicType,
+    de: &mut T,
+    key_de: &mut K,
+) -> Result<Vec<DynamicValue<'t>>, XTypesError>
+where
+    for<'b> &'b mut T: XTypesDeserializer<'a>,
+    for<'b> &'b mut K: XTypesDeserializer<'k>,
+{
+    let mut member_list = Vec::new();
+    for member_descriptor in dynamic_type.into_iter() {
+        let member_descriptor = member_descriptor?;
+        member_list.push(merge_dynamic_value(
+            member_descriptor.type_,
+            member_descriptor.is_key,
+            de,
+            key_de,
+        )?);
+    }
+    Ok(member_list)
+}
+
+fn merge_parameter_list<'t>(
+    dynamic_type: &'t dyn DynamicType,
+    data: &[u8],
+    is_little_endian: bool,
+    key_de: &mut Xcdr1LeDeserializer,
+) -> Result<Vec<DynamicValue<'t>>, XTypesError> {
+    let mut member_list = Vec::new();
+    for descriptor in dynamic_type.into_iter() {
+        let descriptor = descriptor?;
+        // Only the key fields at the top level are part of the key of a mutable type
+        let is_key_field = descriptor.is_key;
+        let member = if is_little_endian {
+            let mut de = Xcdr1LeDeserializer::new(go_to_pid_le(data, descriptor.id)?);
+            if is_key_field {
+                merge_dynamic_value(descriptor.type_, true, &mut de, key_de)?
+            } else {
+                deserialize_dynamic_value(descriptor.type_, &mut de)?
+            }
+        } else {
+            let mut de = Xcdr1BeDeserializer::new(go_to_pid_be(data, descriptor.id)?);
+            if is_key_field {
+                merge_dynamic_value(descriptor.type_, true, &mut de, key_de)?
+            } else {
+                deserialize_dynamic_value(descriptor.type_, &mut de)?
+            }
+        };
+        member_list.push(member);
+    }
+    Ok(member_list)
+}
+
+/// Replaces the key fields of a serialized sample by the ones of a key obtained with
+/// [`get_serialized_key_from_serialized_foo`] leaving all the other fields untouched.
+/// The resulting sample is serialized using the XCDR1 little endian representation.
+pub fn set_serialized_key_in_serialized_foo(
+    mut data: &[u8],
+    serialized_key: &[u8],
+    dynamic_type: &dyn DynamicType,
+) -> Result<Vec<u8>, XTypesError> {
+    if data.len() < 4 || serialized_key.len() < 4 {
+        return Err(XTypesError::InvalidData);
+    }
+    let representation_identifier = [data[0], data[1]];
+    data.consume(4);
+    let key_de = &mut Xcdr1LeDeserializer::new(&serialized_key[4..]);
+
+    let member_list = match representation_identifier {
+        CDR_BE => merge_member_list(dynamic_type, &mut Xcdr1BeDeserializer::new(data), key_de)?,
+        CDR_LE => merge_member_list(dynamic_type, &mut Xcdr1LeDeserializer::new(data), key_de)?,
+        CDR2_BE => merge_member_list(dynamic_type, &mut Xcdr2BeDeserializer::new(data), key_de)?,
+        CDR2_LE => merge_member_list(dynamic_type, &mut Xcdr2LeDeserializer::new(data), key_de)?,
+        PL_CDR_BE => merge_parameter_list(dynamic_type, data, false, key_de)?,
+        PL_CDR_LE => merge_parameter_list(dynamic_type, data, true, key_de)?,
+        _ => return Err(XTypesError::InvalidData),
+    };
+
+    let mut collection = Vec::new();
+    collection.extend_from_slice(&CDR_LE);
+    collection.extend_from_slice(&[0, 0]);
+    DynamicValue::Struct(dynamic_type, member_list)
+        .serialize(&mut Xcdr1LeSerializer::new(&mut collection))?;
+    pad(&mut collection);
     Ok(collection)
 }
 
@@ -629,6 +935,61 @@ mod tests {
         )
     }
 
+    #[test]
+    fn set_key_in_serialized_foo_complex_be() {
+        let data = [
+            0, 0, 0, 0, //rtps header
+            0, 0, 0, 0, 0, 0, 0, 2, //field1 (i64)
+            0, 3, 0, 0, //key_field1 (u16) | padding (2B)
+            0, 0, 0, 4, //field2 (u32)
+            5, 6, //key_field2 (u8, u8)
+        ];
+        let key = [0, 1, 0, 0, 7, 0, 8, 9];
+        let expected_data = vec![
+            0, 1, 0, 2, //rtps header (CDR_LE) | padding (2B)
+            2, 0, 0, 0, 0, 0, 0, 0, //field1 (i64)
+            7, 0, 0, 0, //key_field1 (u16) | padding (2B)
+            4, 0, 0, 0, //field2 (u32)
+            8, 9, 0, 0, //key_field2 (u8, u8) | padding (2B)
+        ];
+        assert_eq!(
+            set_serialized_key_in_serialized_foo(&data, &key, &Complex::get_type()).unwrap(),
+            expected_data
+        );
+    }
+
+    #[test]
+    fn set_key_in_serialized_mutable_struct_le() {
+        let data = [
+            0, 3, 0, 0, //rtps header (PL_CDR_LE: version 1)
+            10, 0, 4, 0, // PID | length (CDR1: incl padding)
+            1, 0, 0, 0, //key_field1 (u8) | padding (3bytes)
+            20, 0, 4, 0, // PID | length (CDR1: incl padding)
+            7, 0, 0, 0, //field_inbetween (u32)
+            11, 0, 4, 0, // PID | length (CDR1: incl padding)
+            2, 0, 0, 0, //key_field2 (u16) | padding (2bytes)
+            1, 0, 0, 0, // Sentinel
+        ];
+        let key = [
+            0, 1, 0, 0, // RTPS header
+            3, 0, 4, 0, // key_field1 (u8) | padding (1byte) | key_field2 (u16)
+        ];
+        let expected_data = vec![
+            0, 1, 0, 0, //rtps header (CDR_LE)
+            10, 0, 1, 0, // PID | length
+            3, 0, 0, 0, //key_field1 (u8) | padding (3bytes)
+            20, 0, 4, 0, // PID | length
+            7, 0, 0, 0, //field_inbetween (u32)
+            11, 0, 2, 0, // PID | length
+            4, 0, 0, 0, //key_field2 (u16) | padding (2bytes)
+            1, 0, 0, 0, // Sentinel
+        ];
+        assert_eq!(
+            set_serialized_key_in_serialized_foo(&data, &key, &MutableStruct::get_type()).unwrap(),
+            expected_data
+        );
+    }
+
     #[derive(TypeSupport)]
     #[dust_dds(extensibility = "Final")]
     struct Large {
@@ -0,0 +1,494 @@
+use super::key_and_instance_handle::{
+    go_to_pid_be, go_to_pid_le, CDR2_BE, CDR2_LE, CDR_BE, CDR_LE, PL_CDR_BE, PL_CDR_LE,
+};
+use crate::{
+    infrastructure::error::DdsResult,
+    topic_definition::type_support::serialize_rtps_xtypes_xcdr1_le,
+    xtypes::{
+        deserializer::{DeserializeSequence, XTypesDeserializer},
+        dynamic_type::{DynamicType, ExtensibilityKind},
+        error::XTypesError,
+        serialize::XTypesSerialize,
+        serializer::{
+            SerializeAppendableStruct, SerializeFinalStruct, SerializeMutableStruct,
+            XTypesSerializer,
+        },
+        type_object::TypeIdentifier,
+        xcdr_deserializer::{
+            Xcdr1BeDeserializer, Xcdr1LeDeserializer, Xcdr2BeDeserializer, Xcdr2LeDeserializer,
+        },
+    },
+};
+use std::{collections::HashMap, io::BufRead};
+
+/// Value of a primitive or string member of a sample. Members of nested structures
+/// are identified by their dot separated path (e.g. "position.x").
+#[derive(Debug, Clone, PartialEq)]
+pub enum MemberValue {
+    Boolean(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Char(char),
+    String(String),
+}
+
+pub type MemberValues = HashMap<String, MemberValue>;
+
+fn deserialize_member_value<'a, T>(
+    type_identifier: &TypeIdentifier,
+    member_name: Option<&str>,
+    de: &mut T,
+    member_values: &mut MemberValues,
+) -> Result<(), XTypesError>
+where
+    for<'b> &'b mut T: XTypesDeserializer<'a>,
+{
+    let value = match type_identifier {
+        TypeIdentifier::TkBoolean => MemberValue::Boolean(de.deserialize_boolean()?),
+        TypeIdentifier::TkInt8Type => MemberValue::Int(de.deserialize_int8()? as i64),
+        TypeIdentifier::TkInt16Type => MemberValue::Int(de.deserialize_int16()? as i64),
+        TypeIdentifier::TkInt32Type => MemberValue::Int(de.deserialize_int32()? as i64),
+        TypeIdentifier::TkInt64Type => MemberValue::Int(de.deserialize_int64()?),
+        TypeIdentifier::TkUint8Type => MemberValue::UInt(de.deserialize_uint8()? as u64),
+        TypeIdentifier::TkUint16Type => MemberValue::UInt(de.deserialize_uint16()? as u64),
+        TypeIdentifier::TkUint32Type => MemberValue::UInt(de.deserialize_uint32()? as u64),
+        TypeIdentifier::TkUint64Type => MemberValue::UInt(de.deserialize_uint64()?),
+        TypeIdentifier::TkFloat32Type => MemberValue::Float(de.deserialize_float32()? as f64),
+        TypeIdentifier::TkFloat64Type => MemberValue::Float(de.deserialize_float64()?),
+        TypeIdentifier::TkChar8Type => MemberValue::Char(de.deserialize_char8()?),
+        TypeIdentifier::TiString8Small { .. } | TypeIdentifier::TiString8Large { .. } => {
+            MemberValue::String(de.deserialize_string()?.to_owned())
+        }
+        // Collection elements can not be addressed by a member name so they
+        // are only deserialized to advance the deserializer.
+        TypeIdentifier::TiPlainSequenceSmall { seq_sdefn } => {
+            let len = de.deserialize_sequence()?.len();
+            for _ in 0..len {
+                deserialize_member_value(&seq_sdefn.element_identifier, None, de, member_values)?;
+            }
+            return Ok(());
+        }
+        TypeIdentifier::TiPlainSequenceLarge { seq_ldefn } => {
+            let len = de.deserialize_sequence()?.len();
+            for _ in 0..len {
+                deserialize_member_value(&seq_ldefn.element_identifier, None, de, member_values)?;
+            }
+            return Ok(());
+        }
+        TypeIdentifier::TiPlainArraySmall { array_sdefn } => {
+            let len: u32 = array_sdefn
+                .array_bound_seq
+                .iter()
+                .map(|&b| b as u32)
+                .product();
+            for _ in 0..len {
+                deserialize_member_value(&array_sdefn.element_identifier, None, de, member_values)?;
+            }
+            return Ok(());
+        }
+        TypeIdentifier::TiPlainArrayLarge { array_ldefn } => {
+            let len: u32 = array_ldefn.array_bound_seq.iter().product();
+            for _ in 0..len {
+                deserialize_member_value(&array_ldefn.element_identifier, None, de, member_values)?;
+            }
+            return Ok(());
+        }
+        TypeIdentifier::EkComplete { complete } => {
+            return push_member_values(complete.as_ref(), member_name, de, member_values);
+        }
+        _ => return Err(XTypesError::InvalidData),
+    };
+    if let Some(name) = member_name {
+        member_values.insert(name.to_owned(), value);
+    }
+    Ok(())
+}
+
+fn member_path(prefix: Option<&str>, name: &str) -> Option<String> {
+    prefix.map(|p| {
+        if p.is_empty() {
+            name.to_owned()
+        } else {
+            format!("{}.{}", p, name)
+        }
+    })
+}
+
+fn push_member_values<'a, T>(
+    dynamic_type: &dyn DynamicType,
+    prefix: Option<&str>,
+    de: &mut T,
+    member_values: &mut MemberValues,
+) -> Result<(), XTypesError>
+where
+    for<'b> &'b mut T: XTypesDeserializer<'a>,
+{
+    for member_descriptor in dynamic_type.into_iter() {
+        let member_descriptor = member_descriptor?;
+        let path = member_path(prefix, &member_descriptor.name);
+        deserialize_member_value(member_descriptor.type_, path.as_deref(), de, member_values)?;
+    }
+    Ok(())
+}
+
+fn push_member_values_parameter_list(
+    dynamic_type: &dyn DynamicType,
+    data: &[u8],
+    is_little_endian: bool,
+    member_values: &mut MemberValues,
+) -> Result<(), XTypesError> {
+    for descriptor in dynamic_type.into_iter() {
+        let descriptor = descriptor?;
+        let buffer = if is_little_endian {
+            go_to_pid_le(data, descriptor.id)
+        } else {
+            go_to_pid_be(data, descriptor.id)
+        };
+        let buffer = match buffer {
+            Ok(b) => b,
+            // Optional members might not be present in the parameter list
+            Err(XTypesError::PidNotFound(_)) if descriptor.is_optional => continue,
+            Err(e) => return Err(e),
+        };
+        let path = member_path(Some(""), &descriptor.name);
+        if is_little_endian {
+            deserialize_member_value(
+                descriptor.type_,
+                path.as_deref(),
+                &mut Xcdr1LeDeserializer::new(buffer),
+                member_values,
+            )?;
+        } else {
+            deserialize_member_value(
+                descriptor.type_,
+                path.as_deref(),
+                &mut Xcdr1BeDeserializer::new(buffer),
+                member_values,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+pub fn get_member_values_from_serialized_foo(
+    mut data: &[u8],
+    dynamic_type: &dyn DynamicType,
+) -> Result<MemberValues, XTypesError> {
+    if data.len() < 4 {
+        return Err(XTypesError::InvalidData);
+    }
+    let mut member_values = MemberValues::new();
+    let representation_identifier = [data[0], data[1]];
+    data.consume(4);
+    let root = Some("");
+    match representation_identifier {
+        CDR_BE => push_member_values(
+            dynamic_type,
+            root,
+            &mut Xcdr1BeDeserializer::new(data),
+            &mut member_values,
+        )?,
+        CDR_LE => push_member_values(
+            dynamic_type,
+            root,
+            &mut Xcdr1LeDeserializer::new(data),
+            &mut member_values,
+        )?,
+        CDR2_BE => push_member_values(
+            dynamic_type,
+            root,
+            &mut Xcdr2BeDeserializer::new(data),
+            &mut member_values,
+        )?,
+        CDR2_LE => push_member_values(
+            dynamic_type,
+            root,
+            &mut Xcdr2LeDeserializer::new(data),
+            &mut member_values,
+        )?,
+        PL_CDR_BE => {
+            push_member_values_parameter_list(dynamic_type, data, false, &mut member_values)?
+        }
+        PL_CDR_LE => {
+            push_member_values_parameter_list(dynamic_type, data, true, &mut member_values)?
+        }
+        _ => return Err(XTypesError::InvalidData),
+    }
+    Ok(member_values)
+}
+
+/// Returns the dot separated paths of all the members of the type which can be used to
+/// identify a primitive or string value in a filter or query expression.
+pub fn get_member_paths(dynamic_type: &dyn DynamicType) -> Result<Vec<String>, XTypesError> {
+    fn push_member_paths(
+        dynamic_type: &dyn DynamicType,
+        prefix: &str,
+        paths: &mut Vec<String>,
+    ) -> Result<(), XTypesError> {
+        for member_descriptor in dynamic_type.into_iter() {
+            let member_descriptor = member_descriptor?;
+            let path = member_path(Some(prefix), &member_descriptor.name)
+                .expect("Prefix is always provided");
+            match member_descriptor.type_ {
+                TypeIdentifier::EkComplete { complete } => {
+                    push_member_paths(complete.as_ref(), &path, paths)?
+                }
+                _ => paths.push(path),
+            }
+        }
+        Ok(())
+    }
+    let mut paths = Vec::new();
+    push_member_paths(dynamic_type, "", &mut paths)?;
+    Ok(paths)
+}
+
+struct StructMemberValues<'a> {
+    dynamic_type: &'a dyn DynamicType,
+    prefix: &'a str,
+    member_values: &'a MemberValues,
+}
+
+impl XTypesSerialize for StructMemberValues<'_> {
+    fn serialize(&self, serializer: impl XTypesSerializer) -> Result<(), XTypesError> {
+        let member = |name: &str, type_identifier| MemberValueRef {
+            type_identifier,
+            path: member_path(Some(self.prefix), name).expect("Prefix is always provided"),
+            member_values: self.member_values,
+        };
+        match self.dynamic_type.get_descriptor()?.extensibility_kind {
+            ExtensibilityKind::Final => {
+                let mut s = serializer.serialize_final_struct()?;
+                for descriptor in self.dynamic_type.into_iter() {
+                    let descriptor = descriptor?;
+                    s.serialize_field(
+                        &member(&descriptor.name, descriptor.type_),
+                        &descriptor.name,
+                    )?;
+                }
+                Ok(())
+            }
+            ExtensibilityKind::Appendable => {
+                let mut s = serializer.serialize_appendable_struct()?;
+                for descriptor in self.dynamic_type.into_iter() {
+                    let descriptor = descriptor?;
+                    s.serialize_field(
+                        &member(&descriptor.name, descriptor.type_),
+                        &descriptor.name,
+                    )?;
+                }
+                Ok(())
+            }
+            ExtensibilityKind::Mutable => {
+                let mut s = serializer.serialize_mutable_struct()?;
+                for descriptor in self.dynamic_type.into_iter() {
+                    let descriptor = descriptor?;
+                    s.serialize_field(
+                        &member(&descriptor.name, descriptor.type_),
+                        descriptor.id,
+                        &descriptor.name,
+                    )?;
+                }
+                s.end()
+            }
+        }
+    }
+}
+
+struct MemberValueRef<'a> {
+    type_identifier: &'a TypeIdentifier,
+    path: String,
+    member_values: &'a MemberValues,
+}
+
+impl XTypesSerialize for MemberValueRef<'_> {
+    fn serialize(&self, serializer: impl XTypesSerializer) -> Result<(), XTypesError> {
+        if let TypeIdentifier::EkComplete { complete } = self.type_identifier {
+            return StructMemberValues {
+                dynamic_type: complete.as_ref(),
+                prefix: &self.path,
+                member_values: self.member_values,
+            }
+            .serialize(serializer);
+        }
+
+        fn int<T: TryFrom<i64> + TryFrom<u64>>(value: &MemberValue) -> Result<T, XTypesError> {
+            match value {
+                MemberValue::Int(v) => T::try_from(*v).map_err(|_| XTypesError::InvalidData),
+                MemberValue::UInt(v) => T::try_from(*v).map_err(|_| XTypesError::InvalidData),
+                _ => Err(XTypesError::InvalidData),
+            }
+        }
+        fn float(value: &MemberValue) -> Result<f64, XTypesError> {
+            match value {
+                MemberValue::Float(v) => Ok(*v),
+                MemberValue::Int(v) => Ok(*v as f64),
+                MemberValue::UInt(v) => Ok(*v as f64),
+                _ => Err(XTypesError::InvalidData),
+            }
+        }
+
+        let value = self
+            .member_values
+            .get(&self.path)
+            .ok_or(XTypesError::InvalidData)?;
+        match (self.type_identifier, value) {
+            (TypeIdentifier::TkBoolean, MemberValue::Boolean(v)) => {
+                serializer.serialize_boolean(*v)
+            }
+            (TypeIdentifier::TkInt8Type, v) => serializer.serialize_int8(int(v)?),
+            (TypeIdentifier::TkInt16Type, v) => serializer.serialize_int16(int(v)?),
+            (TypeIdentifier::TkInt32Type, v) => serializer.serialize_int32(int(v)?),
+            (TypeIdentifier::TkInt64Type, v) => serializer.serialize_int64(int(v)?),
+            (TypeIdentifier::TkUint8Type, v) => serializer.serialize_uint8(int(v)?),
+            (TypeIdentifier::TkUint16Type, v) => serializer.serialize_uint16(int(v)?),
+            (TypeIdentifier::TkUint32Type, v) => serializer.serialize_uint32(int(v)?),
+            (TypeIdentifier::TkUint64Type, v) => serializer.serialize_uint64(int(v)?),
+            (TypeIdentifier::TkFloat32Type, v) => serializer.serialize_float32(float(v)? as f32),
+            (TypeIdentifier::TkFloat64Type, v) => serializer.serialize_float64(float(v)?),
+            (TypeIdentifier::TkChar8Type, MemberValue::Char(v)) => serializer.serialize_char8(*v),
+            (
+                TypeIdentifier::TiString8Small { .. } | TypeIdentifier::TiString8Large { .. },
+                MemberValue::String(v),
+            ) => serializer.serialize_string(v),
+            _ => Err(XTypesError::InvalidData),
+        }
+    }
+}
+
+/// Serializes a sample of the given type using the values of its members. This is the
+/// inverse of [`get_member_values_from_serialized_foo`] and hence only types whose
+/// members are all primitives, strings or nested structures can be serialized.
+pub fn serialize_member_values(
+    member_values: &MemberValues,
+    dynamic_type: &dyn DynamicType,
+) -> DdsResult<Vec<u8>> {
+    serialize_rtps_xtypes_xcdr1_le(&StructMemberValues {
+        dynamic_type,
+        prefix: "",
+        member_values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topic_definition::type_support::TypeSupport;
+    use dust_dds_derive::TypeSupport;
+
+    #[derive(TypeSupport)]
+    #[dust_dds(extensibility = "Final")]
+    struct Position {
+        _x: i16,
+        _y: f32,
+    }
+
+    #[derive(TypeSupport)]
+    #[dust_dds(extensibility = "Final")]
+    struct Sensor {
+        #[dust_dds(key)]
+        _id: u8,
+        _readings: Vec<u8>,
+        _name: String,
+        _position: Position,
+    }
+
+    #[test]
+    fn member_values_from_final_struct_le() {
+        let data = [
+            0, 1, 0, 0, // rtps header (CDR_LE)
+            7, 0, 0, 0, // id (u8) | padding (3 bytes)
+            2, 0, 0, 0, // readings length
+            1, 2, 0, 0, // readings | padding (2 bytes)
+            4, 0, 0, 0, // name length
+            b'a', b'b', b'c', 0, // name
+            3, 0, 0, 0, // position.x (i16) | padding (2 bytes)
+            0, 0, 0x80, 0x3f, // position.y (f32)
+        ];
+        let member_values =
+            get_member_values_from_serialized_foo(&data, &Sensor::get_type()).unwrap();
+
+        assert_eq!(member_values.len(), 4);
+        assert_eq!(member_values["_id"], MemberValue::UInt(7));
+        assert_eq!(
+            member_values["_name"],
+            MemberValue::String("abc".to_string())
+        );
+        assert_eq!(member_values["_position._x"], MemberValue::Int(3));
+        assert_eq!(member_values["_position._y"], MemberValue::Float(1.0));
+    }
+
+    #[derive(TypeSupport)]
+    #[dust_dds(extensibility = "Mutable")]
+    struct MutableStruct {
+        #[dust_dds(key, id = 10)]
+        _key_field: u8,
+        #[dust_dds(id = 20)]
+        _value: i32,
+    }
+
+    #[test]
+    fn member_values_from_mutable_struct_be() {
+        let data = [
+            0, 2, 0, 0, // rtps header (PL_CDR_BE)
+            0, 20, 0, 4, // PID | length
+            0xff, 0xff, 0xff, 0xfe, // value (i32)
+            0, 10, 0, 4, // PID | length
+            1, 0, 0, 0, // key_field (u8) | padding (3 bytes)
+            0, 1, 0, 0, // Sentinel
+        ];
+        let member_values =
+            get_member_values_from_serialized_foo(&data, &MutableStruct::get_type()).unwrap();
+
+        assert_eq!(member_values["_key_field"], MemberValue::UInt(1));
+        assert_eq!(member_values["_value"], MemberValue::Int(-2));
+    }
+
+    #[derive(TypeSupport)]
+    #[dust_dds(extensibility = "Final")]
+    struct NamedPosition {
+        _name: String,
+        _position: Position,
+    }
+
+    #[test]
+    fn serialize_member_values_roundtrip() {
+        let member_values = MemberValues::from([
+            ("_name".to_string(), MemberValue::String("abc".to_string())),
+            ("_position._x".to_string(), MemberValue::UInt(3)),
+            ("_position._y".to_string(), MemberValue::Float(1.0)),
+        ]);
+        let data = serialize_member_values(&member_values, &NamedPosition::get_type()).unwrap();
+        assert_eq!(
+            data,
+            vec![
+                0, 1, 0, 0, // rtps header (CDR_LE)
+                4, 0, 0, 0, // name length
+                b'a', b'b', b'c', 0, // name
+                3, 0, 0, 0, // position.x (i16) | padding (2 bytes)
+                0, 0, 0x80, 0x3f, // position.y (f32)
+            ]
+        );
+        assert_eq!(
+            get_member_values_from_serialized_foo(&data, &NamedPosition::get_type()).unwrap(),
+            MemberValues::from([
+                ("_name".to_string(), MemberValue::String("abc".to_string())),
+                ("_position._x".to_string(), MemberValue::Int(3)),
+                ("_position._y".to_string(), MemberValue::Float(1.0)),
+            ])
+        );
+
+        let missing_member =
+            MemberValues::from([("_name".to_string(), MemberValue::String("abc".to_string()))]);
+        assert!(serialize_member_values(&missing_member, &NamedPosition::get_type()).is_err());
+    }
+
+    #[test]
+    fn member_paths_of_nested_struct() {
+        assert_eq!(
+            get_member_paths(&Sensor::get_type()).unwrap(),
+            vec!["_id", "_readings", "_name", "_position._x", "_position._y"]
+        );
+    }
+}
@@ -29,6 +29,12 @@ pub const ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER: EntityId =
 pub const ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR: EntityId =
     EntityId::new([0, 0, 0x04], BUILT_IN_READER_WITH_KEY);
 
+pub const ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER: EntityId =
+    EntityId::new([0x00, 0x02, 0x00], BUILT_IN_WRITER_WITH_KEY);
+
+pub const ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER: EntityId =
+    EntityId::new([0x00, 0x02, 0x00], BUILT_IN_READER_WITH_KEY);
+
 #[derive(PartialEq, Eq, Debug, Clone, Copy, XTypesSerialize, XTypesDeserialize)]
 pub struct BuiltinEndpointSet(pub u32);
 
@@ -41,6 +47,8 @@ impl Default for BuiltinEndpointSet {
                 | Self::BUILTIN_ENDPOINT_PUBLICATIONS_DETECTOR
                 | Self::BUILTIN_ENDPOINT_SUBSCRIPTIONS_ANNOUNCER
                 | Self::BUILTIN_ENDPOINT_SUBSCRIPTIONS_DETECTOR
+                | Self::BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_WRITER
+                | Self::BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_READER
                 | Self::BUILTIN_ENDPOINT_TOPICS_ANNOUNCER
                 | Self::BUILTIN_ENDPOINT_TOPICS_DETECTOR,
         )
@@ -67,8 +75,8 @@ impl BuiltinEndpointSet {
     @position(9) DISC_BUILTIN_ENDPOINT_PARTICIPANT_STATE_DETECTOR,
     */
 
-    pub const _BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_WRITER: u32 = 1 << 10;
-    pub const _BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_READER: u32 = 1 << 11;
+    pub const BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_WRITER: u32 = 1 << 10;
+    pub const BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_READER: u32 = 1 << 11;
 
     /*
     Bits 12-15 have been reserved by the DDS-Xtypes 1.2 Specification
@@ -243,7 +243,6 @@ pub fn write_into_bytes_vec(value: impl WriteIntoBytes) -> Vec<u8> {
     cursor.into_inner()
 }
 
-#[allow(dead_code)] // Only used as convenience in tests
 pub fn write_submessage_into_bytes_vec(value: &(dyn Submessage + Send)) -> Vec<u8> {
     let mut cursor = Cursor::new(Vec::new());
     value.write_submessage_into_bytes(&mut cursor);
@@ -42,6 +42,23 @@ impl HeartbeatMachine {
             self.count,
         )
     }
+    pub fn generate_new_liveliness_heartbeat(
+        &mut self,
+        writer_id: EntityId,
+        first_sn: SequenceNumber,
+        last_sn: SequenceNumber,
+    ) -> HeartbeatSubmessage {
+        self.count = self.count.wrapping_add(1);
+        HeartbeatSubmessage::new(
+            true,
+            true,
+            self.reader_id,
+            writer_id,
+            first_sn,
+            last_sn,
+            self.count,
+        )
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -134,6 +151,10 @@ impl RtpsReaderProxy {
         self.unicast_locator_list.as_slice()
     }
 
+    pub fn multicast_locator_list(&self) -> &[Locator] {
+        self.multicast_locator_list.as_slice()
+    }
+
     pub fn reliability(&self) -> ReliabilityKind {
         self.reliability
     }
@@ -310,7 +310,11 @@ impl Locator {
     }
 
     pub fn from_ip_and_port(ip_addr: &Addr, port: u32) -> Self {
-        match ip_addr.ip() {
+        Self::from_ip_addr_and_port(ip_addr.ip(), port)
+    }
+
+    pub fn from_ip_addr_and_port(ip_addr: IpAddr, port: u32) -> Self {
+        match ip_addr {
             IpAddr::V4(a) => Self {
                 kind: LOCATOR_KIND_UDP_V4,
                 port,
@@ -63,6 +63,10 @@ impl RtpsWriter {
         self.data_max_size_serialized
     }
 
+    pub fn next_sequence_number(&self) -> SequenceNumber {
+        self.last_change_sequence_number + 1
+    }
+
     pub fn new_change(
         &mut self,
         kind: ChangeKind,
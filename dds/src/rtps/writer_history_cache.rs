@@ -1,12 +1,15 @@
 use super::{
     behavior_types::InstanceHandle,
+    error::{RtpsError, RtpsErrorKind, RtpsResult},
     messages::{
         self,
+        overall_structure::{Endianness, TryReadFromBytes, WriteIntoBytes},
         submessage_elements::{Data, ParameterList},
         submessages::data::DataSubmessage,
     },
-    types::{ChangeKind, EntityId, Guid, SequenceNumber},
+    types::{ChangeKind, EntityId, Guid, GuidPrefix, SequenceNumber},
 };
+use std::io::{Read, Write};
 
 pub struct RtpsWriterCacheChange {
     kind: ChangeKind,
@@ -20,10 +23,23 @@ pub struct RtpsWriterCacheChange {
 
 impl RtpsWriterCacheChange {
     pub fn as_data_submessage(&self, reader_id: EntityId) -> DataSubmessage {
+        self.as_data_submessage_with_inline_qos(reader_id, self.inline_qos.clone())
+    }
+
+    pub fn as_data_submessage_with_inline_qos(
+        &self,
+        reader_id: EntityId,
+        inline_qos: ParameterList,
+    ) -> DataSubmessage {
         let (data_flag, key_flag) = match self.kind() {
-            ChangeKind::Alive => (true, false),
-            ChangeKind::NotAliveDisposed | ChangeKind::NotAliveUnregistered => (false, true),
-            _ => todo!(),
+            // Changes without data only carry inline QoS (e.g. the end of a coherent set)
+            ChangeKind::Alive | ChangeKind::AliveFiltered if self.data_value.is_empty() => {
+                (false, false)
+            }
+            ChangeKind::Alive | ChangeKind::AliveFiltered => (true, false),
+            ChangeKind::NotAliveDisposed
+            | ChangeKind::NotAliveUnregistered
+            | ChangeKind::NotAliveDisposedUnregistered => (false, true),
         };
 
         DataSubmessage::new(
@@ -34,7 +50,7 @@ impl RtpsWriterCacheChange {
             reader_id,
             self.writer_guid().entity_id(),
             self.sequence_number(),
-            self.inline_qos.clone(),
+            inline_qos,
             self.data_value.clone(),
         )
     }
@@ -91,3 +107,137 @@ impl RtpsWriterCacheChange {
         &self.inline_qos
     }
 }
+
+// The serialized change is used to keep the changes in a persistent storage.
+// It is not part of the RTPS protocol and always uses little endian.
+impl WriteIntoBytes for RtpsWriterCacheChange {
+    fn write_into_bytes(&self, buf: &mut dyn Write) {
+        let kind: u8 = match self.kind {
+            ChangeKind::Alive => 0,
+            ChangeKind::AliveFiltered => 1,
+            ChangeKind::NotAliveDisposed => 2,
+            ChangeKind::NotAliveUnregistered => 3,
+            ChangeKind::NotAliveDisposedUnregistered => 4,
+        };
+        [kind, 0, 0, 0].write_into_bytes(buf);
+        self.writer_guid.prefix().write_into_bytes(buf);
+        self.writer_guid.entity_id().write_into_bytes(buf);
+        self.sequence_number.write_into_bytes(buf);
+        self.instance_handle.0.write_into_bytes(buf);
+        self.timestamp.write_into_bytes(buf);
+        self.inline_qos.write_into_bytes(buf);
+        (self.data_value.len() as u32).write_into_bytes(buf);
+        self.data_value.write_into_bytes(buf);
+    }
+}
+
+impl TryReadFromBytes for RtpsWriterCacheChange {
+    fn try_read_from_bytes(data: &mut &[u8], endianness: &Endianness) -> RtpsResult<Self> {
+        let mut kind = [0; 4];
+        data.read_exact(&mut kind)?;
+        let kind = match kind[0] {
+            0 => ChangeKind::Alive,
+            1 => ChangeKind::AliveFiltered,
+            2 => ChangeKind::NotAliveDisposed,
+            3 => ChangeKind::NotAliveUnregistered,
+            4 => ChangeKind::NotAliveDisposedUnregistered,
+            _ => {
+                return Err(RtpsError::new(
+                    RtpsErrorKind::InvalidData,
+                    "Invalid change kind",
+                ))
+            }
+        };
+        let prefix = GuidPrefix::try_read_from_bytes(data, endianness)?;
+        let entity_id = EntityId::try_read_from_bytes(data, endianness)?;
+        let sequence_number = SequenceNumber::try_read_from_bytes(data, endianness)?;
+        let mut instance_handle = [0; 16];
+        data.read_exact(&mut instance_handle)?;
+        let seconds = u32::try_read_from_bytes(data, endianness)?;
+        let fraction = u32::try_read_from_bytes(data, endianness)?;
+        let inline_qos = ParameterList::try_read_from_bytes(data, endianness)?;
+        let length = u32::try_read_from_bytes(data, endianness)? as usize;
+        if data.len() < length {
+            return Err(RtpsError::new(
+                RtpsErrorKind::NotEnoughData,
+                "Available data for change less than length",
+            ));
+        }
+        let data_value = Data::from(data[..length].to_vec());
+        *data = &data[length..];
+
+        Ok(Self::new(
+            kind,
+            Guid::new(prefix, entity_id),
+            InstanceHandle(instance_handle),
+            sequence_number,
+            messages::types::Time::new(seconds, fraction),
+            data_value,
+            inline_qos,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtps::{
+        messages::submessage_elements::Parameter,
+        types::{ENTITYID_UNKNOWN, USER_DEFINED_WRITER_WITH_KEY},
+    };
+
+    #[test]
+    fn serialized_change_can_be_read_back() {
+        let change = RtpsWriterCacheChange::new(
+            ChangeKind::NotAliveDisposed,
+            Guid::new(
+                [3; 12],
+                EntityId::new([1, 2, 3], USER_DEFINED_WRITER_WITH_KEY),
+            ),
+            InstanceHandle([7; 16]),
+            5,
+            messages::types::Time::new(10, 20),
+            Data::from(vec![1, 2, 3, 4, 5]),
+            ParameterList::new(vec![Parameter::new(0x0071, vec![0, 0, 0, 1].into())]),
+        );
+        let mut bytes = Vec::new();
+        change.write_into_bytes(&mut bytes);
+        bytes.push(0xff);
+
+        let mut data = bytes.as_slice();
+        let read_change =
+            RtpsWriterCacheChange::try_read_from_bytes(&mut data, &Endianness::LittleEndian)
+                .unwrap();
+
+        assert_eq!(read_change.kind(), change.kind());
+        assert_eq!(read_change.writer_guid(), change.writer_guid());
+        assert_eq!(read_change.sequence_number(), change.sequence_number());
+        assert_eq!(read_change.instance_handle(), change.instance_handle());
+        assert_eq!(read_change.timestamp(), change.timestamp());
+        assert_eq!(read_change.data_value(), change.data_value());
+        assert_eq!(read_change.inline_qos(), change.inline_qos());
+        assert_eq!(data, &[0xff]);
+    }
+
+    #[test]
+    fn serialized_change_with_invalid_kind_is_rejected() {
+        let change = RtpsWriterCacheChange::new(
+            ChangeKind::Alive,
+            Guid::new([3; 12], ENTITYID_UNKNOWN),
+            InstanceHandle([7; 16]),
+            1,
+            messages::types::Time::new(0, 0),
+            Data::default(),
+            ParameterList::empty(),
+        );
+        let mut bytes = Vec::new();
+        change.write_into_bytes(&mut bytes);
+        bytes[0] = 10;
+
+        assert!(RtpsWriterCacheChange::try_read_from_bytes(
+            &mut bytes.as_slice(),
+            &Endianness::LittleEndian
+        )
+        .is_err());
+    }
+}
@@ -2,9 +2,16 @@ use dust_dds::{
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
         error::DdsError,
-        qos::{DataWriterQos, QosKind},
-        qos_policy::{HistoryQosPolicy, HistoryQosPolicyKind, Length, ResourceLimitsQosPolicy},
-        status::NO_STATUS,
+        instance::InstanceHandle,
+        qos::{DataReaderQos, DataWriterQos, QosKind},
+        qos_policy::{
+            HistoryQosPolicy, HistoryQosPolicyKind, Length, LivelinessQosPolicy,
+            LivelinessQosPolicyKind, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+            ResourceLimitsQosPolicy, RELIABILITY_QOS_POLICY_ID,
+        },
+        status::{StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
+        wait_set::{Condition, WaitSet},
     },
     topic_definition::type_support::DdsType,
 };
@@ -125,3 +132,254 @@ fn data_writer_write_more_than_max_samples_should_fail() {
     let result = data_writer.write(&data_instance3, None);
     assert_eq!(result, Err(DdsError::OutOfResources));
 }
+
+#[test]
+fn data_writer_write_with_handle() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let data_instance1 = KeyedData { id: 1, value: 0 };
+    let data_instance2 = KeyedData { id: 2, value: 0 };
+    let handle1 = data_writer.register_instance(&data_instance1).unwrap();
+    let handle2 = data_writer.register_instance(&data_instance2).unwrap();
+
+    assert_eq!(data_writer.write(&data_instance1, handle1), Ok(()));
+    assert!(matches!(
+        data_writer.write(&data_instance1, handle2),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+    assert_eq!(
+        data_writer.write(&data_instance1, Some(InstanceHandle::new([99; 16]))),
+        Err(DdsError::BadParameter)
+    );
+}
+
+#[test]
+fn data_writer_get_key_value() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let data_instance = KeyedData { id: 7, value: 3 };
+    let handle = data_writer
+        .register_instance(&data_instance)
+        .unwrap()
+        .unwrap();
+
+    let mut key_holder = KeyedData { id: 0, value: 0 };
+    data_writer.get_key_value(&mut key_holder, handle).unwrap();
+    assert_eq!(key_holder.id, data_instance.id);
+    assert_eq!(
+        data_writer.get_key_value(&mut key_holder, InstanceHandle::new([99; 16])),
+        Err(DdsError::BadParameter)
+    );
+}
+
+#[test]
+fn data_writer_get_key_value_only_fills_key_after_later_write() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let handle = data_writer
+        .register_instance(&KeyedData { id: 7, value: 3 })
+        .unwrap()
+        .unwrap();
+    data_writer
+        .write(&KeyedData { id: 7, value: 10 }, None)
+        .unwrap();
+
+    let mut key_holder = KeyedData { id: 0, value: 99 };
+    data_writer.get_key_value(&mut key_holder, handle).unwrap();
+    assert_eq!(key_holder, KeyedData { id: 7, value: 99 });
+}
+
+#[test]
+fn data_writer_unregister_instance_releases_handle() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let data_instance = KeyedData { id: 7, value: 3 };
+    let handle = data_writer
+        .register_instance(&data_instance)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        data_writer.lookup_instance(&data_instance).unwrap(),
+        Some(handle)
+    );
+
+    data_writer
+        .unregister_instance(&data_instance, Some(handle))
+        .unwrap();
+    assert_eq!(data_writer.lookup_instance(&data_instance).unwrap(), None);
+}
+
+#[test]
+fn data_writer_liveliness_lost_when_not_asserted() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let data_writer_qos = DataWriterQos {
+        liveliness: LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::ManualByTopic,
+            lease_duration: DurationKind::Finite(Duration::new(0, 200_000_000)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Specific(data_writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    data_writer.assert_liveliness().unwrap();
+    assert_eq!(
+        data_writer
+            .get_liveliness_lost_status()
+            .unwrap()
+            .total_count,
+        0
+    );
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::LivelinessLost])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(2, 0)).unwrap();
+
+    assert!(data_writer
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::LivelinessLost));
+    let status = data_writer.get_liveliness_lost_status().unwrap();
+    assert_eq!(status.total_count, 1);
+    assert_eq!(status.total_count_change, 1);
+    let status = data_writer.get_liveliness_lost_status().unwrap();
+    assert_eq!(status.total_count, 1);
+    assert_eq!(status.total_count_change, 0);
+    assert!(!data_writer
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::LivelinessLost));
+}
+
+#[test]
+fn data_writer_offered_incompatible_qos_status() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let data_writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Specific(data_writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let data_reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let _data_reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(data_reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::OfferedIncompatibleQos])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let status = data_writer.get_offered_incompatible_qos_status().unwrap();
+    assert_eq!(status.total_count, 1);
+    assert_eq!(status.total_count_change, 1);
+    assert_eq!(status.last_policy_id, RELIABILITY_QOS_POLICY_ID);
+    let status = data_writer.get_offered_incompatible_qos_status().unwrap();
+    assert_eq!(status.total_count_change, 0);
+    assert!(!data_writer
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::OfferedIncompatibleQos));
+}
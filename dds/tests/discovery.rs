@@ -1,19 +1,39 @@
-use std::time::Instant;
+use std::{
+    sync::{
+        atomic::{AtomicU32, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Instant,
+};
 
 use dust_dds::{
-    domain::domain_participant_factory::DomainParticipantFactory,
+    configuration::{DustDdsConfiguration, DustDdsConfigurationBuilder, IpVersionMode},
+    dds_async::{
+        domain_participant_factory::DomainParticipantFactoryAsync,
+        wait_set::{ConditionAsync, WaitSetAsync},
+    },
+    domain::domain_participant_factory::{DomainId, DomainParticipantFactory},
     infrastructure::{
+        error::{DdsError, DdsResult},
         qos::{DataReaderQos, DataWriterQos, PublisherQos, QosKind, SubscriberQos},
         qos_policy::{
             DataRepresentationQosPolicy, OwnershipQosPolicy, OwnershipQosPolicyKind,
-            PartitionQosPolicy, UserDataQosPolicy, XCDR2_DATA_REPRESENTATION,
-            XCDR_DATA_REPRESENTATION,
+            PartitionQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind, UserDataQosPolicy,
+            XCDR2_DATA_REPRESENTATION, XCDR_DATA_REPRESENTATION,
         },
         status::{StatusKind, NO_STATUS},
-        time::Duration,
+        time::{Duration, DurationKind},
         wait_set::{Condition, WaitSet},
     },
+    rtps::{
+        messages::overall_structure::RtpsMessageRead,
+        types::{Locator, LOCATOR_KIND_INVALID, LOCATOR_KIND_RESERVED, LOCATOR_KIND_UDP_V4},
+    },
+    subscription::sample_info::{
+        InstanceStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE,
+    },
     topic_definition::type_support::DdsType,
+    transport::{Transport, TransportFactory, TransportLocators, TransportReceiver},
 };
 
 mod utils;
@@ -477,6 +497,91 @@ fn publisher_and_subscriber_different_partition_not_matched() {
     assert!(wait_set.wait(Duration::new(5, 0)).is_err());
 }
 
+#[test]
+fn changing_partition_rematches_publisher_and_subscriber() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = dp
+        .create_topic::<UserType>("topic_name", "UserType", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let partition_a = PartitionQosPolicy {
+        name: vec!["A".to_string()],
+    };
+    let partition_b = PartitionQosPolicy {
+        name: vec!["B".to_string()],
+    };
+    let publisher = dp
+        .create_publisher(
+            QosKind::Specific(PublisherQos {
+                partition: partition_a.clone(),
+                ..Default::default()
+            }),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let subscriber = dp
+        .create_subscriber(
+            QosKind::Specific(SubscriberQos {
+                partition: partition_a,
+                ..Default::default()
+            }),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let wait_for_matched_count = |count: i32| {
+        let start_time = Instant::now();
+        loop {
+            if data_reader
+                .get_subscription_matched_status()
+                .unwrap()
+                .current_count
+                == count
+                && data_writer
+                    .get_publication_matched_status()
+                    .unwrap()
+                    .current_count
+                    == count
+            {
+                break;
+            }
+            if start_time.elapsed() > std::time::Duration::from_secs(10) {
+                panic!("Matched count not reached before timeout")
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    };
+
+    wait_for_matched_count(1);
+
+    publisher
+        .set_qos(QosKind::Specific(PublisherQos {
+            partition: partition_b.clone(),
+            ..Default::default()
+        }))
+        .unwrap();
+    wait_for_matched_count(0);
+
+    subscriber
+        .set_qos(QosKind::Specific(SubscriberQos {
+            partition: partition_b,
+            ..Default::default()
+        }))
+        .unwrap();
+    wait_for_matched_count(1);
+}
+
 #[test]
 fn publisher_and_subscriber_regex_partition_is_matched() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -984,3 +1089,637 @@ fn participant_removed_after_lease_duration() {
 
     assert_eq!(discovered_participant.len(), 1);
 }
+
+#[tokio::test]
+async fn remote_participant_is_removed_when_its_lease_expires() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let reader_participant_factory = DomainParticipantFactoryAsync::new();
+    let reader_participant = reader_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+
+    // The writer participant is not announced again before its lease expires
+    // which is what a crashed participant looks like for the remote participants
+    let writer_participant_factory = DomainParticipantFactoryAsync::new();
+    writer_participant_factory
+        .set_configuration(
+            DustDdsConfigurationBuilder::new()
+                .participant_announcement_interval(std::time::Duration::from_secs(100))
+                .participant_lease_duration(std::time::Duration::from_secs(3))
+                .build()
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let writer_participant = writer_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+
+    let reader_topic = reader_participant
+        .create_topic::<UserType>("topic_name", "UserType", QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let subscriber = reader_participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<UserType>(&reader_topic, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+
+    let writer_topic = writer_participant
+        .create_topic::<UserType>("topic_name", "UserType", QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let publisher = writer_participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<UserType>(&writer_topic, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+
+    // Setting the QoS announces the participants so that they discover each other
+    // before the lease of the writer participant expires
+    writer_participant.set_qos(QosKind::Default).await.unwrap();
+    reader_participant.set_qos(QosKind::Default).await.unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .await
+        .unwrap();
+    let mut wait_set = WaitSetAsync::new();
+    wait_set
+        .attach_condition(ConditionAsync::StatusCondition(cond))
+        .await
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).await.unwrap();
+    assert_eq!(
+        reader
+            .get_subscription_matched_status()
+            .await
+            .unwrap()
+            .current_count,
+        1
+    );
+    let writer_participant_handle = writer_participant.get_instance_handle().await.unwrap();
+    assert!(reader_participant
+        .get_discovered_participants()
+        .await
+        .unwrap()
+        .contains(&writer_participant_handle));
+
+    writer.write(&UserType(1), None).await.unwrap();
+
+    wait_set.wait(Duration::new(10, 0)).await.unwrap();
+    assert_eq!(
+        reader
+            .get_subscription_matched_status()
+            .await
+            .unwrap()
+            .current_count,
+        0
+    );
+    assert!(!reader_participant
+        .get_discovered_participants()
+        .await
+        .unwrap()
+        .contains(&writer_participant_handle));
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .await
+        .unwrap();
+    assert!(!samples.is_empty());
+    assert_eq!(
+        samples[0].sample_info().instance_state,
+        InstanceStateKind::NotAliveNoWriters
+    );
+}
+
+async fn write_and_read_sample_between_factories(
+    writer_configuration: DustDdsConfiguration,
+    reader_configuration: DustDdsConfiguration,
+) {
+    let writer_participant_factory = DomainParticipantFactoryAsync::new();
+    writer_participant_factory
+        .set_configuration(writer_configuration)
+        .await
+        .unwrap();
+    let reader_participant_factory = DomainParticipantFactoryAsync::new();
+    reader_participant_factory
+        .set_configuration(reader_configuration)
+        .await
+        .unwrap();
+
+    write_and_read_sample_between_participant_factories(
+        &writer_participant_factory,
+        &reader_participant_factory,
+        Vec::new(),
+    )
+    .await;
+}
+
+async fn write_and_read_sample_between_participant_factories(
+    writer_participant_factory: &DomainParticipantFactoryAsync,
+    reader_participant_factory: &DomainParticipantFactoryAsync,
+    transport_factory_list: Vec<(i32, Arc<dyn TransportFactory>)>,
+) {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let reader_participant = reader_participant_factory
+        .create_participant_with_transports(
+            domain_id,
+            QosKind::Default,
+            None,
+            NO_STATUS,
+            transport_factory_list.clone(),
+        )
+        .await
+        .unwrap();
+    let writer_participant = writer_participant_factory
+        .create_participant_with_transports(
+            domain_id,
+            QosKind::Default,
+            None,
+            NO_STATUS,
+            transport_factory_list,
+        )
+        .await
+        .unwrap();
+
+    let reader_topic = reader_participant
+        .create_topic::<UserType>("topic_name", "UserType", QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let subscriber = reader_participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<UserType>(
+            &reader_topic,
+            QosKind::Specific(reader_qos),
+            None,
+            NO_STATUS,
+        )
+        .await
+        .unwrap();
+
+    let writer_topic = writer_participant
+        .create_topic::<UserType>("topic_name", "UserType", QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let publisher = writer_participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<UserType>(&writer_topic, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .await
+        .unwrap();
+    let mut wait_set = WaitSetAsync::new();
+    wait_set
+        .attach_condition(ConditionAsync::StatusCondition(cond))
+        .await
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).await.unwrap();
+
+    writer.write(&UserType(8), None).await.unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .await
+        .unwrap();
+
+    let samples = reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .await
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap().0, 8);
+}
+
+#[tokio::test]
+async fn participants_communicate_over_ipv6_only() {
+    let configuration = DustDdsConfigurationBuilder::new()
+        .ip_version_mode(IpVersionMode::Ipv6Only)
+        .build()
+        .unwrap();
+
+    write_and_read_sample_between_factories(configuration.clone(), configuration).await;
+}
+
+#[tokio::test]
+async fn dual_stack_participant_communicates_with_ipv4_only_participant() {
+    let dual_stack_configuration = DustDdsConfigurationBuilder::new()
+        .ip_version_mode(IpVersionMode::DualStack)
+        .build()
+        .unwrap();
+    let ipv4_only_configuration = DustDdsConfigurationBuilder::new().build().unwrap();
+
+    write_and_read_sample_between_factories(dual_stack_configuration, ipv4_only_configuration)
+        .await;
+}
+
+#[tokio::test]
+async fn participants_are_discovered_by_unicast_from_initial_peers() {
+    // Only the writer participant knows its peer, the reader participant
+    // discovers it from its unicast announcement and announces itself back
+    let writer_configuration = DustDdsConfigurationBuilder::new()
+        .enable_multicast(false)
+        .initial_peers(vec!["127.0.0.1".parse().unwrap()])
+        .build()
+        .unwrap();
+    let reader_configuration = DustDdsConfigurationBuilder::new()
+        .enable_multicast(false)
+        .build()
+        .unwrap();
+
+    write_and_read_sample_between_factories(writer_configuration, reader_configuration).await;
+}
+
+const LOCATOR_KIND_IN_MEMORY: i32 = 0x0100_0000;
+
+struct InMemoryTransportBus {
+    locator_kind: i32,
+    max_datagram_size: usize,
+    next_port: AtomicU32,
+    sent_message_count: AtomicUsize,
+    largest_user_defined_datagram_size: AtomicUsize,
+    closed_transport_count: AtomicUsize,
+    receiver_list: Mutex<Vec<(Locator, bool, TransportReceiver)>>,
+}
+
+/// Transport delivering the messages of all the participants of the process through memory
+#[derive(Clone)]
+struct InMemoryTransport(Arc<InMemoryTransportBus>);
+
+impl InMemoryTransport {
+    fn new(locator_kind: i32) -> Self {
+        Self::with_max_datagram_size(locator_kind, 65507)
+    }
+
+    fn with_max_datagram_size(locator_kind: i32, max_datagram_size: usize) -> Self {
+        Self(Arc::new(InMemoryTransportBus {
+            locator_kind,
+            max_datagram_size,
+            next_port: AtomicU32::new(0),
+            sent_message_count: AtomicUsize::new(0),
+            largest_user_defined_datagram_size: AtomicUsize::new(0),
+            closed_transport_count: AtomicUsize::new(0),
+            receiver_list: Mutex::new(Vec::new()),
+        }))
+    }
+}
+
+impl Transport for InMemoryTransport {
+    fn send(&self, datagram: &[u8], locator: &Locator) {
+        self.0.sent_message_count.fetch_add(1, Ordering::Relaxed);
+        for (receiver_locator, is_metatraffic, transport_receiver) in
+            self.0.receiver_list.lock().unwrap().iter()
+        {
+            if receiver_locator == locator {
+                let rtps_message = RtpsMessageRead::try_from(datagram).unwrap();
+                if *is_metatraffic {
+                    transport_receiver.receive_metatraffic(rtps_message).ok();
+                } else {
+                    self.0
+                        .largest_user_defined_datagram_size
+                        .fetch_max(datagram.len(), Ordering::Relaxed);
+                    transport_receiver.receive_user_defined(rtps_message).ok();
+                }
+            }
+        }
+    }
+
+    fn max_datagram_size(&self) -> usize {
+        self.0.max_datagram_size
+    }
+
+    fn close(&self) {
+        self.0
+            .closed_transport_count
+            .fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl TransportFactory for InMemoryTransport {
+    fn create_transport(
+        &self,
+        domain_id: DomainId,
+        transport_receiver: TransportReceiver,
+    ) -> DdsResult<(Box<dyn Transport>, TransportLocators)> {
+        let locator_kind = self.0.locator_kind;
+        let port = self.0.next_port.fetch_add(2, Ordering::Relaxed) + 1;
+        let transport_locators = TransportLocators {
+            default_unicast_locator_list: vec![Locator::new(locator_kind, port, [0; 16])],
+            metatraffic_unicast_locator_list: vec![Locator::new(locator_kind, port + 1, [0; 16])],
+            metatraffic_multicast_locator_list: vec![Locator::new(
+                locator_kind,
+                0,
+                [domain_id as u8; 16],
+            )],
+        };
+        let mut receiver_list = self.0.receiver_list.lock().unwrap();
+        for &locator in &transport_locators.default_unicast_locator_list {
+            receiver_list.push((locator, false, transport_receiver.clone()));
+        }
+        for &locator in transport_locators
+            .metatraffic_unicast_locator_list
+            .iter()
+            .chain(&transport_locators.metatraffic_multicast_locator_list)
+        {
+            receiver_list.push((locator, true, transport_receiver.clone()));
+        }
+        Ok((Box::new(self.clone()), transport_locators))
+    }
+}
+
+struct FailingTransportFactory;
+
+impl TransportFactory for FailingTransportFactory {
+    fn create_transport(
+        &self,
+        _domain_id: DomainId,
+        _transport_receiver: TransportReceiver,
+    ) -> DdsResult<(Box<dyn Transport>, TransportLocators)> {
+        Err(DdsError::OutOfResources)
+    }
+}
+
+#[tokio::test]
+async fn participants_communicate_over_registered_transport() {
+    // Without multicast and initial peers the participants can only
+    // discover each other through the registered transport
+    let configuration = DustDdsConfigurationBuilder::new()
+        .enable_multicast(false)
+        .build()
+        .unwrap();
+    let transport = InMemoryTransport::new(LOCATOR_KIND_IN_MEMORY);
+
+    let writer_participant_factory = DomainParticipantFactoryAsync::new();
+    writer_participant_factory
+        .set_configuration(configuration.clone())
+        .await
+        .unwrap();
+    writer_participant_factory
+        .register_transport(LOCATOR_KIND_IN_MEMORY, Arc::new(transport.clone()))
+        .await
+        .unwrap();
+    let reader_participant_factory = DomainParticipantFactoryAsync::new();
+    reader_participant_factory
+        .set_configuration(configuration)
+        .await
+        .unwrap();
+    reader_participant_factory
+        .register_transport(LOCATOR_KIND_IN_MEMORY, Arc::new(transport.clone()))
+        .await
+        .unwrap();
+
+    write_and_read_sample_between_participant_factories(
+        &writer_participant_factory,
+        &reader_participant_factory,
+        Vec::new(),
+    )
+    .await;
+
+    assert!(transport.0.sent_message_count.load(Ordering::Relaxed) > 0);
+}
+
+#[tokio::test]
+async fn participants_communicate_over_transport_replacing_udp() {
+    // The UDPv4 locators are the only ones of the participants
+    let configuration = DustDdsConfigurationBuilder::new()
+        .ip_version_mode(IpVersionMode::Ipv4Only)
+        .enable_multicast(false)
+        .build()
+        .unwrap();
+    let participant_factory = DomainParticipantFactoryAsync::new();
+    participant_factory
+        .set_configuration(configuration)
+        .await
+        .unwrap();
+    let transport = InMemoryTransport::new(LOCATOR_KIND_UDP_V4);
+
+    write_and_read_sample_between_participant_factories(
+        &participant_factory,
+        &participant_factory,
+        vec![(LOCATOR_KIND_UDP_V4, Arc::new(transport.clone()))],
+    )
+    .await;
+
+    assert!(transport.0.sent_message_count.load(Ordering::Relaxed) > 0);
+}
+
+#[derive(DdsType)]
+struct LargeUserType(Vec<u8>);
+
+#[tokio::test]
+async fn samples_are_fragmented_to_fit_the_transport_datagrams() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let configuration = DustDdsConfigurationBuilder::new()
+        .ip_version_mode(IpVersionMode::Ipv4Only)
+        .enable_multicast(false)
+        .build()
+        .unwrap();
+    let participant_factory = DomainParticipantFactoryAsync::new();
+    participant_factory
+        .set_configuration(configuration)
+        .await
+        .unwrap();
+    let transport = InMemoryTransport::with_max_datagram_size(LOCATOR_KIND_UDP_V4, 512);
+
+    let reader_participant = participant_factory
+        .create_participant_with_transports(
+            domain_id,
+            QosKind::Default,
+            None,
+            NO_STATUS,
+            vec![(LOCATOR_KIND_UDP_V4, Arc::new(transport.clone()))],
+        )
+        .await
+        .unwrap();
+    let writer_participant = participant_factory
+        .create_participant_with_transports(
+            domain_id,
+            QosKind::Default,
+            None,
+            NO_STATUS,
+            vec![(LOCATOR_KIND_UDP_V4, Arc::new(transport.clone()))],
+        )
+        .await
+        .unwrap();
+
+    let reader_topic = reader_participant
+        .create_topic::<LargeUserType>(
+            "topic_name",
+            "LargeUserType",
+            QosKind::Default,
+            None,
+            NO_STATUS,
+        )
+        .await
+        .unwrap();
+    let subscriber = reader_participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<LargeUserType>(
+            &reader_topic,
+            QosKind::Specific(reader_qos),
+            None,
+            NO_STATUS,
+        )
+        .await
+        .unwrap();
+
+    let writer_topic = writer_participant
+        .create_topic::<LargeUserType>(
+            "topic_name",
+            "LargeUserType",
+            QosKind::Default,
+            None,
+            NO_STATUS,
+        )
+        .await
+        .unwrap();
+    let publisher = writer_participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<LargeUserType>(&writer_topic, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .await
+        .unwrap();
+    let mut wait_set = WaitSetAsync::new();
+    wait_set
+        .attach_condition(ConditionAsync::StatusCondition(cond))
+        .await
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).await.unwrap();
+
+    let data = vec![7; 4000];
+    writer
+        .write(&LargeUserType(data.clone()), None)
+        .await
+        .unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .await
+        .unwrap();
+
+    let samples = reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .await
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap().0, data);
+    let largest_datagram_size = transport
+        .0
+        .largest_user_defined_datagram_size
+        .load(Ordering::Relaxed);
+    assert!(largest_datagram_size > 0 && largest_datagram_size <= 512);
+}
+
+#[tokio::test]
+async fn transport_is_closed_when_participant_is_deleted() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactoryAsync::new();
+    let transport = InMemoryTransport::new(LOCATOR_KIND_IN_MEMORY);
+
+    let participant = participant_factory
+        .create_participant_with_transports(
+            domain_id,
+            QosKind::Default,
+            None,
+            NO_STATUS,
+            vec![(LOCATOR_KIND_IN_MEMORY, Arc::new(transport.clone()))],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        transport.0.closed_transport_count.load(Ordering::Relaxed),
+        0
+    );
+
+    participant_factory
+        .delete_participant(&participant)
+        .await
+        .unwrap();
+    assert_eq!(
+        transport.0.closed_transport_count.load(Ordering::Relaxed),
+        1
+    );
+}
+
+#[tokio::test]
+async fn transports_are_closed_when_participant_creation_fails() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactoryAsync::new();
+    let transport = InMemoryTransport::new(LOCATOR_KIND_IN_MEMORY);
+
+    // The transports are created in the order of their locator kind
+    let result = participant_factory
+        .create_participant_with_transports(
+            domain_id,
+            QosKind::Default,
+            None,
+            NO_STATUS,
+            vec![
+                (LOCATOR_KIND_IN_MEMORY, Arc::new(transport.clone())),
+                (
+                    LOCATOR_KIND_IN_MEMORY + 1,
+                    Arc::new(FailingTransportFactory),
+                ),
+            ],
+        )
+        .await;
+
+    assert!(matches!(result, Err(DdsError::OutOfResources)));
+    assert_eq!(
+        transport.0.closed_transport_count.load(Ordering::Relaxed),
+        1
+    );
+}
+
+#[tokio::test]
+async fn transport_cannot_be_registered_for_invalid_locator_kinds() {
+    let participant_factory = DomainParticipantFactoryAsync::new();
+    let transport = InMemoryTransport::new(LOCATOR_KIND_IN_MEMORY);
+
+    for locator_kind in [LOCATOR_KIND_INVALID, LOCATOR_KIND_RESERVED] {
+        assert_eq!(
+            participant_factory
+                .register_transport(locator_kind, Arc::new(transport.clone()))
+                .await,
+            Err(DdsError::BadParameter)
+        );
+    }
+}
@@ -394,6 +394,168 @@ fn allowed_to_delete_topic_with_created_and_deleted_reader() {
     assert_eq!(participant.delete_topic(&reader_topic), Ok(()));
 }
 
+#[test]
+fn create_delete_contentfilteredtopic() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>("MyTopic", "MyData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    assert_eq!(
+        participant
+            .create_contentfilteredtopic(
+                "FilteredTopic",
+                &topic,
+                "unknown_member = 1".to_string(),
+                &[],
+            )
+            .err(),
+        Some(DdsError::BadParameter)
+    );
+    assert_eq!(
+        participant
+            .create_contentfilteredtopic("FilteredTopic", &topic, "value > %0".to_string(), &[])
+            .err(),
+        Some(DdsError::BadParameter)
+    );
+
+    let content_filtered_topic = participant
+        .create_contentfilteredtopic(
+            "FilteredTopic",
+            &topic,
+            "value > %0".to_string(),
+            &["1".to_string()],
+        )
+        .unwrap();
+    assert_eq!(content_filtered_topic.get_name(), "FilteredTopic");
+    assert_eq!(content_filtered_topic.get_type_name(), "MyData");
+    assert_eq!(content_filtered_topic.get_filter_expression(), "value > %0");
+    assert_eq!(content_filtered_topic.get_related_topic().get_name(), "MyTopic");
+
+    assert_eq!(
+        participant.delete_topic(&topic),
+        Err(DdsError::PreconditionNotMet(
+            "Topic still related to some content filtered topic".to_string()
+        ))
+    );
+    assert_eq!(
+        participant.delete_contentfilteredtopic(&content_filtered_topic),
+        Ok(())
+    );
+    assert_eq!(participant.delete_topic(&topic), Ok(()));
+}
+
+#[test]
+fn not_allowed_to_delete_contentfilteredtopic_attached_to_reader() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>("MyTopic", "MyData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let content_filtered_topic = participant
+        .create_contentfilteredtopic("FilteredTopic", &topic, "value > 1".to_string(), &[])
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<MyData>(&content_filtered_topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    assert_eq!(
+        participant.delete_contentfilteredtopic(&content_filtered_topic),
+        Err(DdsError::PreconditionNotMet(
+            "Content filtered topic still attached to some data reader".to_string()
+        ))
+    );
+
+    subscriber.delete_datareader(&reader).unwrap();
+    assert_eq!(
+        participant.delete_contentfilteredtopic(&content_filtered_topic),
+        Ok(())
+    );
+}
+
+#[test]
+fn create_delete_multitopic() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>("MyTopic", "MyData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    assert_eq!(
+        participant
+            .create_multitopic::<MyData>(
+                "MultiTopic",
+                "MyData",
+                "SELECT * FROM UnknownTopic".to_string(),
+                &[],
+            )
+            .err(),
+        Some(DdsError::PreconditionNotMet(
+            "Related topic UnknownTopic does not exist".to_string()
+        ))
+    );
+    assert_eq!(
+        participant
+            .create_multitopic::<MyData>(
+                "MultiTopic",
+                "MyData",
+                "SELECT * FROM MyTopic WHERE value > %0".to_string(),
+                &[],
+            )
+            .err(),
+        Some(DdsError::BadParameter)
+    );
+
+    let multi_topic = participant
+        .create_multitopic::<MyData>(
+            "MultiTopic",
+            "MyData",
+            "SELECT * FROM MyTopic WHERE value > %0".to_string(),
+            &["1".to_string()],
+        )
+        .unwrap();
+    assert_eq!(multi_topic.get_name(), "MultiTopic");
+    assert_eq!(multi_topic.get_type_name(), "MyData");
+    assert_eq!(
+        multi_topic.get_subscription_expression(),
+        "SELECT * FROM MyTopic WHERE value > %0"
+    );
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<MyData>(&multi_topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    assert_eq!(
+        participant.delete_topic(&topic),
+        Err(DdsError::PreconditionNotMet(
+            "Topic still related to some multi topic".to_string()
+        ))
+    );
+    assert_eq!(
+        participant.delete_multitopic(&multi_topic),
+        Err(DdsError::PreconditionNotMet(
+            "Multi topic still attached to some data reader".to_string()
+        ))
+    );
+
+    subscriber.delete_datareader(&reader).unwrap();
+    assert_eq!(participant.delete_multitopic(&multi_topic), Ok(()));
+    assert_eq!(participant.delete_topic(&topic), Ok(()));
+}
+
 #[test]
 fn default_publisher_qos() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -845,6 +1007,186 @@ fn ignore_subscription() {
     assert!(wait_set.wait(Duration::new(2, 0)).is_err());
 }
 
+#[test]
+fn ignore_topic() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>("MyTopic", "MyData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let discovered_topic_handle = || {
+        participant
+            .get_discovered_topics()
+            .unwrap()
+            .into_iter()
+            .find(|&handle| {
+                participant
+                    .get_discovered_topic_data(handle)
+                    .map(|t| t.name() == "MyTopic")
+                    .unwrap_or(false)
+            })
+    };
+    let start = std::time::Instant::now();
+    let topic_handle = loop {
+        if let Some(handle) = discovered_topic_handle() {
+            break handle;
+        }
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    };
+
+    participant.ignore_topic(topic_handle).unwrap();
+    assert_eq!(discovered_topic_handle(), None);
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    // Endpoints of an ignored topic should never match
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    assert!(wait_set.wait(Duration::new(2, 0)).is_err());
+    assert_eq!(discovered_topic_handle(), None);
+    assert_eq!(
+        reader
+            .get_subscription_matched_status()
+            .unwrap()
+            .total_count,
+        0
+    );
+}
+
+#[test]
+fn ignore_topic_unmatches_matched_endpoints() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>("MyTopic", "MyData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let topic_handle = participant
+        .get_discovered_topics()
+        .unwrap()
+        .into_iter()
+        .find(|&handle| {
+            participant
+                .get_discovered_topic_data(handle)
+                .map(|t| t.name() == "MyTopic")
+                .unwrap_or(false)
+        })
+        .unwrap();
+    participant.ignore_topic(topic_handle).unwrap();
+
+    let start = std::time::Instant::now();
+    while writer
+        .get_publication_matched_status()
+        .unwrap()
+        .current_count
+        != 0
+        || reader
+            .get_subscription_matched_status()
+            .unwrap()
+            .current_count
+            != 0
+    {
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn contains_entity() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let other_participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>("MyTopic", "MyData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let other_publisher = other_participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    for handle in [
+        topic.get_instance_handle().unwrap(),
+        publisher.get_instance_handle().unwrap(),
+        writer.get_instance_handle().unwrap(),
+        subscriber.get_instance_handle().unwrap(),
+        reader.get_instance_handle().unwrap(),
+    ] {
+        assert_eq!(participant.contains_entity(handle), Ok(true));
+    }
+    assert_eq!(
+        participant.contains_entity(other_publisher.get_instance_handle().unwrap()),
+        Ok(false)
+    );
+    assert_eq!(
+        participant.contains_entity(participant.get_instance_handle().unwrap()),
+        Ok(false)
+    );
+}
+
 #[test]
 #[ignore = "Test is flaky. Needs to be investigated"]
 fn ignore_participant() {
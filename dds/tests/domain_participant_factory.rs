@@ -1,4 +1,8 @@
+use std::net::{Ipv4Addr, UdpSocket};
+
 use dust_dds::{
+    configuration::DustDdsConfigurationBuilder,
+    dds_async::domain_participant_factory::DomainParticipantFactoryAsync,
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
         qos::{DomainParticipantQos, QosKind},
@@ -117,3 +121,62 @@ fn allowed_to_delete_participant_after_delete_contained_entities() {
         .delete_participant(&participant)
         .is_ok());
 }
+
+#[tokio::test]
+async fn participants_use_well_known_unicast_ports() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactoryAsync::new();
+    let _participant0 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let _participant1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+
+    // RTPS 9.6.1.1: PB + DG * domain_id + d1 + PG * participant_id for the metatraffic
+    // and PB + DG * domain_id + d3 + PG * participant_id for the user-defined traffic
+    let port_base = 7400 + 250 * domain_id as u16;
+    for participant_id in 0..2 {
+        let metatraffic_unicast_port = port_base + 10 + 2 * participant_id;
+        let user_unicast_port = port_base + 11 + 2 * participant_id;
+        assert!(UdpSocket::bind((Ipv4Addr::UNSPECIFIED, metatraffic_unicast_port)).is_err());
+        assert!(UdpSocket::bind((Ipv4Addr::UNSPECIFIED, user_unicast_port)).is_err());
+    }
+}
+
+#[tokio::test]
+async fn participant_with_configured_participant_id() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactoryAsync::new();
+    domain_participant_factory
+        .set_configuration(
+            DustDdsConfigurationBuilder::new()
+                .participant_id(Some(3))
+                .build()
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let _participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+
+    let metatraffic_unicast_port = 7400 + 250 * domain_id as u16 + 10 + 2 * 3;
+    assert!(UdpSocket::bind((Ipv4Addr::UNSPECIFIED, metatraffic_unicast_port)).is_err());
+    // The ports of the configured participant id are already in use
+    assert!(domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .await
+        .is_err());
+}
+
+#[test]
+fn participant_id_out_of_range_is_rejected() {
+    assert!(DustDdsConfigurationBuilder::new()
+        .participant_id(Some(120))
+        .build()
+        .is_err());
+}
@@ -1,12 +1,14 @@
 use dust_dds::{
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
+        error::DdsError,
         qos::{DataReaderQos, QosKind},
-        qos_policy::UserDataQosPolicy,
+        qos_policy::{TransportMulticastQosPolicy, TransportMulticastSettings, UserDataQosPolicy},
         status::NO_STATUS,
     },
     topic_definition::type_support::DdsType,
 };
+use std::net::{IpAddr, Ipv4Addr};
 
 mod utils;
 use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
@@ -164,3 +166,61 @@ fn data_reader_get_topicdescription_should_return_same_topic_as_used_for_creatio
             == topic.get_instance_handle().unwrap()
     );
 }
+
+#[test]
+fn end_access_requires_matching_begin() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    assert!(matches!(
+        subscriber.end_access(),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+
+    subscriber.begin_access().unwrap();
+    subscriber.begin_access().unwrap();
+    assert_eq!(subscriber.end_access(), Ok(()));
+    assert_eq!(subscriber.end_access(), Ok(()));
+    assert!(matches!(
+        subscriber.end_access(),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+}
+
+#[test]
+fn create_datareader_with_unicast_transport_multicast_address_fails() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<UserType>("Topic", "UserType", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let qos = DataReaderQos {
+        transport_multicast: TransportMulticastQosPolicy {
+            value: vec![TransportMulticastSettings {
+                receive_address: IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+                receive_port: None,
+            }],
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        subscriber
+            .create_datareader::<UserType>(&topic, QosKind::Specific(qos), None, NO_STATUS)
+            .err(),
+        Some(DdsError::BadParameter)
+    );
+}
@@ -2,12 +2,17 @@ mod utils;
 use dust_dds::{
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
+        condition::{Entity, GuardCondition},
+        error::DdsError,
         qos::{DataReaderQos, DataWriterQos, QosKind},
         qos_policy::{DeadlineQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind},
         status::{StatusKind, NO_STATUS},
         time::{Duration, DurationKind},
         wait_set::{Condition, WaitSet},
     },
+    subscription::sample_info::{
+        SampleStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE,
+    },
     topic_definition::type_support::DdsType,
 };
 
@@ -96,3 +101,302 @@ fn writer_offered_deadline_missed_waitset() {
     assert_eq!(status.total_count, 1);
     assert_eq!(status.total_count_change, 1);
 }
+
+#[test]
+fn read_condition_waitset() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>("MyTopic", "MyData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let read_condition = reader
+        .create_readcondition(
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
+        .unwrap();
+    assert_eq!(read_condition.get_trigger_value(), Ok(false));
+
+    writer.write(&MyData { id: 1, value: 1 }, None).unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::ReadCondition(read_condition.clone()))
+        .unwrap();
+    let triggered = wait_set.wait(Duration::new(10, 0)).unwrap();
+    assert_eq!(triggered.len(), 1);
+
+    let samples = reader.read_w_condition(10, &read_condition).unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), MyData { id: 1, value: 1 });
+    assert_eq!(read_condition.get_trigger_value(), Ok(false));
+    assert_eq!(
+        reader.read_w_condition(10, &read_condition),
+        Err(DdsError::NoData)
+    );
+
+    reader.delete_readcondition(&read_condition).unwrap();
+    assert!(matches!(
+        reader.read_w_condition(10, &read_condition),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+}
+
+#[test]
+fn query_condition_read_and_take() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>("MyTopic", "MyData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    assert!(matches!(
+        reader.create_querycondition(
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+            "unknown_member > 40".to_string(),
+            &[],
+        ),
+        Err(DdsError::BadParameter)
+    ));
+
+    let query_condition = reader
+        .create_querycondition(
+            ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+            "value > %0".to_string(),
+            &["40".to_string()],
+        )
+        .unwrap();
+    assert_eq!(query_condition.get_query_expression(), "value > %0");
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    writer.write(&MyData { id: 1, value: 10 }, None).unwrap();
+    writer.write(&MyData { id: 2, value: 50 }, None).unwrap();
+    writer.write(&MyData { id: 3, value: 60 }, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::QueryCondition(query_condition.clone()))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let samples = reader.read_w_condition(10, &query_condition).unwrap();
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].data().unwrap(), MyData { id: 2, value: 50 });
+    assert_eq!(samples[1].data().unwrap(), MyData { id: 3, value: 60 });
+
+    query_condition
+        .set_query_parameters(&["55".to_string()])
+        .unwrap();
+    assert_eq!(
+        query_condition.get_query_parameters(),
+        Ok(vec!["55".to_string()])
+    );
+    let samples = reader.take_w_condition(10, &query_condition).unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), MyData { id: 3, value: 60 });
+
+    let remaining = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(remaining.len(), 2);
+}
+
+#[test]
+fn guard_condition_wakes_up_waiting_thread() {
+    let guard_condition = GuardCondition::new();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::GuardCondition(guard_condition.clone()))
+        .unwrap();
+
+    assert_eq!(
+        wait_set.wait(Duration::new(0, 100_000_000)).err(),
+        Some(DdsError::Timeout)
+    );
+
+    let start = std::time::Instant::now();
+    let trigger_thread = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        guard_condition.set_trigger_value(true).unwrap();
+    });
+    let triggered_conditions = wait_set.wait(Duration::new(10, 0)).unwrap();
+    trigger_thread.join().unwrap();
+
+    assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    assert_eq!(triggered_conditions.len(), 1);
+    assert!(matches!(
+        &triggered_conditions[0],
+        Condition::GuardCondition(c) if c.get_trigger_value() == Ok(true)
+    ));
+}
+
+#[test]
+fn detach_condition_removes_it_while_waiting() {
+    let detached_condition = GuardCondition::new();
+    let attached_condition = GuardCondition::new();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::GuardCondition(detached_condition.clone()))
+        .unwrap();
+    wait_set
+        .attach_condition(Condition::GuardCondition(attached_condition.clone()))
+        .unwrap();
+    let wait_set = std::sync::Arc::new(wait_set);
+
+    let waiting_wait_set = wait_set.clone();
+    let wait_thread = std::thread::spawn(move || waiting_wait_set.wait(Duration::new(10, 0)));
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    wait_set
+        .detach_condition(Condition::GuardCondition(detached_condition.clone()))
+        .unwrap();
+    detached_condition.set_trigger_value(true).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    attached_condition.set_trigger_value(true).unwrap();
+
+    let triggered_conditions = wait_thread.join().unwrap().unwrap();
+    assert_eq!(triggered_conditions.len(), 1);
+    assert_eq!(wait_set.get_conditions().unwrap().len(), 1);
+    assert!(matches!(
+        wait_set.detach_condition(Condition::GuardCondition(detached_condition)),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+}
+
+#[test]
+fn status_condition_get_entity_returns_owning_entity() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>("MyTopic", "MyData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    assert_eq!(
+        participant.get_statuscondition().get_entity(),
+        Ok(Entity::DomainParticipant(
+            participant.get_instance_handle().unwrap()
+        ))
+    );
+    assert_eq!(
+        topic.get_statuscondition().get_entity(),
+        Ok(Entity::Topic(topic.get_instance_handle().unwrap()))
+    );
+    assert_eq!(
+        publisher.get_statuscondition().get_entity(),
+        Ok(Entity::Publisher(publisher.get_instance_handle().unwrap()))
+    );
+    assert_eq!(
+        writer.get_statuscondition().get_entity(),
+        Ok(Entity::DataWriter(writer.get_instance_handle().unwrap()))
+    );
+    assert_eq!(
+        subscriber.get_statuscondition().get_entity(),
+        Ok(Entity::Subscriber(subscriber.get_instance_handle().unwrap()))
+    );
+    assert_eq!(
+        reader.get_statuscondition().get_entity(),
+        Ok(Entity::DataReader(reader.get_instance_handle().unwrap()))
+    );
+}
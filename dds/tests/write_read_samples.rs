@@ -1,25 +1,43 @@
 use dust_dds::{
-    domain::domain_participant_factory::DomainParticipantFactory,
+    dds_async::{
+        domain_participant_factory::DomainParticipantFactoryAsync,
+        wait_set::{ConditionAsync, WaitSetAsync},
+    },
+    domain::domain_participant_factory::{DomainId, DomainParticipantFactory},
     infrastructure::{
-        error::DdsError,
+        error::{DdsError, DdsResult},
         instance::InstanceHandle,
-        qos::{DataReaderQos, DataWriterQos, QosKind, TopicQos},
+        qos::{DataReaderQos, DataWriterQos, PublisherQos, QosKind, SubscriberQos, TopicQos},
         qos_policy::{
             DeadlineQosPolicy, DestinationOrderQosPolicy, DestinationOrderQosPolicyKind,
-            DurabilityQosPolicy, DurabilityQosPolicyKind, HistoryQosPolicy, HistoryQosPolicyKind,
-            Length, LifespanQosPolicy, OwnershipQosPolicy, OwnershipQosPolicyKind,
-            OwnershipStrengthQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
-            ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, WriterDataLifecycleQosPolicy,
+            DurabilityQosPolicy, DurabilityQosPolicyKind, DurabilityServiceQosPolicy,
+            HistoryQosPolicy, HistoryQosPolicyKind, Length, LifespanQosPolicy, LivelinessQosPolicy,
+            LivelinessQosPolicyKind, OwnershipQosPolicy, OwnershipQosPolicyKind,
+            OwnershipStrengthQosPolicy, PresentationQosPolicy,
+            PresentationQosPolicyAccessScopeKind, ReaderDataLifecycleQosPolicy,
+            ReliabilityQosPolicy, ReliabilityQosPolicyKind, ResourceLimitsQosPolicy,
+            TimeBasedFilterQosPolicy, TransportMulticastQosPolicy, TransportMulticastSettings,
+            WriterDataLifecycleQosPolicy,
         },
         status::{StatusKind, NO_STATUS},
         time::{Duration, DurationKind, Time},
         wait_set::{Condition, WaitSet},
     },
+    persistent_storage::{AppendOnlyLogStorage, PersistentStorage},
+    rtps::{
+        messages::overall_structure::{RtpsMessageRead, RtpsSubmessageReadKind},
+        types::{EntityId, Locator},
+    },
     subscription::sample_info::{
         InstanceStateKind, SampleStateKind, ViewStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE,
         ANY_VIEW_STATE,
     },
-    topic_definition::type_support::DdsType,
+    topic_definition::type_support::{DdsDeserialize, DdsType},
+    transport::{Transport, TransportFactory, TransportLocators, TransportReceiver},
+};
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    sync::{Arc, Mutex},
 };
 
 mod utils;
@@ -42,6 +60,13 @@ struct LargeData {
     value: Vec<u8>,
 }
 
+#[derive(Debug, PartialEq, DdsType)]
+struct LargeKeyData {
+    #[dust_dds(key)]
+    id: Vec<u8>,
+    value: u8,
+}
+
 #[test]
 fn large_data_should_be_fragmented() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -1338,6 +1363,327 @@ fn take_specific_unknown_instance() {
     );
 }
 
+#[test]
+fn reader_lookup_instance_and_get_key_value() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 10 };
+
+    assert_eq!(reader.lookup_instance(&data1), Ok(None));
+
+    writer.write(&data1, None).unwrap();
+    writer.write(&data2, None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let data2_handle = reader.lookup_instance(&data2).unwrap();
+    assert_eq!(data2_handle, writer.lookup_instance(&data2).unwrap());
+    assert_eq!(
+        reader.lookup_instance(&KeyedData { id: 3, value: 10 }),
+        Ok(None)
+    );
+
+    let mut key_holder = KeyedData { id: 0, value: 99 };
+    reader
+        .get_key_value(&mut key_holder, data2_handle.unwrap())
+        .unwrap();
+    assert_eq!(key_holder, KeyedData { id: 2, value: 99 });
+    assert_eq!(
+        reader.get_key_value(&mut key_holder, InstanceHandle::new([99; 16])),
+        Err(DdsError::BadParameter)
+    );
+}
+
+#[test]
+fn reader_status_changes_are_reset_by_reading_the_status() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let _writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    assert!(reader
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::SubscriptionMatched));
+    assert_eq!(
+        reader
+            .get_subscription_matched_status()
+            .unwrap()
+            .current_count,
+        1
+    );
+    assert!(!reader
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::SubscriptionMatched));
+
+    assert_eq!(reader.get_sample_lost_status().unwrap().total_count, 0);
+    assert_eq!(reader.get_sample_rejected_status().unwrap().total_count, 0);
+    assert_eq!(
+        reader
+            .get_requested_incompatible_qos_status()
+            .unwrap()
+            .total_count,
+        0
+    );
+    assert_eq!(
+        reader.get_liveliness_changed_status().unwrap().alive_count,
+        0
+    );
+}
+
+#[test]
+fn reader_liveliness_changed_when_manual_by_topic_writer_stops_asserting() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        liveliness: LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::ManualByTopic,
+            lease_duration: DurationKind::Finite(Duration::new(0, 500_000_000)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::LivelinessChanged])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+
+    writer.assert_liveliness().unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    let status = reader.get_liveliness_changed_status().unwrap();
+    assert_eq!(status.alive_count, 1);
+    assert_eq!(status.not_alive_count, 0);
+    assert_eq!(
+        status.last_publication_handle,
+        writer.get_instance_handle().unwrap()
+    );
+
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    let status = reader.get_liveliness_changed_status().unwrap();
+    assert_eq!(status.alive_count, 0);
+    assert_eq!(status.alive_count_change, -1);
+    assert_eq!(status.not_alive_count, 1);
+    assert_eq!(status.not_alive_count_change, 1);
+}
+
+#[test]
+fn automatic_writer_is_kept_alive_by_the_participant() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        liveliness: LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::Automatic,
+            lease_duration: DurationKind::Finite(Duration::new(0, 300_000_000)),
+        },
+        ..Default::default()
+    };
+    let _writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::LivelinessChanged])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    assert_eq!(
+        reader.get_liveliness_changed_status().unwrap().alive_count,
+        1
+    );
+
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    let status = reader.get_liveliness_changed_status().unwrap();
+    assert_eq!(status.alive_count, 1);
+    assert_eq!(status.not_alive_count, 0);
+}
+
+#[test]
+fn participant_assert_liveliness_keeps_manual_by_participant_writer_alive() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        liveliness: LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::ManualByParticipant,
+            lease_duration: DurationKind::Finite(Duration::new(0, 500_000_000)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::LivelinessChanged])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    participant.assert_liveliness().unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    for _ in 0..10 {
+        participant.assert_liveliness().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    assert_eq!(writer.get_liveliness_lost_status().unwrap().total_count, 0);
+    let status = reader.get_liveliness_changed_status().unwrap();
+    assert_eq!(status.alive_count, 1);
+    assert_eq!(status.not_alive_count, 0);
+}
+
 #[test]
 fn write_read_disposed_samples() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -2425,10 +2771,11 @@ fn write_read_unregistered_samples_are_also_disposed() {
 }
 
 #[test]
-fn transient_local_writer_does_not_deliver_lifespan_expired_data_at_write() {
+fn deleted_writer_disposes_its_instances() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
 
-    let participant = DomainParticipantFactory::get_instance()
+    let participant = participant_factory
         .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
         .unwrap();
 
@@ -2440,81 +2787,79 @@ fn transient_local_writer_does_not_deliver_lifespan_expired_data_at_write() {
         .create_publisher(QosKind::Default, None, NO_STATUS)
         .unwrap();
     let writer_qos = DataWriterQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        lifespan: LifespanQosPolicy {
-            duration: DurationKind::Finite(Duration::new(1, 0)),
+        writer_data_lifecycle: WriterDataLifecycleQosPolicy {
+            autodispose_unregistered_instances: true,
         },
         ..Default::default()
     };
     let writer = publisher
         .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
         .unwrap();
-    let data1 = KeyedData { id: 1, value: 1 };
-    let data2 = KeyedData { id: 2, value: 2 };
-    writer
-        .write_w_timestamp(&data1, None, Time::new(0, 0))
-        .unwrap();
-    writer
-        .write_w_timestamp(&data2, None, Time::new(i32::MAX, 0))
-        .unwrap(); // Never stale sample
 
     let subscriber = participant
         .create_subscriber(QosKind::Default, None, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
         .unwrap();
 
-    let cond = reader.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
-
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    reader
-        .wait_for_historical_data(Duration::new(10, 0))
+    writer.write(&KeyedData { id: 1, value: 1 }, None).unwrap();
+    writer.write(&KeyedData { id: 2, value: 2 }, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
+
+    publisher.delete_datawriter(&writer).unwrap();
+
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        let samples = reader
+            .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .unwrap();
+        if samples
+            .iter()
+            .all(|s| s.sample_info().instance_state == InstanceStateKind::NotAliveDisposed)
+        {
+            break;
+        }
+    }
+
     let samples = reader
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data2);
+    assert_eq!(samples.len(), 2);
+    assert!(samples
+        .iter()
+        .all(|s| s.sample_info().instance_state == InstanceStateKind::NotAliveDisposed));
 }
 
 #[test]
-fn transient_local_writer_does_not_deliver_lifespan_expired_data_after_write() {
-    const LIFESPAN_MS: u32 = 100;
+fn deleted_writer_without_autodispose_unregisters_its_instances() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
 
-    let participant = DomainParticipantFactory::get_instance()
+    let participant = participant_factory
         .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
         .unwrap();
 
@@ -2526,80 +2871,76 @@ fn transient_local_writer_does_not_deliver_lifespan_expired_data_after_write() {
         .create_publisher(QosKind::Default, None, NO_STATUS)
         .unwrap();
     let writer_qos = DataWriterQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        lifespan: LifespanQosPolicy {
-            duration: DurationKind::Finite(Duration::new(0, LIFESPAN_MS * 1_000_000)),
+        writer_data_lifecycle: WriterDataLifecycleQosPolicy {
+            autodispose_unregistered_instances: false,
         },
         ..Default::default()
     };
     let writer = publisher
         .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
         .unwrap();
-    let data1 = KeyedData { id: 1, value: 1 };
-    let data2 = KeyedData { id: 2, value: 2 };
-    writer.write(&data1, None).unwrap();
-    writer
-        .write_w_timestamp(&data2, None, Time::new(i32::MAX, 0))
-        .unwrap(); // Never stale sample
-
-    std::thread::sleep(std::time::Duration::from_millis(LIFESPAN_MS as u64 * 2));
 
     let subscriber = participant
         .create_subscriber(QosKind::Default, None, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
         .unwrap();
 
-    let cond = reader.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
-
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    reader
-        .wait_for_historical_data(Duration::new(10, 0))
+    writer.write(&KeyedData { id: 1, value: 1 }, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
+
+    publisher.delete_datawriter(&writer).unwrap();
+
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        let samples = reader
+            .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .unwrap();
+        if samples[0].sample_info().instance_state == InstanceStateKind::NotAliveNoWriters {
+            break;
+        }
+    }
+
     let samples = reader
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-
     assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data2);
+    assert_eq!(
+        samples[0].sample_info().instance_state,
+        InstanceStateKind::NotAliveNoWriters
+    );
 }
 
 #[test]
-fn reader_joining_after_writer_writes_many_samples() {
+fn transient_writer_samples_are_served_to_late_joining_reader_after_writer_is_deleted() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
 
-    let participant = DomainParticipantFactory::get_instance()
+    let participant = participant_factory
         .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
         .unwrap();
 
@@ -2615,99 +2956,204 @@ fn reader_joining_after_writer_writes_many_samples() {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Transient,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(3),
+        },
+        durability_service: DurabilityServiceQosPolicy {
+            history_kind: HistoryQosPolicyKind::KeepLast(2),
+            ..Default::default()
+        },
         ..Default::default()
     };
-
     let writer = publisher
         .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
         .unwrap();
 
+    writer.write(&KeyedData { id: 1, value: 1 }, None).unwrap();
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+    writer.write(&KeyedData { id: 1, value: 3 }, None).unwrap();
+
+    publisher.delete_datawriter(&writer).unwrap();
+
     let subscriber = participant
         .create_subscriber(QosKind::Default, None, NO_STATUS)
         .unwrap();
-
-    for value in 0..500 {
-        let data = KeyedData { id: 1, value };
-        writer.write(&data, None).unwrap();
-    }
-
     let reader_qos = DataReaderQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Transient,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(3),
+        },
         ..Default::default()
     };
     let reader = subscriber
         .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
         .unwrap();
 
-    let cond = writer.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
         .unwrap();
-
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
     wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let new_data = KeyedData { id: 1, value: 1000 };
-    writer.write(&new_data, None).unwrap();
-    writer
-        .wait_for_acknowledgments(Duration::new(20, 0))
+    reader
+        .wait_for_historical_data(Duration::new(10, 0))
         .unwrap();
 
     let samples = reader
-        .take(5, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), new_data);
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 2 });
+    assert_eq!(samples[1].data().unwrap(), KeyedData { id: 1, value: 3 });
+    assert_eq!(
+        samples[1].sample_info().instance_state,
+        InstanceStateKind::Alive
+    );
 }
 
-#[test]
-fn reader_with_exclusive_ownership_should_not_read_samples_from_second_weaker_writer() {
+#[tokio::test]
+async fn persistent_writer_log_is_compacted_while_writing() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let storage_directory = std::env::temp_dir().join(format!(
+        "dust_dds_persistent_writer_compaction_{}_{}",
+        domain_id,
+        std::process::id()
+    ));
+    std::fs::remove_dir_all(&storage_directory).ok();
+    let storage = Arc::new(AppendOnlyLogStorage::new(&storage_directory));
+    let participant_factory = DomainParticipantFactoryAsync::new();
+    participant_factory
+        .set_persistent_storage(Some(storage.clone()))
+        .await
+        .unwrap();
 
-    let participant = DomainParticipantFactory::get_instance()
+    let participant = participant_factory
         .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .await
         .unwrap();
-
     let topic = participant
         .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .await
         .unwrap();
-
-    let publisher = participant
-        .create_publisher(QosKind::Default, None, NO_STATUS)
-        .unwrap();
-    let writer1_qos = DataWriterQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+    let writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Persistent,
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(1),
         },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
         ..Default::default()
     };
-    let writer1 = publisher
-        .create_datawriter(&topic, QosKind::Specific(writer1_qos), None, NO_STATUS)
+    let writer = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap()
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .await
         .unwrap();
-    let writer2_qos = DataWriterQos {
+
+    for value in 0..1000 {
+        writer
+            .write(&KeyedData { id: 1, value }, None)
+            .await
+            .unwrap();
+    }
+
+    // The log keeps the last sample and a bounded number of evicted ones
+    let stored_changes = storage.read("MyTopic").unwrap();
+    std::fs::remove_dir_all(&storage_directory).ok();
+    assert!(stored_changes.len() <= 64, "{}", stored_changes.len());
+}
+
+#[test]
+fn persistent_writer_samples_are_replayed_after_participant_restart() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+    let storage_directory = std::env::temp_dir().join(format!(
+        "dust_dds_persistent_writer_{}_{}",
+        domain_id,
+        std::process::id()
+    ));
+    std::fs::remove_dir_all(&storage_directory).ok();
+    participant_factory
+        .set_persistent_storage(Some(Arc::new(AppendOnlyLogStorage::new(
+            &storage_directory,
+        ))))
+        .unwrap();
+
+    let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Persistent,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        durability_service: DurabilityServiceQosPolicy {
+            history_kind: HistoryQosPolicyKind::KeepLast(2),
+            ..Default::default()
         },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
         ..Default::default()
     };
-    let writer2 = publisher
-        .create_datawriter(&topic, QosKind::Specific(writer2_qos), None, NO_STATUS)
+
+    // First execution of the application
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    writer.write(&KeyedData { id: 1, value: 1 }, None).unwrap();
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+    writer.write(&KeyedData { id: 1, value: 3 }, None).unwrap();
+    writer.write(&KeyedData { id: 2, value: 10 }, None).unwrap();
+    writer.write(&KeyedData { id: 3, value: 20 }, None).unwrap();
+    writer
+        .dispose(&KeyedData { id: 3, value: 20 }, None)
+        .unwrap();
+    participant.delete_contained_entities().unwrap();
+    participant_factory
+        .delete_participant(&participant)
+        .unwrap();
+
+    // Second execution of the application
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let _writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
         .unwrap();
 
     let subscriber = participant
@@ -2718,76 +3164,58 @@ fn reader_with_exclusive_ownership_should_not_read_samples_from_second_weaker_wr
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::Persistent,
+        },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
         .unwrap();
 
-    let start_time = std::time::Instant::now();
-    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
-    {
-        if reader.get_matched_publications().unwrap().len() >= 2 {
-            break;
-        }
-    }
-    assert_eq!(
-        reader.get_matched_publications().unwrap().len(),
-        2,
-        "Reader must have 2 matched writers"
-    );
-
-    let cond = writer1.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
         .unwrap();
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let cond = writer2.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
-        .unwrap();
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
-        .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
-
-    let data1 = KeyedData { id: 1, value: 10 };
-    writer1.write(&data1, None).unwrap();
-    writer1
-        .wait_for_acknowledgments(Duration::new(10, 0))
-        .unwrap();
-
-    let data2 = KeyedData { id: 1, value: 20 };
-    writer2.write(&data2, None).unwrap();
-    writer2
-        .wait_for_acknowledgments(Duration::new(10, 0))
+    reader
+        .wait_for_historical_data(Duration::new(10, 0))
         .unwrap();
 
-    let samples = reader
+    let mut data: Vec<_> = reader
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
-        .unwrap();
+        .unwrap()
+        .into_iter()
+        .map(|s| s.data().unwrap())
+        .collect();
+    data.sort_by_key(|d| (d.id, d.value));
 
-    assert_eq!(samples.len(), 1);
-    assert_eq!(samples[0].data().unwrap(), data1);
+    participant_factory.set_persistent_storage(None).unwrap();
+    std::fs::remove_dir_all(&storage_directory).ok();
+
+    assert_eq!(
+        data,
+        vec![
+            KeyedData { id: 1, value: 2 },
+            KeyedData { id: 1, value: 3 },
+            KeyedData { id: 2, value: 10 },
+        ]
+    );
 }
 
 #[test]
-fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_with_higher_ownership_strength(
-) {
+fn reader_purges_disposed_instances_after_autopurge_delay() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
 
-    let participant = DomainParticipantFactory::get_instance()
+    let participant = participant_factory
         .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
         .unwrap();
 
@@ -2798,34 +3226,15 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_with_h
     let publisher = participant
         .create_publisher(QosKind::Default, None, NO_STATUS)
         .unwrap();
-    let writer1_qos = DataWriterQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
-        ..Default::default()
-    };
-    let writer1 = publisher
-        .create_datawriter(&topic, QosKind::Specific(writer1_qos), None, NO_STATUS)
-        .unwrap();
-
-    let writer2_qos = DataWriterQos {
+    let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
         ..Default::default()
     };
-    let writer2 = publisher
-        .create_datawriter(&topic, QosKind::Specific(writer2_qos), None, NO_STATUS)
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
         .unwrap();
 
     let subscriber = participant
@@ -2836,76 +3245,55 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_with_h
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+        reader_data_lifecycle: ReaderDataLifecycleQosPolicy {
+            autopurge_disposed_samples_delay: DurationKind::Finite(Duration::new(0, 200_000_000)),
+            ..Default::default()
         },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
         .unwrap();
 
-    let start_time = std::time::Instant::now();
-    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
-    {
-        if reader.get_matched_publications().unwrap().len() >= 2 {
-            break;
-        }
-    }
-    assert_eq!(
-        reader.get_matched_publications().unwrap().len(),
-        2,
-        "Reader must have 2 matched writers"
-    );
-
-    let cond = writer1.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
-        .unwrap();
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
-        .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
-
-    let cond = writer2.get_statuscondition();
+    let cond = writer.get_statuscondition();
     cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let data1 = KeyedData { id: 1, value: 10 };
-    writer1.write(&data1, None).unwrap();
-    writer1
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 2 };
+    writer.write(&data1, None).unwrap();
+    writer.write(&data2, None).unwrap();
+    writer.dispose(&data1, None).unwrap();
+    writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    let data2 = KeyedData { id: 1, value: 20 };
-    writer2.write(&data2, None).unwrap();
-    writer2
-        .wait_for_acknowledgments(Duration::new(10, 0))
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
+    assert_eq!(samples.len(), 2);
+
+    std::thread::sleep(std::time::Duration::from_millis(500));
 
     let samples = reader
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
-
-    assert_eq!(samples.len(), 2);
-    assert_eq!(samples[0].data().unwrap(), data1);
-    assert_eq!(samples[1].data().unwrap(), data2);
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data2);
+    assert_eq!(reader.lookup_instance(&data1), Ok(None));
 }
 
 #[test]
-fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_after_deadline_missed() {
+fn reader_purges_instances_without_writers_after_autopurge_delay() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
 
-    let participant = DomainParticipantFactory::get_instance()
+    let participant = participant_factory
         .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
         .unwrap();
 
@@ -2916,40 +3304,18 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_after_
     let publisher = participant
         .create_publisher(QosKind::Default, None, NO_STATUS)
         .unwrap();
-    let writer1_qos = DataWriterQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
-        deadline: DeadlineQosPolicy {
-            period: DurationKind::Finite(Duration::new(1, 0)),
-        },
-        ..Default::default()
-    };
-    let writer1 = publisher
-        .create_datawriter(&topic, QosKind::Specific(writer1_qos), None, NO_STATUS)
-        .unwrap();
-
-    let writer2_qos = DataWriterQos {
+    let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
-        deadline: DeadlineQosPolicy {
-            period: DurationKind::Finite(Duration::new(1, 0)),
+        writer_data_lifecycle: WriterDataLifecycleQosPolicy {
+            autodispose_unregistered_instances: false,
         },
         ..Default::default()
     };
-    let writer2 = publisher
-        .create_datawriter(&topic, QosKind::Specific(writer2_qos), None, NO_STATUS)
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
         .unwrap();
 
     let subscriber = participant
@@ -2960,86 +3326,50 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_after_
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepAll,
-        },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
-        deadline: DeadlineQosPolicy {
-            period: DurationKind::Finite(Duration::new(1, 0)),
+        reader_data_lifecycle: ReaderDataLifecycleQosPolicy {
+            autopurge_nowriter_samples_delay: DurationKind::Finite(Duration::new(0, 200_000_000)),
+            ..Default::default()
         },
         ..Default::default()
     };
-
     let reader = subscriber
         .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
         .unwrap();
 
-    let start_time = std::time::Instant::now();
-    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
-    {
-        if reader.get_matched_publications().unwrap().len() >= 2 {
-            break;
-        }
-    }
-    assert_eq!(
-        reader.get_matched_publications().unwrap().len(),
-        2,
-        "Reader must have 2 matched writers"
-    );
-
-    let cond = writer1.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
-        .unwrap();
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
-        .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
-
-    let cond = writer2.get_statuscondition();
+    let cond = writer.get_statuscondition();
     cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
         .unwrap();
     let mut wait_set = WaitSet::new();
     wait_set
         .attach_condition(Condition::StatusCondition(cond))
         .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
-
-    let data1 = KeyedData { id: 1, value: 10 };
-    writer1.write(&data1, None).unwrap();
-    writer1
-        .wait_for_acknowledgments(Duration::new(10, 0))
-        .unwrap();
-
-    let cond = reader.get_statuscondition();
-    cond.set_enabled_statuses(&[StatusKind::RequestedDeadlineMissed])
-        .unwrap();
-    let mut wait_set = WaitSet::new();
-    wait_set
-        .attach_condition(Condition::StatusCondition(cond))
-        .unwrap();
-    wait_set.wait(Duration::new(5, 0)).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
 
-    let data2 = KeyedData { id: 1, value: 20 };
-    writer2.write(&data2, None).unwrap();
-    writer2
+    let data1 = KeyedData { id: 1, value: 1 };
+    writer.write(&data1, None).unwrap();
+    writer.unregister_instance(&data1, None).unwrap();
+    writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
     let samples = reader
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
+    assert_eq!(
+        samples[0].sample_info().instance_state,
+        InstanceStateKind::NotAliveNoWriters
+    );
 
-    assert_eq!(samples.len(), 2);
-    assert_eq!(samples[0].data().unwrap(), data1);
-    assert_eq!(samples[1].data().unwrap(), data2);
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    assert_eq!(
+        reader.read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE),
+        Err(DdsError::NoData)
+    );
 }
 
 #[test]
-fn reader_with_exclusive_ownership_should_read_samples_from_second_weaker_writer_after_unregister()
-{
+fn transient_local_writer_does_not_deliver_lifespan_expired_data_at_write() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let participant = DomainParticipantFactory::get_instance()
@@ -3053,49 +3383,291 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_weaker_writer
     let publisher = participant
         .create_publisher(QosKind::Default, None, NO_STATUS)
         .unwrap();
-    let writer1_qos = DataWriterQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+    let writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
         },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
-        ..Default::default()
-    };
-    let writer1 = publisher
-        .create_datawriter(&topic, QosKind::Specific(writer1_qos), None, NO_STATUS)
-        .unwrap();
-    let writer2_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
+        lifespan: LifespanQosPolicy {
+            duration: DurationKind::Finite(Duration::new(1, 0)),
         },
-        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
         ..Default::default()
     };
-    let writer2 = publisher
-        .create_datawriter(&topic, QosKind::Specific(writer2_qos), None, NO_STATUS)
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
         .unwrap();
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 2 };
+    writer
+        .write_w_timestamp(&data1, None, Time::new(0, 0))
+        .unwrap();
+    writer
+        .write_w_timestamp(&data2, None, Time::new(i32::MAX, 0))
+        .unwrap(); // Never stale sample
 
     let subscriber = participant
         .create_subscriber(QosKind::Default, None, NO_STATUS)
         .unwrap();
     let reader_qos = DataReaderQos {
-        reliability: ReliabilityQosPolicy {
-            kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
         },
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
         },
-        ownership: OwnershipQosPolicy {
-            kind: OwnershipQosPolicyKind::Exclusive,
-        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    reader
+        .wait_for_historical_data(Duration::new(10, 0))
+        .unwrap();
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data2);
+}
+
+#[test]
+fn transient_local_writer_does_not_deliver_lifespan_expired_data_after_write() {
+    const LIFESPAN_MS: u32 = 100;
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        lifespan: LifespanQosPolicy {
+            duration: DurationKind::Finite(Duration::new(0, LIFESPAN_MS * 1_000_000)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+    let data1 = KeyedData { id: 1, value: 1 };
+    let data2 = KeyedData { id: 2, value: 2 };
+    writer.write(&data1, None).unwrap();
+    writer
+        .write_w_timestamp(&data2, None, Time::new(i32::MAX, 0))
+        .unwrap(); // Never stale sample
+
+    std::thread::sleep(std::time::Duration::from_millis(LIFESPAN_MS as u64 * 2));
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    reader
+        .wait_for_historical_data(Duration::new(10, 0))
+        .unwrap();
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), data2);
+}
+
+#[test]
+fn reader_joining_after_writer_writes_many_samples() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    for value in 0..500 {
+        let data = KeyedData { id: 1, value };
+        writer.write(&data, None).unwrap();
+    }
+
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let new_data = KeyedData { id: 1, value: 1000 };
+    writer.write(&new_data, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(20, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(5, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), new_data);
+}
+
+#[test]
+fn reader_with_exclusive_ownership_should_not_read_samples_from_second_weaker_writer() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer1_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer1_qos), None, NO_STATUS)
+        .unwrap();
+    let writer2_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
+        ..Default::default()
+    };
+    let writer2 = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer2_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
         ..Default::default()
     };
 
@@ -3140,11 +3712,6 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_weaker_writer
         .wait_for_acknowledgments(Duration::new(10, 0))
         .unwrap();
 
-    writer1.unregister_instance(&data1, None).unwrap();
-    writer1
-        .wait_for_acknowledgments(Duration::new(10, 0))
-        .unwrap();
-
     let data2 = KeyedData { id: 1, value: 20 };
     writer2.write(&data2, None).unwrap();
     writer2
@@ -3155,7 +3722,1874 @@ fn reader_with_exclusive_ownership_should_read_samples_from_second_weaker_writer
         .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
         .unwrap();
 
-    assert_eq!(samples.len(), 3);
+    assert_eq!(samples.len(), 1);
     assert_eq!(samples[0].data().unwrap(), data1);
-    assert_eq!(samples[2].data().unwrap(), data2);
+}
+
+#[test]
+fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_with_higher_ownership_strength(
+) {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer1_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer1_qos), None, NO_STATUS)
+        .unwrap();
+
+    let writer2_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
+        ..Default::default()
+    };
+    let writer2 = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer2_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        if reader.get_matched_publications().unwrap().len() >= 2 {
+            break;
+        }
+    }
+    assert_eq!(
+        reader.get_matched_publications().unwrap().len(),
+        2,
+        "Reader must have 2 matched writers"
+    );
+
+    let cond = writer1.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let cond = writer2.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 10 };
+    writer1.write(&data1, None).unwrap();
+    writer1
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let data2 = KeyedData { id: 1, value: 20 };
+    writer2.write(&data2, None).unwrap();
+    writer2
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(samples[1].data().unwrap(), data2);
+}
+
+#[test]
+fn reader_with_exclusive_ownership_should_read_samples_from_second_writer_after_deadline_missed() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer1_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer1_qos), None, NO_STATUS)
+        .unwrap();
+
+    let writer2_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer2 = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer2_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        if reader.get_matched_publications().unwrap().len() >= 2 {
+            break;
+        }
+    }
+    assert_eq!(
+        reader.get_matched_publications().unwrap().len(),
+        2,
+        "Reader must have 2 matched writers"
+    );
+
+    let cond = writer1.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let cond = writer2.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 10 };
+    writer1.write(&data1, None).unwrap();
+    writer1
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::RequestedDeadlineMissed])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data2 = KeyedData { id: 1, value: 20 };
+    writer2.write(&data2, None).unwrap();
+    writer2
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(samples[1].data().unwrap(), data2);
+}
+
+#[test]
+fn reader_with_exclusive_ownership_should_read_samples_from_second_weaker_writer_after_unregister()
+{
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer1_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 10 },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer1_qos), None, NO_STATUS)
+        .unwrap();
+    let writer2_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ownership_strength: OwnershipStrengthQosPolicy { value: 1 },
+        ..Default::default()
+    };
+    let writer2 = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer2_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ..Default::default()
+    };
+
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while std::time::Instant::now().duration_since(start_time) < std::time::Duration::from_secs(10)
+    {
+        if reader.get_matched_publications().unwrap().len() >= 2 {
+            break;
+        }
+    }
+    assert_eq!(
+        reader.get_matched_publications().unwrap().len(),
+        2,
+        "Reader must have 2 matched writers"
+    );
+
+    let cond = writer1.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let cond = writer2.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    let data1 = KeyedData { id: 1, value: 10 };
+    writer1.write(&data1, None).unwrap();
+    writer1
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    writer1.unregister_instance(&data1, None).unwrap();
+    writer1
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let data2 = KeyedData { id: 1, value: 20 };
+    writer2.write(&data2, None).unwrap();
+    writer2
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+
+    assert_eq!(samples.len(), 3);
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert_eq!(samples[2].data().unwrap(), data2);
+}
+
+#[test]
+fn reader_on_content_filtered_topic_receives_only_matching_samples() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let content_filtered_topic = participant
+        .create_contentfilteredtopic(
+            "MyFilteredTopic",
+            &topic,
+            "value > %0".to_string(),
+            &["10".to_string()],
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &content_filtered_topic,
+            QosKind::Specific(reader_qos),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    writer.write(&KeyedData { id: 1, value: 5 }, None).unwrap();
+    writer.write(&KeyedData { id: 2, value: 20 }, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 2, value: 20 });
+
+    content_filtered_topic
+        .set_expression_parameters(&["1".to_string()])
+        .unwrap();
+    assert_eq!(
+        content_filtered_topic.get_expression_parameters(),
+        Ok(vec!["1".to_string()])
+    );
+
+    // The writer keeps filtering with the previous parameters until it discovers the new ones
+    let mut samples = Vec::new();
+    for _ in 0..50 {
+        writer.write(&KeyedData { id: 3, value: 5 }, None).unwrap();
+        writer
+            .wait_for_acknowledgments(Duration::new(10, 0))
+            .unwrap();
+        if let Ok(received_samples) =
+            reader.take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        {
+            samples = received_samples;
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 3, value: 5 });
+}
+
+const LOCATOR_KIND_RECORDING: i32 = 0x0100_0000;
+const PID_CONTENT_FILTER_INFO: i16 = 0x0055;
+
+/// Transport keeping a copy of the user-defined messages sent to its locator without delivering them
+#[derive(Clone, Default)]
+struct RecordingTransport(Arc<Mutex<Vec<Vec<u8>>>>);
+
+impl Transport for RecordingTransport {
+    fn send(&self, datagram: &[u8], _locator: &Locator) {
+        self.0.lock().unwrap().push(datagram.to_vec());
+    }
+}
+
+impl TransportFactory for RecordingTransport {
+    fn create_transport(
+        &self,
+        _domain_id: DomainId,
+        _transport_receiver: TransportReceiver,
+    ) -> DdsResult<(Box<dyn Transport>, TransportLocators)> {
+        let transport_locators = TransportLocators {
+            default_unicast_locator_list: vec![Locator::new(LOCATOR_KIND_RECORDING, 1, [0; 16])],
+            ..Default::default()
+        };
+        Ok((Box::new(self.clone()), transport_locators))
+    }
+}
+
+#[tokio::test]
+async fn writer_does_not_send_samples_rejected_by_reader_content_filter() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let transport = RecordingTransport::default();
+    let participant_factory = DomainParticipantFactoryAsync::new();
+    participant_factory
+        .register_transport(LOCATOR_KIND_RECORDING, Arc::new(transport.clone()))
+        .await
+        .unwrap();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let content_filtered_topic = participant
+        .create_contentfilteredtopic(
+            "MyFilteredTopic",
+            &topic,
+            "value > %0".to_string(),
+            &["10".to_string()],
+        )
+        .await
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .await
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .await
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &content_filtered_topic,
+            QosKind::Specific(reader_qos),
+            None,
+            NO_STATUS,
+        )
+        .await
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .await
+        .unwrap();
+    let mut wait_set = WaitSetAsync::new();
+    wait_set
+        .attach_condition(ConditionAsync::StatusCondition(cond))
+        .await
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).await.unwrap();
+
+    writer
+        .write(&KeyedData { id: 1, value: 5 }, None)
+        .await
+        .unwrap();
+    writer
+        .write(&KeyedData { id: 2, value: 20 }, None)
+        .await
+        .unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .await
+        .unwrap();
+
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .await
+        .unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 2, value: 20 });
+
+    let writer_handle: [u8; 16] = writer.get_instance_handle().await.unwrap().into();
+    let writer_id = EntityId::new(
+        [writer_handle[12], writer_handle[13], writer_handle[14]],
+        writer_handle[15],
+    );
+    let sent_data_list: Vec<_> = transport
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .flat_map(|datagram| {
+            RtpsMessageRead::try_from(datagram.as_slice())
+                .unwrap()
+                .submessages()
+        })
+        .filter_map(|submessage| match submessage {
+            RtpsSubmessageReadKind::Data(data) if data.writer_id() == writer_id => Some(data),
+            _ => None,
+        })
+        .collect();
+    assert!(!sent_data_list.is_empty());
+    for data in sent_data_list {
+        assert_eq!(
+            KeyedData::deserialize_data(data.serialized_payload().as_ref()).unwrap(),
+            KeyedData { id: 2, value: 20 }
+        );
+        assert!(data
+            .inline_qos()
+            .parameter()
+            .iter()
+            .any(|p| p.parameter_id() == PID_CONTENT_FILTER_INFO));
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct SensorTemperature {
+    #[dust_dds(key)]
+    sensor_id: u8,
+    temperature: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct SensorLocation {
+    #[dust_dds(key)]
+    sensor_id: u8,
+    room: String,
+}
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct RoomTemperature {
+    #[dust_dds(key)]
+    room: String,
+    temperature: i32,
+}
+
+#[test]
+fn reader_on_multi_topic_receives_joined_samples() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let temperature_topic = participant
+        .create_topic::<SensorTemperature>(
+            "SensorTemperature",
+            "SensorTemperature",
+            QosKind::Default,
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let location_topic = participant
+        .create_topic::<SensorLocation>(
+            "SensorLocation",
+            "SensorLocation",
+            QosKind::Default,
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let multi_topic = participant
+        .create_multitopic::<RoomTemperature>(
+            "RoomTemperature",
+            "RoomTemperature",
+            "SELECT room, temperature FROM SensorTemperature NATURAL JOIN SensorLocation WHERE temperature > %0"
+                .to_string(),
+            &["0".to_string()],
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let temperature_writer = publisher
+        .create_datawriter(
+            &temperature_topic,
+            QosKind::Specific(writer_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let location_writer = publisher
+        .create_datawriter(
+            &location_topic,
+            QosKind::Specific(writer_qos),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<RoomTemperature>(
+            &multi_topic,
+            QosKind::Specific(reader_qos),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    for writer_cond in [
+        temperature_writer.get_statuscondition(),
+        location_writer.get_statuscondition(),
+    ] {
+        writer_cond
+            .set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(writer_cond))
+            .unwrap();
+        wait_set.wait(Duration::new(5, 0)).unwrap();
+    }
+
+    location_writer
+        .write(
+            &SensorLocation {
+                sensor_id: 1,
+                room: "Kitchen".to_string(),
+            },
+            None,
+        )
+        .unwrap();
+    location_writer
+        .write(
+            &SensorLocation {
+                sensor_id: 2,
+                room: "Garage".to_string(),
+            },
+            None,
+        )
+        .unwrap();
+    temperature_writer
+        .write(
+            &SensorTemperature {
+                sensor_id: 1,
+                temperature: 21,
+            },
+            None,
+        )
+        .unwrap();
+    temperature_writer
+        .write(
+            &SensorTemperature {
+                sensor_id: 2,
+                temperature: -3,
+            },
+            None,
+        )
+        .unwrap();
+    location_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    temperature_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(
+        samples[0].data().unwrap(),
+        RoomTemperature {
+            room: "Kitchen".to_string(),
+            temperature: 21
+        }
+    );
+
+    multi_topic
+        .set_expression_parameters(&["-10".to_string()])
+        .unwrap();
+    temperature_writer
+        .write(
+            &SensorTemperature {
+                sensor_id: 2,
+                temperature: -4,
+            },
+            None,
+        )
+        .unwrap();
+    temperature_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(
+        samples[0].data().unwrap(),
+        RoomTemperature {
+            room: "Garage".to_string(),
+            temperature: -4
+        }
+    );
+}
+
+#[test]
+fn coherent_changes_are_received_when_the_set_ends() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let presentation = PresentationQosPolicy {
+        access_scope: PresentationQosPolicyAccessScopeKind::Topic,
+        coherent_access: true,
+        ordered_access: false,
+    };
+    let publisher_qos = PublisherQos {
+        presentation: presentation.clone(),
+        ..Default::default()
+    };
+    let publisher = participant
+        .create_publisher(QosKind::Specific(publisher_qos), None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber_qos = SubscriberQos {
+        presentation,
+        ..Default::default()
+    };
+    let subscriber = participant
+        .create_subscriber(QosKind::Specific(subscriber_qos), None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    publisher.begin_coherent_changes().unwrap();
+    writer.write(&KeyedData { id: 1, value: 1 }, None).unwrap();
+    writer.write(&KeyedData { id: 2, value: 2 }, None).unwrap();
+    writer.write(&KeyedData { id: 3, value: 3 }, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(1, 0))
+        .unwrap();
+
+    assert_eq!(
+        reader
+            .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .err(),
+        Some(DdsError::NoData)
+    );
+
+    publisher.end_coherent_changes().unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(1, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 3);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 1 });
+    assert_eq!(samples[2].data().unwrap(), KeyedData { id: 3, value: 3 });
+}
+
+#[test]
+fn last_coherent_set_is_received_by_late_joining_reader() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let presentation = PresentationQosPolicy {
+        access_scope: PresentationQosPolicyAccessScopeKind::Topic,
+        coherent_access: true,
+        ordered_access: false,
+    };
+    let publisher_qos = PublisherQos {
+        presentation: presentation.clone(),
+        ..Default::default()
+    };
+    let publisher = participant
+        .create_publisher(QosKind::Specific(publisher_qos), None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    for value in [1, 2] {
+        publisher.begin_coherent_changes().unwrap();
+        writer.write(&KeyedData { id: 1, value }, None).unwrap();
+        writer.write(&KeyedData { id: 2, value }, None).unwrap();
+        publisher.end_coherent_changes().unwrap();
+    }
+
+    let subscriber_qos = SubscriberQos {
+        presentation,
+        ..Default::default()
+    };
+    let subscriber = participant
+        .create_subscriber(QosKind::Specific(subscriber_qos), None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(5, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let received_data: Vec<_> = samples.iter().map(|s| s.data().unwrap()).collect();
+    assert_eq!(
+        received_data,
+        vec![
+            KeyedData { id: 1, value: 1 },
+            KeyedData { id: 2, value: 1 },
+            KeyedData { id: 1, value: 2 },
+            KeyedData { id: 2, value: 2 },
+        ]
+    );
+}
+
+#[test]
+fn group_coherent_changes_are_received_in_publisher_order() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic_a = participant
+        .create_topic::<KeyedData>("TopicA", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic_b = participant
+        .create_topic::<KeyedData>("TopicB", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let presentation = PresentationQosPolicy {
+        access_scope: PresentationQosPolicyAccessScopeKind::Group,
+        coherent_access: true,
+        ordered_access: true,
+    };
+    let publisher_qos = PublisherQos {
+        presentation: presentation.clone(),
+        ..Default::default()
+    };
+    let publisher = participant
+        .create_publisher(QosKind::Specific(publisher_qos), None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer_a = publisher
+        .create_datawriter(
+            &topic_a,
+            QosKind::Specific(writer_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let writer_b = publisher
+        .create_datawriter(&topic_b, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber_qos = SubscriberQos {
+        presentation,
+        ..Default::default()
+    };
+    let subscriber = participant
+        .create_subscriber(QosKind::Specific(subscriber_qos), None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader_a = subscriber
+        .create_datareader::<KeyedData>(
+            &topic_a,
+            QosKind::Specific(reader_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let reader_b = subscriber
+        .create_datareader::<KeyedData>(&topic_b, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    for cond in [
+        writer_a.get_statuscondition(),
+        writer_b.get_statuscondition(),
+    ] {
+        cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(5, 0)).unwrap();
+    }
+
+    publisher.begin_coherent_changes().unwrap();
+    writer_a
+        .write(&KeyedData { id: 1, value: 1 }, None)
+        .unwrap();
+    writer_b
+        .write(&KeyedData { id: 2, value: 2 }, None)
+        .unwrap();
+    writer_a
+        .write(&KeyedData { id: 3, value: 3 }, None)
+        .unwrap();
+    writer_a
+        .wait_for_acknowledgments(Duration::new(1, 0))
+        .unwrap();
+    writer_b
+        .wait_for_acknowledgments(Duration::new(1, 0))
+        .unwrap();
+
+    subscriber.begin_access().unwrap();
+    assert!(subscriber
+        .get_datareaders::<KeyedData>(ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap()
+        .is_empty());
+    subscriber.end_access().unwrap();
+
+    publisher.end_coherent_changes().unwrap();
+    for cond in [
+        reader_a.get_statuscondition(),
+        reader_b.get_statuscondition(),
+    ] {
+        cond.set_enabled_statuses(&[StatusKind::DataAvailable])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(5, 0)).unwrap();
+    }
+
+    subscriber.begin_access().unwrap();
+    let reader_list = subscriber
+        .get_datareaders::<KeyedData>(ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let reader_handle_list: Vec<_> = reader_list
+        .iter()
+        .map(|r| r.get_instance_handle().unwrap())
+        .collect();
+    assert_eq!(
+        reader_handle_list,
+        vec![
+            reader_a.get_instance_handle().unwrap(),
+            reader_b.get_instance_handle().unwrap(),
+            reader_a.get_instance_handle().unwrap(),
+        ]
+    );
+    let data: Vec<_> = reader_list
+        .iter()
+        .map(|r| {
+            r.take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+                .unwrap()[0]
+                .data()
+                .unwrap()
+        })
+        .collect();
+    subscriber.end_access().unwrap();
+
+    assert_eq!(
+        data,
+        vec![
+            KeyedData { id: 1, value: 1 },
+            KeyedData { id: 2, value: 2 },
+            KeyedData { id: 3, value: 3 },
+        ]
+    );
+}
+
+#[test]
+fn suspended_publications_are_received_after_resume() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+
+    publisher.suspend_publications().unwrap();
+    for i in 0..200u8 {
+        writer
+            .write(
+                &KeyedData {
+                    id: i,
+                    value: i as u32,
+                },
+                None,
+            )
+            .unwrap();
+    }
+    std::thread::sleep(std::time::Duration::from_millis(200));
+
+    assert_eq!(
+        reader
+            .take(500, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .err(),
+        Some(DdsError::NoData)
+    );
+
+    publisher.resume_publications().unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(5, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(500, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 200);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 0, value: 0 });
+    assert_eq!(
+        samples[199].data().unwrap(),
+        KeyedData {
+            id: 199,
+            value: 199
+        }
+    );
+}
+
+#[test]
+fn other_publisher_communicates_while_publications_are_resumed() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let suspended_topic = participant
+        .create_topic::<KeyedData>(
+            "SuspendedTopic",
+            "KeyedData",
+            QosKind::Default,
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let active_topic = participant
+        .create_topic::<KeyedData>(
+            "ActiveTopic",
+            "KeyedData",
+            QosKind::Default,
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let suspended_publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let suspended_writer = suspended_publisher
+        .create_datawriter(
+            &suspended_topic,
+            QosKind::Specific(writer_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let active_publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let active_writer = active_publisher
+        .create_datawriter(
+            &active_topic,
+            QosKind::Specific(writer_qos),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let suspended_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &suspended_topic,
+            QosKind::Specific(reader_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let active_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &active_topic,
+            QosKind::Specific(reader_qos),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    for writer in [&suspended_writer, &active_writer] {
+        let cond = writer.get_statuscondition();
+        cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(5, 0)).unwrap();
+    }
+
+    suspended_publisher.suspend_publications().unwrap();
+    for i in 0..200u8 {
+        suspended_writer
+            .write(
+                &KeyedData {
+                    id: i,
+                    value: i as u32,
+                },
+                None,
+            )
+            .unwrap();
+    }
+
+    // The other publisher is not affected by the suspended one
+    active_writer
+        .write(&KeyedData { id: 0, value: 0 }, None)
+        .unwrap();
+    active_writer
+        .wait_for_acknowledgments(Duration::new(5, 0))
+        .unwrap();
+
+    let resume_thread = std::thread::spawn(move || {
+        suspended_publisher.resume_publications().unwrap();
+    });
+    for i in 1..20u8 {
+        active_writer
+            .write(
+                &KeyedData {
+                    id: i,
+                    value: i as u32,
+                },
+                None,
+            )
+            .unwrap();
+    }
+    active_writer
+        .wait_for_acknowledgments(Duration::new(5, 0))
+        .unwrap();
+    let active_samples = active_reader
+        .take(500, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(active_samples.len(), 20);
+
+    resume_thread.join().unwrap();
+    suspended_writer
+        .wait_for_acknowledgments(Duration::new(30, 0))
+        .unwrap();
+    let suspended_samples = suspended_reader
+        .take(500, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(suspended_samples.len(), 200);
+}
+
+#[test]
+fn publisher_wait_for_acknowledgments_waits_for_all_writers() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic1 = participant
+        .create_topic::<KeyedData>("MyTopic1", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic2 = participant
+        .create_topic::<KeyedData>("MyTopic2", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(
+            &topic1,
+            QosKind::Specific(writer_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let writer2 = publisher
+        .create_datawriter(&topic2, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader1 = subscriber
+        .create_datareader::<KeyedData>(
+            &topic1,
+            QosKind::Specific(reader_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let reader2 = subscriber
+        .create_datareader::<KeyedData>(&topic2, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    for writer in [&writer1, &writer2] {
+        let cond = writer.get_statuscondition();
+        cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(5, 0)).unwrap();
+    }
+
+    // Suspended changes are not sent so they can not be acknowledged
+    publisher.suspend_publications().unwrap();
+    writer1.write(&KeyedData { id: 1, value: 1 }, None).unwrap();
+    writer2.write(&KeyedData { id: 2, value: 2 }, None).unwrap();
+    assert_eq!(
+        publisher.wait_for_acknowledgments(Duration::new(0, 200_000_000)),
+        Err(DdsError::Timeout)
+    );
+
+    publisher.resume_publications().unwrap();
+    publisher
+        .wait_for_acknowledgments(Duration::new(5, 0))
+        .unwrap();
+
+    assert_eq!(
+        reader1
+            .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .unwrap()
+            .len(),
+        1
+    );
+    assert_eq!(
+        reader2
+            .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn publisher_reports_writers_not_acknowledged_in_time() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic1 = participant
+        .create_topic::<KeyedData>("Topic1", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let topic2 = participant
+        .create_topic::<KeyedData>("Topic2", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer1 = publisher
+        .create_datawriter(
+            &topic1,
+            QosKind::Specific(writer_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let writer2 = publisher
+        .create_datawriter(&topic2, QosKind::Specific(writer_qos), None, NO_STATUS)
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let _reader1 = subscriber
+        .create_datareader::<KeyedData>(
+            &topic1,
+            QosKind::Specific(reader_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let _reader2 = subscriber
+        .create_datareader::<KeyedData>(&topic2, QosKind::Specific(reader_qos), None, NO_STATUS)
+        .unwrap();
+
+    for writer in [&writer1, &writer2] {
+        let cond = writer.get_statuscondition();
+        cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(5, 0)).unwrap();
+    }
+
+    writer1.write(&KeyedData { id: 1, value: 1 }, None).unwrap();
+    assert_eq!(
+        publisher.wait_for_unacknowledged_writers(Duration::new(5, 0)),
+        Ok(vec![])
+    );
+
+    // Only the writer with suspended changes is left unacknowledged
+    publisher.suspend_publications().unwrap();
+    writer2.write(&KeyedData { id: 2, value: 2 }, None).unwrap();
+    assert_eq!(
+        publisher.wait_for_unacknowledged_writers(Duration::new(0, 200_000_000)),
+        Ok(vec![writer2.get_instance_handle().unwrap()])
+    );
+
+    publisher.resume_publications().unwrap();
+    assert_eq!(
+        publisher.wait_for_unacknowledged_writers(Duration::new(5, 0)),
+        Ok(vec![])
+    );
+}
+
+#[test]
+fn best_effort_readers_receive_samples_on_transport_multicast_group() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+    let writer_participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let writer_topic = writer_participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_topic = reader_participant
+        .create_topic::<KeyedData>("MyTopic", "KeyedData", QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let publisher = writer_participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &writer_topic,
+            QosKind::Specific(writer_qos),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Best-effort readers only receive the samples sent on the multicast group
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        transport_multicast: TransportMulticastQosPolicy {
+            value: vec![TransportMulticastSettings {
+                receive_address: IpAddr::V4(Ipv4Addr::new(239, 255, 0, 2)),
+                receive_port: None,
+            }],
+        },
+        ..Default::default()
+    };
+    let local_reader = writer_participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap()
+        .create_datareader::<KeyedData>(
+            &writer_topic,
+            QosKind::Specific(reader_qos.clone()),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let remote_reader = reader_participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap()
+        .create_datareader::<KeyedData>(
+            &reader_topic,
+            QosKind::Specific(reader_qos),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while writer
+        .get_publication_matched_status()
+        .unwrap()
+        .current_count
+        < 2
+        || local_reader
+            .get_subscription_matched_status()
+            .unwrap()
+            .current_count
+            < 1
+        || remote_reader
+            .get_subscription_matched_status()
+            .unwrap()
+            .current_count
+            < 1
+    {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    let data = KeyedData { id: 1, value: 7 };
+    writer.write(&data, None).unwrap();
+
+    for reader in [&local_reader, &remote_reader] {
+        let cond = reader.get_statuscondition();
+        cond.set_enabled_statuses(&[StatusKind::DataAvailable])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+
+        let samples = reader
+            .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .unwrap();
+        assert_eq!(samples[0].data().unwrap(), data);
+    }
+}
+
+#[test]
+fn fragmented_dispose_is_received_on_transport_multicast_group() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+    let writer_participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+    let reader_participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, None, NO_STATUS)
+        .unwrap();
+
+    let writer_topic = writer_participant
+        .create_topic::<LargeKeyData>(
+            "LargeKeyTopic",
+            "LargeKeyData",
+            QosKind::Default,
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+    let reader_topic = reader_participant
+        .create_topic::<LargeKeyData>(
+            "LargeKeyTopic",
+            "LargeKeyData",
+            QosKind::Default,
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = writer_participant
+        .create_publisher(QosKind::Default, None, NO_STATUS)
+        .unwrap()
+        .create_datawriter(
+            &writer_topic,
+            QosKind::Specific(writer_qos),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        transport_multicast: TransportMulticastQosPolicy {
+            value: vec![TransportMulticastSettings {
+                receive_address: IpAddr::V4(Ipv4Addr::new(239, 255, 0, 3)),
+                receive_port: None,
+            }],
+        },
+        ..Default::default()
+    };
+    let reader = reader_participant
+        .create_subscriber(QosKind::Default, None, NO_STATUS)
+        .unwrap()
+        .create_datareader::<LargeKeyData>(
+            &reader_topic,
+            QosKind::Specific(reader_qos),
+            None,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::DataAvailable])
+        .unwrap();
+    let mut reader_wait_set = WaitSet::new();
+    reader_wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+
+    // The key alone is larger than a single fragment
+    let data = LargeKeyData {
+        id: vec![8; 15000],
+        value: 1,
+    };
+    writer.write(&data, None).unwrap();
+    reader_wait_set.wait(Duration::new(10, 0)).unwrap();
+    let samples = reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap(), data);
+
+    writer.dispose(&data, None).unwrap();
+    reader_wait_set.wait(Duration::new(10, 0)).unwrap();
+    let samples = reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(
+        samples[0].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
 }